@@ -0,0 +1,24 @@
+// Defines the `lazy_backend` cfg, if any lazy backend is enabled via the crate
+// features, which gates everything requiring `lazy` properties by a single
+// condition, independent of the specific backend.
+
+const LAZY_BACKENDS: &[&str] = &[
+	"lazy",
+	"std-lazy",
+	"lazylock",
+	"once_cell",
+	"no_std-lazy",
+	"unsync-lazy",
+];
+
+fn main() {
+	println!("cargo:rerun-if-changed=build.rs");
+
+	let enabled = LAZY_BACKENDS.iter().any(|feature| {
+		let var = format!("CARGO_FEATURE_{}", feature.to_uppercase().replace('-', "_"));
+		std::env::var_os(var).is_some()
+	});
+	if enabled {
+		println!("cargo:rustc-cfg=lazy_backend");
+	}
+}
@@ -115,3 +115,664 @@ props! {
 pub fn quad_1000_lazy_access(b: &mut Bencher) {
 	b.iter(|| test_1000(Quad::C, |p: &IntPropLazy| p.int));
 }
+
+
+
+// All variants in a single lazy table
+struct IntPropGrouped {
+	int: u32,
+}
+
+props! {
+	impl EnumProp for Singleton as lazy(grouped) IntPropGrouped {
+		Singleton::Foo => {
+			int: 42
+		}
+	}
+}
+#[bench]
+pub fn singelton_1000_lazy_grouped_access(b: &mut Bencher) {
+	b.iter(|| test_1000(Singleton::Foo, |p: &IntPropGrouped| p.int));
+}
+
+props! {
+	impl EnumProp for Quad as lazy(grouped) IntPropGrouped {
+		Quad::A => {
+			int: 3
+		}
+		Quad::B => {
+			int: 5
+		}
+		Quad::C => {
+			int: 7
+		}
+		Quad::D => {
+			int: 11
+		}
+	}
+}
+#[bench]
+pub fn quad_1000_lazy_grouped_access(b: &mut Bencher) {
+	b.iter(|| test_1000(Quad::C, |p: &IntPropGrouped| p.int));
+}
+
+
+
+// Lazy branches with non-trivial initializers, which are kept out of the
+// accessor, thus its access is the same as of trivial ones
+struct IntPropHeavy {
+	int: u32,
+}
+
+fn collatz_steps(mut n: u32) -> u32 {
+	let mut steps = 0;
+	while n != 1 {
+		n = if n % 2 == 0 { n / 2 } else { 3 * n + 1 };
+		steps += 1;
+	}
+	steps
+}
+
+props! {
+	impl EnumProp for Quad as lazy IntPropHeavy {
+		Quad::A => {
+			int: (1..100).map(collatz_steps).max().unwrap()
+		}
+		Quad::B => {
+			int: (1..200).map(collatz_steps).max().unwrap()
+		}
+		Quad::C => {
+			int: (1..300).map(collatz_steps).max().unwrap()
+		}
+		Quad::D => {
+			int: (1..400).map(collatz_steps).max().unwrap()
+		}
+	}
+}
+#[bench]
+pub fn quad_1000_lazy_heavy_access(b: &mut Bencher) {
+	b.iter(|| test_1000(Quad::C, |p: &IntPropHeavy| p.int));
+}
+
+
+
+// A const property with a single lazy branch, whose other branches keep the
+// zero overhead of const properties
+struct IntPropMixed {
+	int: u32,
+}
+
+props! {
+	impl EnumProp for Quad as const IntPropMixed {
+		Quad::A => {
+			int: 3
+		}
+		Quad::B => {
+			int: 5
+		}
+		Quad::C => {
+			int: 7
+		}
+		Quad::D => lazy {
+			int: 11
+		}
+	}
+}
+#[bench]
+pub fn quad_1000_mixed_const_access(b: &mut Bencher) {
+	b.iter(|| test_1000(Quad::C, |p: &IntPropMixed| p.int));
+}
+#[bench]
+pub fn quad_1000_mixed_lazy_access(b: &mut Bencher) {
+	b.iter(|| test_1000(Quad::D, |p: &IntPropMixed| p.int));
+}
+
+
+
+// Explicitly selected lazy backends, to compare their access overhead
+// independently of the default backend chosen via the crate features.
+macro_rules! lazy_backend_props {
+	($backend:ident, $prop_name:ident) => {
+		struct $prop_name {
+			int: u32,
+		}
+
+		impl EnumProp<$prop_name> for Singleton {
+			fn property(&self) -> &'static $prop_name {
+				match self {
+					Singleton::Foo => {
+						crate::$backend!($prop_name, {
+							$prop_name {
+								int: 42,
+							}
+						})
+					},
+				}
+			}
+		}
+
+		impl EnumProp<$prop_name> for Quad {
+			fn property(&self) -> &'static $prop_name {
+				match self {
+					Quad::A => {
+						crate::$backend!($prop_name, {
+							$prop_name {
+								int: 3,
+							}
+						})
+					},
+					Quad::B => {
+						crate::$backend!($prop_name, {
+							$prop_name {
+								int: 5,
+							}
+						})
+					},
+					Quad::C => {
+						crate::$backend!($prop_name, {
+							$prop_name {
+								int: 7,
+							}
+						})
+					},
+					Quad::D => {
+						crate::$backend!($prop_name, {
+							$prop_name {
+								int: 11,
+							}
+						})
+					},
+				}
+			}
+		}
+	};
+}
+
+lazy_backend_props!(internal_lazy_with_lazy_static, IntPropLazyStatic);
+#[bench]
+pub fn singelton_1000_lazy_static_access(b: &mut Bencher) {
+	b.iter(|| test_1000(Singleton::Foo, |p: &IntPropLazyStatic| p.int));
+}
+#[bench]
+pub fn quad_1000_lazy_static_access(b: &mut Bencher) {
+	b.iter(|| test_1000(Quad::C, |p: &IntPropLazyStatic| p.int));
+}
+
+#[cfg(feature = "once_cell")]
+mod once_cell_backend {
+	use super::*;
+
+	lazy_backend_props!(internal_lazy_with_once_cell, IntPropOnceCell);
+	#[bench]
+	pub fn singelton_1000_once_cell_access(b: &mut Bencher) {
+		b.iter(|| test_1000(Singleton::Foo, |p: &IntPropOnceCell| p.int));
+	}
+	#[bench]
+	pub fn quad_1000_once_cell_access(b: &mut Bencher) {
+		b.iter(|| test_1000(Quad::C, |p: &IntPropOnceCell| p.int));
+	}
+}
+
+
+#[cfg(feature = "lazylock")]
+mod lazylock_backend {
+	use super::*;
+
+	lazy_backend_props!(internal_lazy_with_lazylock, IntPropLazyLock);
+	#[bench]
+	pub fn singelton_1000_lazylock_access(b: &mut Bencher) {
+		b.iter(|| test_1000(Singleton::Foo, |p: &IntPropLazyLock| p.int));
+	}
+	#[bench]
+	pub fn quad_1000_lazylock_access(b: &mut Bencher) {
+		b.iter(|| test_1000(Quad::C, |p: &IntPropLazyLock| p.int));
+	}
+}
+
+
+
+// Many threads racing on the first access of a large lazy value.
+//
+// Statics can only be initialized once, thus, these benchmarks use fresh
+// local cells in each iteration, which use the same synchronization primitives
+// as the corresponding lazy backends.
+const CONTENDERS: usize = 16;
+
+fn large_value() -> [u32; 1024] {
+	let mut arr = [0; 1024];
+	for (i, a) in arr.iter_mut().enumerate() {
+		*a = test::black_box(i as u32);
+	}
+	arr
+}
+
+fn contended_first_access(force: impl Fn() -> u32 + Sync) -> u32 {
+	let barrier = std::sync::Barrier::new(CONTENDERS);
+	std::thread::scope(|s| {
+		let handles: std::vec::Vec<_> = (0..CONTENDERS)
+			.map(|_| {
+				s.spawn(|| {
+					barrier.wait();
+					force()
+				})
+			})
+			.collect();
+		handles.into_iter().map(|h| h.join().unwrap()).sum()
+	})
+}
+
+#[bench]
+pub fn contended_first_access_std(b: &mut Bencher) {
+	b.iter(|| {
+		let cell = std::sync::OnceLock::new();
+		contended_first_access(|| cell.get_or_init(large_value)[1023])
+	});
+}
+
+#[cfg(feature = "lazylock")]
+#[bench]
+pub fn contended_first_access_lazylock(b: &mut Bencher) {
+	b.iter(|| {
+		let cell = std::sync::LazyLock::new(large_value);
+		contended_first_access(|| cell[1023])
+	});
+}
+
+// With the `parking_lot` feature, this uses parking_lot's primitives
+#[cfg(feature = "once_cell")]
+#[bench]
+pub fn contended_first_access_once_cell(b: &mut Bencher) {
+	b.iter(|| {
+		let cell = once_cell::sync::Lazy::new(large_value);
+		contended_first_access(|| cell[1023])
+	});
+}
+
+
+
+// Parsing a large enum by a string field, comparing the linear comparison of
+// `from_str` with the perfect hash map of `from_str(phf ...)`, and accessing
+// its properties, comparing the match of `EnumProp` with the `table`
+struct KeywordDef {
+	name: &'static str,
+}
+
+macro_rules! keyword_props {
+	($enum_name:ident, [$($clauses:tt)*], [$($variant:ident = $name:literal,)*]) => {
+		#[derive(Copy, Clone)]
+		#[allow(dead_code)]
+		pub enum $enum_name {
+			$($variant,)*
+		}
+
+		props! {
+			impl EnumProp for $enum_name as const KeywordDef {
+				$($clauses)*
+				$(
+					Self::$variant => {
+						name: $name,
+					}
+				)*
+			}
+		}
+	};
+}
+
+macro_rules! large_keywords {
+	($($variant:ident = $name:literal,)*) => {
+		keyword_props!(LargeLinear, [from_str(name)], [$($variant = $name,)*]);
+		#[cfg(feature = "phf")]
+		keyword_props!(LargePhf, [from_str(phf name as KEYWORDS)], [$($variant = $name,)*]);
+		keyword_props!(LargeTable, [table from_str(name)], [$($variant = $name,)*]);
+	};
+}
+
+large_keywords! {
+	K000 = "kw_000",
+	K001 = "kw_001",
+	K002 = "kw_002",
+	K003 = "kw_003",
+	K004 = "kw_004",
+	K005 = "kw_005",
+	K006 = "kw_006",
+	K007 = "kw_007",
+	K008 = "kw_008",
+	K009 = "kw_009",
+	K010 = "kw_010",
+	K011 = "kw_011",
+	K012 = "kw_012",
+	K013 = "kw_013",
+	K014 = "kw_014",
+	K015 = "kw_015",
+	K016 = "kw_016",
+	K017 = "kw_017",
+	K018 = "kw_018",
+	K019 = "kw_019",
+	K020 = "kw_020",
+	K021 = "kw_021",
+	K022 = "kw_022",
+	K023 = "kw_023",
+	K024 = "kw_024",
+	K025 = "kw_025",
+	K026 = "kw_026",
+	K027 = "kw_027",
+	K028 = "kw_028",
+	K029 = "kw_029",
+	K030 = "kw_030",
+	K031 = "kw_031",
+	K032 = "kw_032",
+	K033 = "kw_033",
+	K034 = "kw_034",
+	K035 = "kw_035",
+	K036 = "kw_036",
+	K037 = "kw_037",
+	K038 = "kw_038",
+	K039 = "kw_039",
+	K040 = "kw_040",
+	K041 = "kw_041",
+	K042 = "kw_042",
+	K043 = "kw_043",
+	K044 = "kw_044",
+	K045 = "kw_045",
+	K046 = "kw_046",
+	K047 = "kw_047",
+	K048 = "kw_048",
+	K049 = "kw_049",
+	K050 = "kw_050",
+	K051 = "kw_051",
+	K052 = "kw_052",
+	K053 = "kw_053",
+	K054 = "kw_054",
+	K055 = "kw_055",
+	K056 = "kw_056",
+	K057 = "kw_057",
+	K058 = "kw_058",
+	K059 = "kw_059",
+	K060 = "kw_060",
+	K061 = "kw_061",
+	K062 = "kw_062",
+	K063 = "kw_063",
+	K064 = "kw_064",
+	K065 = "kw_065",
+	K066 = "kw_066",
+	K067 = "kw_067",
+	K068 = "kw_068",
+	K069 = "kw_069",
+	K070 = "kw_070",
+	K071 = "kw_071",
+	K072 = "kw_072",
+	K073 = "kw_073",
+	K074 = "kw_074",
+	K075 = "kw_075",
+	K076 = "kw_076",
+	K077 = "kw_077",
+	K078 = "kw_078",
+	K079 = "kw_079",
+	K080 = "kw_080",
+	K081 = "kw_081",
+	K082 = "kw_082",
+	K083 = "kw_083",
+	K084 = "kw_084",
+	K085 = "kw_085",
+	K086 = "kw_086",
+	K087 = "kw_087",
+	K088 = "kw_088",
+	K089 = "kw_089",
+	K090 = "kw_090",
+	K091 = "kw_091",
+	K092 = "kw_092",
+	K093 = "kw_093",
+	K094 = "kw_094",
+	K095 = "kw_095",
+	K096 = "kw_096",
+	K097 = "kw_097",
+	K098 = "kw_098",
+	K099 = "kw_099",
+	K100 = "kw_100",
+	K101 = "kw_101",
+	K102 = "kw_102",
+	K103 = "kw_103",
+	K104 = "kw_104",
+	K105 = "kw_105",
+	K106 = "kw_106",
+	K107 = "kw_107",
+	K108 = "kw_108",
+	K109 = "kw_109",
+	K110 = "kw_110",
+	K111 = "kw_111",
+	K112 = "kw_112",
+	K113 = "kw_113",
+	K114 = "kw_114",
+	K115 = "kw_115",
+	K116 = "kw_116",
+	K117 = "kw_117",
+	K118 = "kw_118",
+	K119 = "kw_119",
+	K120 = "kw_120",
+	K121 = "kw_121",
+	K122 = "kw_122",
+	K123 = "kw_123",
+	K124 = "kw_124",
+	K125 = "kw_125",
+	K126 = "kw_126",
+	K127 = "kw_127",
+	K128 = "kw_128",
+	K129 = "kw_129",
+	K130 = "kw_130",
+	K131 = "kw_131",
+	K132 = "kw_132",
+	K133 = "kw_133",
+	K134 = "kw_134",
+	K135 = "kw_135",
+	K136 = "kw_136",
+	K137 = "kw_137",
+	K138 = "kw_138",
+	K139 = "kw_139",
+	K140 = "kw_140",
+	K141 = "kw_141",
+	K142 = "kw_142",
+	K143 = "kw_143",
+	K144 = "kw_144",
+	K145 = "kw_145",
+	K146 = "kw_146",
+	K147 = "kw_147",
+	K148 = "kw_148",
+	K149 = "kw_149",
+	K150 = "kw_150",
+	K151 = "kw_151",
+	K152 = "kw_152",
+	K153 = "kw_153",
+	K154 = "kw_154",
+	K155 = "kw_155",
+	K156 = "kw_156",
+	K157 = "kw_157",
+	K158 = "kw_158",
+	K159 = "kw_159",
+	K160 = "kw_160",
+	K161 = "kw_161",
+	K162 = "kw_162",
+	K163 = "kw_163",
+	K164 = "kw_164",
+	K165 = "kw_165",
+	K166 = "kw_166",
+	K167 = "kw_167",
+	K168 = "kw_168",
+	K169 = "kw_169",
+	K170 = "kw_170",
+	K171 = "kw_171",
+	K172 = "kw_172",
+	K173 = "kw_173",
+	K174 = "kw_174",
+	K175 = "kw_175",
+	K176 = "kw_176",
+	K177 = "kw_177",
+	K178 = "kw_178",
+	K179 = "kw_179",
+	K180 = "kw_180",
+	K181 = "kw_181",
+	K182 = "kw_182",
+	K183 = "kw_183",
+	K184 = "kw_184",
+	K185 = "kw_185",
+	K186 = "kw_186",
+	K187 = "kw_187",
+	K188 = "kw_188",
+	K189 = "kw_189",
+	K190 = "kw_190",
+	K191 = "kw_191",
+	K192 = "kw_192",
+	K193 = "kw_193",
+	K194 = "kw_194",
+	K195 = "kw_195",
+	K196 = "kw_196",
+	K197 = "kw_197",
+	K198 = "kw_198",
+	K199 = "kw_199",
+	K200 = "kw_200",
+	K201 = "kw_201",
+	K202 = "kw_202",
+	K203 = "kw_203",
+	K204 = "kw_204",
+	K205 = "kw_205",
+	K206 = "kw_206",
+	K207 = "kw_207",
+	K208 = "kw_208",
+	K209 = "kw_209",
+	K210 = "kw_210",
+	K211 = "kw_211",
+	K212 = "kw_212",
+	K213 = "kw_213",
+	K214 = "kw_214",
+	K215 = "kw_215",
+	K216 = "kw_216",
+	K217 = "kw_217",
+	K218 = "kw_218",
+	K219 = "kw_219",
+	K220 = "kw_220",
+	K221 = "kw_221",
+	K222 = "kw_222",
+	K223 = "kw_223",
+	K224 = "kw_224",
+	K225 = "kw_225",
+	K226 = "kw_226",
+	K227 = "kw_227",
+	K228 = "kw_228",
+	K229 = "kw_229",
+	K230 = "kw_230",
+	K231 = "kw_231",
+	K232 = "kw_232",
+	K233 = "kw_233",
+	K234 = "kw_234",
+	K235 = "kw_235",
+	K236 = "kw_236",
+	K237 = "kw_237",
+	K238 = "kw_238",
+	K239 = "kw_239",
+	K240 = "kw_240",
+	K241 = "kw_241",
+	K242 = "kw_242",
+	K243 = "kw_243",
+	K244 = "kw_244",
+	K245 = "kw_245",
+	K246 = "kw_246",
+	K247 = "kw_247",
+	K248 = "kw_248",
+	K249 = "kw_249",
+	K250 = "kw_250",
+	K251 = "kw_251",
+	K252 = "kw_252",
+	K253 = "kw_253",
+	K254 = "kw_254",
+	K255 = "kw_255",
+	K256 = "kw_256",
+	K257 = "kw_257",
+	K258 = "kw_258",
+	K259 = "kw_259",
+	K260 = "kw_260",
+	K261 = "kw_261",
+	K262 = "kw_262",
+	K263 = "kw_263",
+	K264 = "kw_264",
+	K265 = "kw_265",
+	K266 = "kw_266",
+	K267 = "kw_267",
+	K268 = "kw_268",
+	K269 = "kw_269",
+	K270 = "kw_270",
+	K271 = "kw_271",
+	K272 = "kw_272",
+	K273 = "kw_273",
+	K274 = "kw_274",
+	K275 = "kw_275",
+	K276 = "kw_276",
+	K277 = "kw_277",
+	K278 = "kw_278",
+	K279 = "kw_279",
+	K280 = "kw_280",
+	K281 = "kw_281",
+	K282 = "kw_282",
+	K283 = "kw_283",
+	K284 = "kw_284",
+	K285 = "kw_285",
+	K286 = "kw_286",
+	K287 = "kw_287",
+	K288 = "kw_288",
+	K289 = "kw_289",
+	K290 = "kw_290",
+	K291 = "kw_291",
+	K292 = "kw_292",
+	K293 = "kw_293",
+	K294 = "kw_294",
+	K295 = "kw_295",
+	K296 = "kw_296",
+	K297 = "kw_297",
+	K298 = "kw_298",
+	K299 = "kw_299",
+}
+
+// Some names spread over the variants, along with an unknown one
+const KEYWORD_SAMPLES: [&str; 5] = ["kw_000", "kw_100", "kw_200", "kw_299", "kw_300"];
+
+fn parse_samples<E: core::str::FromStr>() -> usize {
+	let mut found = 0;
+	for _ in 0..200 {
+		for name in KEYWORD_SAMPLES {
+			found += test::black_box(name).parse::<E>().is_ok() as usize;
+		}
+	}
+	found
+}
+
+#[bench]
+pub fn large_1000_from_str_linear(b: &mut Bencher) {
+	b.iter(parse_samples::<LargeLinear>);
+}
+
+#[cfg(feature = "phf")]
+#[bench]
+pub fn large_1000_from_str_phf(b: &mut Bencher) {
+	b.iter(parse_samples::<LargePhf>);
+}
+
+fn access_samples<E>(b: &mut Bencher)
+where
+	E: Copy + EnumProp<KeywordDef> + core::str::FromStr,
+{
+	let samples = ["kw_000", "kw_150", "kw_299"].map(|name| name.parse::<E>().ok().unwrap());
+	b.iter(|| {
+		samples
+			.iter()
+			.map(|&e| test_1000(e, |p: &KeywordDef| p.name.len() as u32))
+			.sum::<u32>()
+	});
+}
+
+#[bench]
+pub fn large_1000_access_match(b: &mut Bencher) {
+	access_samples::<LargeLinear>(b);
+}
+
+#[bench]
+pub fn large_1000_access_table(b: &mut Bencher) {
+	access_samples::<LargeTable>(b);
+}
@@ -0,0 +1,905 @@
+// The lazy backends used by the `lazy` modifier of the `props` macro.
+//
+// Each backend is a macro taking the property type and the initialization
+// expression, and evaluates to a `&'static` reference to the lazily
+// initialized value. Which backend is used by default is decided by the crate
+// features, see `internal_lazy_with_default`.
+
+
+// The backend based on `lazy_static`
+#[cfg(feature = "lazy")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! internal_lazy_with_lazy_static {
+	($prop_name:ty, $init:expr) => {{
+		// A static reference via lazy_static.
+
+		// `FOO` is rather arbitrary here, maybe different name would be better
+		$crate::lazy_static::lazy_static! {
+			static ref FOO: $prop_name = $init;
+		}
+
+		&*FOO
+	}};
+}
+
+#[cfg(not(feature = "lazy"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! internal_lazy_with_lazy_static {
+	($($tokens:tt)*) => {
+		::core::compile_error!(
+			"the `lazy_static` lazy backend requires the `lazy` feature of `enumeraties`"
+		)
+	};
+}
+
+// The backend based on `once_cell`
+#[cfg(feature = "once_cell")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! internal_lazy_with_once_cell {
+	($prop_name:ty, $init:expr) => {{
+		// A static reference via once_cell.
+
+		static FOO: $crate::once_cell::sync::Lazy<$prop_name> =
+			$crate::once_cell::sync::Lazy::new(|| $init);
+
+		&*FOO
+	}};
+}
+
+#[cfg(not(feature = "once_cell"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! internal_lazy_with_once_cell {
+	($($tokens:tt)*) => {
+		::core::compile_error!(
+			"the `once_cell` lazy backend requires the `once_cell` feature of `enumeraties`"
+		)
+	};
+}
+
+// The backend based on `std::sync::OnceLock`
+#[cfg(feature = "std-lazy")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! internal_lazy_with_std {
+	($prop_name:ty, $init:expr) => {{
+		// A static reference via std's OnceLock, no third-party dependency.
+
+		static CELL: $crate::OnceLock<$prop_name> = $crate::OnceLock::new();
+
+		CELL.get_or_init(|| $init)
+	}};
+}
+
+#[cfg(not(feature = "std-lazy"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! internal_lazy_with_std {
+	($($tokens:tt)*) => {
+		::core::compile_error!(
+			"the `once_lock` lazy backend requires the `std-lazy` feature of `enumeraties`"
+		)
+	};
+}
+
+// The backend based on `std::sync::LazyLock`
+#[cfg(feature = "lazylock")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! internal_lazy_with_lazylock {
+	($prop_name:ty, $init:expr) => {{
+		// A static reference via std's LazyLock, no third-party dependency.
+
+		static FOO: $crate::LazyLock<$prop_name> = $crate::LazyLock::new(|| $init);
+
+		&*FOO
+	}};
+}
+
+#[cfg(not(feature = "lazylock"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! internal_lazy_with_lazylock {
+	($($tokens:tt)*) => {
+		::core::compile_error!(
+			"the `lazy_lock` lazy backend requires the `lazylock` feature of `enumeraties`"
+		)
+	};
+}
+
+// The backend based on `spin`, usable without std
+#[cfg(feature = "no_std-lazy")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! internal_lazy_with_spin {
+	($prop_name:ty, $init:expr) => {{
+		// A static reference via a spin-lock based lazy, which works without
+		// an operating system.
+
+		static FOO: $crate::spin::Lazy<$prop_name> = $crate::spin::Lazy::new(|| $init);
+
+		&*FOO
+	}};
+}
+
+#[cfg(not(feature = "no_std-lazy"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! internal_lazy_with_spin {
+	($($tokens:tt)*) => {
+		::core::compile_error!(
+			"the `spin` lazy backend requires the `no_std-lazy` feature of `enumeraties`"
+		)
+	};
+}
+
+
+// The backend for single-threaded targets, based on thread locals
+#[cfg(feature = "unsync-lazy")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! internal_lazy_with_unsync {
+	($prop_name:ty, $init:expr) => {{
+		// A thread local holding a leaked reference, this is only sound to be
+		// used as `&'static` because it is initialized on a single thread only.
+
+		// Tracks whether any thread did initialize the value already, notice that
+		// this must not be named like the static in `internal_lazy_tracked`
+		static ACCESSED: ::core::sync::atomic::AtomicBool =
+			::core::sync::atomic::AtomicBool::new(false);
+
+		$crate::thread_local! {
+			static FOO: &'static $prop_name = {
+				if ACCESSED.swap(true, ::core::sync::atomic::Ordering::Relaxed) {
+					::core::panic!(
+						"`lazy` property `{}` accessed from multiple threads, \
+						which is not supported with the `unsync-lazy` feature",
+						::core::any::type_name::<$prop_name>()
+					)
+				}
+				$crate::Box::leak($crate::Box::new($init))
+			};
+		}
+
+		FOO.with(|foo| *foo)
+	}};
+}
+
+#[cfg(not(feature = "unsync-lazy"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! internal_lazy_with_unsync {
+	($($tokens:tt)*) => {
+		::core::compile_error!(
+			"the `unsync` lazy backend requires the `unsync-lazy` feature of `enumeraties`"
+		)
+	};
+}
+
+
+// The statics tracking the state of a lazy value
+//
+// With `--cfg loom`, these are loom's primitives, which are reset for each
+// execution of the model.
+
+#[cfg(not(all(loom, feature = "std")))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! internal_lazy_state {
+	(@initialized $initialized:ident) => {
+		static $initialized: ::core::sync::atomic::AtomicBool =
+			::core::sync::atomic::AtomicBool::new(false);
+	};
+	(@in_progress $in_progress:ident) => {
+		$crate::thread_local! {
+			static $in_progress: ::core::cell::Cell<bool> = const { ::core::cell::Cell::new(false) };
+		}
+	};
+}
+
+#[cfg(all(loom, feature = "std"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! internal_lazy_state {
+	(@initialized $initialized:ident) => {
+		$crate::loom::lazy_static! {
+			static ref $initialized: $crate::loom::sync::atomic::AtomicBool =
+				$crate::loom::sync::atomic::AtomicBool::new(false);
+		}
+	};
+	(@in_progress $in_progress:ident) => {
+		// Loom's thread locals do not support const initializers
+		$crate::thread_local! {
+			static $in_progress: ::core::cell::Cell<bool> = ::core::cell::Cell::new(false);
+		}
+	};
+}
+
+
+// Wraps a backend, tracking whether the value has been initialized
+//
+// Evaluates to `Some` reference to the value, if it is already initialized or
+// if `$init` is true, in which case the value gets initialized. Otherwise, the
+// initialization is not triggered and `None` is returned.
+//
+// The enum, the property, and the context (e.g. the branch) are used to report
+// a recursive initialization, which would otherwise deadlock.
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! internal_lazy_tracked {
+	(
+		$init:ident,
+		$backend:ident $(($arg:ident))?,
+		$cell_ty:ty,
+		($enum_name:ty, $prop_name:ty, $context:expr),
+		$value:expr
+	) => {{
+		$crate::internal_lazy_state! { @initialized INITIALIZED }
+		$crate::internal_lazy_recursion! { @static IN_PROGRESS }
+
+		// The initializer runs only once, thus, it is marked cold to keep it
+		// out of the accessor. It is not forced `#[inline(never)]`, which
+		// slowed down the access of initialized values in the benchmarks.
+		#[cold]
+		fn internal_lazy_init() -> $cell_ty {
+			let value = $crate::internal_lazy_recursion! { @enter IN_PROGRESS, $value };
+			INITIALIZED.store(true, ::core::sync::atomic::Ordering::Release);
+			value
+		}
+
+		let initialized = INITIALIZED.load(::core::sync::atomic::Ordering::Acquire);
+		if $init || initialized {
+			if !initialized {
+				// Must be checked before entering the backend, which might
+				// deadlock otherwise
+				$crate::internal_lazy_recursion! {
+					@check IN_PROGRESS, $enum_name, $prop_name, $context
+				}
+			}
+
+			::core::option::Option::Some($crate::$backend!($($arg,)? $cell_ty, internal_lazy_init()))
+		} else {
+			::core::option::Option::None
+		}
+	}};
+}
+
+
+// Detects the recursive initialization of a lazy value on the same thread,
+// only available with std
+//
+// Other threads accessing a value during its initialization just wait for it
+// as usual, thus, the state is tracked per thread. Hence, a cycle entered from
+// several threads at once is not detected, but deadlocks.
+
+#[cfg(feature = "std")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! internal_lazy_recursion {
+	(@static $in_progress:ident) => {
+		$crate::internal_lazy_state! { @in_progress $in_progress }
+	};
+	(@check $in_progress:ident, $enum_name:ty, $prop_name:ty, $context:expr) => {
+		if $in_progress.with(|in_progress| in_progress.get()) {
+			::core::panic!(
+				"recursive initialization of `lazy` property `{}` of `{}` ({})",
+				::core::any::type_name::<$prop_name>(),
+				::core::any::type_name::<$enum_name>(),
+				$context
+			)
+		}
+	};
+	(@enter $in_progress:ident, $value:expr) => {{
+		// Resets the state, even if the initialization panics
+		struct Guard;
+		impl ::core::ops::Drop for Guard {
+			fn drop(&mut self) {
+				$in_progress.with(|in_progress| in_progress.set(false));
+			}
+		}
+
+		$in_progress.with(|in_progress| in_progress.set(true));
+		let _guard = Guard;
+		$value
+	}};
+}
+
+#[cfg(not(feature = "std"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! internal_lazy_recursion {
+	(@static $in_progress:ident) => {};
+	(@check $in_progress:ident, $enum_name:ty, $prop_name:ty, $context:expr) => {};
+	(@enter $in_progress:ident, $value:expr) => {
+		$value
+	};
+}
+
+
+// Annotates a panic of the initialization with the enum, the property, and
+// the branch, only available with std
+
+#[cfg(feature = "std")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! internal_lazy_annotated {
+	($enum_name:expr, $prop_name:ty, $branch:pat, $init:expr) => {
+		// The initialization can not capture anything, since it lives in its
+		// own function, hence, it is unwind safe by itself
+		match $crate::catch_unwind(|| $init) {
+			::core::result::Result::Ok(value) => value,
+			::core::result::Result::Err(payload) => {
+				// The context is reported by a panic of its own, i.e. via the
+				// panic hook, which is caught right away, while the original
+				// payload is passed on unchanged
+				let _ = $crate::catch_unwind(|| {
+					::core::panic!(
+						"initialization of `lazy` property `{}` of `{}` (branch `{}`) panicked",
+						::core::any::type_name::<$prop_name>(),
+						$enum_name,
+						::core::stringify!($branch),
+					)
+				});
+				$crate::resume_unwind(payload)
+			},
+		}
+	};
+}
+
+#[cfg(not(feature = "std"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! internal_lazy_annotated {
+	($enum_name:expr, $prop_name:ty, $branch:pat, $init:expr) => {
+		$init
+	};
+}
+
+
+// Wraps the initialization in a `tracing` span, only with the `tracing`
+// feature
+
+#[cfg(feature = "tracing")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! internal_lazy_traced {
+	($enum_name:expr, $prop_name:ty, $branch:pat, $init:expr) => {{
+		let _span = $crate::tracing::info_span!(
+			"enum_prop_init",
+			r#enum = $enum_name,
+			prop = ::core::any::type_name::<$prop_name>(),
+			branch = ::core::stringify!($branch),
+		)
+		.entered();
+
+		$init
+	}};
+}
+
+#[cfg(not(feature = "tracing"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! internal_lazy_traced {
+	($enum_name:expr, $prop_name:ty, $branch:pat, $init:expr) => {
+		$init
+	};
+}
+
+
+// The explicitly selected backend, i.e. `lazy(<BACKEND>)`, regardless of
+// the default backend selected via the crate features
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! internal_lazy_with_backend {
+	(lazy_static, $($tokens:tt)*) => {
+		$crate::internal_lazy_with_lazy_static!{ $($tokens)* }
+	};
+	(once_cell, $($tokens:tt)*) => {
+		$crate::internal_lazy_with_once_cell!{ $($tokens)* }
+	};
+	(once_lock, $($tokens:tt)*) => {
+		$crate::internal_lazy_with_std!{ $($tokens)* }
+	};
+	(lazy_lock, $($tokens:tt)*) => {
+		$crate::internal_lazy_with_lazylock!{ $($tokens)* }
+	};
+	(spin, $($tokens:tt)*) => {
+		$crate::internal_lazy_with_spin!{ $($tokens)* }
+	};
+	(unsync, $($tokens:tt)*) => {
+		$crate::internal_lazy_with_unsync!{ $($tokens)* }
+	};
+	($backend:ident, $($tokens:tt)*) => {
+		::core::compile_error!(::core::concat!(
+			"unknown lazy backend `",
+			::core::stringify!($backend),
+			"`, expected one of: `lazy_static`, `once_cell`, `once_lock`, `lazy_lock`, `spin`, \
+			 `unsync`, or `grouped`"
+		))
+	};
+}
+
+
+// The backend for the `loom` tests, based on loom's primitives
+//
+// The value is put into a loom mutex, which is reset for each execution of the
+// model, therefore, this backend is only used with `--cfg loom`. It stands in
+// for the actual backends, whose std primitives can not be model checked, thus,
+// only the tracking and the recursion detection around it are model checked.
+#[cfg(all(loom, feature = "std"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! internal_lazy_with_loom {
+	($prop_name:ty, $init:expr) => {{
+		$crate::loom::lazy_static! {
+			static ref CELL: $crate::loom::sync::Mutex<::core::option::Option<&'static $prop_name>> =
+				$crate::loom::sync::Mutex::new(::core::option::Option::None);
+		}
+
+		let mut cell = CELL.lock().unwrap();
+		match *cell {
+			::core::option::Option::Some(value) => value,
+			::core::option::Option::None => {
+				let value: &'static $prop_name = $crate::Box::leak($crate::Box::new($init));
+				*cell = ::core::option::Option::Some(value);
+				value
+			},
+		}
+	}};
+}
+
+
+// The default backend, selected via the crate features
+//
+// The `unsync-lazy` backend takes precedence over all synchronized backends,
+// since it is only enabled for single-threaded targets. However, with
+// `--cfg loom` the `loom` backend is used regardless of the features.
+
+#[cfg(all(loom, feature = "std"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! internal_lazy_with_default {
+	($($tokens:tt)*) => {
+		$crate::internal_lazy_with_loom!{ $($tokens)* }
+	};
+}
+
+#[cfg(all(feature = "unsync-lazy", not(all(loom, feature = "std"))))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! internal_lazy_with_default {
+	($($tokens:tt)*) => {
+		$crate::internal_lazy_with_unsync!{ $($tokens)* }
+	};
+}
+
+#[cfg(not(any(feature = "unsync-lazy", all(loom, feature = "std"))))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! internal_lazy_with_default {
+	($($tokens:tt)*) => {
+		$crate::internal_lazy_with_sync_default!{ $($tokens)* }
+	};
+}
+
+
+// The default synchronized backend, selected via the crate features
+//
+// Explicitly enabled backends take precedence over `lazy_static`, in the
+// order: `parking_lot` (i.e. `once_cell` with parking_lot internals),
+// `lazylock`, `std-lazy`, `once_cell`, `no_std-lazy`. Without any backend,
+// `lazy` properties are a compile error.
+
+#[cfg(all(feature = "lazylock", not(feature = "parking_lot")))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! internal_lazy_with_sync_default {
+	($($tokens:tt)*) => {
+		$crate::internal_lazy_with_lazylock!{ $($tokens)* }
+	};
+}
+
+#[cfg(all(
+	feature = "std-lazy",
+	not(any(feature = "parking_lot", feature = "lazylock"))
+))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! internal_lazy_with_sync_default {
+	($($tokens:tt)*) => {
+		$crate::internal_lazy_with_std!{ $($tokens)* }
+	};
+}
+
+#[cfg(any(
+	feature = "parking_lot",
+	all(
+		feature = "once_cell",
+		not(any(feature = "std-lazy", feature = "lazylock"))
+	)
+))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! internal_lazy_with_sync_default {
+	($($tokens:tt)*) => {
+		$crate::internal_lazy_with_once_cell!{ $($tokens)* }
+	};
+}
+
+#[cfg(all(
+	feature = "no_std-lazy",
+	not(any(feature = "std-lazy", feature = "lazylock", feature = "once_cell"))
+))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! internal_lazy_with_sync_default {
+	($($tokens:tt)*) => {
+		$crate::internal_lazy_with_spin!{ $($tokens)* }
+	};
+}
+
+#[cfg(all(
+	feature = "lazy",
+	not(any(
+		feature = "std-lazy",
+		feature = "lazylock",
+		feature = "once_cell",
+		feature = "no_std-lazy"
+	))
+))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! internal_lazy_with_sync_default {
+	($($tokens:tt)*) => {
+		$crate::internal_lazy_with_lazy_static!{ $($tokens)* }
+	};
+}
+
+#[cfg(not(any(
+	feature = "lazy",
+	feature = "std-lazy",
+	feature = "lazylock",
+	feature = "once_cell",
+	feature = "no_std-lazy"
+)))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! internal_lazy_with_sync_default {
+	($($tokens:tt)*) => {
+		::core::compile_error!(
+			"`lazy` properties require a lazy backend, enable the `lazy` feature of `enumeraties` \
+			 (or one of `std-lazy`, `lazylock`, `once_cell`, `no_std-lazy`, `unsync-lazy`)"
+		)
+	};
+}
+
+
+
+// The thread local storage for `thread_lazy`, only available with std
+
+#[cfg(feature = "std")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! internal_thread_lazy {
+	($prop_name:path, $f:ident, $init:expr) => {{
+		$crate::thread_local! {
+			static FOO: $prop_name = $init;
+		}
+
+		FOO.with($f)
+	}};
+}
+
+#[cfg(not(feature = "std"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! internal_thread_lazy {
+	($($tokens:tt)*) => {
+		::core::compile_error!(
+			"`thread_lazy` properties require the `std` feature of `enumeraties`"
+		)
+	};
+}
+
+
+
+// The items of `dynamic` properties, only available with alloc
+
+#[cfg(feature = "alloc")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! internal_dynamic {
+	($($item:tt)*) => {
+		$($item)*
+	};
+}
+
+#[cfg(not(feature = "alloc"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! internal_dynamic {
+	($($item:tt)*) => {
+		::core::compile_error!("`dynamic` properties require the `alloc` feature of `enumeraties`");
+	};
+}
+
+
+// The items of `memo` properties, only available with std
+
+#[cfg(feature = "std")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! internal_memo {
+	($($item:tt)*) => {
+		$($item)*
+	};
+}
+
+#[cfg(not(feature = "std"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! internal_memo {
+	($($item:tt)*) => {
+		::core::compile_error!("`memo` properties require the `std` feature of `enumeraties`");
+	};
+}
+
+
+
+// The `PropMap` of the `enum_map` clause, only available with the `enum_map`
+// feature
+
+#[cfg(feature = "enum_map")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! internal_enum_map {
+	($($item:tt)*) => {
+		$($item)*
+	};
+}
+
+#[cfg(not(feature = "enum_map"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! internal_enum_map {
+	($($item:tt)*) => {
+		::core::compile_error!("the `enum_map` requires the `enum_map` feature of `enumeraties`");
+	};
+}
+
+
+
+// The perfect hash map of `from_str(phf ...)`, only available with the `phf`
+// feature
+
+#[cfg(feature = "phf")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! internal_phf {
+	($($item:tt)*) => {
+		$($item)*
+	};
+}
+
+#[cfg(not(feature = "phf"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! internal_phf {
+	($($item:tt)*) => {
+		::core::compile_error!(
+			"the `from_str(phf ...)` requires the `phf` feature of `enumeraties`"
+		);
+	};
+}
+
+
+
+// The `ValueEnum` impl of `value_enum(...)`, only available with the `clap`
+// feature
+
+#[cfg(feature = "clap")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! internal_clap {
+	($($item:tt)*) => {
+		$($item)*
+	};
+}
+
+#[cfg(not(feature = "clap"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! internal_clap {
+	($($item:tt)*) => {
+		::core::compile_error!(
+			"the `value_enum(...)` requires the `clap` feature of `enumeraties`"
+		);
+	};
+}
+
+
+
+// The weighted sampling of `sample_weighted(...)`, only available with the
+// `rand` feature
+
+#[cfg(feature = "rand")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! internal_rand {
+	($($item:tt)*) => {
+		$($item)*
+	};
+}
+
+#[cfg(not(feature = "rand"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! internal_rand {
+	($($item:tt)*) => {
+		::core::compile_error!(
+			"the `sample_weighted(...)` requires the `rand` feature of `enumeraties`"
+		);
+	};
+}
+
+
+
+// The `defmt::Format` impl of `defmt(...)`, only available with the `defmt`
+// feature
+
+#[cfg(feature = "defmt")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! internal_defmt {
+	($($item:tt)*) => {
+		$($item)*
+	};
+}
+
+#[cfg(not(feature = "defmt"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! internal_defmt {
+	($($item:tt)*) => {
+		::core::compile_error!("the `defmt(...)` requires the `defmt` feature of `enumeraties`");
+	};
+}
+
+
+
+// The serialization of `serialize_as(...)` and `deserialize_from(...)`, only
+// available with the `serde` feature
+
+#[cfg(feature = "serde")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! internal_serde {
+	($($item:tt)*) => {
+		$($item)*
+	};
+}
+
+#[cfg(not(feature = "serde"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! internal_serde {
+	($($item:tt)*) => {
+		::core::compile_error!(
+			"the `serialize_as(...)` and `deserialize_from(...)` require the `serde` feature of \
+			 `enumeraties`"
+		);
+	};
+}
+
+#[cfg(feature = "enumset")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! internal_enumset {
+	($($item:tt)*) => {
+		$($item)*
+	};
+}
+
+#[cfg(not(feature = "enumset"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! internal_enumset {
+	($($item:tt)*) => {
+		::core::compile_error!(
+			"the `set_where(...)` requires the `enumset` feature of `enumeraties`"
+		);
+	};
+}
+
+
+
+// The eager initialization before `main` for `eager`, only available with the
+// `eager` feature
+
+#[cfg(feature = "eager")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! internal_eager {
+	($prop_name:path, $init:expr) => {{
+		fn foo_init() -> $prop_name {
+			$init
+		}
+
+		static FOO: $crate::OnceLock<$prop_name> = $crate::OnceLock::new();
+
+		// Force the initialization before `main`, if supported
+		$crate::internal_eager_ctor! {
+			unsafe fn foo_eager_init() {
+				FOO.get_or_init(foo_init);
+			}
+		}
+
+		// Otherwise, this falls back to lazy initialization
+		FOO.get_or_init(foo_init)
+	}};
+}
+
+#[cfg(not(feature = "eager"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! internal_eager {
+	($($tokens:tt)*) => {
+		::core::compile_error!("`eager` properties require the `eager` feature of `enumeraties`")
+	};
+}
+
+// The targets supported by `ctor`, on all other targets, the constructor is
+// omitted.
+#[cfg(all(
+	feature = "eager",
+	any(
+		target_os = "linux",
+		target_os = "android",
+		target_os = "freebsd",
+		target_os = "netbsd",
+		target_os = "openbsd",
+		target_os = "dragonfly",
+		target_os = "illumos",
+		target_os = "haiku",
+		target_vendor = "apple",
+		target_family = "wasm",
+		windows
+	)
+))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! internal_eager_ctor {
+	($($item:tt)*) => {
+		$crate::ctor::declarative::ctor! {
+			#[ctor(crate_path = $crate::ctor)]
+			$($item)*
+		}
+	};
+}
+
+#[cfg(all(
+	feature = "eager",
+	not(any(
+		target_os = "linux",
+		target_os = "android",
+		target_os = "freebsd",
+		target_os = "netbsd",
+		target_os = "openbsd",
+		target_os = "dragonfly",
+		target_os = "illumos",
+		target_os = "haiku",
+		target_vendor = "apple",
+		target_family = "wasm",
+		windows
+	))
+))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! internal_eager_ctor {
+	($($item:tt)*) => {};
+}
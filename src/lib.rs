@@ -1,5 +1,8 @@
+#![no_std]
 #![forbid(unsafe_code)]
 #![cfg_attr(feature = "bench", feature(test))]
+// The large synthetic enums of the benchmarks exceed the default recursion limit
+#![cfg_attr(feature = "bench", recursion_limit = "2048")]
 //! This crate provides a macro to add static, const, or lazy-initialized
 //! properties to enum variants.
 //!
@@ -39,6 +42,74 @@
 //! // Accessing the property on `Foo`
 //! assert_eq!(Foo::A.name, "Foo");
 //! ```
+//!
+//! # Crate Features
+//!
+//! This crate is `no_std` and `const` and `static` properties work without
+//! any dependencies. Other modifiers need additional crate features:
+//!
+//! * `std` (default): enables `thread_lazy` and `memo` properties, which
+//!   require thread local storage and a hash map, respectively, and implies
+//!   `alloc`
+//! * `alloc`: enables `dynamic` properties, which require an allocator
+//! * `eager`: enables `eager` properties, which are initialized before `main`
+//!   via [`ctor`](https://docs.rs/ctor)
+//! * `defmt`: implements [`defmt::Format`](https://docs.rs/defmt) by a string
+//!   field of the properties, i.e. `defmt(...)`, for logging on embedded
+//!   targets
+//! * `enum_map`: enables the `enum_map` of the properties of all variants, see
+//!   [`PropMap`], which requires [`enum-map`](https://docs.rs/enum-map) and a
+//!   lazy backend
+//! * `enumset`: enables the sets of the variants by boolean fields of their
+//!   properties, i.e. `set_where(...)`, as [`EnumSet`](https://docs.rs/enumset)
+//!   consts
+//! * `phf`: enables parsing via a perfect hash map, i.e. `from_str(phf ...)`,
+//!   using [`phf`](https://docs.rs/phf)
+//! * `clap`: implements [`ValueEnum`](https://docs.rs/clap/latest/clap/trait.ValueEnum.html)
+//!   of [`clap`](https://docs.rs/clap) by the fields of the properties, i.e.
+//!   `value_enum(...)`, and implies `std`
+//! * `rand`: enables sampling variants randomly by a weight field of their
+//!   properties, i.e. `sample_weighted(...)`, via [`rand`](https://docs.rs/rand),
+//!   which requires a lazy backend
+//! * `serde`: enables serializing and deserializing enums as a string field
+//!   of their properties, i.e. `serialize_as(...)` and `deserialize_from(...)`,
+//!   via [`serde`](https://docs.rs/serde)
+//! * `strum`: enables iterating the variants of [`strum`](https://docs.rs/strum)
+//!   enums along with their properties, see [`iter_props`]
+//!
+//! Further, `lazy` properties require a lazy backend, which is selected via
+//! the crate features:
+//!
+//! * `lazy` (default): use [`lazy_static`](https://docs.rs/lazy_static)
+//! * `std-lazy`: use the standard library's
+//!   [`OnceLock`](https://doc.rust-lang.org/std/sync/struct.OnceLock.html),
+//!   without any third-party dependency (requires Rust 1.70)
+//! * `lazylock`: use the standard library's
+//!   [`LazyLock`](https://doc.rust-lang.org/std/sync/struct.LazyLock.html),
+//!   without any third-party dependency (requires Rust 1.80)
+//! * `once_cell`: use [`once_cell`](https://docs.rs/once_cell)'s `sync::Lazy`
+//! * `parking_lot`: like `once_cell`, but using
+//!   [`parking_lot`](https://docs.rs/parking_lot)'s synchronization
+//!   primitives, which may reduce the overhead when many threads race on the
+//!   first access
+//! * `no_std-lazy`: use [`spin`](https://docs.rs/spin)'s spin-lock based
+//!   `Lazy`, which works without std, e.g. on bare-metal targets. However,
+//!   threads racing on the first access will busy-wait, thus it is only
+//!   used if explicitly selected.
+//! * `unsync-lazy`: use unsynchronized thread local storage, which works on
+//!   single-threaded targets without atomics, e.g. `wasm32-unknown-unknown`.
+//!   This backend does not support multiple threads at all, and accessing a
+//!   `lazy` property from a second thread will panic. Thus, it should only be
+//!   enabled by the final binary crate.
+//!
+//! If multiple backends are enabled, `unsync-lazy` is used first, then
+//! `parking_lot`, `lazylock`, `std-lazy`, `once_cell`, and `no_std-lazy`, and
+//! `lazy` only if none of them is enabled. Using `lazy` properties without any backend
+//! results in a compile error.
+//!
+//! Finally, the `tracing` feature wraps the initialization of `lazy`
+//! properties in a [`tracing`](https://docs.rs/tracing) span named
+//! `enum_prop_init`, with the enum, the property, and the branch as fields.
 
 
 
@@ -103,256 +174,12928 @@
 /// assert_eq!(to_name(Bar::C), "Bar");
 /// ```
 ///
-pub trait EnumProp<Prop> {
+#[diagnostic::on_unimplemented(
+	message = "`{Self}` has no property of type `{Prop}`",
+	label = "no `{Prop}` property",
+	note = "implement it via `props!`, e.g. `impl EnumProp for {Self} as const {Prop} {{ ... }}`"
+)]
+pub trait EnumProp<Prop: ?Sized> {
 	fn property(&self) -> &'static Prop;
 }
 
-// For the macro
-#[doc(hidden)]
-pub use core::ops::Deref;
-
-// Could still be feature gated
-#[doc(hidden)]
-pub use lazy_static; // 1.4.0
-
-// The public front-end macro
-
-/// Adds a property onto an enum
+/// The trait that is implemented through [`props`] macro for `const`
+/// properties, via `impl EnumPropConst for`, along with [`EnumProp`].
 ///
-/// # Const, Static, Lazy
+/// Since trait methods can not be `const`, generic code can not call
+/// [`EnumProp::property`] in const contexts. Instead, this trait provides the
+/// properties of all match branches as an associated const, in the order of
+/// the branches, e.g. to compute a maximum across the enum at compile time.
+/// Notice, it has one entry per branch, not per variant, thus a branch
+/// matching several variants is listed once, and the wildcard branch is
+/// listed as well.
 ///
-/// This macro allows implement properties in three different ways:
-/// * as `const`, a constant
-/// * as `static`, a global variable
-/// * as `lazy`, a lazily initialized static
+/// # Example
 ///
-/// `const` and `static` are very similar, but have subtle difference:
-/// the property type put into a `static` must implement `Send`. However,
-/// with a `static` it is guaranteed that for each variant there is exactly one
-/// unique property value and thus a unique reference address.
-/// With `const` the compiler is allowed to merge properties (if they are equal)
-/// or to inline and instantiated the same logical property multiple times,
-/// i.e. the same logical property might be accessed via different reference
-/// addresses.
-/// In the very most cases, the actual reference address should not be of any
-/// concern and thus it is recommended to use `const` over `static`.
+/// ```
+/// use enumeraties::props;
+/// use enumeraties::EnumPropConst;
 ///
-/// One notable use-case for `static` is when the property contains interior
-/// mutability.
-/// In these cases, `const` shouldn't even compile.
+/// struct Prop {
+///     size: usize,
+/// }
 ///
-/// `lazy`, on the other hand, is quite different from the `const` and `static`.
-/// While `const` and `static` require constant initialized values computed at
-/// compile-time, `lazy` allows to evaluate the value lazily at runtime,
-/// instead. However, this feature incurs some overhead at each access, because
-/// it must be checked that the value was indeed already initialized.
-/// And of course, the first access to a `lazy` value, will incur the additional
-/// delay to initialize the value.
+/// // A generic `const fn` that works for any enum that has the `Prop` property
+/// const fn max_size<E: EnumPropConst<Prop>>() -> usize {
+///     let table = E::PROPERTY_TABLE;
+///     let mut max = 0;
+///     let mut i = 0;
+///     while i < table.len() {
+///         if table[i].size > max {
+///             max = table[i].size;
+///         }
+///         i += 1;
+///     }
+///     max
+/// }
 ///
+/// enum Foo {
+///     A,
+///     B,
+/// }
+/// props! {
+///     impl EnumPropConst for Foo as const Prop {
+///         Self::A => {
+///             size: 3,
+///         }
+///         Self::B => {
+///             size: 5,
+///         }
+///     }
+/// }
 ///
-/// # Syntax
+/// // E.g. as the length of a buffer fitting any variant
+/// let buffer = [0u8; max_size::<Foo>()];
+/// assert_eq!(buffer.len(), 5);
+/// ```
 ///
-/// This macro comes with essentially three different syntaxes: to implement
-/// `Deref` (for the primary property), add an inherent access method
-/// (for secondary properties), or just implementing `EnumProp` onto it (e.g.,
-/// if only used by generic code).
+#[diagnostic::on_unimplemented(
+	message = "`{Self}` does not implement `EnumPropConst<{Prop}>`",
+	note = "implement it via `props!`, e.g. `impl EnumPropConst for {Self} as const {Prop} {{ ... \
+	        }}`"
+)]
+pub trait EnumPropConst<Prop: ?Sized + 'static>: EnumProp<Prop> {
+	/// The properties of all match branches, in order
+	const PROPERTY_TABLE: &'static [&'static Prop];
+}
+
+/// The trait that is implemented through [`props`] macro for `const` and
+/// `static` properties, via `impl EnumPropAll for`, along with [`EnumProp`].
 ///
-/// ## Implementing [Deref](core::ops::Deref)
+/// It provides all variants along with their properties, in the order of the
+/// branches, e.g. to list them generically in a user interface. Therefore,
+/// all branches must name fieldless variants, i.e. there can be neither a
+/// wildcard branch nor branches matching variant data. For enums deriving
+/// `strum::EnumIter`, see also [`iter_props`] (with the `strum` crate
+/// feature), which works for any property and any branches.
 ///
-/// Syntax:
+/// # Example
 ///
-/// ```text
-/// impl Deref for <ENUM> as (const|static|lazy) <PROPERTY> {
-///     <VARIANT> => {
-///         <FIELD> : <VALUE>,
-///         ...
-///     },
-///     ...
+/// ```
+/// use enumeraties::props;
+/// use enumeraties::EnumPropAll;
+///
+/// struct Label {
+///     text: &'static str,
+/// }
+///
+/// #[derive(Debug, PartialEq)]
+/// enum Quality {
+///     Low,
+///     High,
+/// }
+/// props! {
+///     impl EnumPropAll for Quality as const Label {
+///         Self::Low => {
+///             text: "Low",
+///         }
+///         Self::High => {
+///             text: "High",
+///         }
+///     }
+/// }
+///
+/// // E.g. the options of a setting
+/// fn options<E: EnumPropAll<Label>>() -> impl Iterator<Item = &'static str> {
+///     E::all().iter().map(|(_, label)| label.text)
 /// }
+/// assert!(options::<Quality>().eq(["Low", "High"]));
+/// assert_eq!(Quality::all()[1].0, Quality::High);
 /// ```
 ///
-/// Example:
+/// Further, the variants can be looked up by a predicate on their properties,
+/// e.g. by a field given at runtime, via [`find_by`](EnumPropAll::find_by)
+/// for the first match and [`find_all_by`](EnumPropAll::find_all_by) for all
+/// of them, in the order of the branches. Both require the enum to be `Clone`.
 ///
 /// ```
-/// # use enumeraties::props;
-/// struct Prop { name: &'static str }
-/// enum Foo {A}
+/// use enumeraties::props;
+/// use enumeraties::EnumPropAll;
+///
+/// struct ShapeDef {
+///     vertices: u32,
+/// }
+///
+/// #[derive(Debug, Clone, PartialEq)]
+/// enum Shape {
+///     Triangle,
+///     Square,
+///     Rhombus,
+/// }
 /// props! {
-///     impl Deref for Foo as const Prop {
-///         Self::A => {
-///             name: "Foo",
+///     impl EnumPropAll for Shape as const ShapeDef {
+///         Self::Triangle => {
+///             vertices: 3,
+///         }
+///         Self::Square | Self::Rhombus => {
+///             vertices: 4,
 ///         }
 ///     }
 /// }
-/// // Direct access due to deref
-/// assert_eq!(Foo::A.name, "Foo");
+///
+/// let vertices = 3;
+/// assert_eq!(Shape::find_by(|def| def.vertices == vertices), Some(Shape::Triangle));
+/// assert!(Shape::find_all_by(|def| def.vertices == 4).eq([Shape::Square, Shape::Rhombus]));
 /// ```
 ///
+#[diagnostic::on_unimplemented(
+	message = "`{Self}` does not implement `EnumPropAll<{Prop}>`",
+	note = "implement it via `props!`, e.g. `impl EnumPropAll for {Self} as const {Prop} {{ ... \
+	        }}`"
+)]
+pub trait EnumPropAll<Prop: ?Sized + 'static>: EnumProp<Prop> + Sized + 'static {
+	/// All variants along with their properties, in order
+	fn all() -> &'static [(Self, &'static Prop)];
+
+	/// The first variant, in order, whose property matches the predicate
+	fn find_by(pred: impl Fn(&Prop) -> bool) -> Option<Self>
+	where
+		Self: Clone,
+	{
+		Self::all()
+			.iter()
+			.find(|(_, prop)| pred(prop))
+			.map(|(variant, _)| variant.clone())
+	}
+
+	/// All variants, in order, whose properties match the predicate
+	fn find_all_by(pred: impl Fn(&Prop) -> bool) -> impl Iterator<Item = Self>
+	where
+		Self: Clone,
+	{
+		Self::all()
+			.iter()
+			.filter(move |(_, prop)| pred(prop))
+			.map(|(variant, _)| variant.clone())
+	}
+}
+
+/// The [`EnumMap`](enum_map::EnumMap) of the properties of all variants,
+/// generated as a `static` via `enum_map` of the [`props`] macro.
 ///
-/// ## Implementing an inherent method
+/// The map is built lazily at its first access, via the lazy backend, and
+/// then dereferences to the same `&'static EnumMap` at each access. Its
+/// entries are the same references as returned by [`EnumProp::property`],
+/// e.g. for a `static` property, each entry has the address of the property
+/// of its variant.
 ///
-/// Syntax:
+/// Notice, this requires the `enum_map` crate feature, along with a lazy
+/// backend, and the enum must implement [`enum_map::Enum`] (e.g. via
+/// `#[derive(Enum)]`), and must not be generic.
 ///
-/// ```text
-/// impl <ENUM> : <VIS> fn <FN_NAME> as (const|static|lazy) <PROPERTY> {
-///     <VARIANT> => {
-///         <FIELD> : <VALUE>,
-///         ...
-///     },
-///     ...
+/// # Example
+///
+#[cfg_attr(feature = "lazy", doc = "```")]
+#[cfg_attr(not(feature = "lazy"), doc = "```ignore")]
+/// use enum_map::Enum;
+/// use enumeraties::props;
+///
+/// struct ShapeDef {
+///     vertices: usize,
 /// }
-/// ```
 ///
-/// Example:
+/// #[derive(Enum, Clone, Copy)]
+/// enum Shape {
+///     Triangle,
+///     Square,
+/// }
+/// props! {
+///     impl Deref for Shape as static ShapeDef {
+///         enum_map {
+///             pub static SHAPE_DEFS
+///         }
+///         Self::Triangle => {
+///             vertices: 3,
+///         }
+///         Self::Square => {
+///             vertices: 4,
+///         }
+///     }
+/// }
 ///
+/// assert_eq!(SHAPE_DEFS[Shape::Square].vertices, 4);
+/// assert!(core::ptr::eq(SHAPE_DEFS[Shape::Triangle], &*Shape::Triangle));
+/// assert_eq!(SHAPE_DEFS.values().map(|def| def.vertices).sum::<usize>(), 7);
 /// ```
-/// # use enumeraties::props;
-/// struct Prop { name: &'static str }
-/// enum Foo {A}
+///
+#[cfg(feature = "enum_map")]
+pub struct PropMap<E: enum_map::Enum<&'static Prop> + 'static, Prop: ?Sized + 'static> {
+	map: fn() -> &'static enum_map::EnumMap<E, &'static Prop>,
+}
+
+#[cfg(feature = "enum_map")]
+impl<E: enum_map::Enum<&'static Prop> + 'static, Prop: ?Sized + 'static> PropMap<E, Prop> {
+	// Used by the macro, do not use, its API may change at any time
+	#[doc(hidden)]
+	pub const fn internal_new(map: fn() -> &'static enum_map::EnumMap<E, &'static Prop>) -> Self {
+		Self {
+			map,
+		}
+	}
+}
+
+#[cfg(feature = "enum_map")]
+impl<E: enum_map::Enum<&'static Prop> + 'static, Prop: ?Sized + 'static> core::ops::Deref
+	for PropMap<E, Prop>
+{
+	type Target = enum_map::EnumMap<E, &'static Prop>;
+
+	fn deref(&self) -> &Self::Target {
+		(self.map)()
+	}
+}
+
+/// Iterates all variants of an enum along with their properties, using the
+/// [`IntoEnumIterator`](strum::IntoEnumIterator) of `strum`, e.g. via
+/// `#[derive(EnumIter)]`, see also [`PropIterExt::iter_with`].
+///
+/// This is the recommended way to get the whole table of properties at
+/// runtime, since it works for any property of any `strum` enum, without the
+/// [`props`] macro having to know the variants. In contrast to
+/// [`EnumPropAll`], it yields each variant, even if a branch matches several
+/// of them, in the order of the enum definition.
+///
+/// Notice, this requires the `strum` crate feature.
+///
+/// # Example
+///
+#[cfg_attr(feature = "strum", doc = "```")]
+#[cfg_attr(not(feature = "strum"), doc = "```ignore")]
+/// use enumeraties::props;
+/// use enumeraties::iter_props;
+/// use strum::EnumIter;
+///
+/// struct ShapeDef {
+///     vertices: usize,
+/// }
+///
+/// #[derive(EnumIter, Debug, PartialEq)]
+/// enum Shape {
+///     Triangle,
+///     Square,
+///     Rhombus,
+/// }
 /// props! {
-///     // Of course, an arbitrary function names can be used instead of `getter`
-///     impl Foo : fn getter as const Prop {
-///         Self::A => {
-///             name: "Foo",
+///     impl Deref for Shape as const ShapeDef {
+///         Self::Triangle => {
+///             vertices: 3,
+///         }
+///         Self::Square | Self::Rhombus => {
+///             vertices: 4,
 ///         }
 ///     }
 /// }
-/// // Access via inherent method
-/// assert_eq!(Foo::A.getter().name, "Foo");
+///
+/// let table: Vec<_> = iter_props::<Shape, ShapeDef>()
+///     .map(|(shape, def)| (shape, def.vertices))
+///     .collect();
+/// assert_eq!(table, [(Shape::Triangle, 3), (Shape::Square, 4), (Shape::Rhombus, 4)]);
 /// ```
 ///
-/// ## Implementing only `EnumProp`
+#[cfg(feature = "strum")]
+pub fn iter_props<E, P>() -> impl Iterator<Item = (E, &'static P)>
+where
+	E: strum::IntoEnumIterator + EnumProp<P>,
+	P: ?Sized + 'static,
+{
+	E::iter().map(|variant| {
+		let prop = variant.property();
+		(variant, prop)
+	})
+}
+
+/// An extension trait to iterate the variants of any `strum` enum along with
+/// a property, i.e. `E::iter_with::<P>()`, see [`iter_props`].
 ///
-/// Syntax:
+/// Notice, this requires the `strum` crate feature.
 ///
-/// ```text
-/// impl EnumProp for <ENUM> as (const|static|lazy) <PROPERTY> {
-///     <VARIANT> => {
-///         <FIELD> : <VALUE>,
-///         ...
-///     },
-///     ...
+/// # Example
+///
+#[cfg_attr(feature = "strum", doc = "```")]
+#[cfg_attr(not(feature = "strum"), doc = "```ignore")]
+/// use enumeraties::props;
+/// use enumeraties::PropIterExt;
+/// use strum::EnumIter;
+///
+/// struct Name(&'static str);
+///
+/// #[derive(EnumIter)]
+/// enum Shape {
+///     Triangle,
+///     Square,
+/// }
+/// props! {
+///     impl EnumProp for Shape as static Name {
+///         Self::Triangle => Name("triangle"),
+///         Self::Square => Name("square"),
+///     }
 /// }
+///
+/// assert!(Shape::iter_with::<Name>().map(|(_, name)| name.0).eq(["triangle", "square"]));
 /// ```
 ///
-/// Example:
+#[cfg(feature = "strum")]
+pub trait PropIterExt: strum::IntoEnumIterator {
+	/// All variants along with their property, in the order of the enum
+	fn iter_with<Prop: ?Sized + 'static>() -> impl Iterator<Item = (Self, &'static Prop)>
+	where
+		Self: EnumProp<Prop>;
+}
+
+#[cfg(feature = "strum")]
+impl<T: strum::IntoEnumIterator> PropIterExt for T {
+	fn iter_with<Prop: ?Sized + 'static>() -> impl Iterator<Item = (Self, &'static Prop)>
+	where
+		Self: EnumProp<Prop>,
+	{
+		iter_props()
+	}
+}
+
+/// The error of parsing an enum via the `FromStr` impl of `from_str(<FIELD>)`
+///
+/// It is returned if the string is not the value of the field of any variant.
 ///
 /// ```
 /// # use enumeraties::props;
+/// # use enumeraties::UnknownVariant;
 /// struct Prop { name: &'static str }
-/// enum Foo {A}
+/// enum Foo {A, B}
 /// props! {
-///     impl EnumProp for Foo as const Prop {
+///     impl Deref for Foo as const Prop {
+///         from_str(name)
 ///         Self::A => {
-///             name: "Foo",
+///             name: "a",
+///         }
+///         Self::B => {
+///             name: "b",
 ///         }
 ///     }
 /// }
-/// // Access via universal function call
-/// use enumeraties::EnumProp;
-/// assert_eq!(EnumProp::<Prop>::property(&Foo::A).name, "Foo");
+/// assert!(matches!("b".parse(), Ok(Foo::B)));
+/// assert_eq!("c".parse::<Foo>().err(), Some(UnknownVariant));
 /// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct UnknownVariant;
+
+impl core::fmt::Display for UnknownVariant {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		f.write_str("the string does not name any variant")
+	}
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for UnknownVariant {}
+
+/// The error of converting a value into an enum via the `TryFrom` impl of
+/// `try_from(<FIELD>: <TYPE>)`
 ///
-#[macro_export]
-macro_rules! props {
-	(
-		// A lazy/const impl that will be promoted to `Deref` (also impls `EnumProp`)
-		impl Deref for $enum_name:ty as $modifier:ident $prop_name:path { $($matching:tt)* }
-	) => {
-		// Add the EnumProp impl
-		$crate::internal_props_impl_macro!{
-			@EnumProp
-			mod($modifier) ($prop_name) for $enum_name {
-				$($matching)*
-			}
-		}
+/// It holds the value, which is not the value of the field of any variant.
+///
+/// ```
+/// # use enumeraties::props;
+/// # use enumeraties::UnknownValue;
+/// struct Prop { code: u8 }
+/// #[derive(Debug)]
+/// enum Foo {A, B}
+/// props! {
+///     impl Deref for Foo as const Prop {
+///         try_from(code: u8)
+///         Self::A => {
+///             code: 1,
+///         }
+///         Self::B => {
+///             code: 2,
+///         }
+///     }
+/// }
+/// assert!(matches!(Foo::try_from(2), Ok(Foo::B)));
+/// assert_eq!(Foo::try_from(3).err(), Some(UnknownValue(3)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct UnknownValue<T>(pub T);
 
-		// Add the deref forwarding
-		impl $crate::Deref for $enum_name {
-			type Target = $prop_name;
-			fn deref(&self) -> &Self::Target {
-				$crate::EnumProp::<$prop_name>::property(self)
-			}
-		}
+impl<T: core::fmt::Display> core::fmt::Display for UnknownValue<T> {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		write!(f, "the value {} does not belong to any variant", self.0)
+	}
+}
+
+#[cfg(feature = "std")]
+impl<T: core::fmt::Debug + core::fmt::Display> std::error::Error for UnknownValue<T> {}
+
+// Used by the macro, do not use, its API may change at any time
+#[doc(hidden)]
+#[diagnostic::on_unimplemented(
+	message = "`{Self}` can not be aggregated at compile time",
+	note = "only fields of primitive number types, e.g. `u32` or `f64`, can be aggregated"
+)]
+pub trait InternalAggregate {}
+
+macro_rules! internal_aggregate_impls {
+	($($ty:ty),*) => {
+		$(
+			impl InternalAggregate for $ty {}
+		)*
 	};
-	(
-		// The lazy/const impl via inherent method (also impls `EnumProp`)
-		impl $enum_name:ty : $fn_vis:vis fn $fn_name:ident as $modifier:ident $prop_name:path { $($matching:tt)* }
-	) => {
-		// Add the EnumProp impl
-		$crate::internal_props_impl_macro!{
-			@EnumProp
-			mod($modifier) ($prop_name) for $enum_name {
-				$($matching)*
+}
+
+internal_aggregate_impls!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+
+// Used by the macro, do not use, its API may change at any time
+#[doc(hidden)]
+pub struct InternalUnique<'a, T: ?Sized>(pub &'a T);
+
+macro_rules! internal_unique_impls {
+	($($ty:ty),*) => {
+		$(
+			impl InternalUnique<'_, $ty> {
+				pub const fn is_eq(self, other: &$ty) -> bool {
+					*self.0 == *other
+				}
 			}
-		}
+		)*
+	};
+}
 
-		// Add the inherent method forwarding
-		impl $enum_name {
-			$fn_vis fn $fn_name(&self) -> &'static $prop_name {
-				$crate::EnumProp::<$prop_name>::property(self)
+internal_unique_impls!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, char, bool);
+
+impl InternalUnique<'_, &str> {
+	pub const fn is_eq(self, other: &&str) -> bool {
+		let (a, b) = (self.0.as_bytes(), other.as_bytes());
+		if a.len() != b.len() {
+			return false;
+		}
+		let mut i = 0;
+		while i < a.len() {
+			if a[i] != b[i] {
+				return false;
 			}
+			i += 1;
 		}
-	};
-	(
-		// The lazy/const impl `EnumProp` only
-		impl EnumProp for $enum_name:ty as $modifier:ident $prop_name:path { $($matching:tt)* }
-	) => {
+		true
+	}
+}
+
+// Used by the macro, do not use, its API may change at any time
+//
+// Comparing the fields of the derived comparisons, taking the field of `self`
+// alone first, thus a missing impl of the field points at it
+#[doc(hidden)]
+pub fn internal_partial_eq<T: ?Sized + PartialEq>(a: &T) -> impl Fn(&T) -> bool + '_ {
+	move |b| a == b
+}
+
+// Used by the macro, do not use, its API may change at any time
+#[doc(hidden)]
+pub fn internal_eq<T: ?Sized + Eq>(a: &T) -> impl Fn(&T) -> bool + '_ {
+	move |b| a == b
+}
+
+// Used by the macro, do not use, its API may change at any time
+#[doc(hidden)]
+pub fn internal_partial_cmp<T: ?Sized + PartialOrd>(
+	a: &T,
+) -> impl Fn(&T) -> Option<core::cmp::Ordering> + '_ {
+	move |b| a.partial_cmp(b)
+}
+
+// Used by the macro, do not use, its API may change at any time
+//
+// Copying the field of a conversion, thus a field that is not `Copy` is
+// pointed at
+#[doc(hidden)]
+pub fn internal_copy<T: Copy>(field: &T) -> T {
+	*field
+}
+
+// Used by the macro, do not use, its API may change at any time
+//
+// The address of a static property, compared and hashed by the identity of
+// the variants. Any metadata (i.e. the vtable of a `dyn` property) is dropped,
+// since it is not unique.
+#[doc(hidden)]
+pub fn internal_address<T: ?Sized>(prop: &'static T) -> *const () {
+	prop as *const T as *const ()
+}
+
+// Used by the macro, do not use, its API may change at any time
+#[doc(hidden)]
+pub fn internal_cmp<T: ?Sized + Ord>(a: &T) -> impl Fn(&T) -> core::cmp::Ordering + '_ {
+	move |b| a.cmp(b)
+}
+
+// Used by the macro, do not use, its API may change at any time
+//
+// Copying the variant indexing the `table`, which is only implemented for
+// `Copy` enums, thus others are pointed at instead of the move out of `self`
+#[doc(hidden)]
+#[diagnostic::on_unimplemented(
+	message = "the `table` requires `{Self}` to be `Copy`, to index it by the discriminant",
+	label = "the enum of the `table` must be `Copy`"
+)]
+pub trait InternalTableCopy {
+	fn internal_copy(&self) -> Self;
+}
+
+impl<T: Copy> InternalTableCopy for T {
+	fn internal_copy(&self) -> Self {
+		*self
+	}
+}
+
+// Used by the macro, do not use, its API may change at any time
+//
+// Serializing the field of `serialize_as`, which is only implemented for
+// `&'static str`, thus any other field is pointed at
+#[cfg(feature = "serde")]
+#[doc(hidden)]
+#[diagnostic::on_unimplemented(
+	message = "the field of `serialize_as` must be a `&'static str`, found `{Self}`"
+)]
+pub trait InternalSerializeAs {
+	fn internal_serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>;
+}
+
+#[cfg(feature = "serde")]
+impl InternalSerializeAs for &'static str {
+	fn internal_serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		serializer.serialize_str(self)
+	}
+}
+
+// Used by the macro, do not use, its API may change at any time
+//
+// Writing the field of `defmt`, which is only implemented for `&'static str`,
+// thus any other field is pointed at. The `write!` is expanded here, since it
+// refers to the `defmt` crate by its name.
+#[cfg(feature = "defmt")]
+#[doc(hidden)]
+#[diagnostic::on_unimplemented(
+	message = "the field of `defmt` must be a `&'static str`, found `{Self}`"
+)]
+pub trait InternalDefmtStr {
+	fn internal_format(&self, fmt: defmt::Formatter<'_>);
+}
+
+#[cfg(feature = "defmt")]
+impl InternalDefmtStr for &'static str {
+	fn internal_format(&self, fmt: defmt::Formatter<'_>) {
+		defmt::write!(fmt, "{=str}", self)
+	}
+}
+
+// Used by the macro, do not use, its API may change at any time
+//
+// The cumulative weights of `sample_weighted`, checking each weight once
+#[cfg(feature = "rand")]
+#[doc(hidden)]
+pub fn internal_cumulative<const N: usize>(weights: [f32; N]) -> [f32; N] {
+	let mut total = 0.0;
+	weights.map(|weight| {
+		assert!(
+			weight >= 0.0 && weight.is_finite(),
+			"the weights of `sample_weighted` must be finite and not negative, found {weight}"
+		);
+		total += weight;
+		total
+	})
+}
+
+// Used by the macro, do not use, its API may change at any time
+//
+// The index of a weighted random variant, i.e. the first whose cumulative
+// weight exceeds a random value below the total, thus a zero weight is never
+// picked
+#[cfg(feature = "rand")]
+#[doc(hidden)]
+pub fn internal_sample_index<R: rand::Rng + ?Sized>(cumulative: &[f32], rng: &mut R) -> usize {
+	let total = cumulative.last().copied().unwrap_or(0.0);
+	assert!(
+		total > 0.0,
+		"the `sample_weighted` requires a positive weight for some variant, but all weights are \
+		 zero"
+	);
+	let value = rng.random::<f32>() * total;
+	let index = cumulative.partition_point(|&weight| weight <= value);
+	if index < cumulative.len() {
+		index
+	} else {
+		// Rounded up to the total, thus the last variant with a positive weight
+		cumulative.partition_point(|&weight| weight < total)
+	}
+}
+
+// Used by the macro, do not use, its API may change at any time
+//
+// The visitor of `deserialize_from`, mapping a name to its variant, and
+// listing the expected names otherwise
+#[cfg(feature = "serde")]
+#[doc(hidden)]
+pub struct InternalNameVisitor<T> {
+	names: &'static [&'static str],
+	from_name: fn(&str) -> Option<T>,
+}
+
+#[cfg(feature = "serde")]
+impl<T> InternalNameVisitor<T> {
+	pub fn new(names: &'static [&'static str], from_name: fn(&str) -> Option<T>) -> Self {
+		Self {
+			names,
+			from_name,
+		}
+	}
+}
+
+#[cfg(feature = "serde")]
+impl<T> serde::de::Visitor<'_> for InternalNameVisitor<T> {
+	type Value = T;
+
+	fn expecting(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		f.write_str("the name of a variant")
+	}
+
+	fn visit_str<E: serde::de::Error>(self, name: &str) -> Result<T, E> {
+		(self.from_name)(name).ok_or_else(|| E::unknown_variant(name, self.names))
+	}
+}
+
+// Used by the macro, do not use, its API may change at any time
+//
+// A message concatenated in a const, since const panics can only format a
+// single `&str`, truncated if it does not fit
+#[doc(hidden)]
+pub struct InternalMessage {
+	bytes: [u8; 256],
+	len: usize,
+}
+
+impl InternalMessage {
+	pub const fn new(parts: &[&str]) -> Self {
+		let mut bytes = [0; 256];
+		let mut len = 0;
+		let mut i = 0;
+		// Only whole parts, thus it stays valid UTF-8
+		while i < parts.len() && len + parts[i].len() <= bytes.len() {
+			let part = parts[i].as_bytes();
+			let mut j = 0;
+			while j < part.len() {
+				bytes[len] = part[j];
+				len += 1;
+				j += 1;
+			}
+			i += 1;
+		}
+		Self {
+			bytes,
+			len,
+		}
+	}
+
+	pub const fn as_str(&self) -> &str {
+		match core::str::from_utf8(self.bytes.split_at(self.len).0) {
+			Ok(message) => message,
+			Err(_) => "",
+		}
+	}
+}
+
+/// The trait that is additionally implemented through [`props`] macro for
+/// `lazy` properties.
+///
+/// It allows to query whether the property of a variant has already been
+/// initialized, without triggering its initialization, e.g. for diagnostics.
+///
+/// # Example
+///
+#[cfg_attr(feature = "lazy", doc = "```")]
+#[cfg_attr(not(feature = "lazy"), doc = "```ignore")]
+/// use enumeraties::props;
+/// use enumeraties::LazyEnumProp;
+///
+/// struct Prop {
+///     name: &'static str,
+/// }
+///
+/// enum Foo {
+///     A,
+///     B,
+/// }
+/// props! {
+///     impl Deref for Foo as lazy Prop {
+///         Self::A => {
+///             name: "Foo",
+///         }
+///         Self::B => {
+///             name: "Bar",
+///         }
+///     }
+/// }
+///
+/// assert!(!Foo::A.is_property_initialized());
+/// assert_eq!(Foo::A.name, "Foo");
+/// assert!(Foo::A.is_property_initialized());
+/// // Each variant is initialized independently
+/// assert!(!Foo::B.is_property_initialized());
+/// ```
+///
+pub trait LazyEnumProp<Prop: 'static>: EnumProp<Prop> {
+	/// Returns the property, if it has already been initialized
+	fn try_property(&self) -> Option<&'static Prop> {
+		self.internal_lazy_property(false)
+	}
+
+	/// Returns whether the property has already been initialized
+	fn is_property_initialized(&self) -> bool {
+		self.try_property().is_some()
+	}
+
+	// Used by the macro, do not use, its API may change at any time
+	#[doc(hidden)]
+	fn internal_lazy_property(&self, init: bool) -> Option<&'static Prop>;
+}
+
+/// The trait that is implemented through [`props`] macro for `lazy`
+/// properties, if all match branches consist only of fieldless variants.
+///
+/// It allows to initialize the property of all variants at once, e.g. to
+/// avoid the initialization delay at the first access later on.
+///
+/// # Example
+///
+#[cfg_attr(feature = "lazy", doc = "```")]
+#[cfg_attr(not(feature = "lazy"), doc = "```ignore")]
+/// use enumeraties::props;
+/// use enumeraties::EnumPropInit;
+/// use enumeraties::LazyEnumProp;
+///
+/// struct Prop {
+///     name: &'static str,
+/// }
+///
+/// enum Foo {
+///     A,
+///     B,
+/// }
+/// props! {
+///     impl Deref for Foo as lazy Prop {
+///         Self::A => {
+///             name: "Foo",
+///         }
+///         Self::B => {
+///             name: "Bar",
+///         }
+///     }
+/// }
+///
+/// <Foo as EnumPropInit<Prop>>::initialize_all();
+/// assert!(Foo::A.is_property_initialized());
+/// assert!(Foo::B.is_property_initialized());
+/// ```
+///
+#[diagnostic::on_unimplemented(
+	message = "`{Self}` does not implement `EnumPropInit<{Prop}>`",
+	note = "`EnumPropInit` is only implemented for `lazy` properties, if all match branches \
+	        consist only of fieldless variants, e.g. `Self::A | Self::B`"
+)]
+pub trait EnumPropInit<Prop: 'static>: LazyEnumProp<Prop> {
+	/// Initializes the property of all variants
+	fn initialize_all();
+}
+
+/// An extension trait to initialize the property of all variants on a
+/// background thread, i.e. [`EnumPropInit::initialize_all`] on a new thread.
+///
+/// This allows to warm up the properties while the application keeps
+/// starting up. Accessing a property, while it is being initialized, blocks
+/// until its initialization is complete, while the properties of the other
+/// variants can be accessed meanwhile. Notice, this can not be used with
+/// the `unsync-lazy` backend, since it does not support multiple threads.
+/// This requires the `std` crate feature.
+///
+/// # Example
+///
+#[cfg_attr(
+	all(feature = "std", feature = "lazy", not(feature = "unsync-lazy")),
+	doc = "```"
+)]
+#[cfg_attr(
+	not(all(feature = "std", feature = "lazy", not(feature = "unsync-lazy"))),
+	doc = "```ignore"
+)]
+/// use enumeraties::props;
+/// use enumeraties::EnumPropWarmup;
+/// use enumeraties::LazyEnumProp;
+///
+/// struct Prop {
+///     name: String,
+/// }
+///
+/// enum Foo {
+///     A,
+///     B,
+/// }
+/// props! {
+///     impl Deref for Foo as lazy Prop {
+///         Self::A => {
+///             name: "Foo".repeat(2),
+///         }
+///         Self::B => {
+///             name: "Bar".repeat(2),
+///         }
+///     }
+/// }
+///
+/// let warmup = <Foo as EnumPropWarmup<Prop>>::spawn_property_warmup();
+/// // Other work, while the properties are initialized
+/// warmup.join().unwrap();
+/// assert!(Foo::A.is_property_initialized());
+/// assert!(Foo::B.is_property_initialized());
+/// ```
+///
+#[cfg(feature = "std")]
+pub trait EnumPropWarmup<Prop: 'static>: EnumPropInit<Prop> + 'static {
+	/// Initializes the property of all variants on a background thread
+	fn spawn_property_warmup() -> std::thread::JoinHandle<()>;
+}
+
+#[cfg(feature = "std")]
+impl<T: EnumPropInit<Prop> + 'static, Prop: 'static> EnumPropWarmup<Prop> for T {
+	fn spawn_property_warmup() -> std::thread::JoinHandle<()> {
+		std::thread::spawn(Self::initialize_all)
+	}
+}
+
+/// The trait that is additionally implemented through [`props`] macro for
+/// `memo` properties.
+///
+/// It allows to query how many properties have been computed and cached so
+/// far, i.e. for how many distinct values of the enum, e.g. to monitor the
+/// growth of the cache, which is never cleared.
+/// This requires the `std` crate feature.
+///
+/// # Example
+///
+#[cfg_attr(feature = "std", doc = "```")]
+#[cfg_attr(not(feature = "std"), doc = "```ignore")]
+/// use enumeraties::props;
+/// use enumeraties::MemoEnumProp;
+///
+/// struct Table {
+///     squares: Vec<u32>,
+/// }
+///
+/// #[derive(Clone, PartialEq, Eq, Hash)]
+/// enum Foo {
+///     A(u32),
+/// }
+/// props! {
+///     impl Deref for Foo as memo Table {
+///         Self::A(n) => {
+///             squares: (0..*n).map(|i| i * i).collect(),
+///         }
+///     }
+/// }
+///
+/// assert_eq!(Foo::A(4).squares, [0, 1, 4, 9]);
+/// assert_eq!(Foo::A(8).squares.len(), 8);
+/// // The same value shares the same property
+/// assert!(core::ptr::eq(&*Foo::A(4), &*Foo::A(4)));
+/// assert_eq!(<Foo as MemoEnumProp<Table>>::memo_len(), 2);
+/// ```
+///
+#[cfg(feature = "std")]
+pub trait MemoEnumProp<Prop: 'static>: EnumProp<Prop> {
+	/// Returns the number of properties computed and cached so far
+	fn memo_len() -> usize;
+}
+
+/// The trait that is implemented through [`props`] macro for `ref`
+/// properties, as well as for all implementors of [`EnumProp`].
+///
+/// Unlike with [`EnumProp`], the property is only borrowed for the lifetime
+/// of the enum value, which allows properties that borrow from the data of
+/// the variant, e.g. a property struct containing `&'a str` slices of a
+/// `Token<'a>`. Thus, generic code that does not need `&'static` references
+/// may use this trait instead, accepting both kinds of properties.
+///
+/// # Example
+///
+/// ```
+/// use enumeraties::props;
+/// use enumeraties::EnumPropRef;
+///
+/// struct Span<'a> {
+///     text: &'a str,
+/// }
+///
+/// enum Token<'a> {
+///     Ident(Span<'a>),
+///     Eof,
+/// }
+/// props! {
+///     impl<'a> EnumPropRef for Token<'a> as ref const Span<'a> {
+///         Self::Ident(span) => span,
+///         Self::Eof => {
+///             text: "",
+///         }
+///     }
+/// }
+///
+/// fn text<'a>(value: &impl EnumPropRef<Span<'a>>) -> &'a str {
+///     value.property_ref().text
+/// }
+///
+/// let source = String::from("foo");
+/// assert_eq!(text(&Token::Ident(Span { text: &source })), "foo");
+/// assert_eq!(text(&Token::Eof), "");
+/// ```
+///
+pub trait EnumPropRef<Prop: ?Sized> {
+	fn property_ref(&self) -> &Prop;
+}
+
+impl<Prop: ?Sized + 'static, T: EnumProp<Prop> + ?Sized> EnumPropRef<Prop> for T {
+	fn property_ref(&self) -> &Prop {
+		self.property()
+	}
+}
+
+/// An extension trait to access any property by its type, implemented for all
+/// types.
+///
+/// If an enum has several properties, calling [`EnumProp::property`] requires
+/// to name the property, e.g. via `EnumProp::<Prop>::property(&value)`.
+/// Instead, this trait allows to select the property via a turbofish, i.e.
+/// `value.prop::<Prop>()`, without naming distinct inherent methods per
+/// property. It is also part of the [`prelude`], thus importing the prelude
+/// provides the method on all enums.
+///
+/// # Example
+///
+/// ```
+/// use enumeraties::prelude::*;
+///
+/// struct Name(&'static str);
+/// struct Size(u32);
+///
+/// enum Shape {
+///     Triangle,
+///     Square,
+/// }
+/// props! {
+///     impl EnumProp for Shape as const Name {
+///         Self::Triangle => Name("triangle"),
+///         Self::Square => Name("square"),
+///     }
+/// }
+/// props! {
+///     impl EnumProp for Shape as const Size {
+///         Self::Triangle => Size(3),
+///         Self::Square => Size(4),
+///     }
+/// }
+///
+/// assert_eq!(Shape::Triangle.prop::<Name>().0, "triangle");
+/// assert_eq!(Shape::Square.prop::<Size>().0, 4);
+/// ```
+///
+pub trait PropExt {
+	fn prop<Prop: ?Sized>(&self) -> &'static Prop
+	where
+		Self: EnumProp<Prop>;
+}
+
+impl<T: ?Sized> PropExt for T {
+	fn prop<Prop: ?Sized>(&self) -> &'static Prop
+	where
+		Self: EnumProp<Prop>,
+	{
+		self.property()
+	}
+}
+
+/// A trait to access two properties at once, implemented for all types that
+/// implement [`EnumProp`] for both of them.
+///
+/// Generic code that needs several properties of the same enum can bound on
+/// this trait instead of on each `EnumProp`, and gets all of them via a single
+/// call. See also [`EnumProps3`] and [`EnumProps4`] for more properties.
+///
+/// # Example
+///
+/// ```
+/// use enumeraties::props;
+/// use enumeraties::EnumProps2;
+///
+/// struct RenderInfo { color: &'static str }
+/// struct Physics { mass: u32 }
+///
+/// enum Shape {
+///     Triangle,
+///     Square,
+/// }
+/// props! {
+///     impl EnumProp for Shape as const RenderInfo {
+///         Self::Triangle => {
+///             color: "red",
+///         }
+///         Self::Square => {
+///             color: "blue",
+///         }
+///     }
+/// }
+/// props! {
+///     impl EnumProp for Shape as static Physics {
+///         Self::Triangle => {
+///             mass: 3,
+///         }
+///         Self::Square => {
+///             mass: 4,
+///         }
+///     }
+/// }
+///
+/// fn describe<E: EnumProps2<RenderInfo, Physics>>(value: &E) -> (&'static str, u32) {
+///     let (render, physics) = value.properties();
+///     (render.color, physics.mass)
+/// }
+/// assert_eq!(describe(&Shape::Square), ("blue", 4));
+/// ```
+///
+pub trait EnumProps2<A: ?Sized, B: ?Sized> {
+	fn properties(&self) -> (&'static A, &'static B);
+}
+
+impl<A: ?Sized, B: ?Sized, T: EnumProp<A> + EnumProp<B> + ?Sized> EnumProps2<A, B> for T {
+	fn properties(&self) -> (&'static A, &'static B) {
+		(EnumProp::<A>::property(self), EnumProp::<B>::property(self))
+	}
+}
+
+/// A trait to access three properties at once, like [`EnumProps2`].
+pub trait EnumProps3<A: ?Sized, B: ?Sized, C: ?Sized> {
+	fn properties(&self) -> (&'static A, &'static B, &'static C);
+}
+
+impl<A: ?Sized, B: ?Sized, C: ?Sized, T: EnumProp<A> + EnumProp<B> + EnumProp<C> + ?Sized>
+	EnumProps3<A, B, C> for T
+{
+	fn properties(&self) -> (&'static A, &'static B, &'static C) {
+		(
+			EnumProp::<A>::property(self),
+			EnumProp::<B>::property(self),
+			EnumProp::<C>::property(self),
+		)
+	}
+}
+
+/// A trait to access four properties at once, like [`EnumProps2`].
+pub trait EnumProps4<A: ?Sized, B: ?Sized, C: ?Sized, D: ?Sized> {
+	fn properties(&self) -> (&'static A, &'static B, &'static C, &'static D);
+}
+
+impl<
+		A: ?Sized,
+		B: ?Sized,
+		C: ?Sized,
+		D: ?Sized,
+		T: EnumProp<A> + EnumProp<B> + EnumProp<C> + EnumProp<D> + ?Sized,
+	> EnumProps4<A, B, C, D> for T
+{
+	fn properties(&self) -> (&'static A, &'static B, &'static C, &'static D) {
+		(
+			EnumProp::<A>::property(self),
+			EnumProp::<B>::property(self),
+			EnumProp::<C>::property(self),
+			EnumProp::<D>::property(self),
+		)
+	}
+}
+
+/// Checks whether two enum values have their property `P` at the same
+/// address, i.e. whether they share the same property instance.
+///
+/// For `static`, `lazy`, and `eager` properties, this holds exactly for the
+/// variants of the same branch, including all variants of an or-pattern, for
+/// branches merged by `dedup`, and for branches referring to the same
+/// `static` item (see [`props`]). For `memo` properties, it holds for equal
+/// values. However, for `const` properties (and `const` branches), the result
+/// is not meaningful, since the compiler may merge equal constants or
+/// duplicate the same one. Also notice, that zero-sized properties may share
+/// their address even if they are distinct `static`s.
+///
+/// The metadata of unsized properties, e.g. the vtable of a `dyn` property,
+/// is ignored, since it is not unique either.
+///
+/// # Example
+///
+/// ```
+/// use enumeraties::props;
+/// use enumeraties::prop_ptr_eq;
+///
+/// struct Prop { name: &'static str }
+///
+/// enum Foo {A, B, C}
+/// props! {
+///     impl EnumProp for Foo as static Prop {
+///         Self::A | Self::B => {
+///             name: "either",
+///         }
+///         Self::C => {
+///             name: "other",
+///         }
+///     }
+/// }
+///
+/// assert!(prop_ptr_eq::<Prop>(&Foo::A, &Foo::B));
+/// assert!(!prop_ptr_eq::<Prop>(&Foo::A, &Foo::C));
+/// ```
+///
+pub fn prop_ptr_eq<P: ?Sized + 'static>(
+	a: &(impl EnumProp<P> + ?Sized),
+	b: &(impl EnumProp<P> + ?Sized),
+) -> bool {
+	internal_address(a.property()) == internal_address(b.property())
+}
+
+/// Asserts that the given enum values share the same instance of the
+/// property, i.e. at the same address, see [`prop_ptr_eq`].
+///
+/// The property type is given first, followed by the values, which may be of
+/// distinct enums, e.g. `assert_shared_props!(Prop: Foo::A, Foo::B)`.
+///
+/// # Example
+///
+/// ```
+/// use enumeraties::props;
+/// use enumeraties::assert_shared_props;
+///
+/// struct Prop { name: &'static str }
+///
+/// enum Foo {A, B(u8)}
+/// props! {
+///     impl EnumProp for Foo as static Prop {
+///         Self::A | Self::B(_) => {
+///             name: "shared",
+///         }
+///     }
+/// }
+///
+/// assert_shared_props!(Prop: Foo::A, Foo::B(1), Foo::B(2));
+/// ```
+///
+#[macro_export]
+macro_rules! assert_shared_props {
+	($prop:ty : $($value:expr),+ $(,)?) => {{
+		let addresses = [$(
+			$crate::internal_address($crate::EnumProp::<$prop>::property(&$value))
+		),+];
+		let names = [$(stringify!($value)),+];
+		for (address, name) in addresses.iter().zip(&names).skip(1) {
+			if *address != addresses[0] {
+				panic!(
+					"assertion failed: the `{}` of `{}` and `{}` are not shared",
+					stringify!($prop),
+					names[0],
+					name,
+				);
+			}
+		}
+	}};
+}
+
+/// Asserts that the given enum values have pairwise distinct instances of
+/// the property, i.e. at distinct addresses, see [`prop_ptr_eq`].
+///
+/// The property type is given first, followed by the values, which may be of
+/// distinct enums, e.g. `assert_distinct_props!(Prop: Foo::A, Foo::B)`.
+///
+/// # Example
+///
+/// ```
+/// use enumeraties::props;
+/// use enumeraties::assert_distinct_props;
+///
+/// struct Prop { name: &'static str }
+///
+/// enum Foo {A, B, C}
+/// props! {
+///     impl EnumProp for Foo as static Prop {
+///         Self::A => {
+///             name: "a",
+///         }
+///         Self::B => {
+///             name: "b",
+///         }
+///         Self::C => {
+///             name: "c",
+///         }
+///     }
+/// }
+///
+/// assert_distinct_props!(Prop: Foo::A, Foo::B, Foo::C);
+/// ```
+///
+#[macro_export]
+macro_rules! assert_distinct_props {
+	($prop:ty : $($value:expr),+ $(,)?) => {{
+		let addresses = [$(
+			$crate::internal_address($crate::EnumProp::<$prop>::property(&$value))
+		),+];
+		let names = [$(stringify!($value)),+];
+		for (i, (address, name)) in addresses.iter().zip(&names).enumerate() {
+			for (other, other_name) in addresses.iter().zip(&names).skip(i + 1) {
+				if address == other {
+					panic!(
+						"assertion failed: the `{}` of `{}` and `{}` are shared",
+						stringify!($prop),
+						name,
+						other_name,
+					);
+				}
+			}
+		}
+	}};
+}
+
+/// The prelude of this crate, importing the [`props`] macro along with the
+/// traits that provide the methods to access properties, e.g. [`PropExt`].
+///
+/// ```
+/// use enumeraties::prelude::*;
+/// ```
+pub mod prelude {
+	pub use crate::props;
+	pub use crate::EnumProp;
+	pub use crate::EnumPropRef;
+	pub use crate::PropExt;
+	#[cfg(feature = "strum")]
+	pub use crate::PropIterExt;
+}
+
+/// The trait that is implemented through [`props`] macro for keyed
+/// properties.
+///
+/// Keyed properties depend on both the variant and a runtime key, e.g. the
+/// locale of a text, thus they are accessed via the key, still as a
+/// `&'static` reference. Each branch matches the variant along with the key,
+/// and the match must cover all pairs of them.
+///
+/// # Example
+///
+/// ```
+/// use enumeraties::props;
+/// use enumeraties::EnumPropKeyed;
+///
+/// struct Text {
+///     text: &'static str,
+/// }
+///
+/// enum Locale {
+///     En,
+///     De,
+/// }
+///
+/// enum Shape {
+///     Triangle,
+///     Square,
+/// }
+/// props! {
+///     impl EnumPropKeyed<Locale> for Shape as const Text {
+///         (Self::Triangle, Locale::En) => {
+///             text: "triangle",
+///         }
+///         (Self::Triangle, Locale::De) => {
+///             text: "Dreieck",
+///         }
+///         // Any key
+///         (Self::Square, _) => {
+///             text: "square",
+///         }
+///     }
+/// }
+///
+/// assert_eq!(Shape::Triangle.property_for(Locale::De).text, "Dreieck");
+/// assert_eq!(Shape::Square.property_for(Locale::De).text, "square");
+/// ```
+///
+pub trait EnumPropKeyed<Key, Prop: ?Sized> {
+	fn property_for(&self, key: Key) -> &'static Prop;
+}
+
+/// The trait that is implemented through [`props`] macro for tagged
+/// properties.
+///
+/// Tagged properties are distinguished by a marker type, the tag, in addition
+/// to the property type, thus the same property type can be defined several
+/// times on the same enum, once per tag. The tag is never constructed, it only
+/// selects the impl, e.g. via `EnumPropTagged::<Tag, _>::property_tagged`.
+///
+/// # Example
+///
+/// ```
+/// use enumeraties::props;
+/// use enumeraties::EnumPropTagged;
+///
+/// struct Limits {
+///     max: u32,
+/// }
+///
+/// // The tags
+/// enum Soft {}
+/// enum Hard {}
+///
+/// enum Plan {
+///     Free,
+///     Pro,
+/// }
+/// props! {
+///     impl EnumPropTagged for Plan as const Limits @ Soft {
+///         Self::Free => {
+///             max: 10,
+///         }
+///         Self::Pro => {
+///             max: 100,
+///         }
+///     }
+/// }
+/// props! {
+///     // Along with an inherent method
+///     impl Plan : fn hard_limits as const Limits @ Hard {
+///         Self::Free => {
+///             max: 20,
+///         }
+///         Self::Pro => {
+///             max: 200,
+///         }
+///     }
+/// }
+///
+/// fn soft_max<E: EnumPropTagged<Soft, Limits>>(value: &E) -> u32 {
+///     value.property_tagged().max
+/// }
+/// assert_eq!(soft_max(&Plan::Free), 10);
+/// assert_eq!(EnumPropTagged::<Hard, Limits>::property_tagged(&Plan::Pro).max, 200);
+/// assert_eq!(Plan::Pro.hard_limits().max, 200);
+/// ```
+///
+#[diagnostic::on_unimplemented(
+	message = "`{Self}` has no property of type `{Prop}` tagged by `{Tag}`",
+	note = "implement it via `props!`, e.g. `impl EnumPropTagged for {Self} as const {Prop} @ \
+	        {Tag} {{ ... }}`"
+)]
+pub trait EnumPropTagged<Tag: ?Sized, Prop: ?Sized> {
+	fn property_tagged(&self) -> &'static Prop;
+}
+
+/// The trait that is implemented through [`props`] macro for pairwise
+/// properties.
+///
+/// Pairwise properties relate two variants of the same enum, e.g. the ratio of
+/// the areas of two shapes, thus they are accessed via the other value, still
+/// as a `&'static` reference. Each branch matches a pair of variants, and the
+/// match must cover all pairs of them. With `as symmetric`, each branch
+/// covers both orders of its variants.
+///
+/// # Example
+///
+/// ```
+/// use enumeraties::props;
+/// use enumeraties::EnumPropPair;
+///
+/// struct Contact {
+///     touching: bool,
+/// }
+///
+/// enum Shape {
+///     Triangle,
+///     Square,
+///     Circle,
+/// }
+/// props! {
+///     impl EnumPropPair for Shape as symmetric const Contact {
+///         (Self::Triangle, Self::Square) => {
+///             touching: true,
+///         }
+///         (Self::Circle, _) => {
+///             touching: false,
+///         }
+///         (_, _) => {
+///             touching: true,
+///         }
+///     }
+/// }
+///
+/// assert!(Shape::Square.relation(&Shape::Triangle).touching);
+/// assert!(!Shape::Square.relation(&Shape::Circle).touching);
+/// ```
+///
+pub trait EnumPropPair<Prop: ?Sized> {
+	fn relation(&self, other: &Self) -> &'static Prop;
+}
+
+/// The trait that is implemented through [`props`] macro for `thread_lazy`
+/// properties.
+///
+/// Since `thread_lazy` properties are instantiated for each thread, they can
+/// not be accessed via a `&'static` reference as with [`EnumProp`], instead
+/// they are accessed via a closure.
+/// This requires the `std` crate feature.
+///
+/// # Example
+///
+#[cfg_attr(feature = "std", doc = "```")]
+#[cfg_attr(not(feature = "std"), doc = "```ignore")]
+/// use core::cell::RefCell;
+/// use enumeraties::props;
+/// use enumeraties::EnumPropLocal;
+///
+/// // A property that is not `Sync`
+/// struct Scratch {
+///     buffer: RefCell<Vec<u8>>,
+/// }
+///
+/// enum Foo {
+///     A,
+/// }
+/// props! {
+///     impl EnumPropLocal for Foo as thread_lazy Scratch {
+///         Self::A => {
+///             buffer: RefCell::new(Vec::new()),
+///         }
+///     }
+/// }
+///
+/// Foo::A.with_property(|p: &Scratch| p.buffer.borrow_mut().push(42));
+/// let len = Foo::A.with_property(|p: &Scratch| p.buffer.borrow().len());
+/// assert_eq!(len, 1);
+/// ```
+///
+pub trait EnumPropLocal<Prop> {
+	fn with_property<R>(&self, f: impl FnOnce(&Prop) -> R) -> R;
+}
+
+/// The trait that is implemented through [`props`] macro for `dynamic`
+/// properties.
+///
+/// Since `dynamic` properties may be computed from the data of the variant,
+/// they can not be accessed via a `&'static` reference as with [`EnumProp`],
+/// instead they are returned as [`Cow`](alloc::borrow::Cow), which is only
+/// owned for the branches that are actually computed.
+/// This requires the `alloc` crate feature.
+///
+/// # Example
+///
+#[cfg_attr(feature = "alloc", doc = "```")]
+#[cfg_attr(not(feature = "alloc"), doc = "```ignore")]
+/// use std::borrow::Cow;
+/// use enumeraties::props;
+/// use enumeraties::EnumPropDyn;
+///
+/// #[derive(Clone)]
+/// struct Label {
+///     text: Cow<'static, str>,
+/// }
+///
+/// enum Foo {
+///     Alpha,
+///     Beta(u32),
+/// }
+/// props! {
+///     impl EnumPropDyn for Foo as dynamic Label {
+///         Self::Alpha => const {
+///             text: Cow::Borrowed("alpha"),
+///         }
+///         Self::Beta(n) => {
+///             text: Cow::Owned(format!("beta-{n}")),
+///         }
+///     }
+/// }
+///
+/// assert_eq!(Foo::Alpha.property_dyn().text, "alpha");
+/// assert_eq!(Foo::Beta(42).property_dyn().text, "beta-42");
+/// // The `const` branch is not computed, thus it is borrowed
+/// assert!(matches!(Foo::Alpha.property_dyn(), Cow::Borrowed(_)));
+/// ```
+///
+#[cfg(feature = "alloc")]
+pub trait EnumPropDyn<Prop: Clone + 'static> {
+	fn property_dyn(&self) -> alloc::borrow::Cow<'static, Prop>;
+}
+
+// Only some backends and the tests need std
+#[cfg(any(test, feature = "std"))]
+extern crate std;
+
+// Only `dynamic` properties need alloc
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+// For the macro
+#[cfg(feature = "alloc")]
+#[doc(hidden)]
+pub use alloc::borrow::Cow;
+#[doc(hidden)]
+pub use core::ops::Deref;
+#[cfg(feature = "std")]
+#[doc(hidden)]
+pub use std::boxed::Box;
+#[cfg(feature = "std")]
+#[doc(hidden)]
+pub use std::collections::HashMap;
+#[cfg(feature = "std")]
+#[doc(hidden)]
+pub use std::panic::catch_unwind;
+#[cfg(feature = "std")]
+#[doc(hidden)]
+pub use std::panic::resume_unwind;
+#[cfg(feature = "lazylock")]
+#[doc(hidden)]
+pub use std::sync::LazyLock;
+#[cfg(feature = "std")]
+#[doc(hidden)]
+pub use std::sync::Mutex;
+#[cfg(feature = "std")]
+#[doc(hidden)]
+pub use std::sync::OnceLock;
+#[cfg(all(feature = "std", not(loom)))]
+#[doc(hidden)]
+pub use std::thread_local;
+
+// The command line values of `value_enum`
+#[cfg(feature = "clap")]
+#[doc(hidden)]
+pub use clap;
+// The `eager` backend
+#[cfg(feature = "eager")]
+#[doc(hidden)]
+pub use ctor;
+// The logging of `defmt`
+#[cfg(feature = "defmt")]
+#[doc(hidden)]
+pub use defmt;
+// The `EnumMap` of the properties
+#[cfg(feature = "enum_map")]
+#[doc(hidden)]
+pub use enum_map;
+// The variant sets of `set_where`
+#[cfg(feature = "enumset")]
+#[doc(hidden)]
+pub use enumset;
+// The lazy backends
+#[cfg(feature = "lazy")]
+#[doc(hidden)]
+pub use lazy_static; // 1.4.0
+// The model checked primitives for the `loom` tests, see `tests/loom.rs`
+#[cfg(loom)]
+#[doc(hidden)]
+pub use loom;
+#[cfg(all(feature = "std", loom))]
+#[doc(hidden)]
+pub use loom::thread_local;
+#[cfg(feature = "once_cell")]
+#[doc(hidden)]
+pub use once_cell;
+// The perfect hash map of the parsing
+#[cfg(feature = "phf")]
+#[doc(hidden)]
+pub use phf;
+// The weighted sampling of `sample_weighted`
+#[cfg(feature = "rand")]
+#[doc(hidden)]
+pub use rand;
+// The serialization of `serialize_as`
+#[cfg(feature = "serde")]
+#[doc(hidden)]
+pub use serde;
+#[cfg(feature = "no_std-lazy")]
+#[doc(hidden)]
+pub use spin;
+// The `tracing` instrumentation
+#[cfg(feature = "tracing")]
+#[doc(hidden)]
+pub use tracing;
+
+// The public front-end macro
+
+/// Adds a property onto an enum
+///
+/// # Const, Static, Lazy
+///
+/// This macro allows implement properties in seven different ways:
+/// * as `const`, a constant
+/// * as `static`, a global variable
+/// * as `lazy`, a lazily initialized static
+/// * as `eager`, a static initialized at runtime before `main`
+/// * as `thread_lazy`, a lazily initialized thread local
+/// * as `dynamic`, a value computed at each access
+/// * as `memo`, a value computed once for each value of the enum
+///
+/// `const` and `static` are very similar, but have subtle difference:
+/// the property type put into a `static` must implement `Send`. However,
+/// with a `static` it is guaranteed that for each variant there is exactly one
+/// unique property value and thus a unique reference address.
+/// With `const` the compiler is allowed to merge properties (if they are equal)
+/// or to inline and instantiated the same logical property multiple times,
+/// i.e. the same logical property might be accessed via different reference
+/// addresses.
+/// In the very most cases, the actual reference address should not be of any
+/// concern and thus it is recommended to use `const` over `static`.
+/// Where the address does matter, e.g. as the key of a cache, it can be
+/// compared via [`prop_ptr_eq`], and asserted in tests via
+/// [`assert_shared_props`] and [`assert_distinct_props`].
+///
+/// One notable use-case for `static` is when the property contains interior
+/// mutability.
+/// In these cases, `const` shouldn't even compile.
+///
+/// `lazy`, on the other hand, is quite different from the `const` and `static`.
+/// While `const` and `static` require constant initialized values computed at
+/// compile-time, `lazy` allows to evaluate the value lazily at runtime,
+/// instead. However, this feature incurs some overhead at each access, because
+/// it must be checked that the value was indeed already initialized.
+/// And of course, the first access to a `lazy` value, will incur the additional
+/// delay to initialize the value.
+/// Also notice, that `lazy` requires a lazy backend to be enabled via the
+/// [crate features](crate#crate-features), by default `lazy_static` is used.
+/// Regardless of the backend, the property of each branch is initialized
+/// exactly once, even if multiple threads race on its first access, in which
+/// case the other threads block until the initialization is complete, and all
+/// threads observe the same `&'static` reference (except for the
+/// `unsync-lazy` backend, which does not support multiple threads at all).
+/// These guarantees are provided by the once-cells of the backends, while
+/// the tracking of the initialization around them is model checked with
+/// [`loom`](https://docs.rs/loom), against a mutex standing in for the cell.
+/// Further, `lazy` properties implement [`LazyEnumProp`], which allows to
+/// check whether a property has already been initialized, and
+/// [`EnumPropInit`] to initialize all variants at once.
+/// Alternatively, the backend can be selected for each invocation via
+/// `lazy(<BACKEND>)`, e.g. `as lazy(once_cell) Prop`, where `<BACKEND>` is
+/// one of `lazy_static`, `once_cell`, `once_lock`, `lazy_lock`, `spin`, and
+/// `unsync`, which require the crate features `lazy`, `once_cell`,
+/// `std-lazy`, `lazylock`, `no_std-lazy`, and `unsync-lazy`, respectively.
+/// With the `std` crate feature, a panic during the initialization of a `lazy`
+/// property is annotated with the enum, the property, and the branch. The
+/// context is reported by a panic of its own, which is caught right away, thus
+/// it is shown by the panic hook, while the original payload is passed on
+/// unchanged. Further, a recursive initialization, e.g. by two properties
+/// referencing each other, panics instead of deadlocking.
+/// Further, `lazy(on_init = <HOOK>)` (or `lazy(<BACKEND>, on_init = <HOOK>)`)
+/// calls the function `<HOOK>` exactly once after the property of a branch has
+/// been initialized, with the name of the property type and the branch, i.e.
+/// `fn(&'static str, &'static str)`, e.g. to record the startup timing.
+/// Moreover, `lazy(grouped)` stores the properties of all variants in a single
+/// table, using the default backend, which reduces the per-access overhead.
+/// However, the properties of all variants are then initialized together at
+/// the first access of any of them.
+///
+/// The value of a `lazy` branch may reference the properties of other
+/// variants, e.g. `Self::Hexagon => { area: Shape::Triangle.props().area * 6.0 }`.
+/// The referenced properties are initialized on demand, within the
+/// initialization of the referencing branch, thus, an acyclic chain of
+/// references is initialized correctly, regardless of which variant is
+/// accessed first, and from how many threads.
+/// However, references forming a cycle, including a branch referencing its
+/// own property, can not be initialized. With the `std` crate feature, a cycle
+/// entered on a single thread is guaranteed to panic with the `recursive
+/// initialization` of the branch accessed first, along with the annotations
+/// of the branches along the cycle, without `std` it deadlocks. The detection
+/// is per thread, thus, a cycle entered from several threads at once, e.g. one
+/// thread accessing `A` while another accesses `B` of a cycle `A -> B -> A`,
+/// deadlocks even with `std`, each thread waiting for the other.
+/// Since the variants of a `lazy(grouped)` table are initialized together,
+/// they can not reference each other at all.
+///
+/// `eager` allows the same runtime initialization as `lazy`, but the values
+/// are initialized before `main` is entered, thus, there is no initialization
+/// delay at the first access, e.g. within a real-time context.
+/// This uses the [`ctor`](https://docs.rs/ctor) crate and requires the
+/// `eager` crate feature. Notice, that the initialization code then runs
+/// before `main`, when parts of std might not be set up yet, so it should be
+/// kept simple. On platforms that do not support code before `main`, `eager`
+/// properties gracefully fall back to be lazily initialized at the first
+/// access. Further, the generated constructor is `unsafe` code, thus,
+/// `eager` can not be used in crates that forbid `unsafe_code`.
+///
+/// `thread_lazy` is like `lazy`, but instantiates the property once per
+/// thread, which allows properties that are not `Sync`, e.g. containing a
+/// `RefCell`. Since such a property can not be accessed via a `&'static`
+/// reference, it implements [`EnumPropLocal`] instead of [`EnumProp`], and
+/// consequently, it can not be used with the `Deref` syntax.
+/// Notice, `thread_lazy` requires the `std` crate feature.
+///
+/// `dynamic` computes the property at each access, thus, unlike all other
+/// modifiers, the branches may use the data of the variant bound by their
+/// pattern, e.g. `Self::Beta(n) => { label: format!("beta-{n}") }`. Since
+/// such a property can not be accessed via a `&'static` reference either, it
+/// implements [`EnumPropDyn`] instead of [`EnumProp`], which returns a
+/// [`Cow`](alloc::borrow::Cow) and requires the property type to be `Clone`.
+/// Branches that do not need the data of the variant, e.g. of fieldless
+/// variants, should override the modifier by `const` or `static` (see below)
+/// or refer to an existing item, i.e. `<VARIANT> => &<ITEM>,`, which are
+/// borrowed instead of being computed and allocated at each access.
+/// Notice, `dynamic` requires the `alloc` crate feature.
+///
+/// `memo` is in between `lazy` and `dynamic`: the branches may use the data of
+/// the variant as well, but the property is computed only once for each value
+/// of the enum, and then accessed via a `&'static` reference, thus, it
+/// implements [`EnumProp`] and can be used with `Deref`. The computed
+/// properties are kept in a global cache keyed by the enum value, which
+/// therefore must implement `Clone`, `Eq`, `Hash`, and `Send`, and the
+/// properties are leaked to hand out the `&'static` references.
+/// Consequently, the same value always yields the same reference address, but
+/// the cache grows with each distinct value accessed and is never freed, so
+/// `memo` should only be used for enums with a small set of values actually
+/// in use. The number of cached properties is returned by
+/// [`MemoEnumProp::memo_len`]. Branches overriding the modifier, e.g. by
+/// `const`, are not cached.
+/// Notice, `memo` requires the `std` crate feature and does not support
+/// generic enums.
+///
+/// Finally, prefixing the modifier with `ref`, i.e. `as ref const`,
+/// `as ref static`, or `as ref lazy`, relaxes the `&'static` reference to a
+/// reference borrowed for the lifetime of the enum value, thus, it implements
+/// [`EnumPropRef`] instead of [`EnumProp`]. This allows branches given as an
+/// expression to return a reference into the data of the variant bound by
+/// their pattern, e.g. `Self::Ident(span) => span,` for a property type
+/// `Span<'a>` of a `Token<'a>`, while the branches given as fields are
+/// constants as usual. Unlike a `const` item, the `const` branches of a `ref`
+/// property may use the lifetime parameters of the enum (see
+/// [Generic enums](#generic-enums)), which is not possible for `static` and
+/// `lazy` branches. Since every [`EnumProp`] implements [`EnumPropRef`] as
+/// well, generic code bounded by the latter accepts both kinds of properties.
+///
+/// Moreover, single branches may override the modifier of their property by
+/// prefixing their body with `const`, `static`, or `lazy` (optionally with
+/// arguments, e.g. `lazy(once_cell)`), e.g. `Self::A => lazy { ... }` within
+/// a `const` property. Thus, only the branches that actually need the runtime
+/// initialization incur the overhead of `lazy`, while the others remain
+/// plain references. This is not supported by `lazy(grouped)` and
+/// `thread_lazy` properties.
+///
+///
+/// # Syntax
+///
+/// This macro comes with essentially three different syntaxes: to implement
+/// `Deref` (for the primary property), add an inherent access method
+/// (for secondary properties), or just implementing `EnumProp` onto it (e.g.,
+/// if only used by generic code). The first two can also be combined, i.e.
+/// `impl Deref + fn <FN_NAME> for <ENUM> ...`.
+///
+/// In each syntax, the last branch may be the wildcard `_` (or equivalently
+/// `default`), which provides the property of all variants that are not
+/// listed in the preceding branches, e.g. for a `non_exhaustive` enum of
+/// another crate. The wildcard branch must be the last one, and since it does
+/// not name its variants, [`EnumPropInit`] is not implemented with it.
+///
+/// ```
+/// # use enumeraties::props;
+/// struct Prop { name: &'static str }
+/// enum Foo {A, B, C(u8)}
+/// props! {
+///     impl Deref for Foo as const Prop {
+///         Self::A => {
+///             name: "Foo",
+///         }
+///         _ => {
+///             name: "Other",
+///         }
+///     }
+/// }
+/// assert_eq!(Foo::A.name, "Foo");
+/// assert_eq!(Foo::B.name, "Other");
+/// assert_eq!(Foo::C(42).name, "Other");
+/// ```
+///
+/// A branch may list several variants as an or-pattern, e.g.
+/// `Self::A | Self::B => ...`, which then share a single property instance.
+/// Thus, for `static` (and `lazy`) properties, all variants of such a branch
+/// return the same address, while each branch has its own address. Notice,
+/// the address of a `const` property is not guaranteed to be unique, as with
+/// any reference to a `const` in Rust.
+///
+/// ```
+/// # use enumeraties::props;
+/// # use enumeraties::EnumProp;
+/// struct Prop { name: &'static str }
+/// enum Foo {A, B, C}
+/// props! {
+///     impl EnumProp for Foo as static Prop {
+///         Self::A | Self::B => {
+///             name: "either",
+///         }
+///         Self::C => {
+///             name: "other",
+///         }
+///     }
+/// }
+/// let prop = |variant: Foo| EnumProp::<Prop>::property(&variant);
+/// assert!(core::ptr::eq(prop(Foo::A), prop(Foo::B)));
+/// assert!(!core::ptr::eq(prop(Foo::A), prop(Foo::C)));
+/// ```
+///
+/// Branches with identical bodies can be shared as well, i.e. `dedup` before
+/// the named values and all branches of a `const` or `static` property. Then,
+/// a branch of variants (e.g. `Self::B | Self::C`) whose fields are identical
+/// to those of an earlier such branch, compared token-wise, returns the
+/// property of the earlier one, thus for `static` properties they share a
+/// single address. Other branches (e.g. with variant data, a match guard, or a
+/// named value) are kept as they are.
+///
+/// ```
+/// # use enumeraties::props;
+/// # use enumeraties::EnumProp;
+/// struct Prop { name: &'static str }
+/// enum Foo {A, B, C}
+/// props! {
+///     impl EnumProp for Foo as static Prop {
+///         dedup
+///         Self::A => {
+///             name: "same",
+///         }
+///         Self::B => {
+///             name: "other",
+///         }
+///         Self::C => {
+///             name: "same",
+///         }
+///     }
+/// }
+/// let prop = |variant: Foo| EnumProp::<Prop>::property(&variant);
+/// assert!(core::ptr::eq(prop(Foo::A), prop(Foo::C)));
+/// assert!(!core::ptr::eq(prop(Foo::A), prop(Foo::B)));
+/// ```
+///
+/// A branch may also have a match guard, i.e. `<VARIANT> if <GUARD> => ...`,
+/// e.g. to distinguish the variant data. As with any `match`, the branches
+/// must still be exhaustive, thus a guarded variant usually needs another
+/// branch without a guard.
+///
+/// ```
+/// # use enumeraties::props;
+/// struct Prop { name: &'static str }
+/// enum Foo {Alpha, Beta(u32)}
+/// props! {
+///     impl Deref for Foo as const Prop {
+///         Self::Alpha => {
+///             name: "none",
+///         }
+///         Self::Beta(n) if *n < 100 => {
+///             name: "small",
+///         }
+///         Self::Beta(_) => {
+///             name: "large",
+///         }
+///     }
+/// }
+/// assert_eq!(Foo::Beta(42).name, "small");
+/// assert_eq!(Foo::Beta(420).name, "large");
+/// ```
+///
+/// Attributes given before a branch, most notably `#[cfg(...)]` and
+/// `#[cfg_attr(...)]`, are forwarded onto the generated match arm, thus the
+/// branch of a variant that is configured away vanishes entirely, including
+/// its property value.
+///
+/// ```
+/// # use enumeraties::props;
+/// struct Prop { name: &'static str }
+/// enum Foo {
+///     A,
+///     #[cfg(feature = "experimental")]
+///     Experimental,
+/// }
+/// props! {
+///     impl Deref for Foo as const Prop {
+///         Self::A => {
+///             name: "A",
+///         }
+///         #[cfg(feature = "experimental")]
+///         Self::Experimental => {
+///             name: "Experimental",
+///         }
+///     }
+/// }
+/// assert_eq!(Foo::A.name, "A");
+/// ```
+///
+/// Further, the fields of a branch may end with `..<BASE>`, i.e. Rust's
+/// struct update syntax, which takes all fields that are not listed from the
+/// `<BASE>` value, e.g. a common `const` of the property type. Notice, for
+/// `const` and `static` properties `<BASE>` must be a constant expression,
+/// while `lazy` properties may use any expression, e.g. a function call.
+/// Unlike a struct literal, such a branch only requires the listed fields to
+/// be public, thus it also works with property types of other crates that are
+/// `#[non_exhaustive]` or have private fields, as does an expression branch
+/// calling a constructor (see below).
+///
+/// ```
+/// # use enumeraties::props;
+/// struct Shape { vertices: u8, filled: bool, name: &'static str }
+/// const SHAPE_DEFAULTS: Shape = Shape { vertices: 0, filled: true, name: "" };
+/// enum Foo {Triangle, Circle}
+/// props! {
+///     impl Deref for Foo as const Shape {
+///         Self::Triangle => {
+///             vertices: 3,
+///             name: "Triangle",
+///             ..SHAPE_DEFAULTS
+///         }
+///         Self::Circle => {
+///             name: "Circle",
+///             ..SHAPE_DEFAULTS
+///         }
+///     }
+/// }
+/// assert_eq!(Foo::Triangle.vertices, 3);
+/// assert!(Foo::Circle.filled);
+/// ```
+///
+/// Moreover, fields that are shared by most branches can be given once, as
+/// `defaults { <FIELD> : <VALUE>, ... }` before all branches. These are used
+/// for each branch that does not give the respective field itself, i.e. the
+/// fields of a branch take precedence over the defaults, which in turn take
+/// precedence over a `..<BASE>`.
+///
+/// ```
+/// # use enumeraties::props;
+/// struct Unit { name: &'static str, dangerous: bool, speed: f32 }
+/// enum Foo {Mouse, Cat, Tiger}
+/// props! {
+///     impl Deref for Foo as const Unit {
+///         defaults {
+///             dangerous: false,
+///             speed: 1.0,
+///         }
+///         Self::Mouse => {
+///             name: "Mouse",
+///         }
+///         Self::Cat => {
+///             name: "Cat",
+///             speed: 2.0,
+///         }
+///         Self::Tiger => {
+///             name: "Tiger",
+///             dangerous: true,
+///         }
+///     }
+/// }
+/// assert_eq!(Foo::Mouse.speed, 1.0);
+/// assert_eq!(Foo::Cat.speed, 2.0);
+/// assert!(Foo::Tiger.dangerous);
+/// ```
+///
+/// Similarly, a branch may take the remaining fields from the value of a
+/// preceding branch, i.e. `..like <VARIANT>` instead of `..<BASE>`, which
+/// evaluates the body of that branch again (including its defaults), e.g. for
+/// variants that differ from a baseline in just a few fields. The referenced
+/// branch must be a preceding branch of just that variant, e.g. `Self::A`
+/// without a match guard, thus there can be no cycles.
+///
+/// ```
+/// # use enumeraties::props;
+/// struct Unit { name: &'static str, speed: f32, armor: u32 }
+/// enum Foo {Scout, FastScout, Tank}
+/// props! {
+///     impl Deref for Foo as const Unit {
+///         Self::Scout => {
+///             name: "Scout",
+///             speed: 1.0,
+///             armor: 1,
+///         }
+///         Self::FastScout => {
+///             speed: 2.0,
+///             ..like Self::Scout
+///         }
+///         Self::Tank => {
+///             name: "Tank",
+///             speed: 0.5,
+///             armor: 10,
+///         }
+///     }
+/// }
+/// assert_eq!(Foo::FastScout.name, "Scout");
+/// assert_eq!(Foo::FastScout.speed, 2.0);
+/// ```
+///
+/// For property types holding nested structs, a field of a nested struct may
+/// be given via a dotted field path, e.g. `ui.color: Color::RED`, instead of
+/// repeating the entire nested struct in each branch. Since the macro does not
+/// know the types of the nested structs, dotted field paths are assigned onto
+/// a base value, i.e. the nested structs are taken from the `defaults`, a
+/// `..<BASE>`, or a `..like <VARIANT>`, and a branch without any of these can
+/// not use dotted field paths. Further, a field may not be given both as a
+/// whole and via dotted field paths in the same branch.
+///
+/// ```
+/// # use enumeraties::props;
+/// struct Ui { color: u32, icon: &'static str }
+/// struct Audio { volume: f32 }
+/// struct Meta { ui: Ui, audio: Audio }
+/// enum Foo {Click, Alarm}
+/// props! {
+///     impl Deref for Foo as const Meta {
+///         defaults {
+///             ui: Ui { color: 0x000000, icon: "default.png" },
+///             audio: Audio { volume: 0.5 },
+///         }
+///         Self::Click => {
+///             ui.icon: "click.png",
+///         }
+///         Self::Alarm => {
+///             ui.color: 0xff0000,
+///             ui.icon: "alarm.png",
+///             audio.volume: 1.0,
+///         }
+///     }
+/// }
+/// assert_eq!(Foo::Click.ui.icon, "click.png");
+/// assert_eq!(Foo::Click.audio.volume, 0.5);
+/// assert_eq!(Foo::Alarm.ui.color, 0xff0000);
+/// ```
+///
+/// Instead of the fields, a branch may also give the entire property value as
+/// an expression, i.e. `<VARIANT> => <VALUE>,`, e.g. if the property type has
+/// private fields and is constructed via a (`const`) function.
+/// For tuple structs, the positional values can be given in parentheses, i.e.
+/// `<VARIANT> => (<VALUE>, ...),`. The `defaults` are not applied to either of
+/// these branches.
+/// For unit structs, e.g. markers required by generic code, the body can be
+/// empty, i.e. `<VARIANT> => {}`, or omitted entirely, i.e. `<VARIANT>,`.
+///
+/// A generic property type is given along with its generic arguments, e.g.
+/// `as const Limits<u32>` (or `Limits::<u32>`). Since each instantiation is a
+/// distinct type, the same generic struct may be used for several properties
+/// of the same enum.
+///
+/// ```
+/// # use enumeraties::props;
+/// # use enumeraties::EnumProp;
+/// struct Limits<T> { min: T, max: T }
+/// enum Foo {A, B}
+/// props! {
+///     impl EnumProp for Foo as const Limits<u32> {
+///         Self::A => {
+///             min: 1,
+///             max: 10,
+///         }
+///         Self::B => {
+///             min: 5,
+///             max: 50,
+///         }
+///     }
+/// }
+/// props! {
+///     impl Foo : fn names as const Limits<&'static str> {
+///         _ => {
+///             min: "a",
+///             max: "z",
+///         }
+///     }
+/// }
+/// assert_eq!(EnumProp::<Limits<u32>>::property(&Foo::B).max, 50);
+/// assert_eq!(Foo::A.names().min, "a");
+/// ```
+///
+/// The property type is not limited to structs, it can be any `'static` type,
+/// e.g. a primitive, an array, a tuple, or a reference, given as plain
+/// expressions. However, notice that while `Deref` can be implemented for such
+/// property types, it tends to be confusing, e.g. `*Foo::A + 1`, thus the
+/// inherent method syntax is recommended for these.
+///
+/// ```
+/// # use enumeraties::props;
+/// enum Foo {A, B}
+/// props! {
+///     impl Foo : fn weight as const u32 {
+///         Self::A => 3,
+///         Self::B => 5,
+///     }
+/// }
+/// props! {
+///     impl Foo : fn color as const [f32; 3] {
+///         Self::A => [1.0, 0.0, 0.0],
+///         Self::B => [0.0, 1.0, f32::INFINITY],
+///     }
+/// }
+/// assert_eq!(*Foo::A.weight(), 3);
+/// assert_eq!(Foo::B.color()[1], 1.0);
+/// ```
+///
+/// ```
+/// # use enumeraties::props;
+/// struct Prop(&'static str, u8);
+/// enum Foo {A, B}
+/// props! {
+///     impl Deref for Foo as const Prop {
+///         Self::A => Prop("Foo", 3),
+///         Self::B => ("Bar", 4),
+///     }
+/// }
+/// assert_eq!(Foo::A.0, "Foo");
+/// assert_eq!(Foo::B.1, 4);
+/// ```
+///
+/// For `const` and `static` properties, a branch may also refer to an existing
+/// `static` (or `const`) of the property type, i.e. `<VARIANT> => &<ITEM>,`,
+/// which is then returned as is, instead of a copy of it. Thus, the property
+/// of such a branch has the same address as the referenced `static`.
+///
+/// ```
+/// # use enumeraties::props;
+/// struct Shape { vertices: u8 }
+/// static TRIANGLE: Shape = Shape { vertices: 3 };
+/// enum Foo {Triangle, Square}
+/// props! {
+///     impl Deref for Foo as static Shape {
+///         Self::Triangle => &TRIANGLE,
+///         Self::Square => {
+///             vertices: 4,
+///         }
+///     }
+/// }
+/// assert!(core::ptr::eq(&*Foo::Triangle, &TRIANGLE));
+/// assert_eq!(Foo::Square.vertices, 4);
+/// ```
+///
+/// A branch of a variant wrapping another enum with the same property may
+/// delegate to the property of the inner enum, instead of duplicating its
+/// branches, i.e. `<VARIANT> => delegate <EXPR>,`, where `<EXPR>` is a
+/// reference to the inner enum, typically bound by the pattern of the branch.
+/// This returns `EnumProp::<PROPERTY>::property(<EXPR>)` as is, thus, the
+/// inner enum must implement `EnumProp<PROPERTY>`, regardless of its modifier.
+/// Delegating branches are supported by all modifiers except
+/// `lazy(grouped)`, however, `thread_lazy` properties delegate to
+/// [`EnumPropLocal`] and `ref` properties to [`EnumPropRef`] instead.
+///
+/// ```
+/// # use enumeraties::props;
+/// struct ShapeDef { vertices: u8 }
+/// enum Shape {Triangle, Square}
+/// props! {
+///     impl Deref for Shape as const ShapeDef {
+///         Self::Triangle => {
+///             vertices: 3,
+///         }
+///         Self::Square => {
+///             vertices: 4,
+///         }
+///     }
+/// }
+/// enum Outer {Shape(Shape), Misc}
+/// props! {
+///     impl Deref for Outer as const ShapeDef {
+///         Self::Shape(inner) => delegate inner,
+///         Self::Misc => {
+///             vertices: 0,
+///         }
+///     }
+/// }
+/// assert_eq!(Outer::Shape(Shape::Square).vertices, 4);
+/// assert_eq!(Outer::Misc.vertices, 0);
+/// ```
+///
+/// The property may also be a behavior, i.e. a trait object, given as
+/// `as (const|static) dyn <TRAIT>`, which implements `EnumProp<dyn <TRAIT>>`.
+/// Then, each branch gives a reference to its implementor, i.e. any constant
+/// expression coercing to `&'static dyn <TRAIT>`, e.g. `<VARIANT> => &<ITEM>,`.
+/// Notice, for `static` properties the trait object must be `Sync`, e.g.
+/// `dyn <TRAIT> + Sync`.
+///
+/// ```
+/// # use enumeraties::props;
+/// trait Renderer { fn render(&self) -> &'static str; }
+/// struct Sprite;
+/// impl Renderer for Sprite { fn render(&self) -> &'static str { "sprite" } }
+/// struct Mesh { triangles: u32 }
+/// impl Renderer for Mesh { fn render(&self) -> &'static str { "mesh" } }
+/// const SPRITE_RENDERER: Sprite = Sprite;
+/// enum Foo {A, B}
+/// props! {
+///     impl Foo : fn renderer as const dyn Renderer {
+///         Self::A => &SPRITE_RENDERER,
+///         Self::B => &Mesh { triangles: 12 },
+///     }
+/// }
+/// assert_eq!(Foo::A.renderer().render(), "sprite");
+/// assert_eq!(Foo::B.renderer().render(), "mesh");
+/// ```
+///
+/// Similarly, values shared by several branches, which can not be combined
+/// into an or-pattern, can be given a name, i.e. `let <NAME> = <BODY>;` before
+/// all branches, with the same `<BODY>` as a branch. Then, these branches just
+/// give its name, i.e. `<VARIANT> => <NAME>,`. For `static` properties, all of
+/// them share the same address. Named values are only supported for `const`
+/// and `static` properties, and each must be used by some branch.
+///
+/// ```
+/// # use enumeraties::props;
+/// # use enumeraties::EnumProp;
+/// struct Prop { name: &'static str, tier: u8 }
+/// enum Foo {A, B, C}
+/// props! {
+///     impl EnumProp for Foo as static Prop {
+///         let COMMON = {
+///             name: "common",
+///             tier: 1,
+///         };
+///         Self::A => COMMON,
+///         Self::B => {
+///             name: "rare",
+///             tier: 2,
+///         }
+///         Self::C => COMMON,
+///     }
+/// }
+/// let prop = |variant: Foo| EnumProp::<Prop>::property(&variant);
+/// assert!(core::ptr::eq(prop(Foo::A), prop(Foo::C)));
+/// ```
+///
+/// For `const` properties, selected variants can also be given associated
+/// consts of the enum, i.e. `consts { [<ATTRS>] <VIS> <NAME> = <VARIANT>, ... }`
+/// before the named values and all branches, each defined as
+/// `<VIS> const <NAME>: &'static <PROPERTY>`, which is usable in const
+/// contexts without calling a method. Since names can not be derived from the
+/// variant, each const is named explicitly, e.g. with a suffix of the
+/// property, such as `TRIANGLE_SHAPE_DEF`, to avoid collisions between several
+/// properties of the same enum. The `<VARIANT>` is any constant expression of
+/// the enum, thus variants with fields are simply not listed, or given with
+/// specific values, e.g. `POLYGON_5_SHAPE_DEF = Self::Polygon(5)`.
+///
+/// ```
+/// # use enumeraties::props;
+/// struct ShapeDef { name: &'static str, vertices: u8 }
+/// enum Shape {Triangle, Square, Polygon(u8)}
+/// props! {
+///     impl Shape : fn def as const ShapeDef {
+///         consts {
+///             pub TRIANGLE_SHAPE_DEF = Self::Triangle,
+///             pub SQUARE_SHAPE_DEF = Self::Square,
+///         }
+///         Self::Triangle => {
+///             name: "triangle",
+///             vertices: 3,
+///         }
+///         Self::Square => {
+///             name: "square",
+///             vertices: 4,
+///         }
+///         Self::Polygon(_) => {
+///             name: "polygon",
+///             vertices: 0,
+///         }
+///     }
+/// }
+/// const VERTICES: u8 = Shape::SQUARE_SHAPE_DEF.vertices;
+/// assert_eq!(VERTICES, 4);
+/// assert_eq!(Shape::TRIANGLE_SHAPE_DEF.name, "triangle");
+/// ```
+///
+/// Similarly, `const` and `static` properties can be given a table of all
+/// variants, i.e. `all { [<ATTRS>] <VIS> <NAME> }` before the named values and
+/// all branches, defined as `<VIS> const <NAME>: [&'static <PROPERTY>; <N>]`
+/// with one entry per variant, in the order of the branches, e.g. to build
+/// further tables in const contexts. Thus, all branches must consist only of
+/// fieldless variants, e.g. `Self::A | Self::B`, without a wildcard or guards.
+/// For `static` properties, the table refers to the very statics given by
+/// `EnumProp`, thus it requires Rust 1.83.
+///
+/// ```
+/// # use enumeraties::props;
+/// struct ShapeDef { vertices: u8 }
+/// enum Shape {Triangle, Square, Rhombus}
+/// props! {
+///     impl Shape : fn def as const ShapeDef {
+///         all { pub ALL_SHAPE_DEFS }
+///         Self::Triangle => {
+///             vertices: 3,
+///         }
+///         Self::Square | Self::Rhombus => {
+///             vertices: 4,
+///         }
+///     }
+/// }
+/// const COUNT: usize = Shape::ALL_SHAPE_DEFS.len();
+/// assert_eq!(COUNT, 3);
+/// assert_eq!(Shape::ALL_SHAPE_DEFS[2].vertices, 4);
+/// ```
+///
+/// Likewise, the variants can be listed sorted by a field of their properties,
+/// i.e. `ordered_by([<ATTRS>] <FIELD> as <VIS> <NAME>)` before the named values
+/// and all branches, defined as `<VIS> const <NAME>: [Self; <N>]`, e.g. for the
+/// order of a menu. The field must be of a primitive type, and the sort is
+/// stable, thus variants of equal values keep the order of the branches. Like
+/// for `all`, all branches must consist only of fieldless variants.
+///
+/// ```
+/// # use enumeraties::props;
+/// struct ShapeDef { display_order: u16 }
+/// #[derive(Debug, PartialEq)]
+/// enum Shape {Triangle, Square, Circle}
+/// props! {
+///     impl Shape : fn def as const ShapeDef {
+///         ordered_by(display_order as pub SHAPES_BY_DISPLAY_ORDER)
+///         Self::Triangle => {
+///             display_order: 2,
+///         }
+///         Self::Square => {
+///             display_order: 1,
+///         }
+///         Self::Circle => {
+///             display_order: 3,
+///         }
+///     }
+/// }
+/// assert_eq!(
+///     Shape::SHAPES_BY_DISPLAY_ORDER,
+///     [Shape::Square, Shape::Triangle, Shape::Circle]
+/// );
+/// ```
+///
+/// The variants themselves can be listed as well, in the order of the
+/// branches, i.e. `variants { [<ATTRS>] <VIS> <NAME> }` before the named values
+/// and all branches, defined as `<VIS> const <NAME>: [Self; <N>]`. Unlike `all`
+/// and `ordered_by`, it does not evaluate any property, thus it is supported for
+/// any property implementing `EnumProp`, including `lazy` ones. Still, all
+/// branches must consist only of fieldless variants. Since the const is defined
+/// on the enum, its name must be distinct if several properties of the same
+/// enum list their variants, though it is usually given for one of them only.
+///
+/// ```
+/// # use enumeraties::props;
+/// struct ShapeDef { vertices: u8 }
+/// #[derive(Debug, PartialEq)]
+/// enum Shape {Triangle, Square, Rhombus}
+/// props! {
+///     impl Shape : fn def as const ShapeDef {
+///         variants { pub VARIANTS }
+///         Self::Triangle => {
+///             vertices: 3,
+///         }
+///         Self::Square | Self::Rhombus => {
+///             vertices: 4,
+///         }
+///     }
+/// }
+/// assert_eq!(Shape::VARIANTS, [Shape::Triangle, Shape::Square, Shape::Rhombus]);
+/// ```
+///
+/// With the `enum_map` crate feature, the properties of all variants can be
+/// collected into an [`EnumMap`](enum_map::EnumMap) as well, i.e.
+/// `enum_map { [<ATTRS>] <VIS> static <NAME> }` before the named values and
+/// all branches, defined as `<VIS> static <NAME>: PropMap<<ENUM>, <PROPERTY>>`
+/// next to the enum, which dereferences to `EnumMap<<ENUM>, &'static
+/// <PROPERTY>>`. The map is built lazily at its first access, thus it requires
+/// a lazy backend as well, and the enum must implement `enum_map::Enum`. It is
+/// supported for any property implementing `EnumProp` of a non-generic enum,
+/// see [`PropMap`].
+///
+/// Further, `const` properties can be folded at compile time over a field of
+/// all branches, i.e. `aggregate { [<ATTRS>] <OP> <FIELD> as <VIS> <NAME>: <TYPE>,
+/// ... }` before the named values and all branches, where `<OP>` is one of
+/// `max`, `min`, or `sum`, defined as `<VIS> const <NAME>: <TYPE>`. The field
+/// must be of a primitive number type, given again as `<TYPE>`, since it is
+/// the type of the const. Unlike `all`, any branches are folded, including a
+/// wildcard, but at least one branch is required.
+///
+/// ```
+/// # use enumeraties::props;
+/// struct ShapeDef { vertices: u8, weight: f32 }
+/// enum Shape {Triangle, Square, Polygon(u8)}
+/// props! {
+///     impl Shape : fn def as const ShapeDef {
+///         aggregate {
+///             max vertices as pub MAX_VERTICES: u8,
+///             sum weight as pub TOTAL_WEIGHT: f32,
+///         }
+///         Self::Triangle => {
+///             vertices: 3,
+///             weight: 0.5,
+///         }
+///         Self::Square => {
+///             vertices: 4,
+///             weight: 1.0,
+///         }
+///         _ => {
+///             vertices: 8,
+///             weight: 2.0,
+///         }
+///     }
+/// }
+/// let buffer = [0; Shape::MAX_VERTICES as usize];
+/// assert_eq!(buffer.len(), 8);
+/// assert_eq!(Shape::TOTAL_WEIGHT, 3.5);
+/// ```
+///
+/// Moreover, the fields of `const` properties can be required to be distinct
+/// across all branches, i.e. `unique(<FIELD>, ...)` before the named values and
+/// all branches, e.g. for codes of a wire format. It is checked at compile time
+/// for fields of primitive integers, `char`, `bool`, and `&'static str`, thus a
+/// duplicate fails to compile, naming the two branches. Since the check is a
+/// free const item, the values of the branches can not depend on `Self` or
+/// generic parameters.
+///
+/// ```
+/// # use enumeraties::props;
+/// struct Message { code: u16, name: &'static str }
+/// enum Request {Ping, Query, Other(u8)}
+/// props! {
+///     impl Request : fn message as const Message {
+///         unique(code, name)
+///         Self::Ping => {
+///             code: 1,
+///             name: "ping",
+///         }
+///         Self::Query => {
+///             code: 2,
+///             name: "query",
+///         }
+///         Self::Other(_) => {
+///             code: 0xFFFF,
+///             name: "other",
+///         }
+///     }
+/// }
+/// assert_eq!(Request::Query.message().code, 2);
+/// ```
+///
+/// Conversely, a string field of a `const` property can be used to parse the
+/// enum, i.e. `from_str(<FIELD>)` before the named values and all branches,
+/// which implements [`FromStr`](core::str::FromStr) returning the variant whose
+/// field equals the string, or [`UnknownVariant`] otherwise. Like the `all`
+/// table, it requires branches of fieldless variants, where a branch of
+/// several variants parses as its first one. The field is checked to be unique
+/// as well, thus a duplicate fails to compile.
+///
+/// ```
+/// # use enumeraties::props;
+/// struct ShapeDef { name: &'static str, vertices: u8 }
+/// #[derive(Debug, PartialEq)]
+/// enum Shape {Triangle, Square}
+/// props! {
+///     impl Deref for Shape as const ShapeDef {
+///         from_str(name)
+///         Self::Triangle => {
+///             name: "triangle",
+///             vertices: 3,
+///         }
+///         Self::Square => {
+///             name: "square",
+///             vertices: 4,
+///         }
+///     }
+/// }
+/// assert_eq!("square".parse(), Ok(Shape::Square));
+/// assert!("circle".parse::<Shape>().is_err());
+/// ```
+///
+/// For enums with many variants, the linear comparison with each field can be
+/// replaced by a perfect hash map with the `phf` crate feature, i.e.
+/// `from_str(phf <FIELD> as [<ATTRS>] <VIS> <NAME>)`, which defines the map as
+/// `<VIS> const <NAME>: phf::Map<&'static str, Self>`, built at compile time,
+/// and implements [`FromStr`](core::str::FromStr) looking it up. Since its
+/// keys must be literals, the field must be given as a string literal in each
+/// branch (not via `defaults` or a named value), and the branches can not
+/// have attributes. Like the field, the keys are checked to be unique. Note
+/// that a few hundred branches may require raising the `recursion_limit` of
+/// the crate.
+///
+#[cfg_attr(feature = "phf", doc = "```")]
+#[cfg_attr(not(feature = "phf"), doc = "```ignore")]
+/// # use enumeraties::props;
+/// struct KeywordDef { name: &'static str, reserved: bool }
+/// #[derive(Debug, PartialEq)]
+/// enum Keyword {Fn, Let, Match, Async}
+/// props! {
+///     impl Deref for Keyword as const KeywordDef {
+///         from_str(phf name as pub KEYWORDS)
+///         Self::Fn => {
+///             name: "fn",
+///             reserved: true,
+///         }
+///         Self::Let => {
+///             name: "let",
+///             reserved: true,
+///         }
+///         Self::Match | Self::Async => {
+///             name: "match",
+///             reserved: false,
+///         }
+///     }
+/// }
+/// assert_eq!("let".parse(), Ok(Keyword::Let));
+/// assert_eq!("match".parse(), Ok(Keyword::Match));
+/// assert!("loop".parse::<Keyword>().is_err());
+/// assert_eq!(Keyword::KEYWORDS.len(), 3);
+/// ```
+///
+/// Likewise, `try_from(<FIELD>: <TYPE>)` implements
+/// [`TryFrom`](core::convert::TryFrom) of the type of a field, e.g. a numeric
+/// code, returning the variant whose field equals the value, or
+/// [`UnknownValue`] holding the value otherwise. Thus, the mapping from the
+/// codes to the variants can not disagree with the table.
+///
+/// ```
+/// # use enumeraties::props;
+/// struct OpDef { code: u8, args: u8 }
+/// #[derive(Debug, PartialEq)]
+/// enum Op {Nop, Push, Add}
+/// props! {
+///     impl Deref for Op as const OpDef {
+///         try_from(code: u8)
+///         Self::Nop => {
+///             code: 0x00,
+///             args: 0,
+///         }
+///         Self::Push => {
+///             code: 0x10,
+///             args: 1,
+///         }
+///         Self::Add => {
+///             code: 0x20,
+///             args: 0,
+///         }
+///     }
+/// }
+/// assert_eq!(Op::try_from(0x10), Ok(Op::Push));
+/// assert!(Op::try_from(0xff).is_err());
+/// ```
+///
+/// The enum can also implement [`Display`](core::fmt::Display) by writing a
+/// field of its property, i.e. `derive(Display = <FIELD>)` before the named
+/// values and all branches, for any property implementing [`EnumProp`]. The
+/// field must implement `Display` itself.
+///
+/// ```
+/// # use enumeraties::props;
+/// struct ShapeDef { name: &'static str, vertices: u8 }
+/// enum Shape {Triangle, Square}
+/// props! {
+///     impl Deref for Shape as const ShapeDef {
+///         derive(Display = name)
+///         Self::Triangle => {
+///             name: "triangle",
+///             vertices: 3,
+///         }
+///         Self::Square => {
+///             name: "square",
+///             vertices: 4,
+///         }
+///     }
+/// }
+/// assert_eq!(Shape::Square.vertices, 4);
+/// assert_eq!(format!("a {}", Shape::Triangle), "a triangle");
+/// ```
+///
+/// Likewise, `derive(Debug = <INNER>)` implements [`Debug`](core::fmt::Debug)
+/// for the enum, writing the variant via `<INNER>`, a function taking the enum
+/// and the formatter (e.g. a method), followed by the property in parentheses.
+/// The property must implement `Debug`, while the enum must not derive it, as
+/// with any trait impl.
+///
+/// ```
+/// # use enumeraties::props;
+/// use core::fmt;
+/// #[derive(Debug)]
+/// struct ShapeDef { vertices: u8 }
+/// enum Shape {Triangle, Polygon(u8)}
+/// impl Shape {
+///     fn variant(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+///         match self {
+///             Self::Triangle => f.write_str("Triangle"),
+///             Self::Polygon(n) => f.debug_tuple("Polygon").field(n).finish(),
+///         }
+///     }
+/// }
+/// props! {
+///     impl Deref for Shape as const ShapeDef {
+///         derive(Debug = Self::variant)
+///         Self::Triangle => {
+///             vertices: 3,
+///         }
+///         Self::Polygon(_) => {
+///             vertices: 0,
+///         }
+///     }
+/// }
+/// assert_eq!(
+///     format!("{:?}", Shape::Polygon(5)),
+///     "Polygon(5) (ShapeDef { vertices: 0 })",
+/// );
+/// ```
+///
+/// The comparison traits `PartialEq`, `Eq`, `PartialOrd`, and `Ord` are derived
+/// by a field just like `Display`, e.g. `derive(Ord = <FIELD>)`, comparing the
+/// variants by the field of their properties instead of their declaration
+/// order. As `Ord` requires `Eq`, it is derived along with `derive(Eq =
+/// <FIELD>)`, where `Eq` implements `PartialEq` as well, and `Ord` implements
+/// `PartialOrd`. For `lazy` properties, comparing the variants initializes
+/// their properties.
+///
+/// ```
+/// # use enumeraties::props;
+/// struct PrioDef { rank: u8 }
+/// #[derive(Debug, Clone, Copy)]
+/// enum Prio {Low, High, Urgent}
+/// props! {
+///     impl Deref for Prio as const PrioDef {
+///         derive(Eq = rank)
+///         derive(Ord = rank)
+///         Self::Urgent => {
+///             rank: 10,
+///         }
+///         Self::Low => {
+///             rank: 1,
+///         }
+///         Self::High => {
+///             rank: 5,
+///         }
+///     }
+/// }
+/// assert!(Prio::Urgent > Prio::High);
+/// assert_eq!(Prio::Low.max(Prio::High).rank, 5);
+/// ```
+///
+/// With the `serde` crate feature, `serialize_as(<FIELD>)` implements
+/// [`Serialize`](serde::Serialize) for the enum as a string field of its
+/// property, e.g. its name, which thus can not drift apart from the one in the
+/// table. The field must be a `&'static str`, and variants with data are
+/// serialized as the field only, dropping their data.
+///
+#[cfg_attr(feature = "serde", doc = "```")]
+#[cfg_attr(not(feature = "serde"), doc = "```ignore")]
+/// # use enumeraties::props;
+/// struct ColorDef { name: &'static str }
+/// enum Color {Red, Gray(u8)}
+/// props! {
+///     impl Deref for Color as const ColorDef {
+///         serialize_as(name)
+///         Self::Red => {
+///             name: "red",
+///         }
+///         Self::Gray(_) => {
+///             name: "gray",
+///         }
+///     }
+/// }
+/// assert_eq!(serde_json::to_string(&Color::Red).unwrap(), r#""red""#);
+/// assert_eq!(serde_json::to_string(&[Color::Gray(1), Color::Gray(2)]).unwrap(), r#"["gray","gray"]"#);
+/// ```
+///
+/// On embedded targets, with the `defmt` crate feature, `defmt(<FIELD>)`
+/// implements [`defmt::Format`](https://docs.rs/defmt/latest/defmt/trait.Format.html)
+/// for the enum, writing a `&'static str` field of its property, without any
+/// allocation. Like for `serialize_as`, variants with data are written as the
+/// field only. Notice, defmt only interns string literals, thus the field is
+/// transmitted as a string. As the log frames are decoded by the host, the
+/// example needs an embedded target, e.g. logging via RTT:
+///
+/// ```ignore
+/// use defmt_rtt as _;
+/// use enumeraties::props;
+///
+/// struct StateDef { name: &'static str }
+/// enum State {Idle, Busy(u16)}
+/// props! {
+///     impl Deref for State as const StateDef {
+///         defmt(name)
+///         Self::Idle => {
+///             name: "idle",
+///         }
+///         Self::Busy(_) => {
+///             name: "busy",
+///         }
+///     }
+/// }
+///
+/// // Logs e.g. `INFO state: busy`
+/// defmt::info!("state: {}", State::Busy(3));
+/// ```
+///
+/// Conversely, `deserialize_from(<FIELD>)` implements
+/// [`Deserialize`](serde::Deserialize) for the enum from a string, returning
+/// the variant whose field equals it, or an error listing the fields of all
+/// branches otherwise. Like for `from_str`, it is only supported for `const`
+/// properties of fieldless variants without a wildcard branch, and the field
+/// is checked to be unique. Together, both clauses derive the whole
+/// serialized form from the table.
+///
+#[cfg_attr(feature = "serde", doc = "```")]
+#[cfg_attr(not(feature = "serde"), doc = "```ignore")]
+/// # use enumeraties::props;
+/// struct ColorDef { name: &'static str }
+/// #[derive(Debug, PartialEq)]
+/// enum Color {Red, Green}
+/// props! {
+///     impl Deref for Color as const ColorDef {
+///         serialize_as(name)
+///         deserialize_from(name)
+///         Self::Red => {
+///             name: "red",
+///         }
+///         Self::Green => {
+///             name: "green",
+///         }
+///     }
+/// }
+/// let json = serde_json::to_string(&Color::Green).unwrap();
+/// assert_eq!(serde_json::from_str::<Color>(&json).unwrap(), Color::Green);
+/// assert_eq!(
+///     serde_json::from_str::<Color>(r#""blue""#).unwrap_err().to_string(),
+///     "unknown variant `blue`, expected `red` or `green` at line 1 column 6",
+/// );
+/// ```
+///
+/// Likewise, with the `clap` crate feature, `value_enum(<FIELD>)` or
+/// `value_enum(<FIELD>, help = <FIELD>)` implements
+/// [`ValueEnum`](https://docs.rs/clap/latest/clap/trait.ValueEnum.html) for
+/// the enum, taking the name and the help text of each variant from the given
+/// fields of its property, e.g. for a command line argument. Like for
+/// `variants`, all branches must consist only of fieldless variants, which are
+/// listed in the order of the branches, and the enum must neither be generic.
+/// As required by `ValueEnum`, the enum must be `Clone`.
+///
+#[cfg_attr(feature = "clap", doc = "```")]
+#[cfg_attr(not(feature = "clap"), doc = "```ignore")]
+/// # use enumeraties::props;
+/// use clap::ValueEnum;
+///
+/// struct FormatDef { name: &'static str, help: &'static str }
+/// #[derive(Debug, Clone, PartialEq)]
+/// enum Format {Json, Yaml}
+/// props! {
+///     impl Deref for Format as const FormatDef {
+///         value_enum(name, help = help)
+///         Self::Json => {
+///             name: "json",
+///             help: "JavaScript Object Notation",
+///         }
+///         Self::Yaml => {
+///             name: "yaml",
+///             help: "YAML Ain't Markup Language",
+///         }
+///     }
+/// }
+/// let command = clap::Command::new("convert")
+///     .arg(clap::Arg::new("format").value_parser(clap::value_parser!(Format)));
+/// let matches = command.get_matches_from(["convert", "yaml"]);
+/// assert_eq!(matches.get_one::<Format>("format"), Some(&Format::Yaml));
+/// assert_eq!(Format::from_str("json", false), Ok(Format::Json));
+/// ```
+///
+/// With the `rand` crate feature and a lazy backend, `sample_weighted(<FIELD>)`
+/// defines `pub fn sample_weighted<R: rand::Rng + ?Sized>(rng: &mut R) ->
+/// Self`, which picks a random variant with a probability proportional to the
+/// given `f32` field of its property. The cumulative weights are computed once,
+/// in a lazy static, thus all weights must be finite and not negative, and a
+/// variant of zero weight is never picked. However, if all weights are zero,
+/// it panics. Like for `variants`, all branches must consist only of fieldless
+/// variants, and the enum must not be generic.
+///
+#[cfg_attr(all(feature = "rand", feature = "lazy"), doc = "```")]
+#[cfg_attr(not(all(feature = "rand", feature = "lazy")), doc = "```ignore")]
+/// # use enumeraties::props;
+/// use rand::SeedableRng;
+///
+/// struct TileDef { weight: f32 }
+/// #[derive(Debug, PartialEq)]
+/// enum Tile {Grass, Water, Lava}
+/// props! {
+///     impl Deref for Tile as const TileDef {
+///         sample_weighted(weight)
+///         Self::Grass => {
+///             weight: 3.0,
+///         }
+///         Self::Water => {
+///             weight: 1.0,
+///         }
+///         Self::Lava => {
+///             weight: 0.0,
+///         }
+///     }
+/// }
+/// let mut rng = rand::rngs::SmallRng::seed_from_u64(0);
+/// assert_ne!(Tile::sample_weighted(&mut rng), Tile::Lava);
+/// ```
+///
+/// With the `enumset` crate feature, `set_where(<NAME> = <FIELD>, ...)`
+/// defines an associated `pub const <NAME>: EnumSet<Self>` for each given
+/// `bool` field, containing the variants whose field is `true`. The sets are
+/// computed from the table at compile time, thus, like for `deserialize_from`,
+/// it is only supported for `const` properties of fieldless variants without a
+/// wildcard branch. Further, the enum must derive
+/// [`EnumSetType`](https://docs.rs/enumset/latest/enumset/derive.EnumSetType.html).
+///
+#[cfg_attr(feature = "enumset", doc = "```")]
+#[cfg_attr(not(feature = "enumset"), doc = "```ignore")]
+/// # use enumeraties::props;
+/// use enumset::EnumSetType;
+///
+/// struct EnemyDef { flying: bool, aquatic: bool }
+/// #[derive(EnumSetType, Debug)]
+/// enum Enemy {Bat, Crab, Duck}
+/// props! {
+///     impl Deref for Enemy as const EnemyDef {
+///         set_where(FLYING = flying, AQUATIC = aquatic)
+///         Self::Bat => {
+///             flying: true,
+///             aquatic: false,
+///         }
+///         Self::Crab => {
+///             flying: false,
+///             aquatic: true,
+///         }
+///         Self::Duck => {
+///             flying: true,
+///             aquatic: true,
+///         }
+///     }
+/// }
+/// assert_eq!(Enemy::FLYING, Enemy::Bat | Enemy::Duck);
+/// assert_eq!(Enemy::FLYING & Enemy::AQUATIC, Enemy::Duck);
+/// ```
+///
+/// For enums decoded from raw integers, e.g. of a `#[repr(u8)]` enum via FFI,
+/// `discriminant(<INT>)` defines `pub fn property_of_discriminant(discriminant:
+/// <INT>) -> Option<&'static Prop>`, which gets the property of the variant of
+/// the given discriminant without constructing the enum first, or `None` for an
+/// unknown discriminant. Another name can be given via
+/// `discriminant(<INT> as <NAME>)`, e.g. for several properties of the same
+/// enum. The variants are looked up in a dense table indexed by their
+/// discriminant, which is computed at compile time, thus it spans up to the
+/// largest discriminant, which must be less than 4096. Each discriminant must
+/// fit in the given unsigned integer type, usually the `repr` of the enum.
+/// Like for `variants`, all branches must consist only of fieldless variants,
+/// and the enum must not be generic. The table requires Rust 1.79.
+///
+/// ```
+/// # use enumeraties::props;
+/// struct OpcodeDef { mnemonic: &'static str }
+/// #[repr(u8)]
+/// enum Opcode {Nop = 0x00, Load = 0x10, Store = 0x11}
+/// props! {
+///     impl Deref for Opcode as const OpcodeDef {
+///         discriminant(u8)
+///         Self::Nop => {
+///             mnemonic: "nop",
+///         }
+///         Self::Load => {
+///             mnemonic: "ld",
+///         }
+///         Self::Store => {
+///             mnemonic: "st",
+///         }
+///     }
+/// }
+/// assert_eq!(Opcode::property_of_discriminant(0x11).unwrap().mnemonic, "st");
+/// assert!(Opcode::property_of_discriminant(0x01).is_none());
+/// ```
+///
+/// For large enums, the match of the `EnumProp` impl may compile to worse code
+/// than indexing an array. Thus, `table` implements it by a table of the values
+/// of the branches, indexed by the discriminant of the variant, i.e.
+/// `&TABLE[*self as usize]`. It requires the discriminants to be dense, i.e.
+/// `0..N` for `N` variants, such as the implicit ones, which is checked at
+/// compile time. Hence, the enum must be `Copy`, and, like for `variants`, all
+/// branches must consist only of fieldless variants, which can not override the
+/// modifier. It is only supported for `const` properties implementing
+/// `EnumProp` directly, e.g. via `Deref`, not via `const fn`. Since each variant
+/// holds its own value, the properties of variants sharing a branch are not the
+/// same reference. The table requires Rust 1.83.
+///
+/// ```
+/// # use enumeraties::props;
+/// struct RegisterDef { name: &'static str, width: u8 }
+/// #[derive(Clone, Copy)]
+/// #[repr(u8)]
+/// enum Register {Al, Ax, Eax, Rax}
+/// props! {
+///     impl Deref for Register as const RegisterDef {
+///         table
+///         Self::Al => {
+///             name: "al",
+///             width: 8,
+///         }
+///         Self::Ax => {
+///             name: "ax",
+///             width: 16,
+///         }
+///         Self::Eax => {
+///             name: "eax",
+///             width: 32,
+///         }
+///         Self::Rax => {
+///             name: "rax",
+///             width: 64,
+///         }
+///     }
+/// }
+/// assert_eq!(Register::Eax.width, 32);
+/// ```
+///
+/// Since each branch of a `static` property is a distinct static, the
+/// `identity` clause implements `PartialEq`, `Eq`, and `Hash` for the enum by
+/// the address of its property instead, thus the variants of the same branch
+/// are equal, regardless of their data. Therefore, it is only supported for
+/// `static` properties, whose branches may not override the modifier by
+/// `const`. Notice, the branches using the same named value or referring to
+/// the same item share its address as well, and so may all branches of a
+/// zero-sized property type.
+///
+/// ```
+/// # use enumeraties::props;
+/// struct Meta { name: &'static str }
+/// enum Foo {Alpha, Beta(u32)}
+/// props! {
+///     impl Deref for Foo as static Meta {
+///         identity
+///         Self::Alpha => {
+///             name: "alpha",
+///         }
+///         Self::Beta(_) => {
+///             name: "beta",
+///         }
+///     }
+/// }
+/// assert!(Foo::Beta(1) == Foo::Beta(2));
+/// assert!(Foo::Alpha != Foo::Beta(1));
+/// ```
+///
+/// Likewise, a `static` property can be mapped back to its variant by its
+/// address, i.e. `from_property([<ATTRS>] <VIS> fn <NAME>)` before the named
+/// values and all branches, defined as `<VIS> fn <NAME>(property: &'static
+/// <PROPERTY>) -> Option<Self>`, which returns `None` for any other reference,
+/// even to an equal value. Like for `all`, all branches must consist only of
+/// fieldless variants, and like for `identity`, they may not override the
+/// modifier by `const`. If several variants share an address, e.g. by an or
+/// pattern or a named value, the first of them in the order of the branches
+/// is returned.
+///
+/// ```
+/// # use enumeraties::props;
+/// struct Meta { name: &'static str }
+/// #[derive(Debug, PartialEq)]
+/// enum Foo {Alpha, Beta}
+/// props! {
+///     impl Deref for Foo as static Meta {
+///         from_property(pub fn from_meta)
+///         Self::Alpha => {
+///             name: "alpha",
+///         }
+///         Self::Beta => {
+///             name: "beta",
+///         }
+///     }
+/// }
+/// use enumeraties::EnumProp;
+/// let meta: &'static Meta = Foo::Beta.property();
+/// assert_eq!(Foo::from_meta(meta), Some(Foo::Beta));
+/// static OTHER: Meta = Meta { name: "beta" };
+/// assert_eq!(Foo::from_meta(&OTHER), None);
+/// ```
+///
+/// For atomic fields of a `static` property, setters can be defined on the
+/// enum, i.e. `setters[(<ORDERING>)] { [<ATTRS>] <FIELD> as <VIS> fn <NAME>:
+/// <TYPE>, ... }` before the named values and all branches, defined as
+/// `<VIS> fn <NAME>(&self, value: <TYPE>)`, which stores the value into the
+/// field of the property of the variant with the given
+/// [`Ordering`](core::sync::atomic::Ordering), by default `SeqCst`. Since the
+/// store is observed via any variant sharing the same property, it is only
+/// supported for `static` properties, whose branches may not override the
+/// modifier by `const`, like for `identity`. Notice, a `const` property could
+/// not even hold an atomic, since it may be a distinct copy at each access.
+///
+/// ```
+/// # use enumeraties::props;
+/// use core::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+/// struct Endpoint { retry_count: AtomicU32, enabled: AtomicBool }
+/// enum Service {Auth, Billing}
+/// props! {
+///     impl Deref for Service as static Endpoint {
+///         setters(Relaxed) {
+///             retry_count as pub fn set_retry_count: u32,
+///             enabled as pub fn set_enabled: bool,
+///         }
+///         Self::Auth => {
+///             retry_count: AtomicU32::new(3),
+///             enabled: AtomicBool::new(true),
+///         }
+///         Self::Billing => {
+///             retry_count: AtomicU32::new(5),
+///             enabled: AtomicBool::new(true),
+///         }
+///     }
+/// }
+/// Service::Auth.set_retry_count(7);
+/// assert_eq!(Service::Auth.retry_count.load(Ordering::Relaxed), 7);
+/// assert_eq!(Service::Billing.retry_count.load(Ordering::Relaxed), 5);
+/// ```
+///
+/// A variant can be converted into a field of its property via
+/// `into(<FIELD>: <TYPE>)`, which implements `From<ENUM>` and `From<&ENUM>` for
+/// the type of the field, which must be given (as for projections) and must be
+/// `Copy`, e.g. a number or a `&'static str`. Several `into` clauses must have
+/// distinct types, since each type can only be converted from the enum once.
+///
+/// ```
+/// # use enumeraties::props;
+/// struct StatusDef { code: u16, reason: &'static str }
+/// enum Status {Ok, NotFound}
+/// props! {
+///     impl Deref for Status as const StatusDef {
+///         into(code: u16)
+///         into(reason: &'static str)
+///         Self::Ok => {
+///             code: 200,
+///             reason: "OK",
+///         }
+///         Self::NotFound => {
+///             code: 404,
+///             reason: "Not Found",
+///         }
+///     }
+/// }
+/// assert_eq!(u16::from(Status::NotFound), 404);
+/// let reason: &str = (&Status::Ok).into();
+/// assert_eq!(reason, "OK");
+/// ```
+///
+/// Invariants of the table can be given as assertions, i.e. `assert(|<NAME>|
+/// <CONDITION> [, <MESSAGE>])` before the named values and all branches, where
+/// `<NAME>` is bound to the property of each branch. For `const` properties,
+/// they are checked at compile time, thus a failing assertion does not compile,
+/// naming the branch. For `lazy` properties, they are checked at the
+/// initialization of each branch instead, except for branches overriding the
+/// modifier. Using `debug_assert` instead checks them only with debug
+/// assertions enabled.
+///
+/// ```
+/// # use enumeraties::props;
+/// struct ShapeDef { vertices: u8, internal_angle: f32 }
+/// enum Shape {Triangle, Square}
+/// props! {
+///     impl Shape : fn def as const ShapeDef {
+///         assert(|def| def.vertices >= 3)
+///         debug_assert(|def| def.internal_angle > 0.0, "the angle must be positive")
+///         Self::Triangle => {
+///             vertices: 3,
+///             internal_angle: 60.0,
+///         }
+///         Self::Square => {
+///             vertices: 4,
+///             internal_angle: 90.0,
+///         }
+///     }
+/// }
+/// assert_eq!(Shape::Square.def().vertices, 4);
+/// ```
+///
+/// A property struct used by a single table only, may also be defined inline,
+/// i.e. `[<ATTRS>] [<VIS>] struct <PROPERTY> { <FIELDS> }` in place of the
+/// `<PROPERTY>`, followed by the branches. It is a regular struct, thus it can
+/// be used by further properties too.
+///
+/// ```
+/// # use enumeraties::props;
+/// enum Foo {A, B}
+/// props! {
+///     impl Deref for Foo as const #[derive(Debug)] struct Prop {
+///         name: &'static str,
+///         sides: u8,
+///     } {
+///         Self::A => {
+///             name: "Triangle",
+///             sides: 3,
+///         }
+///         Self::B => {
+///             sides: 4,
+///             name: "Square",
+///         }
+///     }
+/// }
+/// assert_eq!(Foo::B.sides, 4);
+/// let prop: &Prop = &Foo::A;
+/// assert_eq!(prop.name, "Triangle");
+/// ```
+///
+/// Finally, each syntax may be preceded by attributes, e.g. `#[cfg(...)]` or
+/// `#[allow(...)]`, which then apply to all the generated items, i.e. the
+/// trait impls and the inherent method.
+///
+/// ```
+/// # use enumeraties::props;
+/// struct Prop { name: &'static str }
+/// enum Foo {A}
+/// props! {
+///     #[cfg(feature = "metadata")]
+///     impl Deref for Foo as const Prop {
+///         Self::A => {
+///             name: "Foo",
+///         }
+///     }
+/// }
+/// props! {
+///     #[allow(non_snake_case)]
+///     impl Foo : fn Prop as const Prop {
+///         Self::A => {
+///             name: "Foo",
+///         }
+///     }
+/// }
+/// assert_eq!(Foo::A.Prop().name, "Foo");
+/// ```
+///
+/// ## Implementing [Deref](core::ops::Deref)
+///
+/// Syntax:
+///
+/// ```text
+/// impl [<ATTRS>] Deref for <ENUM> as (const|static|lazy[(<BACKEND>)]) <PROPERTY> {
+///     <VARIANT> => {
+///         <FIELD> : <VALUE>,
+///         ...
+///     },
+///     ...
+/// }
+/// ```
+///
+/// Example:
+///
+/// ```
+/// # use enumeraties::props;
+/// struct Prop { name: &'static str }
+/// enum Foo {A}
+/// props! {
+///     impl Deref for Foo as const Prop {
+///         Self::A => {
+///             name: "Foo",
+///         }
+///     }
+/// }
+/// // Direct access due to deref
+/// assert_eq!(Foo::A.name, "Foo");
+/// ```
+///
+/// The optional `<ATTRS>`, e.g. `#[inline]`, are applied to the generated
+/// `deref` method.
+///
+///
+/// ## Implementing an inherent method
+///
+/// Syntax:
+///
+/// ```text
+/// impl <ENUM> : [<ATTRS>] <VIS> fn <FN_NAME> as (const|static|lazy[(<BACKEND>)]) <PROPERTY> {
+///     <VARIANT> => {
+///         <FIELD> : <VALUE>,
+///         ...
+///     },
+///     ...
+/// }
+/// ```
+///
+/// Example:
+///
+/// ```
+/// # use enumeraties::props;
+/// struct Prop { name: &'static str }
+/// enum Foo {A}
+/// props! {
+///     // Of course, an arbitrary function names can be used instead of `getter`
+///     impl Foo : fn getter as const Prop {
+///         Self::A => {
+///             name: "Foo",
+///         }
+///     }
+/// }
+/// // Access via inherent method
+/// assert_eq!(Foo::A.getter().name, "Foo");
+/// ```
+///
+/// The optional `<ATTRS>`, including doc comments, are applied to the
+/// generated method, e.g. to document it:
+///
+/// ```
+/// # use enumeraties::props;
+/// # struct Prop { name: &'static str }
+/// # enum Foo {A}
+/// props! {
+///     impl Foo :
+///         /// Returns the property of the variant
+///         #[must_use]
+///         pub fn getter as const Prop {
+///         Self::A => {
+///             name: "Foo",
+///         }
+///     }
+/// }
+/// ```
+///
+/// For `thread_lazy` properties, the generated method takes a closure to
+/// access the property, i.e. `fn <FN_NAME><R>(&self, f: impl FnOnce(&<PROPERTY>)
+/// -> R) -> R`, and [`EnumPropLocal`] is implemented instead of `EnumProp`.
+/// Similarly, for `dynamic` properties, the generated method is
+/// `fn <FN_NAME>(&self) -> Cow<'static, <PROPERTY>>`, and [`EnumPropDyn`] is
+/// implemented instead. For `ref` properties, the generated method is
+/// `fn <FN_NAME>(&self) -> &<PROPERTY>`, and [`EnumPropRef`] is implemented
+/// instead.
+///
+/// For `const` properties, the method may be declared as `const fn`, i.e.
+/// `impl <ENUM> : <VIS> const fn <FN_NAME> as const <PROPERTY> { ... }`, which
+/// makes it usable in const contexts, e.g.
+/// `const N: usize = Shape::Square.getter().vertices as usize;` or an array
+/// length. Then, `EnumProp` is implemented by calling the method. Since trait
+/// methods can not be `const`, this is only supported by the inherent method,
+/// and its branches must be `const` as well, e.g. they can not be overridden
+/// by `lazy`.
+///
+/// Thus, for `const` properties, the `const fn` methods, the associated consts
+/// of `consts`, `all`, `aggregate`, and `ordered_by`, and the table of
+/// [`EnumPropConst`] are guaranteed to be usable in const contexts, e.g. to
+/// initialize further consts and statics, for any kind of branch, i.e. fields,
+/// defaults, named values, `..like`, expressions, and references to existing
+/// items.
+///
+/// ## Projecting a single field
+///
+/// Instead of the whole property, the inherent method may return just one
+/// field of it, given after the property as `<PROPERTY>.<FIELD>` along with
+/// the return type of the method. If the return type is a reference, the
+/// field is borrowed from the property (a field that is a reference itself,
+/// e.g. `&'static str`, is returned directly), otherwise it is returned by
+/// value and must be `Copy`. This implements `EnumProp` as well, thus further
+/// fields of the same property can be projected without repeating its
+/// branches, by omitting the modifier and the branches:
+///
+/// ```text
+/// impl <ENUM> : [<ATTRS>] <VIS> fn <FN_NAME> -> <TYPE> as (const|static|lazy[(<BACKEND>)]) <PROPERTY>.<FIELD> {
+///     <VARIANT> => {
+///         <FIELD> : <VALUE>,
+///         ...
+///     },
+///     ...
+/// }
+/// impl <ENUM> : [<ATTRS>] <VIS> fn <FN_NAME> -> <TYPE> as <PROPERTY>.<FIELD>;
+/// ```
+///
+/// Example:
+///
+/// ```
+/// # use enumeraties::props;
+/// struct Meta { name: &'static str, cost: u32 }
+/// enum Foo {A, B}
+/// props! {
+///     impl Foo : pub fn name -> &'static str as const Meta.name {
+///         Self::A => {
+///             name: "a",
+///             cost: 1,
+///         }
+///         Self::B => {
+///             name: "b",
+///             cost: 2,
+///         }
+///     }
+/// }
+/// props! {
+///     impl Foo : pub fn cost -> u32 as Meta.cost;
+/// }
+/// assert_eq!(Foo::A.name(), "a");
+/// assert_eq!(Foo::B.cost(), 2);
+/// ```
+///
+/// Similarly, several fields can be projected at once, each via an inherent
+/// method named after its field, given along with its return type. The
+/// attributes and the visibility apply to all of these methods, while each
+/// field may have further attributes, e.g. a doc comment:
+///
+/// ```text
+/// impl <ENUM> : [<ATTRS>] <VIS> fields([<ATTRS>] <FIELD>: <TYPE>, ...) as (const|static|lazy[(<BACKEND>)]) <PROPERTY> {
+///     ...
+/// }
+/// impl <ENUM> : [<ATTRS>] <VIS> fields([<ATTRS>] <FIELD>: <TYPE>, ...) as <PROPERTY>;
+/// ```
+///
+/// Example:
+///
+/// ```
+/// # use enumeraties::props;
+/// struct Meta { name: &'static str, cost: u32 }
+/// enum Foo {A}
+/// props! {
+///     impl Foo : pub fields(name: &'static str, cost: u32) as const Meta {
+///         Self::A => {
+///             name: "a",
+///             cost: 1,
+///         }
+///     }
+/// }
+/// assert_eq!(Foo::A.name(), "a");
+/// assert_eq!(Foo::A.cost(), 1);
+/// ```
+///
+/// Likewise, for fields holding function pointers, e.g. per-variant
+/// behavior, `calls` adds inherent methods which call the function of the
+/// variant with the given arguments, instead of returning the field:
+///
+/// ```text
+/// impl <ENUM> : [<ATTRS>] <VIS> calls([<ATTRS>] <FIELD>(<ARG>: <TYPE>, ...) [-> <RETURN>], ...) as (const|static|lazy[(<BACKEND>)]) <PROPERTY> {
+///     ...
+/// }
+/// impl <ENUM> : [<ATTRS>] <VIS> calls([<ATTRS>] <FIELD>(<ARG>: <TYPE>, ...) [-> <RETURN>], ...) as <PROPERTY>;
+/// ```
+///
+/// Example:
+///
+/// ```
+/// # use enumeraties::props;
+/// struct Handler { on_enter: fn(&mut u32) }
+/// enum State {Menu, Game}
+/// props! {
+///     impl State : pub calls(on_enter(counter: &mut u32)) as const Handler {
+///         Self::Menu => {
+///             on_enter: |counter| *counter = 0,
+///         }
+///         Self::Game => {
+///             on_enter: |counter| *counter += 1,
+///         }
+///     }
+/// }
+/// let mut counter = 41;
+/// State::Game.on_enter(&mut counter);
+/// assert_eq!(counter, 42);
+/// State::Menu.on_enter(&mut counter);
+/// assert_eq!(counter, 0);
+/// ```
+///
+/// ## Implementing both `Deref` and an inherent method
+///
+/// Syntax:
+///
+/// ```text
+/// impl [<ATTRS>] Deref + [<ATTRS>] <VIS> fn <FN_NAME> for <ENUM> as (const|static|lazy[(<BACKEND>)]) <PROPERTY> {
+///     <VARIANT> => {
+///         <FIELD> : <VALUE>,
+///         ...
+///     },
+///     ...
+/// }
+/// ```
+///
+/// Example:
+///
+/// ```
+/// # use enumeraties::props;
+/// struct Prop { name: &'static str }
+/// enum Foo {A}
+/// props! {
+///     impl Deref + pub fn getter for Foo as static Prop {
+///         Self::A => {
+///             name: "Foo",
+///         }
+///     }
+/// }
+/// // Both access the very same property
+/// assert_eq!(Foo::A.name, "Foo");
+/// assert!(core::ptr::eq(&*Foo::A, Foo::A.getter()));
+/// ```
+///
+/// ## Implementing only `EnumProp`
+///
+/// Syntax:
+///
+/// ```text
+/// impl EnumProp for <ENUM> as (const|static|lazy[(<BACKEND>)]) <PROPERTY> {
+///     <VARIANT> => {
+///         <FIELD> : <VALUE>,
+///         ...
+///     },
+///     ...
+/// }
+/// ```
+///
+/// Example:
+///
+/// ```
+/// # use enumeraties::props;
+/// struct Prop { name: &'static str }
+/// enum Foo {A}
+/// props! {
+///     impl EnumProp for Foo as const Prop {
+///         Self::A => {
+///             name: "Foo",
+///         }
+///     }
+/// }
+/// // Access via universal function call
+/// use enumeraties::EnumProp;
+/// assert_eq!(EnumProp::<Prop>::property(&Foo::A).name, "Foo");
+/// ```
+///
+/// ## Implementing `AsRef`, `Borrow`, or `From`
+///
+/// Similar to `Deref`, which can only be implemented for a single property,
+/// any number of properties can implement [`AsRef`](core::convert::AsRef) of
+/// the property type for the enum (along with `EnumProp`), thus generic code
+/// taking e.g. `impl AsRef<Prop>` accepts the enum directly:
+///
+/// ```text
+/// impl [<ATTRS>] AsRef for <ENUM> as (const|static|lazy[(<BACKEND>)]) <PROPERTY> {
+///     <VARIANT> => {
+///         <FIELD> : <VALUE>,
+///         ...
+///     },
+///     ...
+/// }
+/// ```
+///
+/// Likewise, `impl Borrow for ...` implements
+/// [`Borrow`](core::borrow::Borrow) of the property type. Notice, `Borrow`
+/// requires that `Eq`, `Ord`, and `Hash` agree between the enum and its
+/// borrowed property, if implemented by both, e.g. for looking up the enum in
+/// a `HashSet` by a property. Thus, if the enum implements these traits, the
+/// property must implement them equivalently, i.e. distinct variants must have
+/// distinct properties, and vice versa.
+///
+/// Example:
+///
+/// ```
+/// # use enumeraties::props;
+/// struct RenderInfo { color: &'static str }
+/// enum Tile {Grass, Water}
+/// props! {
+///     impl AsRef for Tile as const RenderInfo {
+///         Self::Grass => {
+///             color: "green",
+///         }
+///         Self::Water => {
+///             color: "blue",
+///         }
+///     }
+/// }
+/// fn color(info: impl AsRef<RenderInfo>) -> &'static str {
+///     info.as_ref().color
+/// }
+/// assert_eq!(color(Tile::Water), "blue");
+/// ```
+///
+/// Similarly, `impl From for ...` converts the enum into a static reference
+/// to its property, i.e. it implements `From<&ENUM>` and `From<ENUM>` for
+/// `&'static PROPERTY`, for APIs taking e.g. `impl Into<&'static Prop>`.
+/// Since the property is static anyway, the by-value conversion does not
+/// require the enum to be `Copy`, it just drops the given value. Each property
+/// gets its own impls, thus this can be used for any number of properties of
+/// the same enum, as long as no other conversion into the same reference type
+/// exists, e.g. via the `into` clause.
+///
+/// ```
+/// # use enumeraties::props;
+/// struct ShapeDef { corners: u8 }
+/// enum Shape {Triangle, Square}
+/// props! {
+///     impl From for Shape as static ShapeDef {
+///         Self::Triangle => {
+///             corners: 3,
+///         }
+///         Self::Square => {
+///             corners: 4,
+///         }
+///     }
+/// }
+/// fn corners(def: impl Into<&'static ShapeDef>) -> u8 {
+///     def.into().corners
+/// }
+/// assert_eq!(corners(&Shape::Triangle), 3);
+/// assert_eq!(corners(Shape::Square), 4);
+/// ```
+///
+/// ## Implementing `EnumPropConst`
+///
+/// For `const` properties, [`EnumPropConst`] can be implemented along with
+/// `EnumProp`, which provides the properties of all branches as an associated
+/// const, for generic code in const contexts:
+///
+/// ```text
+/// impl EnumPropConst for <ENUM> as const <PROPERTY> {
+///     <VARIANT> => {
+///         <FIELD> : <VALUE>,
+///         ...
+///     },
+///     ...
+/// }
+/// ```
+///
+/// Since the table is a const, all branches must be `const` as well, e.g. they
+/// can not be overridden by `lazy` or delegate to an inner enum.
+/// See [`EnumPropConst`] for an example.
+///
+/// ## Implementing `EnumPropAll`
+///
+/// For `const` and `static` properties, [`EnumPropAll`] can be implemented along
+/// with `EnumProp`, which provides all variants paired with their properties,
+/// e.g. to iterate them in generic code:
+///
+/// ```text
+/// impl EnumPropAll for <ENUM> as (const|static) <PROPERTY> {
+///     <VARIANT> => {
+///         <FIELD> : <VALUE>,
+///         ...
+///     },
+///     ...
+/// }
+/// ```
+///
+/// Like the `all` table, this requires that all branches name fieldless
+/// variants, thus a wildcard branch or a branch matching variant data is an
+/// error. See [`EnumPropAll`] for an example.
+///
+/// ## Implementing only `EnumPropLocal`
+///
+/// For `thread_lazy` properties, only [`EnumPropLocal`] can be implemented:
+///
+/// ```text
+/// impl EnumPropLocal for <ENUM> as thread_lazy <PROPERTY> {
+///     <VARIANT> => {
+///         <FIELD> : <VALUE>,
+///         ...
+///     },
+///     ...
+/// }
+/// ```
+///
+/// See [`EnumPropLocal`] for an example.
+///
+/// ## Implementing only `EnumPropDyn`
+///
+/// For `dynamic` properties, only [`EnumPropDyn`] can be implemented:
+///
+/// ```text
+/// impl EnumPropDyn for <ENUM> as dynamic <PROPERTY> {
+///     <VARIANT> => {
+///         <FIELD> : <VALUE>,
+///         ...
+///     },
+///     ...
+/// }
+/// ```
+///
+/// See [`EnumPropDyn`] for an example.
+///
+/// ## Implementing only `EnumPropRef`
+///
+/// For `ref` properties, `EnumPropRef` is implemented instead of `EnumProp`:
+///
+/// ```text
+/// impl EnumPropRef for <ENUM> as ref (const|static|lazy[(<BACKEND>)]) <PROPERTY> {
+///     <VARIANT> => <REFERENCE>,
+///     <VARIANT> => {
+///         <FIELD> : <VALUE>,
+///         ...
+///     },
+///     ...
+/// }
+/// ```
+///
+/// See [`EnumPropRef`] for an example.
+///
+/// ## Keyed properties
+///
+/// A property may depend on a runtime key in addition to the variant, e.g. a
+/// text for each locale. Each branch then matches the variant along with the
+/// key, i.e. `(<VARIANT>, <KEY_PATTERN>)`, where the key pattern may be `_`
+/// for all keys of that variant, and the branches must cover all pairs of
+/// them. This implements [`EnumPropKeyed`], optionally along with an inherent
+/// method taking the key:
+///
+/// ```text
+/// impl EnumPropKeyed<<KEY>> for <ENUM> as (const|static|lazy[(<BACKEND>)]) <PROPERTY> {
+///     (<VARIANT>, <KEY_PATTERN>) => {
+///         <FIELD> : <VALUE>,
+///         ...
+///     },
+///     ...
+/// }
+/// impl <ENUM> : <VIS> fn <FN_NAME>(<KEY_NAME>: <KEY>) as ... { ... }
+/// ```
+///
+/// See [`EnumPropKeyed`] for an example.
+///
+/// ## Pairwise properties
+///
+/// Similarly, a property may relate two variants of the same enum, e.g. the
+/// ratio of the areas of two shapes. Each branch then matches a pair of
+/// variants, i.e. `(<VARIANT>, <VARIANT>)`, and the branches must cover all
+/// pairs of them. This implements [`EnumPropPair`], which is accessed via
+/// `relation(&other)`. With `as symmetric`, each branch given as a pair also
+/// covers the mirrored pair, e.g. `(Self::A, Self::B)` covers
+/// `(Self::B, Self::A)` as well, thus only one half of the pairs needs to be
+/// given:
+///
+/// ```text
+/// impl EnumPropPair for <ENUM> as [symmetric] (const|static|lazy[(<BACKEND>)]) <PROPERTY> {
+///     (<VARIANT>, <VARIANT>) => {
+///         <FIELD> : <VALUE>,
+///         ...
+///     },
+///     ...
+/// }
+/// ```
+///
+/// See [`EnumPropPair`] for an example.
+///
+/// ## Tagged properties
+///
+/// Since `EnumProp` is implemented once per property type, the same property
+/// type can be defined twice on the same enum only by distinguishing them via
+/// a marker type, the tag, e.g. for soft and hard limits, given after the
+/// property as `<PROPERTY> @ <TAG>`, where the property must be a plain path.
+/// This implements [`EnumPropTagged`] instead of `EnumProp`, optionally along
+/// with an inherent method:
+///
+/// ```text
+/// impl EnumPropTagged for <ENUM> as (const|static|lazy[(<BACKEND>)]) <PROPERTY> @ <TAG> {
+///     <VARIANT> => {
+///         <FIELD> : <VALUE>,
+///         ...
+///     },
+///     ...
+/// }
+/// impl <ENUM> : <VIS> fn <FN_NAME> as ... <PROPERTY> @ <TAG> { ... }
+/// ```
+///
+/// See [`EnumPropTagged`] for an example.
+///
+/// ## Implementing a trait per variant
+///
+/// A user trait can be implemented by giving its methods for each variant,
+/// which are stored as function pointers in a hidden property, i.e. the
+/// implementation matches the variant once and then calls the function of
+/// the branch. The methods must take `&self` and further plain arguments
+/// without generics, and each branch must give all of them with the same
+/// signatures (those of the first branch are used for the trait impl):
+///
+/// ```text
+/// impl trait <TRAIT> for <ENUM> as (const|static|lazy[(<BACKEND>)]) {
+///     <VARIANT> => {
+///         [<ATTRS>] fn <METHOD>(&self, <ARG>: <TYPE>, ...) [-> <RETURN>] { ... }
+///         ...
+///     },
+///     ...
+/// }
+/// ```
+///
+/// Example:
+///
+/// ```
+/// # use enumeraties::props;
+/// trait Greet { fn greet(&self, name: &str) -> usize; }
+/// enum Lang {En, De}
+/// props! {
+///     impl trait Greet for Lang as const {
+///         Self::En => {
+///             fn greet(&self, name: &str) -> usize {
+///                 "Hello ".len() + name.len()
+///             }
+///         }
+///         Self::De => {
+///             fn greet(&self, name: &str) -> usize {
+///                 "Hallo ".len() + name.len()
+///             }
+///         }
+///     }
+/// }
+/// assert_eq!(Lang::De.greet("Welt"), 10);
+/// ```
+///
+/// ## Error enums
+///
+/// An error enum, whose message, code, etc. are given by its property, can
+/// implement [`Error`](core::error::Error) along with `EnumProp`, where the
+/// given field of the property is the message, i.e. written by `Display` like
+/// `derive(Display = <FIELD>)` (see above). As any error, the enum must
+/// implement `Debug` as well. Further fields are accessible via `Deref` or
+/// projections (see [Projecting a single field](#projecting-a-single-field)):
+///
+/// ```text
+/// impl Error(<FIELD>) for <ENUM> as (const|static|lazy[(<BACKEND>)]) <PROPERTY> {
+///     <VARIANT> => {
+///         <FIELD> : <VALUE>,
+///         ...
+///     },
+///     ...
+/// }
+/// ```
+///
+/// Example:
+///
+/// ```
+/// # use enumeraties::props;
+/// struct ErrMeta { message: &'static str, code: u16, retry: bool }
+/// #[derive(Debug)]
+/// enum MyError {NotFound, Busy}
+/// props! {
+///     impl Error(message) for MyError as const ErrMeta {
+///         Self::NotFound => {
+///             message: "not found",
+///             code: 404,
+///             retry: false,
+///         }
+///         Self::Busy => {
+///             message: "busy, try again later",
+///             code: 503,
+///             retry: true,
+///         }
+///     }
+/// }
+/// props! {
+///     impl MyError : pub fn code -> u16 as ErrMeta.code;
+/// }
+/// let err: Box<dyn std::error::Error> = Box::new(MyError::Busy);
+/// assert_eq!(err.to_string(), "busy, try again later");
+/// assert_eq!(MyError::NotFound.code(), 404);
+/// ```
+///
+/// ## Several properties at once
+///
+/// All properties of an enum can be given in one place, each as a section,
+/// whose head is any of the above without the `impl` and `for <ENUM>`:
+///
+/// ```text
+/// impl <ENUM> {
+///     [<ATTRS>] as (const|static|lazy[(<BACKEND>)]) <PROPERTY> {
+///         <VARIANT> => {
+///             <FIELD> : <VALUE>,
+///             ...
+///         },
+///         ...
+///     }
+///     [<ATTRS>] Deref as ... { ... }
+///     [<ATTRS>] <VIS> fn <FN_NAME> as ... { ... }
+///     [<ATTRS>] Deref + <VIS> fn <FN_NAME> as ... { ... }
+///     [<ATTRS>] (EnumProp|EnumPropConst|EnumPropLocal|EnumPropDyn|EnumPropRef|EnumPropPair) as ... { ... }
+///     [<ATTRS>] EnumPropKeyed<<KEY>> as ... { ... }
+///     [<ATTRS>] <VIS> fn <FN_NAME>(<KEY_NAME>: <KEY>) as ... { ... }
+/// }
+/// ```
+///
+/// A section with just `as` implements only `EnumProp`. Each section is
+/// checked on its own, e.g. it must cover all variants, and errors name the
+/// section they are in. The attributes of a section apply to all of its
+/// generated items, just like the attributes of an entire invocation.
+///
+/// Example:
+///
+/// ```
+/// # use enumeraties::props;
+/// struct Shape { sides: u8 }
+/// struct Render { color: u32 }
+/// enum Foo {A, B}
+/// props! {
+///     impl Foo {
+///         as const Shape {
+///             Self::A => {
+///                 sides: 3,
+///             }
+///             Self::B => {
+///                 sides: 4,
+///             }
+///         }
+///         pub fn render as static Render {
+///             Self::A => {
+///                 color: 0xff0000,
+///             }
+///             Self::B => {
+///                 color: 0x00ff00,
+///             }
+///         }
+///         Deref as const &'static str {
+///             Self::A => "triangle",
+///             Self::B => "square",
+///         }
+///     }
+/// }
+/// use enumeraties::EnumProp;
+/// assert_eq!(EnumProp::<Shape>::property(&Foo::A).sides, 3);
+/// assert_eq!(Foo::B.render().color, 0x00ff00);
+/// assert_eq!(*Foo::A, "triangle");
+/// ```
+///
+/// ## Defining the enum along with its property
+///
+/// The enum itself may also be defined by the macro, with its variants given
+/// by the branches (without the `Self::` prefix), implementing `Deref`:
+///
+/// ```text
+/// [<ATTRS>] <VIS> enum <ENUM> as (const|static|lazy[(<BACKEND>)]) <PROPERTY> {
+///     [<ATTRS>] <VARIANT>[(<TYPES>)|{<FIELDS>}] => {
+///         <FIELD> : <VALUE>,
+///         ...
+///     },
+///     ...
+/// }
+/// ```
+///
+/// The attributes, e.g. derives, and the visibility are applied to the enum,
+/// the attributes of a branch, e.g. doc comments, to its variant. Further
+/// properties can be added to such an enum as usual.
+///
+/// Example:
+///
+/// ```
+/// # use enumeraties::props;
+/// struct Prop { name: &'static str }
+/// props! {
+///     #[derive(Debug, Clone, Copy)]
+///     enum Shape as const Prop {
+///         /// A triangle
+///         Triangle => {
+///             name: "Triangle",
+///         }
+///         Circle(f32) => {
+///             name: "Circle",
+///         }
+///         Rect { width: u32, height: u32 } => {
+///             name: "Rectangle",
+///         }
+///     }
+/// }
+/// assert_eq!(Shape::Triangle.name, "Triangle");
+/// assert_eq!(Shape::Circle(1.0).name, "Circle");
+/// ```
+///
+/// ## Several enums at once
+///
+/// Instead of a single enum, any of the above can list several enums,
+/// separated by commas, e.g. `impl Deref for <ENUM>, <ENUM> as ...` or
+/// `impl <ENUM>, <ENUM> : fn ...`. Each enum gets its own impls of the very
+/// same branches, thus these must name the variants via `Self`, and all enums
+/// need variants of the same names (or a wildcard branch).
+///
+/// Example:
+///
+/// ```
+/// # use enumeraties::props;
+/// struct Meta { name: &'static str }
+/// enum Plane {Triangle, Square}
+/// enum Solid {Triangle, Square, Cube}
+/// props! {
+///     impl Deref for Plane, Solid as const Meta {
+///         Self::Triangle => {
+///             name: "Triangle",
+///         }
+///         Self::Square => {
+///             name: "Square",
+///         }
+///         _ => {
+///             name: "Other",
+///         }
+///     }
+/// }
+/// assert_eq!(Plane::Triangle.name, "Triangle");
+/// assert_eq!(Solid::Square.name, "Square");
+/// assert_eq!(Solid::Cube.name, "Other");
+/// ```
+///
+/// ## Generic enums
+///
+/// A generic enum declares its generic parameters (along with their bounds)
+/// right after `impl`, e.g. `impl<T: Clone> Deref for <ENUM><T> as ...`, which
+/// are forwarded to all generated impls. The property values can not depend on
+/// the generic parameters, i.e. all instantiations of the enum share the same
+/// properties (even the same `static` and `lazy` ones).
+/// This includes lifetime parameters, e.g. `impl<'src> Deref for Token<'src>`,
+/// since the `&'static` properties are unrelated to any borrowed payload, as
+/// well as const generic parameters, e.g. `impl<const N: usize>`. Using any of
+/// these parameters within the property values is an error (E0401), except
+/// for the `const` branches and the borrowed references of `ref` properties.
+/// Further bounds can be given as `where` clause right before the branches,
+/// e.g. `impl<T> Deref for <ENUM><T> as const <PROPERTY> where T: Clone { ... }`,
+/// which is likewise forwarded to all generated impls, as required e.g. by
+/// the bounds of the enum itself.
+///
+/// Example:
+///
+/// ```
+/// # use enumeraties::props;
+/// struct Meta { urgent: bool }
+/// enum Message<T> {Text(T), Alert(T)}
+/// props! {
+///     impl<T: Clone> Deref for Message<T> as const Meta {
+///         Self::Text(_) => {
+///             urgent: false,
+///         }
+///         Self::Alert(_) => {
+///             urgent: true,
+///         }
+///     }
+/// }
+/// assert!(!Message::Text("hello").urgent);
+/// assert!(Message::Alert(42).urgent);
+/// ```
+///
+#[macro_export]
+macro_rules! props {
+	(
+		// Attributes on the entire invocation, applied to all generated items
+		// via an anonymous const
+		$(#[$attr:meta])+
+		impl $($rest:tt)+
+	) => {
+		$(#[$attr])+
+		const _: () = {
+			$crate::props!{
+				impl $($rest)+
+			}
+		};
+	};
+	(
+		// Generic parameters of the enum, e.g. `impl<T: 'static> Deref for ...`
+		impl < $($rest:tt)+
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Generics [] [] $($rest)+
+		}
+	};
+	(
+		// The enum itself, defined along with its property, via `Deref`
+		$(#[$enum_attr:meta])*
+		$enum_vis:vis enum $enum_name:ident as $($rest:tt)+
+	) => {
+		$crate::internal_props_impl_macro!{
+			@EnumDef [$(#[$enum_attr])* $enum_vis enum $enum_name] ($enum_name) [] $($rest)+
+		}
+	};
+	(
+		// The const prop impl via inherent `const fn`, which is usable in const
+		// contexts (also impls `EnumProp`, by calling it)
+		impl $(@Generics [$($generics:tt)*])? $enum_name:ty : $(#[$fn_attr:meta])* $fn_vis:vis const fn $fn_name:ident
+		as const $prop_name:path { $($matching:tt)* }
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Arms [@EnumPropConstFn [$(#[$fn_attr])* $fn_vis] $fn_name ($prop_name) for [$($($generics)*)?] $enum_name] [] []
+			$($matching)*
+		}
+	};
+	(
+		// Any other modifier can not be used in a `const fn`
+		impl $(@Generics [$($generics:tt)*])? $enum_name:ty : $(#[$fn_attr:meta])* $fn_vis:vis const fn $fn_name:ident
+		as $modifier:ident $($rest:tt)*
+	) => {
+		::core::compile_error!(::core::concat!(
+			"`const fn` methods require a `const` property, not `",
+			::core::stringify!($modifier),
+			"`"
+		));
+	};
+	(
+		// A user trait implemented by giving its methods for each variant,
+		// which are stored as function pointers in a hidden property (its
+		// signatures are those of the first branch), within an anonymous
+		// const to keep it local
+		impl trait $trait_name:ident $(:: $trait_segment:ident)* for $enum_name:ty as $modifier:ident $(($($args:tt)*))? {
+			$(#[$first_attr:meta])*
+			$first_branch:pat $(if $first_guard:expr)? => {
+				$(
+					$(#[$method_attr:meta])*
+					fn $method:ident (& $first_self:tt $(, $arg:ident : $arg_type:ty)* $(,)?) $(-> $ret:ty)? $first_body:block
+				)*
+			} $(,)?
+			$(
+				$(#[$arm_attr:meta])*
+				$branch:pat $(if $guard:expr)? => { $($methods:tt)* } $(,)?
+			)*
+		}
+	) => {
+		const _: () = {
+			struct InternalPropsVtable {
+				$(
+					$method: fn(&$enum_name $(, $arg_type)*) $(-> $ret)?,
+				)*
+			}
+
+			$crate::props!{
+				impl EnumProp for $enum_name as $modifier $(($($args)*))? InternalPropsVtable {
+					$(#[$first_attr])*
+					$first_branch $(if $first_guard)? => $crate::internal_props_impl_macro!(@Vtable ($enum_name) {
+						$(
+							$(#[$method_attr])*
+							fn $method(& $first_self $(, $arg: $arg_type)*) $(-> $ret)? $first_body
+						)*
+					}),
+					$(
+						$(#[$arm_attr])*
+						$branch $(if $guard)? => $crate::internal_props_impl_macro!(@Vtable ($enum_name) {
+							$($methods)*
+						}),
+					)*
+				}
+			}
+
+			impl $trait_name $(:: $trait_segment)* for $enum_name {
+				$(
+					fn $method(&self $(, $arg: $arg_type)*) $(-> $ret)? {
+						($crate::EnumProp::<InternalPropsVtable>::property(self).$method)(self $(, $arg)*)
+					}
+				)*
+			}
+		};
+	};
+	(
+		// Call-through methods of function pointer fields, each via an inherent
+		// method named after its field (also impls `EnumProp`)
+		impl $(@Generics [$($generics:tt)*])? $enum_name:ty : $(#[$fn_attr:meta])* $fn_vis:vis calls ( $($calls:tt)* )
+		as $modifier:ident $(($($args:tt)*))? $prop_name:path { $($matching:tt)* }
+	) => {
+		// Add the EnumProp impl
+		$crate::props!{
+			impl $(@Generics [$($generics)*])? EnumProp for $enum_name as $modifier $(($($args)*))? $prop_name {
+				$($matching)*
+			}
+		}
+
+		// Add the calling inherent methods
+		$crate::internal_props_impl_macro!{
+			@Calls [$($($generics)*)?] [$enum_name] [$(#[$fn_attr])*] [$fn_vis] ($prop_name) $($calls)*
+		}
+	};
+	(
+		// Call-through methods of function pointer fields of an existing
+		// property
+		impl $(@Generics [$($generics:tt)*])? $enum_name:ty : $(#[$fn_attr:meta])* $fn_vis:vis calls ( $($calls:tt)* )
+		as $prop_name:path $(;)?
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Calls [$($($generics)*)?] [$enum_name] [$(#[$fn_attr])*] [$fn_vis] ($prop_name) $($calls)*
+		}
+	};
+	(
+		// Projections of several fields, each via an inherent method named
+		// after its field (also impls `EnumProp`)
+		impl $(@Generics [$($generics:tt)*])? $enum_name:ty : $(#[$fn_attr:meta])* $fn_vis:vis fields ( $($fields:tt)* )
+		as $modifier:ident $(($($args:tt)*))? $prop_name:path { $($matching:tt)* }
+	) => {
+		// Add the EnumProp impl
+		$crate::props!{
+			impl $(@Generics [$($generics)*])? EnumProp for $enum_name as $modifier $(($($args)*))? $prop_name {
+				$($matching)*
+			}
+		}
+
+		// Add the projecting inherent methods
+		$crate::internal_props_impl_macro!{
+			@Fields [$($($generics)*)?] [$enum_name] [$(#[$fn_attr])*] [$fn_vis] ($prop_name) $($fields)*
+		}
+	};
+	(
+		// Projections of several fields of an existing property, each via an
+		// inherent method named after its field
+		impl $(@Generics [$($generics:tt)*])? $enum_name:ty : $(#[$fn_attr:meta])* $fn_vis:vis fields ( $($fields:tt)* )
+		as $prop_name:path $(;)?
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Fields [$($($generics)*)?] [$enum_name] [$(#[$fn_attr])*] [$fn_vis] ($prop_name) $($fields)*
+		}
+	};
+	(
+		// A projection via inherent method, returning a single field of the
+		// property (also impls `EnumProp`, if given along with the branches)
+		impl $(@Generics [$($generics:tt)*])? $enum_name:ty : $(#[$fn_attr:meta])* $fn_vis:vis fn $fn_name:ident
+		-> $($rest:tt)+
+	) => {
+		$crate::internal_props_impl_macro!{
+			@ProjectionRet [$($($generics)*)?] [$enum_name] [$(#[$fn_attr])* $fn_vis fn $fn_name] [] $($rest)+
+		}
+	};
+	(
+		// The pairwise impl `EnumPropPair`, whose branches match both variants,
+		// each pair given once for both orders
+		impl $(@Generics [$($generics:tt)*])? EnumPropPair for $enum_name:ty
+		as symmetric $modifier:ident $(($($args:tt)*))? $prop_name:path { $($matching:tt)* }
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Arms [@EnumPropKeyed mod($modifier $(($($args)*))?) ($prop_name) [@Pair symmetric] for [$($($generics)*)?] $enum_name] [] []
+			$($matching)*
+		}
+	};
+	(
+		// The pairwise impl `EnumPropPair`, whose branches match both variants
+		impl $(@Generics [$($generics:tt)*])? EnumPropPair for $enum_name:ty
+		as $modifier:ident $(($($args:tt)*))? $prop_name:path { $($matching:tt)* }
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Arms [@EnumPropKeyed mod($modifier $(($($args)*))?) ($prop_name) [@Pair] for [$($($generics)*)?] $enum_name] [] []
+			$($matching)*
+		}
+	};
+	(
+		// The tagged impl `EnumPropTagged`, whose property is distinguished by
+		// the tag, implemented like a keyed one, but matching only the variant
+		impl $(@Generics [$($generics:tt)*])? EnumPropTagged for $enum_name:ty
+		as $modifier:ident $(($($args:tt)*))? $($prop_segment:ident)::+ @ $tag:ty { $($matching:tt)* }
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Arms [@EnumPropKeyed mod($modifier $(($($args)*))?) ($($prop_segment)::+) [@Tag $tag] for [$($($generics)*)?] $enum_name] [] []
+			$($matching)*
+		}
+	};
+	(
+		impl $(@Generics [$($generics:tt)*])? EnumPropTagged for $enum_name:ty as $($rest:tt)*
+	) => {
+		::core::compile_error!(
+			"expected `impl EnumPropTagged for <ENUM> as <MODIFIER> <PROPERTY> @ <TAG> { ... }`, where the property is a plain path"
+		);
+	};
+	(
+		// The tagged impl via inherent method (also impls `EnumPropTagged`)
+		impl $(@Generics [$($generics:tt)*])? $enum_name:ty : $(#[$fn_attr:meta])* $fn_vis:vis fn $fn_name:ident
+		as $modifier:ident $(($($args:tt)*))? $($prop_segment:ident)::+ @ $tag:ty { $($matching:tt)* }
+	) => {
+		// Add the EnumPropTagged impl
+		$crate::internal_props_impl_macro!{
+			@Arms [@EnumPropKeyed mod($modifier $(($($args)*))?) ($($prop_segment)::+) [@Tag $tag] for [$($($generics)*)?] $enum_name] [] []
+			$($matching)*
+		}
+
+		// Add the inherent method forwarding
+		$crate::internal_props_impl_macro!{
+			@Impl [$($($generics)*)?] [$enum_name] {
+				$(#[$fn_attr])*
+				$fn_vis fn $fn_name(&self) -> &'static $($prop_segment)::+ {
+					$crate::EnumPropTagged::<$tag, $($prop_segment)::+>::property_tagged(self)
+				}
+			}
+		}
+	};
+	(
+		// The keyed impl `EnumPropKeyed`, whose branches match the variant
+		// along with the key
+		impl $(@Generics [$($generics:tt)*])? EnumPropKeyed < $key:ty > for $enum_name:ty
+		as $modifier:ident $(($($args:tt)*))? $prop_name:path { $($matching:tt)* }
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Arms [@EnumPropKeyed mod($modifier $(($($args)*))?) ($prop_name) [$key] for [$($($generics)*)?] $enum_name] [] []
+			$($matching)*
+		}
+	};
+	(
+		// The keyed impl via inherent method taking the key (also impls
+		// `EnumPropKeyed`)
+		impl $(@Generics [$($generics:tt)*])? $enum_name:ty : $(#[$fn_attr:meta])* $fn_vis:vis fn $fn_name:ident ($key_name:ident : $key:ty)
+		as $modifier:ident $(($($args:tt)*))? $prop_name:path { $($matching:tt)* }
+	) => {
+		// Add the EnumPropKeyed impl
+		$crate::internal_props_impl_macro!{
+			@Arms [@EnumPropKeyed mod($modifier $(($($args)*))?) ($prop_name) [$key] for [$($($generics)*)?] $enum_name] [] []
+			$($matching)*
+		}
+
+		// Add the inherent method forwarding
+		$crate::internal_props_impl_macro!{
+			@Impl [$($($generics)*)?] [$enum_name] {
+				$(#[$fn_attr])*
+				$fn_vis fn $fn_name(&self, $key_name: $key) -> &'static $prop_name {
+					$crate::EnumPropKeyed::<$key, $prop_name>::property_for(self, $key_name)
+				}
+			}
+		}
+	};
+	(
+		// A borrowing impl with both `Deref` and an inherent method (also
+		// impls `EnumPropRef`)
+		impl $(@Generics [$($generics:tt)*])? $(#[$deref_attr:meta])* Deref + $(#[$fn_attr:meta])* $fn_vis:vis fn $fn_name:ident
+		for $enum_name:ty as ref $modifier:ident $(($($args:tt)*))? $prop_name:path { $($matching:tt)* }
+	) => {
+		// Add the EnumPropRef impl along with the deref forwarding
+		$crate::props!{
+			impl $(@Generics [$($generics)*])? $(#[$deref_attr])* Deref for $enum_name as ref $modifier $(($($args)*))? $prop_name {
+				$($matching)*
+			}
+		}
+
+		// Add the inherent method forwarding
+		$crate::internal_props_impl_macro!{
+			@Impl [$($($generics)*)?] [$enum_name] {
+				$(#[$fn_attr])*
+				$fn_vis fn $fn_name(&self) -> &$prop_name {
+					$crate::EnumPropRef::<$prop_name>::property_ref(self)
+				}
+			}
+		}
+	};
+	(
+		// A borrowing impl that will be promoted to `Deref` (also impls
+		// `EnumPropRef`)
+		impl $(@Generics [$($generics:tt)*])? $(#[$fn_attr:meta])* Deref for $enum_name:ty as ref $modifier:ident $(($($args:tt)*))? $prop_name:path { $($matching:tt)* }
+	) => {
+		// Add the EnumPropRef impl
+		$crate::internal_props_impl_macro!{
+			@Arms [@EnumPropRef mod(ref $modifier $(($($args)*))?) ($prop_name) for [$($($generics)*)?] $enum_name] [] []
+			$($matching)*
+		}
+
+		// Add the deref forwarding
+		$crate::internal_props_impl_macro!{
+			@Impl [$($($generics)*)?] [$crate::Deref for $enum_name] {
+				type Target = $prop_name;
+				$(#[$fn_attr])*
+				fn deref(&self) -> &Self::Target {
+					$crate::EnumPropRef::<$prop_name>::property_ref(self)
+				}
+			}
+		}
+	};
+	(
+		// The borrowing impl via inherent method (also impls `EnumPropRef`)
+		impl $(@Generics [$($generics:tt)*])? $enum_name:ty : $(#[$fn_attr:meta])* $fn_vis:vis fn $fn_name:ident
+		as ref $modifier:ident $(($($args:tt)*))? $prop_name:path { $($matching:tt)* }
+	) => {
+		// Add the EnumPropRef impl
+		$crate::internal_props_impl_macro!{
+			@Arms [@EnumPropRef mod(ref $modifier $(($($args)*))?) ($prop_name) for [$($($generics)*)?] $enum_name] [] []
+			$($matching)*
+		}
+
+		// Add the inherent method forwarding
+		$crate::internal_props_impl_macro!{
+			@Impl [$($($generics)*)?] [$enum_name] {
+				$(#[$fn_attr])*
+				$fn_vis fn $fn_name(&self) -> &$prop_name {
+					$crate::EnumPropRef::<$prop_name>::property_ref(self)
+				}
+			}
+		}
+	};
+	(
+		// The borrowing impl `EnumPropRef` only
+		impl $(@Generics [$($generics:tt)*])? EnumPropRef for $enum_name:ty
+		as ref $modifier:ident $(($($args:tt)*))? $prop_name:path { $($matching:tt)* }
+	) => {
+		// Add the EnumPropRef impl
+		$crate::internal_props_impl_macro!{
+			@Arms [@EnumPropRef mod(ref $modifier $(($($args)*))?) ($prop_name) for [$($($generics)*)?] $enum_name] [] []
+			$($matching)*
+		}
+	};
+	(
+		// Borrowing props can not implement `EnumProp`
+		impl $(@Generics [$($generics:tt)*])? EnumProp for $enum_name:ty as ref $($rest:tt)+
+	) => {
+		::core::compile_error!(
+			"`ref` properties can not implement `EnumProp`, use \
+			`impl EnumPropRef for`, `Deref`, or the inherent method syntax instead"
+		);
+	};
+	(
+		// A borrowing property type that is not a path, with both `Deref` and
+		// an inherent method
+		impl $(@Generics [$($generics:tt)*])? $(#[$deref_attr:meta])* Deref + $(#[$fn_attr:meta])* $fn_vis:vis fn $fn_name:ident
+		for $enum_name:ty as ref $modifier:ident $(($($args:tt)*))? $($rest:tt)+
+	) => {
+		$crate::internal_props_impl_macro!{
+			@PropType [
+				impl $(@Generics [$($generics)*])? $(#[$deref_attr])* Deref + $(#[$fn_attr])* $fn_vis fn $fn_name
+				for $enum_name as ref $modifier $(($($args)*))?
+			] $($rest)+
+		}
+	};
+	(
+		// A borrowing property type that is not a path, via `Deref`
+		impl $(@Generics [$($generics:tt)*])? $(#[$fn_attr:meta])* Deref for $enum_name:ty
+		as ref $modifier:ident $(($($args:tt)*))? $($rest:tt)+
+	) => {
+		$crate::internal_props_impl_macro!{
+			@PropType [
+				impl $(@Generics [$($generics)*])? $(#[$fn_attr])* Deref for $enum_name as ref $modifier $(($($args)*))?
+			] $($rest)+
+		}
+	};
+	(
+		// A borrowing property type that is not a path, via inherent method
+		impl $(@Generics [$($generics:tt)*])? $enum_name:ty : $(#[$fn_attr:meta])* $fn_vis:vis fn $fn_name:ident
+		as ref $modifier:ident $(($($args:tt)*))? $($rest:tt)+
+	) => {
+		$crate::internal_props_impl_macro!{
+			@PropType [
+				impl $(@Generics [$($generics)*])? $enum_name : $(#[$fn_attr])* $fn_vis fn $fn_name
+				as ref $modifier $(($($args)*))?
+			] $($rest)+
+		}
+	};
+	(
+		// A borrowing property type that is not a path, via `EnumPropRef`
+		impl $(@Generics [$($generics:tt)*])? EnumPropRef for $enum_name:ty
+		as ref $modifier:ident $(($($args:tt)*))? $($rest:tt)+
+	) => {
+		$crate::internal_props_impl_macro!{
+			@PropType [
+				impl $(@Generics [$($generics)*])? EnumPropRef for $enum_name as ref $modifier $(($($args)*))?
+			] $($rest)+
+		}
+	};
+	(
+		// A trait object property, with both `Deref` and an inherent method
+		impl $(@Generics [$($generics:tt)*])? $(#[$deref_attr:meta])* Deref + $(#[$fn_attr:meta])* $fn_vis:vis fn $fn_name:ident
+		for $enum_name:ty as $modifier:ident dyn $($rest:tt)+
+	) => {
+		$crate::internal_props_impl_macro!{
+			@DynType [
+				impl $(@Generics [$($generics)*])? $(#[$deref_attr])* Deref + $(#[$fn_attr])* $fn_vis fn $fn_name
+				for $enum_name as
+			] $modifier dyn $($rest)+
+		}
+	};
+	(
+		// A trait object property, via `Deref`
+		impl $(@Generics [$($generics:tt)*])? $(#[$fn_attr:meta])* Deref for $enum_name:ty as $modifier:ident dyn $($rest:tt)+
+	) => {
+		$crate::internal_props_impl_macro!{
+			@DynType [impl $(@Generics [$($generics)*])? $(#[$fn_attr])* Deref for $enum_name as] $modifier dyn $($rest)+
+		}
+	};
+	(
+		// A trait object property, via `EnumProp`
+		impl $(@Generics [$($generics:tt)*])? $trait_name:ident for $enum_name:ty as $modifier:ident dyn $($rest:tt)+
+	) => {
+		$crate::internal_props_impl_macro!{
+			@DynType [impl $(@Generics [$($generics)*])? $trait_name for $enum_name as] $modifier dyn $($rest)+
+		}
+	};
+	(
+		// A trait object property, via inherent method
+		impl $(@Generics [$($generics:tt)*])? $enum_name:ty : $(#[$fn_attr:meta])* $fn_vis:vis fn $fn_name:ident as $modifier:ident
+		dyn $($rest:tt)+
+	) => {
+		$crate::internal_props_impl_macro!{
+			@DynType [impl $(@Generics [$($generics)*])? $enum_name : $(#[$fn_attr])* $fn_vis fn $fn_name as] $modifier dyn $($rest)+
+		}
+	};
+	(
+		// An inline property struct, with both `Deref` and an inherent method
+		// (without attributes or visibility, `struct` would not be rejected as
+		// a path)
+		impl $(@Generics [$($generics:tt)*])? $(#[$deref_attr:meta])* Deref + $(#[$fn_attr:meta])* $fn_vis:vis fn $fn_name:ident
+		for $enum_name:ty as $modifier:ident $(($($args:tt)*))? struct $($rest:tt)+
+	) => {
+		$crate::internal_props_impl_macro!{
+			@PropType [
+				impl $(@Generics [$($generics)*])? $(#[$deref_attr])* Deref + $(#[$fn_attr])* $fn_vis fn $fn_name
+				for $enum_name as $modifier
+			] $(($($args)*))? struct $($rest)+
+		}
+	};
+	(
+		// A lazy/const impl with both `Deref` and an inherent method (also
+		// impls `EnumProp`)
+		impl $(@Generics [$($generics:tt)*])? $(#[$deref_attr:meta])* Deref + $(#[$fn_attr:meta])* $fn_vis:vis fn $fn_name:ident
+		for $enum_name:ty as $modifier:ident $(($($args:tt)*))? $prop_name:path { $($matching:tt)* }
+	) => {
+		// Add the EnumProp impl along with the deref forwarding
+		$crate::props!{
+			impl $(@Generics [$($generics)*])? $(#[$deref_attr])* Deref for $enum_name as $modifier $(($($args)*))? $prop_name {
+				$($matching)*
+			}
+		}
+
+		// Add the inherent method forwarding
+		$crate::internal_props_impl_macro!{
+			@Impl [$($($generics)*)?] [$enum_name] {
+				$(#[$fn_attr])*
+				$fn_vis fn $fn_name(&self) -> &'static $prop_name {
+					$crate::EnumProp::<$prop_name>::property(self)
+				}
+			}
+		}
+	};
+	(
+		// A property type that is not a path, with both `Deref` and an
+		// inherent method
+		impl $(@Generics [$($generics:tt)*])? $(#[$deref_attr:meta])* Deref + $(#[$fn_attr:meta])* $fn_vis:vis fn $fn_name:ident
+		for $enum_name:ty as $modifier:ident $($rest:tt)+
+	) => {
+		$crate::internal_props_impl_macro!{
+			@PropType [
+				impl $(@Generics [$($generics)*])? $(#[$deref_attr])* Deref + $(#[$fn_attr])* $fn_vis fn $fn_name
+				for $enum_name as $modifier
+			] $($rest)+
+		}
+	};
+	(
+		// An inline property struct, via `Deref`
+		impl $(@Generics [$($generics:tt)*])? $(#[$fn_attr:meta])* Deref for $enum_name:ty as $modifier:ident $(($($args:tt)*))? struct $($rest:tt)+
+	) => {
+		$crate::internal_props_impl_macro!{
+			@PropType [impl $(@Generics [$($generics)*])? $(#[$fn_attr])* Deref for $enum_name as $modifier]
+			$(($($args)*))? struct $($rest)+
+		}
+	};
+	(
+		// An inline property struct, via `EnumProp`, `EnumPropLocal`, or
+		// `EnumPropDyn`
+		impl $(@Generics [$($generics:tt)*])? $trait_name:ident for $enum_name:ty as $modifier:ident $(($($args:tt)*))? struct $($rest:tt)+
+	) => {
+		$crate::internal_props_impl_macro!{
+			@PropType [impl $(@Generics [$($generics)*])? $trait_name for $enum_name as $modifier]
+			$(($($args)*))? struct $($rest)+
+		}
+	};
+	(
+		// An inline property struct, via inherent method
+		impl $(@Generics [$($generics:tt)*])? $enum_name:ty : $(#[$fn_attr:meta])* $fn_vis:vis fn $fn_name:ident as $modifier:ident $(($($args:tt)*))?
+		struct $($rest:tt)+
+	) => {
+		$crate::internal_props_impl_macro!{
+			@PropType [impl $(@Generics [$($generics)*])? $enum_name : $(#[$fn_attr])* $fn_vis fn $fn_name as $modifier]
+			$(($($args)*))? struct $($rest)+
+		}
+	};
+	(
+		// Several enums with the same properties, i.e. with the same branches
+		// for each enum, both `Deref` and an inherent method
+		impl $(@Generics [$($generics:tt)*])? $(#[$deref_attr:meta])* Deref + $(#[$fn_attr:meta])* $fn_vis:vis fn $fn_name:ident
+		for $first_enum:ty, $($enum_name:ty),+ as $($rest:tt)+
+	) => {
+		$crate::internal_props_impl_macro!{
+			@EnumsStruct [
+				impl $(@Generics [$($generics)*])? $(#[$deref_attr])* Deref + $(#[$fn_attr])* $fn_vis fn $fn_name for
+			] [] [as $($rest)+] $first_enum, $($enum_name),+
+		}
+	};
+	(
+		// Several enums with the same properties, via `Deref`
+		impl $(@Generics [$($generics:tt)*])? $(#[$fn_attr:meta])* Deref for $first_enum:ty, $($enum_name:ty),+ as $($rest:tt)+
+	) => {
+		$crate::internal_props_impl_macro!{
+			@EnumsStruct [impl $(@Generics [$($generics)*])? $(#[$fn_attr])* Deref for] [] [as $($rest)+] $first_enum, $($enum_name),+
+		}
+	};
+	(
+		// Several enums with the same properties, via `EnumProp`,
+		// `EnumPropConst`, `EnumPropLocal`, `EnumPropDyn`, `EnumPropRef`, or
+		// `EnumPropPair`
+		impl $(@Generics [$($generics:tt)*])? $trait_name:ident for $first_enum:ty, $($enum_name:ty),+ as $($rest:tt)+
+	) => {
+		$crate::internal_props_impl_macro!{
+			@EnumsStruct [impl $(@Generics [$($generics)*])? $trait_name for] [] [as $($rest)+] $first_enum, $($enum_name),+
+		}
+	};
+	(
+		// Several enums with the same properties, via `EnumPropKeyed`
+		impl $(@Generics [$($generics:tt)*])? EnumPropKeyed < $key:ty > for $first_enum:ty, $($enum_name:ty),+ as $($rest:tt)+
+	) => {
+		$crate::internal_props_impl_macro!{
+			@EnumsStruct [impl $(@Generics [$($generics)*])? EnumPropKeyed<$key> for] [] [as $($rest)+] $first_enum, $($enum_name),+
+		}
+	};
+	(
+		// Several enums with the same properties, via inherent method
+		impl $(@Generics [$($generics:tt)*])? $first_enum:ty, $($enum_name:ty),+ : $($rest:tt)+
+	) => {
+		$crate::internal_props_impl_macro!{
+			@EnumsStruct [impl $(@Generics [$($generics)*])?] [] [: $($rest)+] $first_enum, $($enum_name),+
+		}
+	};
+	(
+		// Several enums with the same properties, each given as a section
+		impl $(@Generics [$($generics:tt)*])? $first_enum:ty, $($enum_name:ty),+ { $($sections:tt)* }
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Sections [$($($generics)*)?] ($first_enum, $($enum_name),+) [] $($sections)*
+		}
+	};
+	(
+		// Thread local props can not be promoted to `Deref`
+		impl $(@Generics [$($generics:tt)*])? $(#[$fn_attr:meta])* Deref for $enum_name:ty as thread_lazy $prop_name:path { $($matching:tt)* }
+	) => {
+		::core::compile_error!(
+			"`thread_lazy` properties can not be used with `Deref`, use \
+			`impl EnumPropLocal for` or the inherent method syntax instead"
+		);
+	};
+	(
+		// The thread local impl via inherent method (also impls `EnumPropLocal`)
+		impl $(@Generics [$($generics:tt)*])? $enum_name:ty : $(#[$fn_attr:meta])* $fn_vis:vis fn $fn_name:ident as thread_lazy $prop_name:path { $($matching:tt)* }
+	) => {
+		// Add the EnumPropLocal impl
+		$crate::internal_props_impl_macro!{
+			@Arms [@EnumPropLocal mod(thread_lazy) ($prop_name) for [$($($generics)*)?] $enum_name] [] []
+			$($matching)*
+		}
+
+		// Add the inherent method forwarding
+		$crate::internal_props_impl_macro!{
+			@Impl [$($($generics)*)?] [$enum_name] {
+				$(#[$fn_attr])*
+				$fn_vis fn $fn_name<R>(&self, f: impl FnOnce(&$prop_name) -> R) -> R {
+					$crate::EnumPropLocal::<$prop_name>::with_property(self, f)
+				}
+			}
+		}
+	};
+	(
+		// The thread local impl `EnumPropLocal` only
+		impl $(@Generics [$($generics:tt)*])? EnumPropLocal for $enum_name:ty as thread_lazy $prop_name:path { $($matching:tt)* }
+	) => {
+		// Add the EnumPropLocal impl
+		$crate::internal_props_impl_macro!{
+			@Arms [@EnumPropLocal mod(thread_lazy) ($prop_name) for [$($($generics)*)?] $enum_name] [] []
+			$($matching)*
+		}
+	};
+	(
+		// Dynamic props can not be promoted to `Deref`
+		impl $(@Generics [$($generics:tt)*])? $(#[$fn_attr:meta])* Deref for $enum_name:ty as dynamic $prop_name:path { $($matching:tt)* }
+	) => {
+		::core::compile_error!(
+			"`dynamic` properties can not be used with `Deref`, use \
+			`impl EnumPropDyn for` or the inherent method syntax instead"
+		);
+	};
+	(
+		// The dynamic impl via inherent method (also impls `EnumPropDyn`)
+		impl $(@Generics [$($generics:tt)*])? $enum_name:ty : $(#[$fn_attr:meta])* $fn_vis:vis fn $fn_name:ident as dynamic $prop_name:path { $($matching:tt)* }
+	) => {
+		// Add the EnumPropDyn impl
+		$crate::internal_props_impl_macro!{
+			@Arms [@EnumPropDyn mod(dynamic) ($prop_name) for [$($($generics)*)?] $enum_name] [] []
+			$($matching)*
+		}
+
+		// Add the inherent method forwarding
+		$crate::internal_dynamic!{
+			$crate::internal_props_impl_macro!{
+				@Impl [$($($generics)*)?] [$enum_name] {
+					$(#[$fn_attr])*
+					$fn_vis fn $fn_name(&self) -> $crate::Cow<'static, $prop_name> {
+						$crate::EnumPropDyn::<$prop_name>::property_dyn(self)
+					}
+				}
+			}
+		}
+	};
+	(
+		// The dynamic impl `EnumPropDyn` only
+		impl $(@Generics [$($generics:tt)*])? EnumPropDyn for $enum_name:ty as dynamic $prop_name:path { $($matching:tt)* }
+	) => {
+		// Add the EnumPropDyn impl
+		$crate::internal_props_impl_macro!{
+			@Arms [@EnumPropDyn mod(dynamic) ($prop_name) for [$($($generics)*)?] $enum_name] [] []
+			$($matching)*
+		}
+	};
+	(
+		// A lazy/const impl that will be promoted to `Deref` (also impls `EnumProp`)
+		impl $(@Generics [$($generics:tt)*])? $(#[$fn_attr:meta])* Deref for $enum_name:ty as $modifier:ident $(($($args:tt)*))? $prop_name:path { $($matching:tt)* }
+	) => {
+		// Add the EnumProp impl
+		$crate::internal_props_impl_macro!{
+			@Arms [@EnumProp mod($modifier $(($($args)*))?) ($prop_name) for [$($($generics)*)?] $enum_name] [] []
+			$($matching)*
+		}
+
+		// Allows to initialize all variants at once, if possible
+		$crate::internal_props_impl_macro!{
+			@EnumPropInit mod($modifier $(($($args)*))?) ($prop_name) for [$($($generics)*)?] $enum_name {
+				$($matching)*
+			}
+		}
+
+		// Add the deref forwarding
+		$crate::internal_props_impl_macro!{
+			@Impl [$($($generics)*)?] [$crate::Deref for $enum_name] {
+				type Target = $prop_name;
+				$(#[$fn_attr])*
+				fn deref(&self) -> &Self::Target {
+					$crate::EnumProp::<$prop_name>::property(self)
+				}
+			}
+		}
+	};
+	(
+		// The lazy/const impl via inherent method (also impls `EnumProp`)
+		impl $(@Generics [$($generics:tt)*])? $enum_name:ty : $(#[$fn_attr:meta])* $fn_vis:vis fn $fn_name:ident as $modifier:ident $(($($args:tt)*))? $prop_name:path { $($matching:tt)* }
+	) => {
+		// Add the EnumProp impl
+		$crate::internal_props_impl_macro!{
+			@Arms [@EnumProp mod($modifier $(($($args)*))?) ($prop_name) for [$($($generics)*)?] $enum_name] [] []
+			$($matching)*
+		}
+
+		// Allows to initialize all variants at once, if possible
+		$crate::internal_props_impl_macro!{
+			@EnumPropInit mod($modifier $(($($args)*))?) ($prop_name) for [$($($generics)*)?] $enum_name {
+				$($matching)*
+			}
+		}
+
+		// Add the inherent method forwarding
+		$crate::internal_props_impl_macro!{
+			@Impl [$($($generics)*)?] [$enum_name] {
+				$(#[$fn_attr])*
+				$fn_vis fn $fn_name(&self) -> &'static $prop_name {
+					$crate::EnumProp::<$prop_name>::property(self)
+				}
+			}
+		}
+	};
+	(
+		// The const impl `EnumPropConst` (also impls `EnumProp`)
+		impl $(@Generics [$($generics:tt)*])? EnumPropConst for $enum_name:ty as const $(($($args:tt)*))? $prop_name:path { $($matching:tt)* }
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Arms [@EnumPropConst mod(const $(($($args)*))?) ($prop_name) for [$($($generics)*)?] $enum_name] [] []
+			$($matching)*
+		}
+	};
+	(
+		// A property type that is not a path, via `EnumPropConst`
+		impl $(@Generics [$($generics:tt)*])? EnumPropConst for $enum_name:ty as const $($rest:tt)+
+	) => {
+		$crate::internal_props_impl_macro!{
+			@PropType [impl $(@Generics [$($generics)*])? EnumPropConst for $enum_name as const] $($rest)+
+		}
+	};
+	(
+		// Any other modifier can not be evaluated in a const
+		impl $(@Generics [$($generics:tt)*])? EnumPropConst for $enum_name:ty as $modifier:ident $($rest:tt)*
+	) => {
+		::core::compile_error!(::core::concat!(
+			"`EnumPropConst` is only supported for `const` properties, not `",
+			::core::stringify!($modifier),
+			"`"
+		));
+	};
+	(
+		// The table of all variants `EnumPropAll` (also impls `EnumProp`)
+		impl $(@Generics [$($generics:tt)*])? EnumPropAll for $enum_name:ty as const $(($($args:tt)*))? $prop_name:path { $($matching:tt)* }
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Arms [@EnumProp mod(const $(($($args)*))?) ($prop_name) for [$($($generics)*)?] $enum_name] [] []
+			@PropAll $($matching)*
+		}
+	};
+	(
+		impl $(@Generics [$($generics:tt)*])? EnumPropAll for $enum_name:ty as static $(($($args:tt)*))? $prop_name:path { $($matching:tt)* }
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Arms [@EnumProp mod(static $(($($args)*))?) ($prop_name) for [$($($generics)*)?] $enum_name] [] []
+			@PropAll $($matching)*
+		}
+	};
+	(
+		// Any other modifier can not be evaluated in a const
+		impl $(@Generics [$($generics:tt)*])? EnumPropAll for $enum_name:ty as $modifier:ident $($rest:tt)*
+	) => {
+		::core::compile_error!(::core::concat!(
+			"`EnumPropAll` is only supported for `const` and `static` properties, not `",
+			::core::stringify!($modifier),
+			"`"
+		));
+	};
+	(
+		// The lazy/const impl `EnumProp` only
+		impl $(@Generics [$($generics:tt)*])? EnumProp for $enum_name:ty as $modifier:ident $(($($args:tt)*))? $prop_name:path { $($matching:tt)* }
+	) => {
+		// Add the EnumProp impl
+		$crate::internal_props_impl_macro!{
+			@Arms [@EnumProp mod($modifier $(($($args)*))?) ($prop_name) for [$($($generics)*)?] $enum_name] [] []
+			$($matching)*
+		}
+
+		// Allows to initialize all variants at once, if possible
+		$crate::internal_props_impl_macro!{
+			@EnumPropInit mod($modifier $(($($args)*))?) ($prop_name) for [$($($generics)*)?] $enum_name {
+				$($matching)*
+			}
+		}
+	};
+
+	(
+		// An error enum, whose message is a field of its property (also impls
+		// `EnumProp`)
+		impl $(@Generics [$($generics:tt)*])? Error ($field:tt) for $enum_name:ty
+		as $modifier:ident $(($($args:tt)*))? $prop_name:path { $($matching:tt)* }
+	) => {
+		// Add the EnumProp impl, along with `Display` writing the message
+		$crate::props!{
+			impl $(@Generics [$($generics)*])? EnumProp for $enum_name as $modifier $(($($args)*))? $prop_name {
+				derive(Display = $field)
+				$($matching)*
+			}
+		}
+
+		$crate::internal_props_impl_macro!{
+			@Impl [$($($generics)*)?] [::core::error::Error for $enum_name] {}
+		}
+	};
+
+	(
+		// The `AsRef` impl of the property, e.g. for generic code (also impls
+		// `EnumProp`)
+		impl $(@Generics [$($generics:tt)*])? $(#[$fn_attr:meta])* AsRef for $enum_name:ty
+		as $modifier:ident $(($($args:tt)*))? $prop_name:path { $($matching:tt)* }
+	) => {
+		// Add the EnumProp impl
+		$crate::props!{
+			impl $(@Generics [$($generics)*])? EnumProp for $enum_name as $modifier $(($($args)*))? $prop_name {
+				$($matching)*
+			}
+		}
+
+		// Add the as ref forwarding
+		$crate::internal_props_impl_macro!{
+			@Impl [$($($generics)*)?] [::core::convert::AsRef<$prop_name> for $enum_name] {
+				$(#[$fn_attr])*
+				fn as_ref(&self) -> &$prop_name {
+					$crate::EnumProp::<$prop_name>::property(self)
+				}
+			}
+		}
+	};
+	(
+		// The `Borrow` impl of the property, like `AsRef` (also impls
+		// `EnumProp`)
+		impl $(@Generics [$($generics:tt)*])? $(#[$fn_attr:meta])* Borrow for $enum_name:ty
+		as $modifier:ident $(($($args:tt)*))? $prop_name:path { $($matching:tt)* }
+	) => {
+		// Add the EnumProp impl
+		$crate::props!{
+			impl $(@Generics [$($generics)*])? EnumProp for $enum_name as $modifier $(($($args)*))? $prop_name {
+				$($matching)*
+			}
+		}
+
+		// Add the borrow forwarding
+		$crate::internal_props_impl_macro!{
+			@Impl [$($($generics)*)?] [::core::borrow::Borrow<$prop_name> for $enum_name] {
+				$(#[$fn_attr])*
+				fn borrow(&self) -> &$prop_name {
+					$crate::EnumProp::<$prop_name>::property(self)
+				}
+			}
+		}
+	};
+	(
+		// The `From` impls of the static reference to the property, e.g. for
+		// generic code (also impls `EnumProp`)
+		impl $(@Generics [$($generics:tt)*])? $(#[$fn_attr:meta])* From for $enum_name:ty
+		as $modifier:ident $(($($args:tt)*))? $prop_name:path { $($matching:tt)* }
+	) => {
 		// Add the EnumProp impl
+		$crate::props!{
+			impl $(@Generics [$($generics)*])? EnumProp for $enum_name as $modifier $(($($args)*))? $prop_name {
+				$($matching)*
+			}
+		}
+
+		// Add the conversions, by reference and by value, the latter just
+		// drops the enum, since the property is static anyway
+		$crate::internal_props_impl_macro!{
+			@Impl [$($($generics)*)?] [::core::convert::From<&$enum_name> for &'static $prop_name] {
+				$(#[$fn_attr])*
+				fn from(value: &$enum_name) -> Self {
+					$crate::EnumProp::<$prop_name>::property(value)
+				}
+			}
+		}
+		$crate::internal_props_impl_macro!{
+			@Impl [$($($generics)*)?] [::core::convert::From<$enum_name> for &'static $prop_name] {
+				$(#[$fn_attr])*
+				fn from(value: $enum_name) -> Self {
+					$crate::EnumProp::<$prop_name>::property(&value)
+				}
+			}
+		}
+	};
+
+	(
+		// Several properties at once, each given as a section
+		impl $(@Generics [$($generics:tt)*])? $enum_name:ty { $($sections:tt)* }
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Sections [$($($generics)*)?] ($enum_name) [] $($sections)*
+		}
+	};
+	(
+		// A property type that is not a path, e.g. an array or a reference
+		impl $(@Generics [$($generics:tt)*])? $(#[$fn_attr:meta])* Deref for $enum_name:ty as $modifier:ident $($rest:tt)+
+	) => {
+		$crate::internal_props_impl_macro!{
+			@PropType [impl $(@Generics [$($generics)*])? $(#[$fn_attr])* Deref for $enum_name as $modifier] $($rest)+
+		}
+	};
+	(
+		// A property type that is not a path, via inherent method
+		impl $(@Generics [$($generics:tt)*])? $enum_name:ty : $(#[$fn_attr:meta])* $fn_vis:vis fn $fn_name:ident as $modifier:ident $($rest:tt)+
+	) => {
+		$crate::internal_props_impl_macro!{
+			@PropType [impl $(@Generics [$($generics)*])? $enum_name : $(#[$fn_attr])* $fn_vis fn $fn_name as $modifier] $($rest)+
+		}
+	};
+	(
+		// A property type that is not a path, via `EnumProp`, `EnumPropLocal`,
+		// or `EnumPropDyn`
+		impl $(@Generics [$($generics:tt)*])? $trait_name:ident for $enum_name:ty as $modifier:ident $($rest:tt)+
+	) => {
+		$crate::internal_props_impl_macro!{
+			@PropType [impl $(@Generics [$($generics)*])? $trait_name for $enum_name as $modifier] $($rest)+
+		}
+	};
+}
+
+// The internal marco impl, used by `props`, do not use, its API may change
+// at any time
+#[doc(hidden)]
+#[macro_export]
+macro_rules! internal_props_impl_macro {
+	(
+		// A property struct defined inline, along with the property, thus it
+		// can be used like any other struct
+		@PropType [$($head:tt)*]
+		$(#[$struct_attr:meta])* $struct_vis:vis struct $struct_name:ident { $($fields:tt)* }
+		{ $($matching:tt)* }
+	) => {
+		$(#[$struct_attr])*
+		$struct_vis struct $struct_name {
+			$($fields)*
+		}
+
+		$crate::props!{
+			$($head)* $struct_name { $($matching)* }
+		}
+	};
+	(
+		// A property struct defined inline, with modifier arguments
+		@PropType [$($head:tt)*] ($($args:tt)*)
+		$(#[$struct_attr:meta])* $struct_vis:vis struct $struct_name:ident { $($fields:tt)* }
+		{ $($matching:tt)* }
+	) => {
+		$crate::internal_props_impl_macro!{
+			@PropType [$($head)* ($($args)*)]
+			$(#[$struct_attr])* $struct_vis struct $struct_name { $($fields)* }
+			{ $($matching)* }
+		}
+	};
+	(
+		// A trait object property, the branches are references to the
+		// implementors, marked via the modifier argument
+		@DynType [$($head:tt)*] const $($rest:tt)+
+	) => {
+		$crate::internal_props_impl_macro!{
+			@PropType [$($head)* const] (dyn) $($rest)+
+		}
+	};
+	(
+		// A trait object property, `static`
+		@DynType [$($head:tt)*] static $($rest:tt)+
+	) => {
+		$crate::internal_props_impl_macro!{
+			@PropType [$($head)* static] (dyn) $($rest)+
+		}
+	};
+	(
+		// A trait object property, any other modifier
+		@DynType [$($head:tt)*] $modifier:ident $($rest:tt)+
+	) => {
+		::core::compile_error!("trait object properties are only supported as `const` or `static` properties");
+	};
+	(
+		// A property type that is not a path, is given a name via a type
+		// alias, within an anonymous const to keep it local
+		@PropType [$($head:tt)*] $prop_type:ty { $($matching:tt)* }
+	) => {
+		const _: () = {
+			type InternalPropsType = $prop_type;
+
+			$crate::props!{
+				$($head)* InternalPropsType { @Plain $($matching)* }
+			}
+		};
+	};
+	(
+		// A property type that is not a path, with modifier arguments
+		@PropType [$($head:tt)*] ($($args:tt)*) $prop_type:ty { $($matching:tt)* }
+	) => {
+		const _: () = {
+			type InternalPropsType = $prop_type;
+
+			$crate::props!{
+				$($head)* ($($args)*) InternalPropsType { @Plain $($matching)* }
+			}
+		};
+	};
+	(
+		// An inline property struct for several enums, defined only once
+		@EnumsStruct $head:tt [$($tail:tt)*]
+		[$(#[$struct_attr:meta])* $struct_vis:vis struct $struct_name:ident { $($fields:tt)* } $($rest:tt)*]
+		$($enum_name:ty),+
+	) => {
+		$(#[$struct_attr])*
+		$struct_vis struct $struct_name {
+			$($fields)*
+		}
+
+		$crate::internal_props_impl_macro!{
+			@Enums $head [$($tail)* $struct_name $($rest)*] $($enum_name),+
+		}
+	};
+	(
+		// Searching an inline property struct for several enums
+		@EnumsStruct $head:tt [$($tail:tt)*] [$next:tt $($rest:tt)*] $($enum_name:ty),+
+	) => {
+		$crate::internal_props_impl_macro!{
+			@EnumsStruct $head [$($tail)* $next] [$($rest)*] $($enum_name),+
+		}
+	};
+	(
+		// Searching an inline property struct for several enums, none found
+		@EnumsStruct $head:tt [$($tail:tt)*] [] $($enum_name:ty),+
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Enums $head [$($tail)*] $($enum_name),+
+		}
+	};
+	(
+		// Collecting the generic parameters up to the closing `>`, done
+		@Generics [$($generics:tt)*] [] > $($rest:tt)+
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Where [$($generics)*] [] $($rest)+
+		}
+	};
+	(
+		// Collecting the generic parameters, the arrow of a `Fn` bound is no
+		// closing `>`
+		@Generics [$($generics:tt)*] [$($depth:tt)*] -> $($rest:tt)+
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Generics [$($generics)* ->] [$($depth)*] $($rest)+
+		}
+	};
+	(
+		// Collecting the generic parameters, a `>>` closes two levels, e.g.
+		// `T: Into<Vec<u8>>`
+		@Generics [$($generics:tt)*] [@ $($depth:tt)*] >> $($rest:tt)+
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Generics [$($generics)* >] [$($depth)*] > $($rest)+
+		}
+	};
+	(
+		// Collecting the generic parameters, a nested `<`, e.g. of a bound
+		@Generics [$($generics:tt)*] [$($depth:tt)*] < $($rest:tt)+
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Generics [$($generics)* <] [@ $($depth)*] $($rest)+
+		}
+	};
+	(
+		// Collecting the generic parameters, a nested `>`
+		@Generics [$($generics:tt)*] [@ $($depth:tt)*] > $($rest:tt)+
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Generics [$($generics)* >] [$($depth)*] $($rest)+
+		}
+	};
+	(
+		// Collecting the generic parameters
+		@Generics [$($generics:tt)*] [$($depth:tt)*] $next:tt $($rest:tt)+
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Generics [$($generics)* $next] [$($depth)*] $($rest)+
+		}
+	};
+	(
+		// Searching the `where` clause of the impls, none found
+		@Where [$($generics:tt)*] [$($head:tt)*]
+	) => {
+		$crate::props!{
+			impl @Generics [[$($generics)*] []] $($head)*
+		}
+	};
+	(
+		// Searching the `where` clause of the impls, found
+		@Where [$($generics:tt)*] [$($head:tt)*] where $($rest:tt)+
+	) => {
+		$crate::internal_props_impl_macro!{
+			@WhereClause [$($generics)*] [$($head)*] [where] $($rest)+
+		}
+	};
+	(
+		// Searching the `where` clause of the impls
+		@Where [$($generics:tt)*] [$($head:tt)*] $next:tt $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Where [$($generics)*] [$($head)* $next] $($rest)*
+		}
+	};
+	(
+		// Collecting the `where` clause up to the branches, done
+		@WhereClause [$($generics:tt)*] [$($head:tt)*] [$($where_clause:tt)*] { $($matching:tt)* }
+	) => {
+		$crate::props!{
+			impl @Generics [[$($generics)*] [$($where_clause)*]] $($head)* { $($matching)* }
+		}
+	};
+	(
+		// Collecting the `where` clause up to the branches
+		@WhereClause [$($generics:tt)*] [$($head:tt)*] [$($where_clause:tt)*] $next:tt $($rest:tt)+
+	) => {
+		$crate::internal_props_impl_macro!{
+			@WhereClause [$($generics)*] [$($head)*] [$($where_clause)* $next] $($rest)+
+		}
+	};
+	(
+		// Applying the same properties to each enum, one at a time
+		@Enums $head:tt $tail:tt $enum_name:ty $(, $($rest:tt)+)?
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Enum $head $enum_name $tail
+		}
+
+		$(
+			$crate::internal_props_impl_macro!{
+				@Enums $head $tail $($rest)+
+			}
+		)?
+	};
+	(
+		// Applying the properties to an enum
+		@Enum [$($head:tt)*] $enum_name:ty [$($tail:tt)*]
+	) => {
+		$crate::props!{
+			$($head)* $enum_name $($tail)*
+		}
+	};
+	(
+		// The enum definition, the branches follow the property
+		@EnumDef [$($enum_def:tt)*] ($enum_name:ident) [$($head:tt)+] { $($matching:tt)* }
+	) => {
+		$crate::internal_props_impl_macro!{
+			@EnumDefArms [[$($enum_def)*] ($enum_name) [$($head)+]] [] []
+			$($matching)*
+		}
+	};
+	(
+		// The enum definition, collecting an inline property struct, whose
+		// fields are not the branches
+		@EnumDef [$($enum_def:tt)*] ($enum_name:ident) [$($head:tt)*]
+		struct $struct_name:ident { $($fields:tt)* } $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@EnumDef [$($enum_def)*] ($enum_name) [$($head)* struct $struct_name { $($fields)* }] $($rest)*
+		}
+	};
+	(
+		// The enum definition, collecting the property
+		@EnumDef [$($enum_def:tt)*] ($enum_name:ident) [$($head:tt)*] $next:tt $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@EnumDef [$($enum_def)*] ($enum_name) [$($head)* $next] $($rest)*
+		}
+	};
+	(
+		// The branches of the enum definition, done, defining the enum with
+		// the collected variants, and its property
+		@EnumDefArms [[$($enum_def:tt)*] ($enum_name:ident) [$($head:tt)*]]
+		[$($variants:tt)*] [$($arms:tt)*]
+	) => {
+		$($enum_def)* {
+			$($variants)*
+		}
+
+		$crate::props!{
+			impl Deref for $enum_name as $($head)* {
+				$($arms)*
+			}
+		}
+	};
+	(
+		// The comma between the branches of the enum definition
+		@EnumDefArms $def:tt [$($variants:tt)*] [$($arms:tt)*]
+		, $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@EnumDefArms $def [$($variants)*] [$($arms)*]
+			$($rest)*
+		}
+	};
+	(
+		// The default fields are no variant
+		@EnumDefArms $def:tt [$($variants:tt)*] [$($arms:tt)*]
+		defaults $defaults:tt $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@EnumDefArms $def [$($variants)*] [$($arms)* defaults $defaults]
+			$($rest)*
+		}
+	};
+	(
+		// The associated consts are no variant
+		@EnumDefArms $def:tt [$($variants:tt)*] [$($arms:tt)*]
+		consts $consts:tt $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@EnumDefArms $def [$($variants)*] [$($arms)* consts $consts]
+			$($rest)*
+		}
+	};
+	(
+		// The assertions are no variants
+		@EnumDefArms $def:tt [$($variants:tt)*] [$($arms:tt)*]
+		assert $check:tt $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@EnumDefArms $def [$($variants)*] [$($arms)* assert $check]
+			$($rest)*
+		}
+	};
+	(
+		@EnumDefArms $def:tt [$($variants:tt)*] [$($arms:tt)*]
+		debug_assert $check:tt $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@EnumDefArms $def [$($variants)*] [$($arms)* debug_assert $check]
+			$($rest)*
+		}
+	};
+	(
+		// The sorted variants are no variant
+		@EnumDefArms $def:tt [$($variants:tt)*] [$($arms:tt)*]
+		ordered_by $ordered:tt $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@EnumDefArms $def [$($variants)*] [$($arms)* ordered_by $ordered]
+			$($rest)*
+		}
+	};
+	(
+		// The derived impls are no variant
+		@EnumDefArms $def:tt [$($variants:tt)*] [$($arms:tt)*]
+		derive $derive:tt $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@EnumDefArms $def [$($variants)*] [$($arms)* derive $derive]
+			$($rest)*
+		}
+	};
+	(
+		// The serialization is no variant
+		@EnumDefArms $def:tt [$($variants:tt)*] [$($arms:tt)*]
+		serialize_as $clause:tt $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@EnumDefArms $def [$($variants)*] [$($arms)* serialize_as $clause]
+			$($rest)*
+		}
+	};
+	(
+		// The logging format is no variant
+		@EnumDefArms $def:tt [$($variants:tt)*] [$($arms:tt)*]
+		defmt $clause:tt $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@EnumDefArms $def [$($variants)*] [$($arms)* defmt $clause]
+			$($rest)*
+		}
+	};
+	(
+		// The deserialization is no variant
+		@EnumDefArms $def:tt [$($variants:tt)*] [$($arms:tt)*]
+		deserialize_from $clause:tt $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@EnumDefArms $def [$($variants)*] [$($arms)* deserialize_from $clause]
+			$($rest)*
+		}
+	};
+	(
+		// The variant sets are no variant
+		@EnumDefArms $def:tt [$($variants:tt)*] [$($arms:tt)*]
+		set_where $clause:tt $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@EnumDefArms $def [$($variants)*] [$($arms)* set_where $clause]
+			$($rest)*
+		}
+	};
+	(
+		// The parsing is no variant
+		@EnumDefArms $def:tt [$($variants:tt)*] [$($arms:tt)*]
+		from_str $field:tt $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@EnumDefArms $def [$($variants)*] [$($arms)* from_str $field]
+			$($rest)*
+		}
+	};
+	(
+		// The conversion is no variant
+		@EnumDefArms $def:tt [$($variants:tt)*] [$($arms:tt)*]
+		try_from $field:tt $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@EnumDefArms $def [$($variants)*] [$($arms)* try_from $field]
+			$($rest)*
+		}
+	};
+	(
+		// The dedup is no variant
+		@EnumDefArms $def:tt [$($variants:tt)*] [$($arms:tt)*]
+		dedup $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@EnumDefArms $def [$($variants)*] [$($arms)* dedup]
+			$($rest)*
+		}
+	};
+	(
+		// The conversions are no variant
+		@EnumDefArms $def:tt [$($variants:tt)*] [$($arms:tt)*]
+		into $into:tt $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@EnumDefArms $def [$($variants)*] [$($arms)* into $into]
+			$($rest)*
+		}
+	};
+	(
+		// The identity is no variant
+		@EnumDefArms $def:tt [$($variants:tt)*] [$($arms:tt)*]
+		identity $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@EnumDefArms $def [$($variants)*] [$($arms)* identity]
+			$($rest)*
+		}
+	};
+	(
+		// The setters are no variant
+		@EnumDefArms $def:tt [$($variants:tt)*] [$($arms:tt)*]
+		setters $(($ordering:ident))? $setters:tt $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@EnumDefArms $def [$($variants)*] [$($arms)* setters $(($ordering))? $setters]
+			$($rest)*
+		}
+	};
+	(
+		// The map of the properties is no variant
+		@EnumDefArms $def:tt [$($variants:tt)*] [$($arms:tt)*]
+		enum_map $clause:tt $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@EnumDefArms $def [$($variants)*] [$($arms)* enum_map $clause]
+			$($rest)*
+		}
+	};
+	(
+		// The list of variants is no variant
+		@EnumDefArms $def:tt [$($variants:tt)*] [$($arms:tt)*]
+		variants $clause:tt $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@EnumDefArms $def [$($variants)*] [$($arms)* variants $clause]
+			$($rest)*
+		}
+	};
+	(
+		// The value enum is no variant
+		@EnumDefArms $def:tt [$($variants:tt)*] [$($arms:tt)*]
+		value_enum $clause:tt $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@EnumDefArms $def [$($variants)*] [$($arms)* value_enum $clause]
+			$($rest)*
+		}
+	};
+	(
+		// The weighted sampling is no variant
+		@EnumDefArms $def:tt [$($variants:tt)*] [$($arms:tt)*]
+		sample_weighted $clause:tt $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@EnumDefArms $def [$($variants)*] [$($arms)* sample_weighted $clause]
+			$($rest)*
+		}
+	};
+	(
+		// The lookup by discriminant is no variant
+		@EnumDefArms $def:tt [$($variants:tt)*] [$($arms:tt)*]
+		discriminant $clause:tt $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@EnumDefArms $def [$($variants)*] [$($arms)* discriminant $clause]
+			$($rest)*
+		}
+	};
+	(
+		// The table is no variant
+		@EnumDefArms $def:tt [$($variants:tt)*] [$($arms:tt)*]
+		table $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@EnumDefArms $def [$($variants)*] [$($arms)* table]
+			$($rest)*
+		}
+	};
+	(
+		// The reverse lookup is no variant
+		@EnumDefArms $def:tt [$($variants:tt)*] [$($arms:tt)*]
+		from_property $clause:tt $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@EnumDefArms $def [$($variants)*] [$($arms)* from_property $clause]
+			$($rest)*
+		}
+	};
+	(
+		// The unique fields are no variant
+		@EnumDefArms $def:tt [$($variants:tt)*] [$($arms:tt)*]
+		unique $fields:tt $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@EnumDefArms $def [$($variants)*] [$($arms)* unique $fields]
+			$($rest)*
+		}
+	};
+	(
+		// The aggregates are no variant
+		@EnumDefArms $def:tt [$($variants:tt)*] [$($arms:tt)*]
+		aggregate $aggregates:tt $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@EnumDefArms $def [$($variants)*] [$($arms)* aggregate $aggregates]
+			$($rest)*
+		}
+	};
+	(
+		// The `all` table is no variant
+		@EnumDefArms $def:tt [$($variants:tt)*] [$($arms:tt)*]
+		all $all:tt $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@EnumDefArms $def [$($variants)*] [$($arms)* all $all]
+			$($rest)*
+		}
+	};
+	(
+		// A named value is no variant
+		@EnumDefArms $def:tt [$($variants:tt)*] [$($arms:tt)*]
+		$(#[$attr:meta])* let $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@EnumDefLet $def [$($variants)*] [$($arms)*] [$(#[$attr])* let]
+			$($rest)*
+		}
+	};
+	(
+		// A doc comment, only applied to the variant, since documenting a
+		// match arm is pointless
+		@EnumDefArms $def:tt [$($variants:tt)*] [$($arms:tt)*]
+		#[doc $($doc:tt)*] $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@EnumDefArms $def [$($variants)* #[doc $($doc)*]] [$($arms)*]
+			$($rest)*
+		}
+	};
+	(
+		// Any other attribute, applied to both the variant and its branch
+		@EnumDefArms $def:tt [$($variants:tt)*] [$($arms:tt)*]
+		#[$($attr:tt)*] $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@EnumDefArms $def [$($variants)* #[$($attr)*]] [$($arms)* #[$($attr)*]]
+			$($rest)*
+		}
+	};
+	(
+		// A tuple variant
+		@EnumDefArms $def:tt [$($variants:tt)*] [$($arms:tt)*]
+		$variant:ident ($($fields:tt)*) => $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@EnumDefBody $def
+			[$($variants)* $variant($($fields)*),]
+			[$($arms)*] [Self::$variant(..)] []
+			$($rest)*
+		}
+	};
+	(
+		// A struct variant
+		@EnumDefArms $def:tt [$($variants:tt)*] [$($arms:tt)*]
+		$variant:ident { $($fields:tt)* } => $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@EnumDefBody $def
+			[$($variants)* $variant { $($fields)* },]
+			[$($arms)*] [Self::$variant { .. }] []
+			$($rest)*
+		}
+	};
+	(
+		// A unit variant
+		@EnumDefArms $def:tt [$($variants:tt)*] [$($arms:tt)*]
+		$variant:ident => $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@EnumDefBody $def
+			[$($variants)* $variant,]
+			[$($arms)*] [Self::$variant] []
+			$($rest)*
+		}
+	};
+	(
+		// Anything else is not a variant
+		@EnumDefArms $def:tt [$($variants:tt)*] [$($arms:tt)*]
+		$($rest:tt)+
+	) => {
+		::core::compile_error!(::core::concat!(
+			"expected a variant of the enum, e.g. `<VARIANT> => { ... }`, found `",
+			::core::stringify!($($rest)+),
+			"`"
+		));
+	};
+	(
+		// The body of a variant, overriding the modifier, e.g. `const { ... }`
+		@EnumDefBody $def:tt [$($variants:tt)*] [$($arms:tt)*] [$($branch:tt)*] []
+		$arm_modifier:ident $(($($args:tt)*))? { $($body:tt)* } $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@EnumDefArms $def [$($variants)*] [
+				$($arms)* $($branch)* => $arm_modifier $(($($args)*))? { $($body)* }
+			]
+			$($rest)*
+		}
+	};
+	(
+		// The body of a variant, given as braces
+		@EnumDefBody $def:tt [$($variants:tt)*] [$($arms:tt)*] [$($branch:tt)*] []
+		{ $($body:tt)* } $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@EnumDefArms $def [$($variants)*] [$($arms)* $($branch)* => { $($body)* }]
+			$($rest)*
+		}
+	};
+	(
+		// The body of a variant, given as expression, done
+		@EnumDefBody $def:tt [$($variants:tt)*] [$($arms:tt)*] [$($branch:tt)*] [$($body:tt)+]
+		$(, $($rest:tt)*)?
+	) => {
+		$crate::internal_props_impl_macro!{
+			@EnumDefArms $def [$($variants)*] [$($arms)* $($branch)* => $($body)+,]
+			$($($rest)*)?
+		}
+	};
+	(
+		// The body of a variant, given as expression, collecting it
+		@EnumDefBody $def:tt [$($variants:tt)*] [$($arms:tt)*] [$($branch:tt)*] [$($body:tt)*]
+		$next:tt $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@EnumDefBody $def [$($variants)*] [$($arms)*] [$($branch)*] [$($body)* $next]
+			$($rest)*
+		}
+	};
+	(
+		// A named value of the enum definition, done
+		@EnumDefLet $def:tt [$($variants:tt)*] [$($arms:tt)*] [$($named:tt)*]
+		; $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@EnumDefArms $def [$($variants)*] [$($arms)* $($named)*;]
+			$($rest)*
+		}
+	};
+	(
+		// A named value of the enum definition, collecting it
+		@EnumDefLet $def:tt [$($variants:tt)*] [$($arms:tt)*] [$($named:tt)*]
+		$next:tt $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@EnumDefLet $def [$($variants)*] [$($arms)*] [$($named)* $next]
+			$($rest)*
+		}
+	};
+	(
+		// Splitting the sections, done
+		@Sections $generics:tt ($($enum_name:ty),+) []
+	) => {};
+	(
+		// A section is complete with its branches
+		@Sections $generics:tt ($($enum_name:ty),+) [$($head:tt)+] { $($matching:tt)* } $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Section $generics ($($enum_name),+) [$($head)+] { $($matching)* }
+		}
+
+		$crate::internal_props_impl_macro!{
+			@Sections $generics ($($enum_name),+) [] $($rest)*
+		}
+	};
+	(
+		// The branches of a section without a head
+		@Sections $generics:tt ($($enum_name:ty),+) [] { $($matching:tt)* } $($rest:tt)*
+	) => {
+		::core::compile_error!(
+			"a props section must start with its property, e.g. `as const <PROPERTY> { ... }`"
+		);
+	};
+	(
+		// A section without branches
+		@Sections $generics:tt ($($enum_name:ty),+) [$($head:tt)+]
+	) => {
+		::core::compile_error!(::core::concat!(
+			"the props section `",
+			::core::stringify!($($head)+),
+			"` is missing its branches"
+		));
+	};
+	(
+		// Collecting an inline property struct of a section, whose fields are
+		// not the branches
+		@Sections $generics:tt ($($enum_name:ty),+) [$($head:tt)*] struct $struct_name:ident { $($fields:tt)* } $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Sections $generics ($($enum_name),+) [$($head)* struct $struct_name { $($fields)* }] $($rest)*
+		}
+	};
+	(
+		// Collecting the head of a section
+		@Sections $generics:tt ($($enum_name:ty),+) [$($head:tt)*] $next:tt $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Sections $generics ($($enum_name),+) [$($head)* $next] $($rest)*
+		}
+	};
+	(
+		// A section implementing `EnumProp` only
+		@Section $generics:tt ($($enum_name:ty),+) [$(#[$attr:meta])* as $($prop:tt)+] { $($matching:tt)* }
+	) => {
+		$crate::props!{
+			$(#[$attr])*
+			impl @Generics $generics EnumProp for $($enum_name),+ as $($prop)+ {
+				@Section [as $($prop)+] $($matching)*
+			}
+		}
+	};
+	(
+		// A section implementing both `Deref` and an inherent method
+		@Section $generics:tt ($($enum_name:ty),+)
+		[$(#[$attr:meta])* Deref + $fn_vis:vis fn $fn_name:ident as $($prop:tt)+]
+		{ $($matching:tt)* }
+	) => {
+		$crate::props!{
+			$(#[$attr])*
+			impl @Generics $generics Deref + $fn_vis fn $fn_name for $($enum_name),+ as $($prop)+ {
+				@Section [Deref + $fn_vis fn $fn_name as $($prop)+] $($matching)*
+			}
+		}
+	};
+	(
+		// A section implementing `Deref`
+		@Section $generics:tt ($($enum_name:ty),+) [$(#[$attr:meta])* Deref as $($prop:tt)+] { $($matching:tt)* }
+	) => {
+		$crate::props!{
+			$(#[$attr])*
+			impl @Generics $generics Deref for $($enum_name),+ as $($prop)+ {
+				@Section [Deref as $($prop)+] $($matching)*
+			}
+		}
+	};
+	(
+		// A section implementing an inherent method
+		@Section $generics:tt ($($enum_name:ty),+)
+		[$(#[$attr:meta])* $fn_vis:vis fn $fn_name:ident as $($prop:tt)+]
+		{ $($matching:tt)* }
+	) => {
+		$crate::props!{
+			$(#[$attr])*
+			impl @Generics $generics $($enum_name),+ : $fn_vis fn $fn_name as $($prop)+ {
+				@Section [$fn_vis fn $fn_name as $($prop)+] $($matching)*
+			}
+		}
+	};
+	(
+		// A section implementing the given trait, i.e. `EnumProp`,
+		// `EnumPropConst`, `EnumPropLocal`, `EnumPropDyn`, `EnumPropRef`, or
+		// `EnumPropPair`
+		@Section $generics:tt ($($enum_name:ty),+) [$(#[$attr:meta])* $trait_name:ident as $($prop:tt)+] { $($matching:tt)* }
+	) => {
+		$crate::props!{
+			$(#[$attr])*
+			impl @Generics $generics $trait_name for $($enum_name),+ as $($prop)+ {
+				@Section [$trait_name as $($prop)+] $($matching)*
+			}
+		}
+	};
+	(
+		// A section implementing `EnumPropKeyed`
+		@Section $generics:tt ($($enum_name:ty),+)
+		[$(#[$attr:meta])* EnumPropKeyed < $key:ty > as $($prop:tt)+]
+		{ $($matching:tt)* }
+	) => {
+		$crate::props!{
+			$(#[$attr])*
+			impl @Generics $generics EnumPropKeyed<$key> for $($enum_name),+ as $($prop)+ {
+				@Section [EnumPropKeyed<$key> as $($prop)+] $($matching)*
+			}
+		}
+	};
+	(
+		// A section implementing an inherent `const fn`
+		@Section $generics:tt ($($enum_name:ty),+)
+		[$(#[$attr:meta])* $fn_vis:vis const fn $fn_name:ident as $($prop:tt)+]
+		{ $($matching:tt)* }
+	) => {
+		$crate::props!{
+			$(#[$attr])*
+			impl @Generics $generics $($enum_name),+ : $fn_vis const fn $fn_name as $($prop)+ {
+				@Section [$fn_vis const fn $fn_name as $($prop)+] $($matching)*
+			}
+		}
+	};
+	(
+		// A section implementing an inherent method taking a key
+		@Section $generics:tt ($($enum_name:ty),+)
+		[$(#[$attr:meta])* $fn_vis:vis fn $fn_name:ident ($key_name:ident : $key:ty) as $($prop:tt)+]
+		{ $($matching:tt)* }
+	) => {
+		$crate::props!{
+			$(#[$attr])*
+			impl @Generics $generics $($enum_name),+ : $fn_vis fn $fn_name($key_name: $key) as $($prop)+ {
+				@Section [$fn_vis fn $fn_name($key_name: $key) as $($prop)+] $($matching)*
+			}
+		}
+	};
+	(
+		// Any other section
+		@Section $generics:tt ($($enum_name:ty),+) [$($head:tt)+] { $($matching:tt)* }
+	) => {
+		::core::compile_error!(::core::concat!(
+			"invalid props section `",
+			::core::stringify!($($head)+),
+			"`, expected e.g. `as const <PROPERTY>`, `Deref as const <PROPERTY>` or \
+			`fn <NAME> as const <PROPERTY>`"
+		));
+	};
+	(
+		// Normalizing the match branches of a section, done
+		@Arms [@Section $section:tt $($entry:tt)*] [$($defaults:tt)*] [$($arms:tt)*] $(@Last $(,)?)?
+	) => {
+		$crate::internal_props_impl_macro!{
+			$($entry)* {
+				$($arms)*
+			}
+		}
+	};
+	(
+		// Normalizing the match branches, done
+		@Arms [$($entry:tt)*] [$($defaults:tt)*] [$($arms:tt)*] $(@Last $(,)?)?
+	) => {
+		$crate::internal_props_impl_macro!{
+			$($entry)* {
+				$($arms)*
+			}
+		}
+	};
+	(
+		// The wildcard branch must be the last one
+		@Arms [$($entry:tt)*] [$($defaults:tt)*] [$($arms:tt)*] @Last $($rest:tt)+
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Error [$($entry)*] "the wildcard branch (`_` or `default`) must be the last branch"
+		}
+	};
+	(
+		// A property type that is not a path has no fields, thus all branches
+		// are plain expressions, marked via the (otherwise empty) defaults
+		@Arms [$($entry:tt)*] [] []
+		@Plain $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Arms [$($entry)*] [@Plain] []
+			$($rest)*
+		}
+	};
+	(
+		// The section of a multi-section invocation, kept along with the entry
+		// to name it in errors
+		@Arms [$($entry:tt)*] [$($defaults:tt)*] []
+		@Section $section:tt $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Arms [@Section $section $($entry)*] [$($defaults)*] []
+			$($rest)*
+		}
+	};
+	(
+		// An error within a section, naming the section
+		@Error [$(@Named $lets:tt $used:tt)? @Section [$($section:tt)*] $($entry:tt)*] $($message:expr),+
+	) => {
+		::core::compile_error!(::core::concat!(
+			"in the props section `",
+			::core::stringify!($($section)*),
+			"`: ",
+			$($message),+
+		));
+	};
+	(
+		// An error, not within a section
+		@Error [$($entry:tt)*] $($message:expr),+
+	) => {
+		::core::compile_error!(::core::concat!($($message),+));
+	};
+	(
+		// A clause not supported by the property, unless it follows the
+		// dedup, since the clauses evaluating the branches in consts can not
+		// call the shared branches via the trait
+		@Unsupported [$clause:tt] [$(@Section $section:tt)? @Dedup $($entry:tt)*] $($message:expr),+
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Error [$(@Section $section)? @Dedup $($entry)*] ::core::concat!(
+				"the `dedup` can not be combined with `",
+				::core::stringify!($clause),
+				"`"
+			)
+		}
+	};
+	(
+		@Unsupported [$clause:tt] [$($entry:tt)*] $($message:expr),+
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Error [$($entry)*] $($message),+
+		}
+	};
+	(
+		// The default fields must come before the named values, since these
+		// are merged with them
+		@Arms [@Named $($entry:tt)*] [] []
+		defaults $fields:tt $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Error [@Named $($entry)*] "the `defaults` must be given before the named values"
+		}
+	};
+	(
+		// The default fields, applied to all branches
+		@Arms [$($entry:tt)*] [] []
+		defaults {
+			$($defaults:tt)*
+		} $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Arms [$($entry)*] [$($defaults)*] []
+			$($rest)*
+		}
+	};
+	(
+		// Plain expressions have no fields to default
+		@Arms [$($entry:tt)*] [@Plain] [$($arms:tt)*]
+		defaults $fields:tt $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Error [$($entry)*] "the `defaults` are only supported for struct properties"
+		}
+	};
+	(
+		// The default fields must come first
+		@Arms [$($entry:tt)*] [$($defaults:tt)*] [$($arms:tt)*]
+		defaults $fields:tt $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Error [$($entry)*] "the `defaults` must be given once, before all branches"
+		}
+	};
+	(
+		// The dedup must come before the named values, like the consts
+		@Arms [@Named $($entry:tt)*] [$($defaults:tt)*] []
+		dedup $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Error [@Named $($entry)*] "the `dedup` must be given before the named values"
+		}
+	};
+	(
+		// The dedup of a const or static prop, kept in front of the entry,
+		// along with the rules of the local macro comparing the branches,
+		// which are added by the branches (via the passed `$`)
+		@Arms [$(@Section $section:tt)? @EnumProp mod(const) ($prop_name:path) $($entry:tt)*]
+		[$($defaults:tt)*] []
+		dedup $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Arms [
+				$(@Section $section)? @Dedup ($) [<Self as $crate::EnumProp<$prop_name>>::property] []
+				@EnumProp mod(const) ($prop_name) $($entry)*
+			]
+			[$($defaults)*] []
+			$($rest)*
+		}
+	};
+	(
+		@Arms [$(@Section $section:tt)? @EnumProp mod(static) ($prop_name:path) $($entry:tt)*]
+		[$($defaults:tt)*] []
+		dedup $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Arms [
+				$(@Section $section)? @Dedup ($) [<Self as $crate::EnumProp<$prop_name>>::property] []
+				@EnumProp mod(static) ($prop_name) $($entry)*
+			]
+			[$($defaults)*] []
+			$($rest)*
+		}
+	};
+	(
+		@Arms [$(@Section $section:tt)? @EnumPropConstFn $head:tt $fn_name:ident $($entry:tt)*]
+		[$($defaults:tt)*] []
+		dedup $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Arms [
+				$(@Section $section)? @Dedup ($) [Self::$fn_name] []
+				@EnumPropConstFn $head $fn_name $($entry)*
+			]
+			[$($defaults)*] []
+			$($rest)*
+		}
+	};
+	(
+		// The clauses evaluating the branches in consts can not be combined
+		@Arms [$(@Section $section:tt)? @Consts $($entry:tt)*] [$($defaults:tt)*] []
+		dedup $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Error [$(@Section $section)? @Consts $($entry)*]
+			"the `dedup` can not be combined with `consts`, `all`, `aggregate`, `unique`, `ordered_by`, `assert`, \
+			 `identity`, `from_property`, `variants`, `value_enum`, `sample_weighted`, `discriminant`, \
+			 `table`, `set_where`, `setters`, `enum_map`, `into`, `from_str`, `deserialize_from`, or `try_from`"
+		}
+	};
+	(
+		// The table of `EnumPropConst` is evaluated in a const as well
+		@Arms [$(@Section $section:tt)? @EnumPropConst $($entry:tt)*] [$($defaults:tt)*] []
+		dedup $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Error [$(@Section $section)? @EnumPropConst $($entry)*] "the `dedup` is not supported for `EnumPropConst`"
+		}
+	};
+	(
+		// Any other property has no shareable branches
+		@Arms [$($entry:tt)*] [$($defaults:tt)*] []
+		dedup $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Error [$($entry)*] "the `dedup` is only supported for `const` and `static` properties"
+		}
+	};
+	(
+		// The dedup must come first
+		@Arms [$($entry:tt)*] [$($defaults:tt)*] [$($arms:tt)+]
+		dedup $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Error [$($entry)*] "the `dedup` must be given before all branches"
+		}
+	};
+	(
+		// The serialization must come before the named values, like the
+		// derived impls
+		@Arms [@Named $($entry:tt)*] [$($defaults:tt)*] []
+		serialize_as $clause:tt $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Error [@Named $($entry)*] "the `serialize_as` must be given before the named values"
+		}
+	};
+	(
+		// Plain expressions have no field to serialize
+		@Arms [$($entry:tt)*] [@Plain] []
+		serialize_as($field:tt) $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Error [$($entry)*] "the `serialize_as` is only supported for struct properties"
+		}
+	};
+	(
+		// The `Serialize` impl of the enum as a field of its property, which
+		// is implemented like the derived impls
+		@Arms [$($entry:tt)*] [$($defaults:tt)*] []
+		serialize_as($field:tt) $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Derive [@Serialize $field] [$($entry)*] [$($entry)*]
+		}
+
+		$crate::internal_props_impl_macro!{
+			@Arms [$($entry)*] [$($defaults)*] []
+			$($rest)*
+		}
+	};
+	(
+		@Arms [$($entry:tt)*] [$($defaults:tt)*] []
+		serialize_as $clause:tt $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Error [$($entry)*] "expected `serialize_as(<FIELD>)`, found `serialize_as ",
+			::core::stringify!($clause), "`"
+		}
+	};
+	(
+		// The serialization must come first
+		@Arms [$($entry:tt)*] [$($defaults:tt)*] [$($arms:tt)+]
+		serialize_as $clause:tt $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Error [$($entry)*] "the `serialize_as` must be given before all branches"
+		}
+	};
+	(
+		// The logging format must come before the named values, like the
+		// derived impls
+		@Arms [@Named $($entry:tt)*] [$($defaults:tt)*] []
+		defmt $clause:tt $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Error [@Named $($entry)*] "the `defmt` must be given before the named values"
+		}
+	};
+	(
+		// Plain expressions have no field to write
+		@Arms [$($entry:tt)*] [@Plain] []
+		defmt($field:tt) $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Error [$($entry)*] "the `defmt` is only supported for struct properties"
+		}
+	};
+	(
+		// The `defmt::Format` impl of the enum writing a field of its
+		// property, which is implemented like the derived impls
+		@Arms [$($entry:tt)*] [$($defaults:tt)*] []
+		defmt($field:tt) $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Derive [@Defmt $field] [$($entry)*] [$($entry)*]
+		}
+
+		$crate::internal_props_impl_macro!{
+			@Arms [$($entry)*] [$($defaults)*] []
+			$($rest)*
+		}
+	};
+	(
+		@Arms [$($entry:tt)*] [$($defaults:tt)*] []
+		defmt $clause:tt $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Error [$($entry)*] "expected `defmt(<FIELD>)`, found `defmt ",
+			::core::stringify!($clause), "`"
+		}
+	};
+	(
+		// The logging format must come first
+		@Arms [$($entry:tt)*] [$($defaults:tt)*] [$($arms:tt)+]
+		defmt $clause:tt $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Error [$($entry)*] "the `defmt` must be given before all branches"
+		}
+	};
+	(
+		// The derived impls must come before the named values, like the
+		// consts
+		@Arms [@Named $($entry:tt)*] [$($defaults:tt)*] []
+		derive $derive:tt $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Error [@Named $($entry)*] "the `derive` must be given before the named values"
+		}
+	};
+	(
+		// The derived `Debug` of the enum, writing the variant via the given
+		// inner debug, followed by its property, which is implemented right
+		// away, since it only needs the entry
+		@Arms [$($entry:tt)*] [$($defaults:tt)*] []
+		derive(Debug = $inner:expr) $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Derive [Debug $inner] [$($entry)*] [$($entry)*]
+		}
+
+		$crate::internal_props_impl_macro!{
+			@Arms [$($entry)*] [$($defaults)*] []
+			$($rest)*
+		}
+	};
+	(
+		// Plain expressions have no fields to derive by
+		@Arms [$($entry:tt)*] [@Plain] []
+		derive($trait:ident = $field:tt) $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Error [$($entry)*] "the `derive(", ::core::stringify!($trait), " = ...)` is only supported for struct properties"
+		}
+	};
+	(
+		// The other derived traits of the enum by a field of its property,
+		// e.g. `Display` writing it, or `Ord` comparing it
+		@Arms [$($entry:tt)*] [$($defaults:tt)*] []
+		derive($trait:ident = $field:tt) $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Derive [$trait $field] [$($entry)*] [$($entry)*]
+		}
+
+		$crate::internal_props_impl_macro!{
+			@Arms [$($entry)*] [$($defaults)*] []
+			$($rest)*
+		}
+	};
+	(
+		@Arms [$($entry:tt)*] [$($defaults:tt)*] []
+		derive $derive:tt $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Error [$($entry)*] "expected `derive(<TRAIT> = <FIELD>)` or `derive(Debug = <INNER>)`, found `derive",
+			::core::stringify!($derive), "`"
+		}
+	};
+	(
+		// The derived impls must come first
+		@Arms [$($entry:tt)*] [$($defaults:tt)*] [$($arms:tt)+]
+		derive $derive:tt $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Error [$($entry)*] "the `derive` must be given before all branches"
+		}
+	};
+	(
+		// The identity must come before the named values, like the consts
+		@Arms [@Named $($entry:tt)*] [$($defaults:tt)*] []
+		identity $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Error [@Named $($entry)*] "the `identity` must be given before the named values"
+		}
+	};
+	(
+		// The identity of a static prop, comparing the addresses of its
+		// branches, which are checked like the aggregates
+		@Arms [$(@Section $section:tt)? $(@Consts [$($consts:tt)*])? @EnumProp mod(static) $($entry:tt)*]
+		[$($defaults:tt)*] []
+		identity $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Arms [$(@Section $section)? @Consts [@Identity $(, $($consts)*)?] @EnumProp mod(static) $($entry)*]
+			[$($defaults)*] []
+			$($rest)*
+		}
+	};
+	(
+		// Any other property has no unique addresses, e.g. the branches of a
+		// const prop may share them, or not even have a single one
+		@Arms [$($entry:tt)*] [$($defaults:tt)*] []
+		identity $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Unsupported [identity] [$($entry)*] "the `identity` is only supported for `static` properties"
+		}
+	};
+	(
+		// The identity must come first
+		@Arms [$($entry:tt)*] [$($defaults:tt)*] [$($arms:tt)+]
+		identity $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Error [$($entry)*] "the `identity` must be given before all branches"
+		}
+	};
+	(
+		// The setters must come before the named values, like the consts
+		@Arms [@Named $($entry:tt)*] [$($defaults:tt)*] []
+		setters $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Error [@Named $($entry)*] "the `setters` must be given before the named values"
+		}
+	};
+	(
+		// The setters of the atomic fields of a static prop, whose branches
+		// are checked like for the identity
+		@Arms [$(@Section $section:tt)? $(@Consts [$($consts:tt)*])? @EnumProp mod(static) $($entry:tt)*]
+		[$($defaults:tt)*] []
+		setters $(($ordering:ident))? {
+			$($(#[$attr:meta])* $field:ident as $vis:vis fn $name:ident : $ty:ty),* $(,)?
+		}
+		$($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Arms [
+				$(@Section $section)?
+				@Consts [
+					@Setters [$($ordering)?] [$($(#[$attr])* $field as $vis fn $name: $ty,)*]
+					$(, $($consts)*)?
+				]
+				@EnumProp mod(static) $($entry)*
+			]
+			[$($defaults)*] []
+			$($rest)*
+		}
+	};
+	(
+		@Arms [$(@Section $section:tt)? $(@Consts [$($consts:tt)*])? @EnumProp mod(static) $($entry:tt)*]
+		[$($defaults:tt)*] []
+		setters $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Error [$(@Section $section)? $(@Consts [$($consts)*])? @EnumProp mod(static) $($entry)*]
+			"expected `setters[(<ORDERING>)] { <FIELD> as <VIS> fn <NAME>: <TYPE>, ... }`"
+		}
+	};
+	(
+		// A const prop may be a distinct copy at each access, thus a store
+		// would be lost
+		@Arms [$(@Section $section:tt)? $(@Consts $consts:tt)? @EnumProp mod(const $($args:tt)*) $($entry:tt)*]
+		[$($defaults:tt)*] []
+		setters $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Setters const [$(@Section $section)? $(@Consts $consts)? @EnumProp mod(const $($args)*) $($entry)*]
+		}
+	};
+	(
+		@Arms [$(@Section $section:tt)? $(@Consts $consts:tt)? @EnumPropConst $($entry:tt)*]
+		[$($defaults:tt)*] []
+		setters $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Setters const [$(@Section $section)? $(@Consts $consts)? @EnumPropConst $($entry)*]
+		}
+	};
+	(
+		@Arms [$(@Section $section:tt)? $(@Consts $consts:tt)? @EnumPropConstFn $($entry:tt)*]
+		[$($defaults:tt)*] []
+		setters $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Setters const [$(@Section $section)? $(@Consts $consts)? @EnumPropConstFn $($entry)*]
+		}
+	};
+	(
+		// Any other property has no unique address to store into
+		@Arms [$($entry:tt)*] [$($defaults:tt)*] []
+		setters $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Unsupported [setters] [$($entry)*] "the `setters` are only supported for `static` properties"
+		}
+	};
+	(
+		// The setters must come first
+		@Arms [$($entry:tt)*] [$($defaults:tt)*] [$($arms:tt)+]
+		setters $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Error [$($entry)*] "the `setters` must be given before all branches"
+		}
+	};
+	(
+		// The reverse lookup must come before the named values, like the
+		// `all` table
+		@Arms [@Named $($entry:tt)*] [$($defaults:tt)*] []
+		from_property $clause:tt $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Error [@Named $($entry)*] "the `from_property` must be given before the named values"
+		}
+	};
+	(
+		// The reverse lookup of a static prop by the addresses of its
+		// branches, whose variants are collected like the `all` table
+		@Arms [$(@Section $section:tt)? $(@Consts [$($consts:tt)*])? @EnumProp mod(static) $($entry:tt)*]
+		[$($defaults:tt)*] []
+		from_property($(#[$attr:meta])* $vis:vis fn $name:ident) $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@AllVariants [] ["the `from_property`" @FromProperty [$(#[$attr])* $vis fn $name]] [$(@Section $section)?] [$(, $($consts)*)?]
+			[@EnumProp mod(static) $($entry)*] [$($defaults)*] ($($rest)*) { $($rest)* }
+		}
+	};
+	(
+		// Any other property has no unique addresses, like for the identity
+		@Arms [$($entry:tt)*] [$($defaults:tt)*] []
+		from_property $clause:tt $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Unsupported [from_property] [$($entry)*] "the `from_property` is only supported for `static` properties"
+		}
+	};
+	(
+		// The reverse lookup must come first
+		@Arms [$($entry:tt)*] [$($defaults:tt)*] [$($arms:tt)+]
+		from_property $clause:tt $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Error [$($entry)*] "the `from_property` must be given before all branches"
+		}
+	};
+	(
+		// The list of variants must come before the named values, like the
+		// `all` table
+		@Arms [@Named $($entry:tt)*] [$($defaults:tt)*] []
+		variants $clause:tt $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Error [@Named $($entry)*] "the `variants` must be given before the named values"
+		}
+	};
+	(
+		// The list of variants, collected from the branches like the `all`
+		// table, but without evaluating any property
+		@Arms [$(@Section $section:tt)? $(@Consts [$($consts:tt)*])? @EnumProp $($entry:tt)*]
+		[$($defaults:tt)*] []
+		variants { $(#[$attr:meta])* $vis:vis $name:ident } $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@AllVariants [] ["the `variants`" @VariantList [$(#[$attr])* $vis const $name]] [$(@Section $section)?] [$(, $($consts)*)?]
+			[@EnumProp $($entry)*] [$($defaults)*] ($($rest)*) { $($rest)* }
+		}
+	};
+	(
+		@Arms [$(@Section $section:tt)? $(@Consts [$($consts:tt)*])? @EnumPropConstFn $($entry:tt)*]
+		[$($defaults:tt)*] []
+		variants { $(#[$attr:meta])* $vis:vis $name:ident } $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@AllVariants [] ["the `variants`" @VariantList [$(#[$attr])* $vis const $name]] [$(@Section $section)?] [$(, $($consts)*)?]
+			[@EnumPropConstFn $($entry)*] [$($defaults)*] ($($rest)*) { $($rest)* }
+		}
+	};
+	(
+		@Arms [$(@Section $section:tt)? $(@Consts [$($consts:tt)*])? @EnumPropConst $($entry:tt)*]
+		[$($defaults:tt)*] []
+		variants { $(#[$attr:meta])* $vis:vis $name:ident } $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@AllVariants [] ["the `variants`" @VariantList [$(#[$attr])* $vis const $name]] [$(@Section $section)?] [$(, $($consts)*)?]
+			[@EnumPropConst $($entry)*] [$($defaults)*] ($($rest)*) { $($rest)* }
+		}
+	};
+	(
+		// Any other property, e.g. a keyed one, has no plain variants
+		@Arms [$($entry:tt)*] [$($defaults:tt)*] []
+		variants { $(#[$attr:meta])* $vis:vis $name:ident } $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Unsupported [variants] [$($entry)*] "the `variants` is only supported for properties implementing `EnumProp`"
+		}
+	};
+	(
+		@Arms [$($entry:tt)*] [$($defaults:tt)*] []
+		variants $clause:tt $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Error [$($entry)*] "expected `variants { <VIS> <NAME> }`, found `variants ",
+			::core::stringify!($clause), "`"
+		}
+	};
+	(
+		// The list of variants must come first
+		@Arms [$($entry:tt)*] [$($defaults:tt)*] [$($arms:tt)+]
+		variants $clause:tt $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Error [$($entry)*] "the `variants` must be given before all branches"
+		}
+	};
+	(
+		// The value enum must come before the named values, like the list of
+		// variants
+		@Arms [@Named $($entry:tt)*] [$($defaults:tt)*] []
+		value_enum $clause:tt $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Error [@Named $($entry)*] "the `value_enum` must be given before the named values"
+		}
+	};
+	(
+		// The `ValueEnum` impl by the name and help fields, whose variants are
+		// collected like the list of variants
+		@Arms [$(@Section $section:tt)? $(@Consts [$($consts:tt)*])? @EnumProp $($entry:tt)*]
+		[$($defaults:tt)*] []
+		value_enum($name:ident $(, help = $help:ident)? $(,)?) $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@AllVariants [] ["the `value_enum`" @ValueEnum [$name] [$($help)?]] [$(@Section $section)?] [$(, $($consts)*)?]
+			[@EnumProp $($entry)*] [$($defaults)*] ($($rest)*) { $($rest)* }
+		}
+	};
+	(
+		@Arms [$(@Section $section:tt)? $(@Consts [$($consts:tt)*])? @EnumPropConstFn $($entry:tt)*]
+		[$($defaults:tt)*] []
+		value_enum($name:ident $(, help = $help:ident)? $(,)?) $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@AllVariants [] ["the `value_enum`" @ValueEnum [$name] [$($help)?]] [$(@Section $section)?] [$(, $($consts)*)?]
+			[@EnumPropConstFn $($entry)*] [$($defaults)*] ($($rest)*) { $($rest)* }
+		}
+	};
+	(
+		@Arms [$(@Section $section:tt)? $(@Consts [$($consts:tt)*])? @EnumPropConst $($entry:tt)*]
+		[$($defaults:tt)*] []
+		value_enum($name:ident $(, help = $help:ident)? $(,)?) $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@AllVariants [] ["the `value_enum`" @ValueEnum [$name] [$($help)?]] [$(@Section $section)?] [$(, $($consts)*)?]
+			[@EnumPropConst $($entry)*] [$($defaults)*] ($($rest)*) { $($rest)* }
+		}
+	};
+	(
+		// Any other property, e.g. a keyed one, has no plain variants
+		@Arms [$($entry:tt)*] [$($defaults:tt)*] []
+		value_enum($name:ident $(, help = $help:ident)? $(,)?) $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Unsupported [value_enum] [$($entry)*] "the `value_enum` is only supported for properties implementing `EnumProp`"
+		}
+	};
+	(
+		@Arms [$($entry:tt)*] [$($defaults:tt)*] []
+		value_enum $clause:tt $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Error [$($entry)*] "expected `value_enum(<FIELD>)` or `value_enum(<FIELD>, help = <FIELD>)`, found `value_enum ",
+			::core::stringify!($clause), "`"
+		}
+	};
+	(
+		// The value enum must come first
+		@Arms [$($entry:tt)*] [$($defaults:tt)*] [$($arms:tt)+]
+		value_enum $clause:tt $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Error [$($entry)*] "the `value_enum` must be given before all branches"
+		}
+	};
+	(
+		// The weighted sampling must come before the named values, like the
+		// list of variants
+		@Arms [@Named $($entry:tt)*] [$($defaults:tt)*] []
+		sample_weighted $clause:tt $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Error [@Named $($entry)*] "the `sample_weighted` must be given before the named values"
+		}
+	};
+	(
+		// The weighted sampling by a field, whose variants are collected like
+		// the list of variants
+		@Arms [$(@Section $section:tt)? $(@Consts [$($consts:tt)*])? @EnumProp $($entry:tt)*]
+		[$($defaults:tt)*] []
+		sample_weighted($field:ident) $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@AllVariants [] ["the `sample_weighted`" @SampleWeighted $field] [$(@Section $section)?] [$(, $($consts)*)?]
+			[@EnumProp $($entry)*] [$($defaults)*] ($($rest)*) { $($rest)* }
+		}
+	};
+	(
+		@Arms [$(@Section $section:tt)? $(@Consts [$($consts:tt)*])? @EnumPropConstFn $($entry:tt)*]
+		[$($defaults:tt)*] []
+		sample_weighted($field:ident) $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@AllVariants [] ["the `sample_weighted`" @SampleWeighted $field] [$(@Section $section)?] [$(, $($consts)*)?]
+			[@EnumPropConstFn $($entry)*] [$($defaults)*] ($($rest)*) { $($rest)* }
+		}
+	};
+	(
+		@Arms [$(@Section $section:tt)? $(@Consts [$($consts:tt)*])? @EnumPropConst $($entry:tt)*]
+		[$($defaults:tt)*] []
+		sample_weighted($field:ident) $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@AllVariants [] ["the `sample_weighted`" @SampleWeighted $field] [$(@Section $section)?] [$(, $($consts)*)?]
+			[@EnumPropConst $($entry)*] [$($defaults)*] ($($rest)*) { $($rest)* }
+		}
+	};
+	(
+		// Any other property, e.g. a keyed one, has no plain variants
+		@Arms [$($entry:tt)*] [$($defaults:tt)*] []
+		sample_weighted($field:ident) $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Unsupported [sample_weighted] [$($entry)*] "the `sample_weighted` is only supported for properties implementing `EnumProp`"
+		}
+	};
+	(
+		@Arms [$($entry:tt)*] [$($defaults:tt)*] []
+		sample_weighted $clause:tt $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Error [$($entry)*] "expected `sample_weighted(<FIELD>)`, found `sample_weighted ",
+			::core::stringify!($clause), "`"
+		}
+	};
+	(
+		// The weighted sampling must come first
+		@Arms [$($entry:tt)*] [$($defaults:tt)*] [$($arms:tt)+]
+		sample_weighted $clause:tt $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Error [$($entry)*] "the `sample_weighted` must be given before all branches"
+		}
+	};
+	(
+		// The lookup by discriminant must come before the named values, like
+		// the list of variants
+		@Arms [@Named $($entry:tt)*] [$($defaults:tt)*] []
+		discriminant $clause:tt $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Error [@Named $($entry)*] "the `discriminant` must be given before the named values"
+		}
+	};
+	(
+		// The lookup by the discriminant of the given integer type, whose
+		// variants are collected like the list of variants
+		@Arms [$(@Section $section:tt)? $(@Consts [$($consts:tt)*])? @EnumProp $($entry:tt)*]
+		[$($defaults:tt)*] []
+		discriminant($repr:ident $(as $name:ident)?) $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@AllVariants [] ["the `discriminant`" @Discriminant $repr [$($name)?]] [$(@Section $section)?] [$(, $($consts)*)?]
+			[@EnumProp $($entry)*] [$($defaults)*] ($($rest)*) { $($rest)* }
+		}
+	};
+	(
+		@Arms [$(@Section $section:tt)? $(@Consts [$($consts:tt)*])? @EnumPropConstFn $($entry:tt)*]
+		[$($defaults:tt)*] []
+		discriminant($repr:ident $(as $name:ident)?) $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@AllVariants [] ["the `discriminant`" @Discriminant $repr [$($name)?]] [$(@Section $section)?] [$(, $($consts)*)?]
+			[@EnumPropConstFn $($entry)*] [$($defaults)*] ($($rest)*) { $($rest)* }
+		}
+	};
+	(
+		@Arms [$(@Section $section:tt)? $(@Consts [$($consts:tt)*])? @EnumPropConst $($entry:tt)*]
+		[$($defaults:tt)*] []
+		discriminant($repr:ident $(as $name:ident)?) $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@AllVariants [] ["the `discriminant`" @Discriminant $repr [$($name)?]] [$(@Section $section)?] [$(, $($consts)*)?]
+			[@EnumPropConst $($entry)*] [$($defaults)*] ($($rest)*) { $($rest)* }
+		}
+	};
+	(
+		// Any other property, e.g. a keyed one, has no plain variants
+		@Arms [$($entry:tt)*] [$($defaults:tt)*] []
+		discriminant($repr:ident $(as $name:ident)?) $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Unsupported [discriminant] [$($entry)*] "the `discriminant` is only supported for properties implementing `EnumProp`"
+		}
+	};
+	(
+		@Arms [$($entry:tt)*] [$($defaults:tt)*] []
+		discriminant $clause:tt $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Error [$($entry)*] "expected `discriminant(<INT>)` or `discriminant(<INT> as <NAME>)`, found `discriminant ",
+			::core::stringify!($clause), "`"
+		}
+	};
+	(
+		// The lookup by discriminant must come first
+		@Arms [$($entry:tt)*] [$($defaults:tt)*] [$($arms:tt)+]
+		discriminant $clause:tt $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Error [$($entry)*] "the `discriminant` must be given before all branches"
+		}
+	};
+	(
+		// The table must come before the named values, like the list of
+		// variants
+		@Arms [@Named $($entry:tt)*] [$($defaults:tt)*] []
+		table $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Error [@Named $($entry)*] "the `table` must be given before the named values"
+		}
+	};
+	(
+		// The table of a const prop, indexed by the discriminant, whose
+		// variants are collected like the list of variants
+		@Arms [$(@Section $section:tt)? $(@Consts [$($consts:tt)*])? @EnumProp mod(const) $($entry:tt)*]
+		[$($defaults:tt)*] []
+		table $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@AllVariants [] ["the `table`" @Table] [$(@Section $section)?] [$(, $($consts)*)?]
+			[@EnumProp mod(const) $($entry)*] [$($defaults)*] ($($rest)*) { $($rest)* }
+		}
+	};
+	(
+		// Any other property, e.g. via `const fn`, keeps its match
+		@Arms [$($entry:tt)*] [$($defaults:tt)*] []
+		table $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Unsupported [table] [$($entry)*]
+			"the `table` is only supported for `const` properties implementing `EnumProp` directly, e.g. via \
+			 `Deref`"
+		}
+	};
+	(
+		// The table must come first
+		@Arms [$($entry:tt)*] [$($defaults:tt)*] [$($arms:tt)+]
+		table $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Error [$($entry)*] "the `table` must be given before all branches"
+		}
+	};
+	(
+		// The map of the properties must come before the named values, like
+		// the consts
+		@Arms [@Named $($entry:tt)*] [$($defaults:tt)*] []
+		enum_map $clause:tt $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Error [@Named $($entry)*] "the `enum_map` must be given before the named values"
+		}
+	};
+	(
+		// The map of the properties of all variants, built lazily from the
+		// `EnumProp` impl, thus it needs no branches
+		@Arms [$(@Section $section:tt)? $(@Consts [$($consts:tt)*])? @EnumProp $($entry:tt)*]
+		[$($defaults:tt)*] []
+		enum_map { $(#[$attr:meta])* $vis:vis static $name:ident } $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Arms [
+				$(@Section $section)?
+				@Consts [@EnumMap [$(#[$attr])* $vis static $name] $(, $($consts)*)?]
+				@EnumProp $($entry)*
+			]
+			[$($defaults)*] []
+			$($rest)*
+		}
+	};
+	(
+		@Arms [$(@Section $section:tt)? $(@Consts [$($consts:tt)*])? @EnumPropConstFn $($entry:tt)*]
+		[$($defaults:tt)*] []
+		enum_map { $(#[$attr:meta])* $vis:vis static $name:ident } $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Arms [
+				$(@Section $section)?
+				@Consts [@EnumMap [$(#[$attr])* $vis static $name] $(, $($consts)*)?]
+				@EnumPropConstFn $($entry)*
+			]
+			[$($defaults)*] []
+			$($rest)*
+		}
+	};
+	(
+		@Arms [$(@Section $section:tt)? $(@Consts [$($consts:tt)*])? @EnumPropConst $($entry:tt)*]
+		[$($defaults:tt)*] []
+		enum_map { $(#[$attr:meta])* $vis:vis static $name:ident } $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Arms [
+				$(@Section $section)?
+				@Consts [@EnumMap [$(#[$attr])* $vis static $name] $(, $($consts)*)?]
+				@EnumPropConst $($entry)*
+			]
+			[$($defaults)*] []
+			$($rest)*
+		}
+	};
+	(
+		// Any other property, e.g. a keyed one, has no plain map
+		@Arms [$($entry:tt)*] [$($defaults:tt)*] []
+		enum_map { $(#[$attr:meta])* $vis:vis static $name:ident } $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Unsupported [enum_map] [$($entry)*] "the `enum_map` is only supported for properties implementing `EnumProp`"
+		}
+	};
+	(
+		@Arms [$($entry:tt)*] [$($defaults:tt)*] []
+		enum_map $clause:tt $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Error [$($entry)*] "expected `enum_map { <VIS> static <NAME> }`, found `enum_map ",
+			::core::stringify!($clause), "`"
+		}
+	};
+	(
+		// The map of the properties must come first
+		@Arms [$($entry:tt)*] [$($defaults:tt)*] [$($arms:tt)+]
+		enum_map $clause:tt $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Error [$($entry)*] "the `enum_map` must be given before all branches"
+		}
+	};
+	(
+		// The conversions must come before the named values, like the consts
+		@Arms [@Named $($entry:tt)*] [$($defaults:tt)*] []
+		into $into:tt $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Error [@Named $($entry)*] "the `into` must be given before the named values"
+		}
+	};
+	(
+		// Plain expressions have no fields to convert into
+		@Arms [$($entry:tt)*] [@Plain] []
+		into($field:tt : $($ty:tt)+) $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Error [$($entry)*] "the `into` is only supported for struct properties"
+		}
+	};
+	(
+		// The conversion of the enum into a field of its property, kept along
+		// with the other clauses, thus the conversions into the same type are
+		// found at once (via the passed `$`)
+		@Arms [$(@Section $section:tt)? $(@Consts [$($consts:tt)*])? @EnumProp $($entry:tt)*]
+		[$($defaults:tt)*] []
+		into($field:tt : $($ty:tt)+) $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Arms [$(@Section $section)? @Consts [$($($consts)*,)? @Into ($) $field [$($ty)+]] @EnumProp $($entry)*]
+			[$($defaults)*] []
+			$($rest)*
+		}
+	};
+	(
+		@Arms [$(@Section $section:tt)? $(@Consts [$($consts:tt)*])? @EnumPropConstFn $($entry:tt)*]
+		[$($defaults:tt)*] []
+		into($field:tt : $($ty:tt)+) $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Arms [$(@Section $section)? @Consts [$($($consts)*,)? @Into ($) $field [$($ty)+]] @EnumPropConstFn $($entry)*]
+			[$($defaults)*] []
+			$($rest)*
+		}
+	};
+	(
+		@Arms [$(@Section $section:tt)? $(@Consts [$($consts:tt)*])? @EnumPropConst $($entry:tt)*]
+		[$($defaults:tt)*] []
+		into($field:tt : $($ty:tt)+) $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Arms [$(@Section $section)? @Consts [$($($consts)*,)? @Into ($) $field [$($ty)+]] @EnumPropConst $($entry)*]
+			[$($defaults)*] []
+			$($rest)*
+		}
+	};
+	(
+		// Any other property has no `&'static` reference to convert from
+		@Arms [$($entry:tt)*] [$($defaults:tt)*] []
+		into($field:tt : $($ty:tt)+) $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Unsupported [into] [$($entry)*] "the `into` is only supported for properties implementing `EnumProp`"
+		}
+	};
+	(
+		@Arms [$($entry:tt)*] [$($defaults:tt)*] []
+		into $into:tt $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Error [$($entry)*] "expected `into(<FIELD>: <TYPE>)`, found `into",
+			::core::stringify!($into), "`"
+		}
+	};
+	(
+		// The conversions must come first
+		@Arms [$($entry:tt)*] [$($defaults:tt)*] [$($arms:tt)+]
+		into $into:tt $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Error [$($entry)*] "the `into` must be given before all branches"
+		}
+	};
+	(
+		// The associated consts must come before the named values, like the
+		// defaults
+		@Arms [@Named $($entry:tt)*] [$($defaults:tt)*] []
+		consts $consts:tt $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Error [@Named $($entry)*] "the `consts` must be given before the named values"
+		}
+	};
+	(
+		// The associated consts of a const property, kept in front of the
+		// entry, but behind the section, along with the other clauses
+		@Arms [$(@Section $section:tt)? $(@Consts [$($clauses:tt)*])? @EnumProp mod(const) $($entry:tt)*]
+		[$($defaults:tt)*] []
+		consts {
+			$($consts:tt)*
+		} $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Arms [$(@Section $section)? @Consts [$($($clauses)*,)? $($consts)*] @EnumProp mod(const) $($entry)*]
+			[$($defaults)*] []
+			$($rest)*
+		}
+	};
+	(
+		// The associated consts of a const property via `const fn`
+		@Arms [$(@Section $section:tt)? $(@Consts [$($clauses:tt)*])? @EnumPropConstFn $($entry:tt)*]
+		[$($defaults:tt)*] []
+		consts {
+			$($consts:tt)*
+		} $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Arms [$(@Section $section)? @Consts [$($($clauses)*,)? $($consts)*] @EnumPropConstFn $($entry)*]
+			[$($defaults)*] []
+			$($rest)*
+		}
+	};
+	(
+		// The associated consts of a const property via `EnumPropConst`
+		@Arms [$(@Section $section:tt)? $(@Consts [$($clauses:tt)*])? @EnumPropConst mod(const) $($entry:tt)*]
+		[$($defaults:tt)*] []
+		consts {
+			$($consts:tt)*
+		} $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Arms [$(@Section $section)? @Consts [$($($clauses)*,)? $($consts)*] @EnumPropConst mod(const) $($entry)*]
+			[$($defaults)*] []
+			$($rest)*
+		}
+	};
+	(
+		// Any other property can not be evaluated in a const
+		@Arms [$($entry:tt)*] [$($defaults:tt)*] []
+		consts $consts:tt $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Unsupported [consts] [$($entry)*] "the `consts` are only supported for `const` properties"
+		}
+	};
+	(
+		// The associated consts must come first
+		@Arms [$($entry:tt)*] [$($defaults:tt)*] [$($arms:tt)+]
+		consts $consts:tt $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Error [$($entry)*] "the `consts` must be given before all branches"
+		}
+	};
+	(
+		// The `all` table must come before the named values, like the consts
+		@Arms [@Named $($entry:tt)*] [$($defaults:tt)*] []
+		all $all:tt $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Error [@Named $($entry)*] "the `all` table must be given before the named values"
+		}
+	};
+	(
+		// The `all` table of a const or static property, whose variants are
+		// collected from the branches, before these are normalized
+		@Arms [$(@Section $section:tt)? $(@Consts [$($consts:tt)*])? @EnumProp mod(const) $($entry:tt)*]
+		[$($defaults:tt)*] []
+		all { $(#[$attr:meta])* $vis:vis $name:ident } $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@AllVariants [] ["the `all` table" $(#[$attr])* $vis const $name] [$(@Section $section)?] [$(, $($consts)*)?]
+			[@EnumProp mod(const) $($entry)*] [$($defaults)*] ($($rest)*) { $($rest)* }
+		}
+	};
+	(
+		@Arms [$(@Section $section:tt)? $(@Consts [$($consts:tt)*])? @EnumProp mod(static) $($entry:tt)*]
+		[$($defaults:tt)*] []
+		all { $(#[$attr:meta])* $vis:vis $name:ident } $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@AllVariants [] ["the `all` table" $(#[$attr])* $vis const $name] [$(@Section $section)?] [$(, $($consts)*)?]
+			[@EnumProp mod(static) $($entry)*] [$($defaults)*] ($($rest)*) { $($rest)* }
+		}
+	};
+	(
+		@Arms [$(@Section $section:tt)? $(@Consts [$($consts:tt)*])? @EnumPropConstFn $($entry:tt)*]
+		[$($defaults:tt)*] []
+		all { $(#[$attr:meta])* $vis:vis $name:ident } $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@AllVariants [] ["the `all` table" $(#[$attr])* $vis const $name] [$(@Section $section)?] [$(, $($consts)*)?]
+			[@EnumPropConstFn $($entry)*] [$($defaults)*] ($($rest)*) { $($rest)* }
+		}
+	};
+	(
+		@Arms [$(@Section $section:tt)? $(@Consts [$($consts:tt)*])? @EnumPropConst mod(const) $($entry:tt)*]
+		[$($defaults:tt)*] []
+		all { $(#[$attr:meta])* $vis:vis $name:ident } $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@AllVariants [] ["the `all` table" $(#[$attr])* $vis const $name] [$(@Section $section)?] [$(, $($consts)*)?]
+			[@EnumPropConst mod(const) $($entry)*] [$($defaults)*] ($($rest)*) { $($rest)* }
+		}
+	};
+	(
+		// The table of `EnumPropAll`, always the first clause, collected like
+		// the `all` table
+		@Arms [$($entry:tt)*] [$($defaults:tt)*] []
+		@PropAll $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@AllVariants [] ["the `EnumPropAll`" @PropAll] [] [] [$($entry)*] [$($defaults)*] ($($rest)*) { $($rest)* }
+		}
+	};
+	(
+		// Any other property can not be evaluated in a const
+		@Arms [$($entry:tt)*] [$($defaults:tt)*] []
+		all $all:tt $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Unsupported [all] [$($entry)*] "the `all` table is only supported for `const` and `static` properties"
+		}
+	};
+	(
+		// The `all` table must come first
+		@Arms [$($entry:tt)*] [$($defaults:tt)*] [$($arms:tt)+]
+		all $all:tt $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Error [$($entry)*] "the `all` table must be given before all branches"
+		}
+	};
+	(
+		// The parsing must come before the named values, like the `all` table
+		@Arms [@Named $($entry:tt)*] [$($defaults:tt)*] []
+		from_str $field:tt $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Error [@Named $($entry)*] "the `from_str` must be given before the named values"
+		}
+	};
+	(
+		// The parsing via a perfect hash map of the fields, collected along
+		// with the variants, since its keys must be literals
+		@Arms [$(@Section $section:tt)? $(@Consts [$($consts:tt)*])? @EnumProp mod(const) $($entry:tt)*]
+		[$($defaults:tt)*] []
+		from_str(phf $field:ident as $(#[$attr:meta])* $vis:vis $name:ident) $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@AllVariants [] ["the `from_str`" @FromStr phf $field [$(#[$attr])* $vis const $name] []]
+			[$(@Section $section)?] [, @Unique [$field] $(, $($consts)*)?]
+			[@EnumProp mod(const) $($entry)*] [$($defaults)*] ($($rest)*) { $($rest)* }
+		}
+	};
+	(
+		@Arms [$(@Section $section:tt)? $(@Consts [$($consts:tt)*])? @EnumPropConstFn $($entry:tt)*]
+		[$($defaults:tt)*] []
+		from_str(phf $field:ident as $(#[$attr:meta])* $vis:vis $name:ident) $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@AllVariants [] ["the `from_str`" @FromStr phf $field [$(#[$attr])* $vis const $name] []]
+			[$(@Section $section)?] [, @Unique [$field] $(, $($consts)*)?]
+			[@EnumPropConstFn $($entry)*] [$($defaults)*] ($($rest)*) { $($rest)* }
+		}
+	};
+	(
+		@Arms [$(@Section $section:tt)? $(@Consts [$($consts:tt)*])? @EnumPropConst mod(const) $($entry:tt)*]
+		[$($defaults:tt)*] []
+		from_str(phf $field:ident as $(#[$attr:meta])* $vis:vis $name:ident) $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@AllVariants [] ["the `from_str`" @FromStr phf $field [$(#[$attr])* $vis const $name] []]
+			[$(@Section $section)?] [, @Unique [$field] $(, $($consts)*)?]
+			[@EnumPropConst mod(const) $($entry)*] [$($defaults)*] ($($rest)*) { $($rest)* }
+		}
+	};
+	(
+		// The parsing of a const prop by a field, whose variants are collected
+		// like the `all` table, along with the unique check of the field
+		@Arms [$(@Section $section:tt)? $(@Consts [$($consts:tt)*])? @EnumProp mod(const) $($entry:tt)*]
+		[$($defaults:tt)*] []
+		from_str($field:ident) $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@AllVariants [] ["the `from_str`" @FromStr $field] [$(@Section $section)?] [, @Unique [$field] $(, $($consts)*)?]
+			[@EnumProp mod(const) $($entry)*] [$($defaults)*] ($($rest)*) { $($rest)* }
+		}
+	};
+	(
+		@Arms [$(@Section $section:tt)? $(@Consts [$($consts:tt)*])? @EnumPropConstFn $($entry:tt)*]
+		[$($defaults:tt)*] []
+		from_str($field:ident) $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@AllVariants [] ["the `from_str`" @FromStr $field] [$(@Section $section)?] [, @Unique [$field] $(, $($consts)*)?]
+			[@EnumPropConstFn $($entry)*] [$($defaults)*] ($($rest)*) { $($rest)* }
+		}
+	};
+	(
+		@Arms [$(@Section $section:tt)? $(@Consts [$($consts:tt)*])? @EnumPropConst mod(const) $($entry:tt)*]
+		[$($defaults:tt)*] []
+		from_str($field:ident) $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@AllVariants [] ["the `from_str`" @FromStr $field] [$(@Section $section)?] [, @Unique [$field] $(, $($consts)*)?]
+			[@EnumPropConst mod(const) $($entry)*] [$($defaults)*] ($($rest)*) { $($rest)* }
+		}
+	};
+	(
+		// Any other property can not be checked in a const
+		@Arms [$($entry:tt)*] [$($defaults:tt)*] []
+		from_str $field:tt $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Unsupported [from_str] [$($entry)*] "the `from_str` is only supported for `const` properties"
+		}
+	};
+	(
+		// The parsing must come first
+		@Arms [$($entry:tt)*] [$($defaults:tt)*] [$($arms:tt)+]
+		from_str $field:tt $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Error [$($entry)*] "the `from_str` must be given before all branches"
+		}
+	};
+	(
+		// The deserialization must come before the named values, like the
+		// parsing
+		@Arms [@Named $($entry:tt)*] [$($defaults:tt)*] []
+		deserialize_from $clause:tt $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Error [@Named $($entry)*] "the `deserialize_from` must be given before the named values"
+		}
+	};
+	(
+		// The deserialization of a const prop by a field, whose variants are
+		// collected and checked like the parsing
+		@Arms [$(@Section $section:tt)? $(@Consts [$($consts:tt)*])? @EnumProp mod(const) $($entry:tt)*]
+		[$($defaults:tt)*] []
+		deserialize_from($field:ident) $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@AllVariants [] ["the `deserialize_from`" @Deserialize $field] [$(@Section $section)?] [, @Unique [$field] $(, $($consts)*)?]
+			[@EnumProp mod(const) $($entry)*] [$($defaults)*] ($($rest)*) { $($rest)* }
+		}
+	};
+	(
+		@Arms [$(@Section $section:tt)? $(@Consts [$($consts:tt)*])? @EnumPropConstFn $($entry:tt)*]
+		[$($defaults:tt)*] []
+		deserialize_from($field:ident) $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@AllVariants [] ["the `deserialize_from`" @Deserialize $field] [$(@Section $section)?] [, @Unique [$field] $(, $($consts)*)?]
+			[@EnumPropConstFn $($entry)*] [$($defaults)*] ($($rest)*) { $($rest)* }
+		}
+	};
+	(
+		@Arms [$(@Section $section:tt)? $(@Consts [$($consts:tt)*])? @EnumPropConst mod(const) $($entry:tt)*]
+		[$($defaults:tt)*] []
+		deserialize_from($field:ident) $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@AllVariants [] ["the `deserialize_from`" @Deserialize $field] [$(@Section $section)?] [, @Unique [$field] $(, $($consts)*)?]
+			[@EnumPropConst mod(const) $($entry)*] [$($defaults)*] ($($rest)*) { $($rest)* }
+		}
+	};
+	(
+		// Any other property can not be checked in a const
+		@Arms [$($entry:tt)*] [$($defaults:tt)*] []
+		deserialize_from($field:ident) $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Unsupported [deserialize_from] [$($entry)*] "the `deserialize_from` is only supported for `const` properties"
+		}
+	};
+	(
+		@Arms [$($entry:tt)*] [$($defaults:tt)*] []
+		deserialize_from $clause:tt $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Error [$($entry)*] "expected `deserialize_from(<FIELD>)`, found `deserialize_from ",
+			::core::stringify!($clause), "`"
+		}
+	};
+	(
+		// The deserialization must come first
+		@Arms [$($entry:tt)*] [$($defaults:tt)*] [$($arms:tt)+]
+		deserialize_from $clause:tt $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Error [$($entry)*] "the `deserialize_from` must be given before all branches"
+		}
+	};
+	(
+		// The variant sets must come before the named values, like the list
+		// of variants
+		@Arms [@Named $($entry:tt)*] [$($defaults:tt)*] []
+		set_where $clause:tt $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Error [@Named $($entry)*] "the `set_where` must be given before the named values"
+		}
+	};
+	(
+		// The variant sets of a const prop by its boolean fields, whose
+		// variants are collected like the list of variants
+		@Arms [$(@Section $section:tt)? $(@Consts [$($consts:tt)*])? @EnumProp mod(const) $($entry:tt)*]
+		[$($defaults:tt)*] []
+		set_where($($name:ident = $field:ident),+ $(,)?) $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@AllVariants [] ["the `set_where`" @SetWhere [$($name = $field,)+]] [$(@Section $section)?] [$(, $($consts)*)?]
+			[@EnumProp mod(const) $($entry)*] [$($defaults)*] ($($rest)*) { $($rest)* }
+		}
+	};
+	(
+		@Arms [$(@Section $section:tt)? $(@Consts [$($consts:tt)*])? @EnumPropConstFn $($entry:tt)*]
+		[$($defaults:tt)*] []
+		set_where($($name:ident = $field:ident),+ $(,)?) $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@AllVariants [] ["the `set_where`" @SetWhere [$($name = $field,)+]] [$(@Section $section)?] [$(, $($consts)*)?]
+			[@EnumPropConstFn $($entry)*] [$($defaults)*] ($($rest)*) { $($rest)* }
+		}
+	};
+	(
+		@Arms [$(@Section $section:tt)? $(@Consts [$($consts:tt)*])? @EnumPropConst mod(const) $($entry:tt)*]
+		[$($defaults:tt)*] []
+		set_where($($name:ident = $field:ident),+ $(,)?) $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@AllVariants [] ["the `set_where`" @SetWhere [$($name = $field,)+]] [$(@Section $section)?] [$(, $($consts)*)?]
+			[@EnumPropConst mod(const) $($entry)*] [$($defaults)*] ($($rest)*) { $($rest)* }
+		}
+	};
+	(
+		// Any other property can not be evaluated in a const
+		@Arms [$($entry:tt)*] [$($defaults:tt)*] []
+		set_where($($name:ident = $field:ident),+ $(,)?) $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Unsupported [set_where] [$($entry)*] "the `set_where` is only supported for `const` properties"
+		}
+	};
+	(
+		@Arms [$($entry:tt)*] [$($defaults:tt)*] []
+		set_where $clause:tt $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Error [$($entry)*] "expected `set_where(<NAME> = <FIELD>, ...)`, found `set_where ",
+			::core::stringify!($clause), "`"
+		}
+	};
+	(
+		// The variant sets must come first
+		@Arms [$($entry:tt)*] [$($defaults:tt)*] [$($arms:tt)+]
+		set_where $clause:tt $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Error [$($entry)*] "the `set_where` must be given before all branches"
+		}
+	};
+	(
+		// The conversion must come before the named values, like the parsing
+		@Arms [@Named $($entry:tt)*] [$($defaults:tt)*] []
+		try_from $field:tt $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Error [@Named $($entry)*] "the `try_from` must be given before the named values"
+		}
+	};
+	(
+		// The conversion of a value into a const prop by a field, collected
+		// and checked like the parsing
+		@Arms [$(@Section $section:tt)? $(@Consts [$($consts:tt)*])? @EnumProp mod(const) $($entry:tt)*]
+		[$($defaults:tt)*] []
+		try_from($field:ident : $($ty:tt)+) $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@AllVariants [] ["the `try_from`" @TryFrom $field [$($ty)+]] [$(@Section $section)?] [, @Unique [$field] $(, $($consts)*)?]
+			[@EnumProp mod(const) $($entry)*] [$($defaults)*] ($($rest)*) { $($rest)* }
+		}
+	};
+	(
+		@Arms [$(@Section $section:tt)? $(@Consts [$($consts:tt)*])? @EnumPropConstFn $($entry:tt)*]
+		[$($defaults:tt)*] []
+		try_from($field:ident : $($ty:tt)+) $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@AllVariants [] ["the `try_from`" @TryFrom $field [$($ty)+]] [$(@Section $section)?] [, @Unique [$field] $(, $($consts)*)?]
+			[@EnumPropConstFn $($entry)*] [$($defaults)*] ($($rest)*) { $($rest)* }
+		}
+	};
+	(
+		@Arms [$(@Section $section:tt)? $(@Consts [$($consts:tt)*])? @EnumPropConst mod(const) $($entry:tt)*]
+		[$($defaults:tt)*] []
+		try_from($field:ident : $($ty:tt)+) $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@AllVariants [] ["the `try_from`" @TryFrom $field [$($ty)+]] [$(@Section $section)?] [, @Unique [$field] $(, $($consts)*)?]
+			[@EnumPropConst mod(const) $($entry)*] [$($defaults)*] ($($rest)*) { $($rest)* }
+		}
+	};
+	(
+		// Any other property can not be checked in a const
+		@Arms [$($entry:tt)*] [$($defaults:tt)*] []
+		try_from($field:ident : $($ty:tt)+) $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Unsupported [try_from] [$($entry)*] "the `try_from` is only supported for `const` properties"
+		}
+	};
+	(
+		@Arms [$($entry:tt)*] [$($defaults:tt)*] []
+		try_from $field:tt $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Error [$($entry)*] "expected `try_from(<FIELD>: <TYPE>)`, found `try_from",
+			::core::stringify!($field), "`"
+		}
+	};
+	(
+		// The conversion must come first
+		@Arms [$($entry:tt)*] [$($defaults:tt)*] [$($arms:tt)+]
+		try_from $field:tt $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Error [$($entry)*] "the `try_from` must be given before all branches"
+		}
+	};
+	(
+		// The sorted variants must come before the named values, like the
+		// `all` table
+		@Arms [@Named $($entry:tt)*] [$($defaults:tt)*] []
+		ordered_by $ordered:tt $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Error [@Named $($entry)*] "the `ordered_by` must be given before the named values"
+		}
+	};
+	(
+		// The variants of a const or static property sorted by a field, which
+		// are collected from the branches like the `all` table
+		@Arms [$(@Section $section:tt)? $(@Consts [$($consts:tt)*])? @EnumProp mod(const) $($entry:tt)*]
+		[$($defaults:tt)*] []
+		ordered_by($(#[$attr:meta])* $field:ident as $vis:vis $name:ident) $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@AllVariants [] ["the `ordered_by`" @Ordered $field [$(#[$attr])* $vis const $name]] [$(@Section $section)?] [$(, $($consts)*)?]
+			[@EnumProp mod(const) $($entry)*] [$($defaults)*] ($($rest)*) { $($rest)* }
+		}
+	};
+	(
+		@Arms [$(@Section $section:tt)? $(@Consts [$($consts:tt)*])? @EnumProp mod(static) $($entry:tt)*]
+		[$($defaults:tt)*] []
+		ordered_by($(#[$attr:meta])* $field:ident as $vis:vis $name:ident) $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@AllVariants [] ["the `ordered_by`" @Ordered $field [$(#[$attr])* $vis const $name]] [$(@Section $section)?] [$(, $($consts)*)?]
+			[@EnumProp mod(static) $($entry)*] [$($defaults)*] ($($rest)*) { $($rest)* }
+		}
+	};
+	(
+		@Arms [$(@Section $section:tt)? $(@Consts [$($consts:tt)*])? @EnumPropConstFn $($entry:tt)*]
+		[$($defaults:tt)*] []
+		ordered_by($(#[$attr:meta])* $field:ident as $vis:vis $name:ident) $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@AllVariants [] ["the `ordered_by`" @Ordered $field [$(#[$attr])* $vis const $name]] [$(@Section $section)?] [$(, $($consts)*)?]
+			[@EnumPropConstFn $($entry)*] [$($defaults)*] ($($rest)*) { $($rest)* }
+		}
+	};
+	(
+		@Arms [$(@Section $section:tt)? $(@Consts [$($consts:tt)*])? @EnumPropConst mod(const) $($entry:tt)*]
+		[$($defaults:tt)*] []
+		ordered_by($(#[$attr:meta])* $field:ident as $vis:vis $name:ident) $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@AllVariants [] ["the `ordered_by`" @Ordered $field [$(#[$attr])* $vis const $name]] [$(@Section $section)?] [$(, $($consts)*)?]
+			[@EnumPropConst mod(const) $($entry)*] [$($defaults)*] ($($rest)*) { $($rest)* }
+		}
+	};
+	(
+		// Any other property can not be evaluated in a const
+		@Arms [$($entry:tt)*] [$($defaults:tt)*] []
+		ordered_by $ordered:tt $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Unsupported [ordered_by] [$($entry)*] "the `ordered_by` is only supported for `const` and `static` properties"
+		}
+	};
+	(
+		// The sorted variants must come first
+		@Arms [$($entry:tt)*] [$($defaults:tt)*] [$($arms:tt)+]
+		ordered_by $ordered:tt $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Error [$($entry)*] "the `ordered_by` must be given before all branches"
+		}
+	};
+	(
+		// An assertion, checked for the property of each branch
+		@Arms [$($entry:tt)*] [$($defaults:tt)*] [$($arms:tt)*]
+		assert $check:tt $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Arms [$($entry)*] [$($defaults)*] [$($arms)*]
+			@Assert [assert] $check $($rest)*
+		}
+	};
+	(
+		// An assertion, checked only with debug assertions enabled
+		@Arms [$($entry:tt)*] [$($defaults:tt)*] [$($arms:tt)*]
+		debug_assert $check:tt $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Arms [$($entry)*] [$($defaults)*] [$($arms)*]
+			@Assert [debug_assert] $check $($rest)*
+		}
+	};
+	(
+		// The assertions must come before the named values, like the consts
+		@Arms [@Named $($entry:tt)*] [$($defaults:tt)*] []
+		@Assert [$kind:ident] $check:tt $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Error [@Named $($entry)*] ::core::concat!(
+				"the `",
+				::core::stringify!($kind),
+				"` must be given before the named values"
+			)
+		}
+	};
+	(
+		// The assertions of a const prop, checked at compile time like the
+		// unique fields
+		@Arms [$(@Section $section:tt)? $(@Consts [$($consts:tt)*])? @EnumProp mod(const) $($entry:tt)*]
+		[$($defaults:tt)*] []
+		@Assert [$kind:ident] ($($check:tt)*) $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Arms [$(@Section $section)? @Consts [@Assert [$kind ($($check)*)] $(, $($consts)*)?] @EnumProp mod(const) $($entry)*]
+			[$($defaults)*] []
+			$($rest)*
+		}
+	};
+	(
+		@Arms [$(@Section $section:tt)? $(@Consts [$($consts:tt)*])? @EnumPropConstFn $($entry:tt)*]
+		[$($defaults:tt)*] []
+		@Assert [$kind:ident] ($($check:tt)*) $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Arms [$(@Section $section)? @Consts [@Assert [$kind ($($check)*)] $(, $($consts)*)?] @EnumPropConstFn $($entry)*]
+			[$($defaults)*] []
+			$($rest)*
+		}
+	};
+	(
+		@Arms [$(@Section $section:tt)? $(@Consts [$($consts:tt)*])? @EnumPropConst mod(const) $($entry:tt)*]
+		[$($defaults:tt)*] []
+		@Assert [$kind:ident] ($($check:tt)*) $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Arms [$(@Section $section)? @Consts [@Assert [$kind ($($check)*)] $(, $($consts)*)?] @EnumPropConst mod(const) $($entry)*]
+			[$($defaults)*] []
+			$($rest)*
+		}
+	};
+	(
+		// The assertions of a lazy prop, checked at the initialization of
+		// each branch
+		@Arms [$(@Section $section:tt)? $(@Asserts [$($checks:tt)*])? @EnumProp mod(lazy $($args:tt)?) $($entry:tt)*]
+		[$($defaults:tt)*] []
+		@Assert [$kind:ident] ($($check:tt)*) $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Arms [$(@Section $section)? @Asserts [$($($checks)*)? $kind ($($check)*)] @EnumProp mod(lazy $($args)?) $($entry)*]
+			[$($defaults)*] []
+			$($rest)*
+		}
+	};
+	(
+		// Any other property can not be checked
+		@Arms [$($entry:tt)*] [$($defaults:tt)*] []
+		@Assert [$kind:ident] $check:tt $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Unsupported [$kind] [$($entry)*] ::core::concat!(
+				"the `",
+				::core::stringify!($kind),
+				"` is only supported for `const` and `lazy` properties"
+			)
+		}
+	};
+	(
+		// The assertions must come first
+		@Arms [$($entry:tt)*] [$($defaults:tt)*] [$($arms:tt)+]
+		@Assert [$kind:ident] $check:tt $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Error [$($entry)*] ::core::concat!(
+				"the `",
+				::core::stringify!($kind),
+				"` must be given before all branches"
+			)
+		}
+	};
+	(
+		// The unique fields must come before the named values, like the consts
+		@Arms [@Named $($entry:tt)*] [$($defaults:tt)*] []
+		unique $fields:tt $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Error [@Named $($entry)*] "the `unique` must be given before the named values"
+		}
+	};
+	(
+		// The unique fields of a const prop, checked like the aggregates
+		@Arms [$(@Section $section:tt)? $(@Consts [$($consts:tt)*])? @EnumProp mod(const) $($entry:tt)*]
+		[$($defaults:tt)*] []
+		unique ($($fields:tt)*) $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Arms [$(@Section $section)? @Consts [@Unique [$($fields)*] $(, $($consts)*)?] @EnumProp mod(const) $($entry)*]
+			[$($defaults)*] []
+			$($rest)*
+		}
+	};
+	(
+		@Arms [$(@Section $section:tt)? $(@Consts [$($consts:tt)*])? @EnumPropConstFn $($entry:tt)*]
+		[$($defaults:tt)*] []
+		unique ($($fields:tt)*) $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Arms [$(@Section $section)? @Consts [@Unique [$($fields)*] $(, $($consts)*)?] @EnumPropConstFn $($entry)*]
+			[$($defaults)*] []
+			$($rest)*
+		}
+	};
+	(
+		@Arms [$(@Section $section:tt)? $(@Consts [$($consts:tt)*])? @EnumPropConst mod(const) $($entry:tt)*]
+		[$($defaults:tt)*] []
+		unique ($($fields:tt)*) $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Arms [$(@Section $section)? @Consts [@Unique [$($fields)*] $(, $($consts)*)?] @EnumPropConst mod(const) $($entry)*]
+			[$($defaults)*] []
+			$($rest)*
+		}
+	};
+	(
+		// Any other property can not be evaluated in a const
+		@Arms [$($entry:tt)*] [$($defaults:tt)*] []
+		unique $fields:tt $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Unsupported [unique] [$($entry)*] "the `unique` is only supported for `const` properties"
+		}
+	};
+	(
+		// The unique fields must come first
+		@Arms [$($entry:tt)*] [$($defaults:tt)*] [$($arms:tt)+]
+		unique $fields:tt $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Error [$($entry)*] "the `unique` must be given before all branches"
+		}
+	};
+	(
+		// The aggregates must come before the named values, like the consts
+		@Arms [@Named $($entry:tt)*] [$($defaults:tt)*] []
+		aggregate $aggregates:tt $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Error [@Named $($entry)*] "the `aggregate` must be given before the named values"
+		}
+	};
+	(
+		// The aggregates of a const property, folded like the `all` table
+		@Arms [$(@Section $section:tt)? $(@Consts [$($consts:tt)*])? @EnumProp mod(const) $($entry:tt)*]
+		[$($defaults:tt)*] []
+		aggregate { $($aggregates:tt)* } $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Arms [$(@Section $section)? @Consts [@Aggregate [$($aggregates)*] $(, $($consts)*)?] @EnumProp mod(const) $($entry)*]
+			[$($defaults)*] []
+			$($rest)*
+		}
+	};
+	(
+		@Arms [$(@Section $section:tt)? $(@Consts [$($consts:tt)*])? @EnumPropConstFn $($entry:tt)*]
+		[$($defaults:tt)*] []
+		aggregate { $($aggregates:tt)* } $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Arms [$(@Section $section)? @Consts [@Aggregate [$($aggregates)*] $(, $($consts)*)?] @EnumPropConstFn $($entry)*]
+			[$($defaults)*] []
+			$($rest)*
+		}
+	};
+	(
+		@Arms [$(@Section $section:tt)? $(@Consts [$($consts:tt)*])? @EnumPropConst mod(const) $($entry:tt)*]
+		[$($defaults:tt)*] []
+		aggregate { $($aggregates:tt)* } $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Arms [$(@Section $section)? @Consts [@Aggregate [$($aggregates)*] $(, $($consts)*)?] @EnumPropConst mod(const) $($entry)*]
+			[$($defaults)*] []
+			$($rest)*
+		}
+	};
+	(
+		// Any other property can not be evaluated in a const
+		@Arms [$($entry:tt)*] [$($defaults:tt)*] []
+		aggregate $aggregates:tt $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Unsupported [aggregate] [$($entry)*] "the `aggregate` is only supported for `const` properties"
+		}
+	};
+	(
+		// The aggregates must come first
+		@Arms [$($entry:tt)*] [$($defaults:tt)*] [$($arms:tt)+]
+		aggregate $aggregates:tt $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Error [$($entry)*] "the `aggregate` must be given before all branches"
+		}
+	};
+	(
+		// The variants of the `all` table are collected, continuing with the
+		// branches, the table is then defined like the associated consts
+		@AllVariants [$($variants:tt)*] [$clause:literal $($head:tt)*] [$($section:tt)*] [$($consts:tt)*]
+		[$($entry:tt)*] [$($defaults:tt)*] ($($rest:tt)*) {}
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Arms [$($section)* @Consts [@All [$($head)*] [$($variants)*] $($consts)*] $($entry)*]
+			[$($defaults)*] []
+			$($rest)*
+		}
+	};
+	(
+		// The variants of a branch, one entry each, with the attributes of
+		// the branch, e.g. `cfg`
+		@AllVariants [$($variants:tt)*] $head:tt $section:tt $consts:tt $entry:tt $defaults:tt $rest:tt {
+			@Variants [$($attr:tt)*] [$variant:path, $($more:tt)*] $($matching:tt)*
+		}
+	) => {
+		$crate::internal_props_impl_macro!{
+			@AllVariants [$($variants)* [$($attr)*] $variant,] $head $section $consts $entry $defaults $rest {
+				@Variants [$($attr)*] [$($more)*] $($matching)*
+			}
+		}
+	};
+	(
+		@AllVariants $variants:tt $head:tt $section:tt $consts:tt $entry:tt $defaults:tt $rest:tt {
+			@Variants $attrs:tt [] $($matching:tt)*
+		}
+	) => {
+		$crate::internal_props_impl_macro!{
+			@AllVariants $variants $head $section $consts $entry $defaults $rest {
+				$($matching)*
+			}
+		}
+	};
+	(
+		// The rest of a named value is no branch
+		@AllVariants $variants:tt $head:tt $section:tt $consts:tt $entry:tt $defaults:tt $rest:tt {
+			@Let ; $($matching:tt)*
+		}
+	) => {
+		$crate::internal_props_impl_macro!{
+			@AllVariants $variants $head $section $consts $entry $defaults $rest {
+				$($matching)*
+			}
+		}
+	};
+	(
+		@AllVariants $variants:tt $head:tt $section:tt $consts:tt $entry:tt $defaults:tt $rest:tt {
+			@Let $next:tt $($matching:tt)*
+		}
+	) => {
+		$crate::internal_props_impl_macro!{
+			@AllVariants $variants $head $section $consts $entry $defaults $rest {
+				@Let $($matching)*
+			}
+		}
+	};
+	(
+		// A named value is no branch, skipped until its `;`
+		@AllVariants $variants:tt $head:tt $section:tt $consts:tt $entry:tt $defaults:tt $rest:tt {
+			$(#[$attr:meta])* let $($matching:tt)*
+		}
+	) => {
+		$crate::internal_props_impl_macro!{
+			@AllVariants $variants $head $section $consts $entry $defaults $rest {
+				@Let $($matching)*
+			}
+		}
+	};
+	(
+		// The comma between the branches
+		@AllVariants $variants:tt $head:tt $section:tt $consts:tt $entry:tt $defaults:tt $rest:tt {
+			, $($matching:tt)*
+		}
+	) => {
+		$crate::internal_props_impl_macro!{
+			@AllVariants $variants $head $section $consts $entry $defaults $rest {
+				$($matching)*
+			}
+		}
+	};
+	(
+		// The other clauses are no branch, their order is checked later on
+		@AllVariants $variants:tt $head:tt $section:tt $consts:tt $entry:tt $defaults:tt $rest:tt {
+			defaults $clause:tt $($matching:tt)*
+		}
+	) => {
+		$crate::internal_props_impl_macro!{
+			@AllVariants $variants $head $section $consts $entry $defaults $rest {
+				$($matching)*
+			}
+		}
+	};
+	(
+		@AllVariants $variants:tt $head:tt $section:tt $consts:tt $entry:tt $defaults:tt $rest:tt {
+			consts $clause:tt $($matching:tt)*
+		}
+	) => {
+		$crate::internal_props_impl_macro!{
+			@AllVariants $variants $head $section $consts $entry $defaults $rest {
+				$($matching)*
+			}
+		}
+	};
+	(
+		@AllVariants $variants:tt $head:tt $section:tt $consts:tt $entry:tt $defaults:tt $rest:tt {
+			all $clause:tt $($matching:tt)*
+		}
+	) => {
+		$crate::internal_props_impl_macro!{
+			@AllVariants $variants $head $section $consts $entry $defaults $rest {
+				$($matching)*
+			}
+		}
+	};
+	(
+		@AllVariants $variants:tt $head:tt $section:tt $consts:tt $entry:tt $defaults:tt $rest:tt {
+			aggregate $clause:tt $($matching:tt)*
+		}
+	) => {
+		$crate::internal_props_impl_macro!{
+			@AllVariants $variants $head $section $consts $entry $defaults $rest {
+				$($matching)*
+			}
+		}
+	};
+	(
+		@AllVariants $variants:tt $head:tt $section:tt $consts:tt $entry:tt $defaults:tt $rest:tt {
+			assert $clause:tt $($matching:tt)*
+		}
+	) => {
+		$crate::internal_props_impl_macro!{
+			@AllVariants $variants $head $section $consts $entry $defaults $rest {
+				$($matching)*
+			}
+		}
+	};
+	(
+		@AllVariants $variants:tt $head:tt $section:tt $consts:tt $entry:tt $defaults:tt $rest:tt {
+			debug_assert $clause:tt $($matching:tt)*
+		}
+	) => {
+		$crate::internal_props_impl_macro!{
+			@AllVariants $variants $head $section $consts $entry $defaults $rest {
+				$($matching)*
+			}
+		}
+	};
+	(
+		@AllVariants $variants:tt $head:tt $section:tt $consts:tt $entry:tt $defaults:tt $rest:tt {
+			ordered_by $clause:tt $($matching:tt)*
+		}
+	) => {
+		$crate::internal_props_impl_macro!{
+			@AllVariants $variants $head $section $consts $entry $defaults $rest {
+				$($matching)*
+			}
+		}
+	};
+	(
+		@AllVariants $variants:tt $head:tt $section:tt $consts:tt $entry:tt $defaults:tt $rest:tt {
+			derive $clause:tt $($matching:tt)*
+		}
+	) => {
+		$crate::internal_props_impl_macro!{
+			@AllVariants $variants $head $section $consts $entry $defaults $rest {
+				$($matching)*
+			}
+		}
+	};
+	(
+		@AllVariants $variants:tt $head:tt $section:tt $consts:tt $entry:tt $defaults:tt $rest:tt {
+			serialize_as $clause:tt $($matching:tt)*
+		}
+	) => {
+		$crate::internal_props_impl_macro!{
+			@AllVariants $variants $head $section $consts $entry $defaults $rest {
+				$($matching)*
+			}
+		}
+	};
+	(
+		@AllVariants $variants:tt $head:tt $section:tt $consts:tt $entry:tt $defaults:tt $rest:tt {
+			defmt $clause:tt $($matching:tt)*
+		}
+	) => {
+		$crate::internal_props_impl_macro!{
+			@AllVariants $variants $head $section $consts $entry $defaults $rest {
+				$($matching)*
+			}
+		}
+	};
+	(
+		@AllVariants $variants:tt $head:tt $section:tt $consts:tt $entry:tt $defaults:tt $rest:tt {
+			deserialize_from $clause:tt $($matching:tt)*
+		}
+	) => {
+		$crate::internal_props_impl_macro!{
+			@AllVariants $variants $head $section $consts $entry $defaults $rest {
+				$($matching)*
+			}
+		}
+	};
+	(
+		@AllVariants $variants:tt $head:tt $section:tt $consts:tt $entry:tt $defaults:tt $rest:tt {
+			set_where $clause:tt $($matching:tt)*
+		}
+	) => {
+		$crate::internal_props_impl_macro!{
+			@AllVariants $variants $head $section $consts $entry $defaults $rest {
+				$($matching)*
+			}
+		}
+	};
+	(
+		@AllVariants $variants:tt $head:tt $section:tt $consts:tt $entry:tt $defaults:tt $rest:tt {
+			from_str $clause:tt $($matching:tt)*
+		}
+	) => {
+		$crate::internal_props_impl_macro!{
+			@AllVariants $variants $head $section $consts $entry $defaults $rest {
+				$($matching)*
+			}
+		}
+	};
+	(
+		@AllVariants $variants:tt $head:tt $section:tt $consts:tt $entry:tt $defaults:tt $rest:tt {
+			try_from $clause:tt $($matching:tt)*
+		}
+	) => {
+		$crate::internal_props_impl_macro!{
+			@AllVariants $variants $head $section $consts $entry $defaults $rest {
+				$($matching)*
+			}
+		}
+	};
+	(
+		@AllVariants $variants:tt $head:tt $section:tt $consts:tt $entry:tt $defaults:tt $rest:tt {
+			into $clause:tt $($matching:tt)*
+		}
+	) => {
+		$crate::internal_props_impl_macro!{
+			@AllVariants $variants $head $section $consts $entry $defaults $rest {
+				$($matching)*
+			}
+		}
+	};
+	(
+		@AllVariants $variants:tt $head:tt $section:tt $consts:tt $entry:tt $defaults:tt $rest:tt {
+			dedup $($matching:tt)*
+		}
+	) => {
+		$crate::internal_props_impl_macro!{
+			@AllVariants $variants $head $section $consts $entry $defaults $rest {
+				$($matching)*
+			}
+		}
+	};
+	(
+		@AllVariants $variants:tt $head:tt $section:tt $consts:tt $entry:tt $defaults:tt $rest:tt {
+			identity $($matching:tt)*
+		}
+	) => {
+		$crate::internal_props_impl_macro!{
+			@AllVariants $variants $head $section $consts $entry $defaults $rest {
+				$($matching)*
+			}
+		}
+	};
+	(
+		@AllVariants $variants:tt $head:tt $section:tt $consts:tt $entry:tt $defaults:tt $rest:tt {
+			from_property $clause:tt $($matching:tt)*
+		}
+	) => {
+		$crate::internal_props_impl_macro!{
+			@AllVariants $variants $head $section $consts $entry $defaults $rest {
+				$($matching)*
+			}
+		}
+	};
+	(
+		@AllVariants $variants:tt $head:tt $section:tt $consts:tt $entry:tt $defaults:tt $rest:tt {
+			enum_map $clause:tt $($matching:tt)*
+		}
+	) => {
+		$crate::internal_props_impl_macro!{
+			@AllVariants $variants $head $section $consts $entry $defaults $rest {
+				$($matching)*
+			}
+		}
+	};
+	(
+		@AllVariants $variants:tt $head:tt $section:tt $consts:tt $entry:tt $defaults:tt $rest:tt {
+			variants $clause:tt $($matching:tt)*
+		}
+	) => {
+		$crate::internal_props_impl_macro!{
+			@AllVariants $variants $head $section $consts $entry $defaults $rest {
+				$($matching)*
+			}
+		}
+	};
+	(
+		@AllVariants $variants:tt $head:tt $section:tt $consts:tt $entry:tt $defaults:tt $rest:tt {
+			value_enum $clause:tt $($matching:tt)*
+		}
+	) => {
+		$crate::internal_props_impl_macro!{
+			@AllVariants $variants $head $section $consts $entry $defaults $rest {
+				$($matching)*
+			}
+		}
+	};
+	(
+		@AllVariants $variants:tt $head:tt $section:tt $consts:tt $entry:tt $defaults:tt $rest:tt {
+			sample_weighted $clause:tt $($matching:tt)*
+		}
+	) => {
+		$crate::internal_props_impl_macro!{
+			@AllVariants $variants $head $section $consts $entry $defaults $rest {
+				$($matching)*
+			}
+		}
+	};
+	(
+		@AllVariants $variants:tt $head:tt $section:tt $consts:tt $entry:tt $defaults:tt $rest:tt {
+			discriminant $clause:tt $($matching:tt)*
+		}
+	) => {
+		$crate::internal_props_impl_macro!{
+			@AllVariants $variants $head $section $consts $entry $defaults $rest {
+				$($matching)*
+			}
+		}
+	};
+	(
+		@AllVariants $variants:tt $head:tt $section:tt $consts:tt $entry:tt $defaults:tt $rest:tt {
+			table $($matching:tt)*
+		}
+	) => {
+		$crate::internal_props_impl_macro!{
+			@AllVariants $variants $head $section $consts $entry $defaults $rest {
+				$($matching)*
+			}
+		}
+	};
+	(
+		@AllVariants $variants:tt $head:tt $section:tt $consts:tt $entry:tt $defaults:tt $rest:tt {
+			setters $(($ordering:ident))? $clause:tt $($matching:tt)*
+		}
+	) => {
+		$crate::internal_props_impl_macro!{
+			@AllVariants $variants $head $section $consts $entry $defaults $rest {
+				$($matching)*
+			}
+		}
+	};
+	(
+		@AllVariants $variants:tt $head:tt $section:tt $consts:tt $entry:tt $defaults:tt $rest:tt {
+			unique $clause:tt $($matching:tt)*
+		}
+	) => {
+		$crate::internal_props_impl_macro!{
+			@AllVariants $variants $head $section $consts $entry $defaults $rest {
+				$($matching)*
+			}
+		}
+	};
+	(
+		// The wildcard branch does not name its variants, neither for the
+		// clause collecting them, e.g. the `all` table
+		@AllVariants $variants:tt [$clause:literal $($head:tt)*] [$($section:tt)*] $consts:tt [$($entry:tt)*] $defaults:tt $rest:tt {
+			$(#[$attr:meta])* _ $($matching:tt)*
+		}
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Error [$($section)* $($entry)*]
+			$clause,
+			" requires all variants to be named, thus it can not have a wildcard branch"
+		}
+	};
+	(
+		@AllVariants $variants:tt [$clause:literal $($head:tt)*] [$($section:tt)*] $consts:tt [$($entry:tt)*] $defaults:tt $rest:tt {
+			$(#[$attr:meta])* default $($matching:tt)*
+		}
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Error [$($section)* $($entry)*]
+			$clause,
+			" requires all variants to be named, thus it can not have a wildcard branch"
+		}
+	};
+	(
+		// A branch of fieldless variants given as fields, whose first variant
+		// is parsed from its fields, via the perfect hash map
+		@AllVariants $variants:tt [$clause:literal @FromStr phf $field:ident $map:tt [$($pairs:tt)*]] $section:tt $consts:tt $entry:tt
+		$defaults:tt $rest:tt {
+			$(|)? $($first:ident)::+ $(| $($segment:ident)::+)*
+			=> $($arm_modifier:ident $(($($args:tt)*))?)? { $($fields:tt)* }
+			$($matching:tt)*
+		}
+	) => {
+		$crate::internal_props_impl_macro!{
+			@AllVariants $variants [$clause @FromStr phf $field $map [$($pairs)* ($($first)::+) { $($fields)* }]] $section $consts
+			$entry $defaults $rest {
+				@Variants [] [$($first)::+, $($($segment)::+,)*] $($matching)*
+			}
+		}
+	};
+	(
+		// The perfect hash map can not hold attributes, e.g. `cfg`
+		@AllVariants $variants:tt [$clause:literal @FromStr phf $($head:tt)*] [$($section:tt)*] $consts:tt [$($entry:tt)*] $defaults:tt $rest:tt {
+			#[$attr:meta] $($matching:tt)*
+		}
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Error [$($section)* $($entry)*]
+			"the `from_str(phf ...)` does not support attributes on branches, found `#[",
+			::core::stringify!($attr),
+			"]`"
+		}
+	};
+	(
+		// Any other branch of fieldless variants has no field to parse from
+		@AllVariants $variants:tt [$clause:literal @FromStr phf $field:ident $($head:tt)*] [$($section:tt)*] $consts:tt [$($entry:tt)*]
+		$defaults:tt $rest:tt {
+			$(|)? $($($segment:ident)::+)|+ => $($matching:tt)*
+		}
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Error [$($section)* $($entry)*]
+			"the `from_str(phf ",
+			::core::stringify!($field),
+			")` requires each branch to be given as fields, not `",
+			::core::stringify!($($($segment)::+)|+),
+			"`"
+		}
+	};
+	(
+		@AllVariants $variants:tt [$clause:literal @FromStr phf $field:ident $($head:tt)*] [$($section:tt)*] $consts:tt [$($entry:tt)*]
+		$defaults:tt $rest:tt {
+			$(|)? $($($segment:ident)::+)|+ $(, $($matching:tt)*)?
+		}
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Error [$($section)* $($entry)*]
+			"the `from_str(phf ",
+			::core::stringify!($field),
+			")` requires each branch to be given as fields, not `",
+			::core::stringify!($($($segment)::+)|+),
+			"`"
+		}
+	};
+	(
+		// A fieldless branch, one entry per variant
+		@AllVariants $variants:tt $head:tt $section:tt $consts:tt $entry:tt $defaults:tt $rest:tt {
+			$(#[$arm_attr:meta])* $(|)? $($($segment:ident)::+)|+ => { $($fields:tt)* }
+			$($matching:tt)*
+		}
+	) => {
+		$crate::internal_props_impl_macro!{
+			@AllVariants $variants $head $section $consts $entry $defaults $rest {
+				@Variants [$(#[$arm_attr])*] [$($($segment)::+,)+] $($matching)*
+			}
+		}
+	};
+	(
+		// A fieldless branch, overriding the modifier, e.g. `static { ... }`
+		@AllVariants $variants:tt $head:tt $section:tt $consts:tt $entry:tt $defaults:tt $rest:tt {
+			$(#[$arm_attr:meta])* $(|)? $($($segment:ident)::+)|+
+			=> $arm_modifier:ident $(($($args:tt)*))? { $($fields:tt)* }
+			$($matching:tt)*
+		}
+	) => {
+		$crate::internal_props_impl_macro!{
+			@AllVariants $variants $head $section $consts $entry $defaults $rest {
+				@Variants [$(#[$arm_attr])*] [$($($segment)::+,)+] $($matching)*
+			}
+		}
+	};
+	(
+		// A fieldless branch, given as an expression
+		@AllVariants $variants:tt $head:tt $section:tt $consts:tt $entry:tt $defaults:tt $rest:tt {
+			$(#[$arm_attr:meta])* $(|)? $($($segment:ident)::+)|+ => $value:expr
+			$(, $($matching:tt)*)?
+		}
+	) => {
+		$crate::internal_props_impl_macro!{
+			@AllVariants $variants $head $section $consts $entry $defaults $rest {
+				@Variants [$(#[$arm_attr])*] [$($($segment)::+,)+] $($($matching)*)?
+			}
+		}
+	};
+	(
+		// A fieldless branch without a body
+		@AllVariants $variants:tt $head:tt $section:tt $consts:tt $entry:tt $defaults:tt $rest:tt {
+			$(#[$arm_attr:meta])* $(|)? $($($segment:ident)::+)|+
+			$(, $($matching:tt)*)?
+		}
+	) => {
+		$crate::internal_props_impl_macro!{
+			@AllVariants $variants $head $section $consts $entry $defaults $rest {
+				@Variants [$(#[$arm_attr])*] [$($($segment)::+,)+] $($($matching)*)?
+			}
+		}
+	};
+	(
+		// Any other branch, e.g. with variant data or a guard, can not be
+		// collected as its variants
+		@AllVariants $variants:tt [$clause:literal $($head:tt)*] [$($section:tt)*] $consts:tt [$($entry:tt)*] $defaults:tt $rest:tt {
+			$(#[$attr:meta])* $branch:pat $(if $guard:expr)? => $($matching:tt)*
+		}
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Error [$($section)* $($entry)*]
+			$clause,
+			" requires branches of fieldless variants, e.g. `Self::A | Self::B`, not `",
+			::core::stringify!($branch $(if $guard)?),
+			"`"
+		}
+	};
+	(
+		// A named value, which can be used by several branches, collected
+		// along with the entry, to be defined once the branches are done
+		@Arms [@Named [$($lets:tt)*] [] $($entry:tt)*] [$($defaults:tt)*] []
+		$(#[$attr:meta])* let $name:ident = $($rest:tt)+
+	) => {
+		$crate::internal_props_impl_macro!{
+			@ArmsLet [@Named [$($lets)*] [] $($entry)*] [$($defaults)*]
+			[$(#[$attr])* $name]
+			$($rest)+
+		}
+	};
+	(
+		// The first named value
+		@Arms [$($entry:tt)*] [$($defaults:tt)*] []
+		$(#[$attr:meta])* let $($rest:tt)+
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Arms [@Named [] [] $($entry)*] [$($defaults)*] []
+			$(#[$attr])* let $($rest)+
+		}
+	};
+	(
+		// The named values must come first
+		@Arms [$($entry:tt)*] [$($defaults:tt)*] [$($arms:tt)*]
+		$(#[$attr:meta])* let $($rest:tt)+
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Error [$($entry)*] "the named values must be given before all branches"
+		}
+	};
+	(
+		// The comma between the branches
+		@Arms [$($entry:tt)*] [$($defaults:tt)*] [$($arms:tt)*]
+		, $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Arms [$($entry)*] [$($defaults)*] [$($arms)*]
+			$($rest)*
+		}
+	};
+	(
+		// The wildcard branch, used for all other variants, which is fine to
+		// be unreachable, e.g. if the enum is `non_exhaustive`
+		@Arms [$($entry:tt)*] [$($defaults:tt)*] [$($arms:tt)*]
+		$(#[$attr:meta])* _ => $($rest:tt)+
+	) => {
+		$crate::internal_props_impl_macro!{
+			@ArmsBody [$($entry)*] [$($defaults)*] [$($arms)*]
+			[$(#[$attr])* #[allow(unreachable_patterns)] _] [@Last]
+			$($rest)+
+		}
+	};
+	(
+		// The `default` branch, an alias for the wildcard branch
+		@Arms [$($entry:tt)*] [$($defaults:tt)*] [$($arms:tt)*]
+		$(#[$attr:meta])* default => $($rest:tt)+
+	) => {
+		$crate::internal_props_impl_macro!{
+			@ArmsBody [$($entry)*] [$($defaults)*] [$($arms)*]
+			[$(#[$attr])* #[allow(unreachable_patterns)] _] [@Last]
+			$($rest)+
+		}
+	};
+	(
+		// The wildcard branch without a body, i.e. a unit struct
+		@Arms [$($entry:tt)*] [$($defaults:tt)*] [$($arms:tt)*]
+		$(#[$attr:meta])* _ $(, $($rest:tt)*)?
+	) => {
+		$crate::internal_props_impl_macro!{
+			@ArmsBody [$($entry)*] [$($defaults)*] [$($arms)*]
+			[$(#[$attr])* #[allow(unreachable_patterns)] _] [@Last]
+			{} $($($rest)*)?
+		}
+	};
+	(
+		// The `default` branch without a body, i.e. a unit struct
+		@Arms [$($entry:tt)*] [$($defaults:tt)*] [$($arms:tt)*]
+		$(#[$attr:meta])* default $(, $($rest:tt)*)?
+	) => {
+		$crate::internal_props_impl_macro!{
+			@ArmsBody [$($entry)*] [$($defaults)*] [$($arms)*]
+			[$(#[$attr])* #[allow(unreachable_patterns)] _] [@Last]
+			{} $($($rest)*)?
+		}
+	};
+	(
+		// A branch of variants (e.g. `Self::A | Self::B`) of a dedup prop,
+		// with its body kept twice, once as raw tokens to be compared
+		@Arms [$(@Named $lets:tt $used:tt)? $(@Section $section:tt)? @Dedup $dedup:tt $reference:tt $rules:tt $($entry:tt)*]
+		[$($defaults:tt)*] [$($arms:tt)*]
+		$(|)? $($first:ident)::+ $(| $($segment:ident)::+)* => $body:tt $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@ArmsDedup [$(@Named $lets $used)? $(@Section $section)?] $dedup $reference $rules [$($entry)*]
+			[$($defaults)*] [$($arms)*]
+			[$($first)::+ $(| $($segment)::+)*] [$($first)::+] $body $body $($rest)*
+		}
+	};
+	(
+		// A regular branch, given as a plain expression
+		@Arms [$($entry:tt)*] [@Plain] [$($arms:tt)*]
+		$(#[$attr:meta])* $branch:pat $(if $guard:expr)? => $($rest:tt)+
+	) => {
+		$crate::internal_props_impl_macro!{
+			@ArmsBody [$($entry)*] [@Plain] [$($arms)*]
+			[$(#[$attr])* $branch $(if $guard)?] []
+			$($rest)+
+		}
+	};
+	(
+		// A branch of a symmetric pairwise property, given as a pair, which is
+		// mirrored to match both orders of its variants
+		@Arms [$(@Section $section:tt)? @EnumPropKeyed mod $modifier:tt $prop_name:tt [@Pair symmetric] $($entry:tt)*]
+		[$($defaults:tt)*] [$($arms:tt)*]
+		$(#[$attr:meta])* ($first:pat, $second:pat) $(if $guard:expr)? => $($rest:tt)+
+	) => {
+		$crate::internal_props_impl_macro!{
+			@ArmsBody [$(@Section $section)? @EnumPropKeyed mod $modifier $prop_name [@Pair symmetric] $($entry)*]
+			[$($defaults)*] [$($arms)*]
+			[$(#[$attr])* ($first, $second) | ($second, $first) $(if $guard)?] []
+			$($rest)+
+		}
+	};
+	(
+		// Four branches of single variants (e.g. `Self::A`) with fields at
+		// once, to reduce the recursion depth, if followed by further
+		// branches. The variants are kept as tokens (instead of patterns),
+		// thus they can be referenced via `..like`.
+		@Arms [$($entry:tt)*] [$($defaults:tt)*] [$($arms:tt)*]
+		$segment1:tt $sep1:tt $variant1:tt => { $($field1:ident : $value1:expr),* $(,)? }
+		$segment2:tt $sep2:tt $variant2:tt => { $($field2:ident : $value2:expr),* $(,)? }
+		$segment3:tt $sep3:tt $variant3:tt => { $($field3:ident : $value3:expr),* $(,)? }
+		$segment4:tt $sep4:tt $variant4:tt => { $($field4:ident : $value4:expr),* $(,)? }
+		$($rest:tt)+
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Arms [$($entry)*] [$($defaults)*] [
+				$($arms)*
+				$segment1 $sep1 $variant1 => { [$($defaults)*] $($field1 : $value1 ,)* },
+				$segment2 $sep2 $variant2 => { [$($defaults)*] $($field2 : $value2 ,)* },
+				$segment3 $sep3 $variant3 => { [$($defaults)*] $($field3 : $value3 ,)* },
+				$segment4 $sep4 $variant4 => { [$($defaults)*] $($field4 : $value4 ,)* },
+			]
+			$($rest)+
+		}
+	};
+	(
+		// Four branches of single variants with fields at once, separated by
+		// commas
+		@Arms [$($entry:tt)*] [$($defaults:tt)*] [$($arms:tt)*]
+		$segment1:tt $sep1:tt $variant1:tt => { $($field1:ident : $value1:expr),* $(,)? } ,
+		$segment2:tt $sep2:tt $variant2:tt => { $($field2:ident : $value2:expr),* $(,)? } ,
+		$segment3:tt $sep3:tt $variant3:tt => { $($field3:ident : $value3:expr),* $(,)? } ,
+		$segment4:tt $sep4:tt $variant4:tt => { $($field4:ident : $value4:expr),* $(,)? }
+		$($rest:tt)+
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Arms [$($entry)*] [$($defaults)*] [
+				$($arms)*
+				$segment1 $sep1 $variant1 => { [$($defaults)*] $($field1 : $value1 ,)* },
+				$segment2 $sep2 $variant2 => { [$($defaults)*] $($field2 : $value2 ,)* },
+				$segment3 $sep3 $variant3 => { [$($defaults)*] $($field3 : $value3 ,)* },
+				$segment4 $sep4 $variant4 => { [$($defaults)*] $($field4 : $value4 ,)* },
+			]
+			$($rest)+
+		}
+	};
+	(
+		// A branch of a single variant, kept as tokens, see above. Notice,
+		// these must be matched as `tt`, which keeps their spacing (e.g. for
+		// the branch names in messages), thus they can not have attributes.
+		@Arms [$($entry:tt)*] [$($defaults:tt)*] [$($arms:tt)*]
+		$segment:tt $sep:tt $variant:tt => $($rest:tt)+
+	) => {
+		$crate::internal_props_impl_macro!{
+			@ArmsBody [$($entry)*] [$($defaults)*] [$($arms)*]
+			[$segment $sep $variant] []
+			$($rest)+
+		}
+	};
+	(
+		// A branch of a single variant without a prefix, kept as tokens
+		@Arms [$($entry:tt)*] [$($defaults:tt)*] [$($arms:tt)*]
+		$variant:ident => $($rest:tt)+
+	) => {
+		$crate::internal_props_impl_macro!{
+			@ArmsBody [$($entry)*] [$($defaults)*] [$($arms)*]
+			[$variant] []
+			$($rest)+
+		}
+	};
+	(
+		// Four regular branches with fields at once, to reduce the recursion
+		// depth, if followed by further branches
+		@Arms [$($entry:tt)*] [$($defaults:tt)*] [$($arms:tt)*]
+		$(#[$attr1:meta])* $branch1:pat => { $($field1:ident : $value1:expr),* $(,)? } $(,)?
+		$(#[$attr2:meta])* $branch2:pat => { $($field2:ident : $value2:expr),* $(,)? } $(,)?
+		$(#[$attr3:meta])* $branch3:pat => { $($field3:ident : $value3:expr),* $(,)? } $(,)?
+		$(#[$attr4:meta])* $branch4:pat => { $($field4:ident : $value4:expr),* $(,)? }
+		$($rest:tt)+
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Arms [$($entry)*] [$($defaults)*] [
+				$($arms)*
+				$(#[$attr1])* $branch1 => { [$($defaults)*] $($field1 : $value1 ,)* },
+				$(#[$attr2])* $branch2 => { [$($defaults)*] $($field2 : $value2 ,)* },
+				$(#[$attr3])* $branch3 => { [$($defaults)*] $($field3 : $value3 ,)* },
+				$(#[$attr4])* $branch4 => { [$($defaults)*] $($field4 : $value4 ,)* },
+			]
+			$($rest)+
+		}
+	};
+	(
+		// A regular branch, optionally with a match guard
+		@Arms [$($entry:tt)*] [$($defaults:tt)*] [$($arms:tt)*]
+		$(#[$attr:meta])* $branch:pat $(if $guard:expr)? => $($rest:tt)+
+	) => {
+		$crate::internal_props_impl_macro!{
+			@ArmsBody [$($entry)*] [$($defaults)*] [$($arms)*]
+			[$(#[$attr])* $branch $(if $guard)?] []
+			$($rest)+
+		}
+	};
+	(
+		// A regular branch without a body, i.e. a unit struct
+		@Arms [$($entry:tt)*] [$($defaults:tt)*] [$($arms:tt)*]
+		$(#[$attr:meta])* $branch:pat $(, $($rest:tt)*)?
+	) => {
+		$crate::internal_props_impl_macro!{
+			@ArmsBody [$($entry)*] [$($defaults)*] [$($arms)*]
+			[$(#[$attr])* $branch] []
+			{} $($($rest)*)?
+		}
+	};
+	(
+		// A branch of a dedup prop given as a plain expression is kept as is
+		@ArmsDedup [$($prefix:tt)*] $dedup:tt $reference:tt $rules:tt [$($entry:tt)*]
+		[@Plain] $arms:tt $pattern:tt $first:tt $raw:tt $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@ArmsBody [$($prefix)* @Dedup $dedup $reference $rules $($entry)*]
+			[@Plain] $arms $pattern [] $($rest)*
+		}
+	};
+	(
+		// A branch of a dedup prop given as fields, the local macro gets a
+		// rule returning the own value of the branch, followed by a rule
+		// returning the value of this branch for any later identical body
+		@ArmsDedup [$($prefix:tt)*] ($d:tt) [$($reference:tt)*] [$($rules:tt)*] [$($entry:tt)*]
+		[$($defaults:tt)*] [$($arms:tt)*] [$($pattern:tt)*] [$($first:tt)*]
+		$raw:tt { $($field:ident : $value:expr),* $(,)? } $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Arms [
+				$($prefix)* @Dedup ($d) [$($reference)*] [
+					$($rules)*
+					($raw [$($pattern)*] $d own:tt) => { $d own };
+					($raw $d other:tt $d own:tt) => { $($reference)*(&$($first)*) };
+				]
+				$($entry)*
+			]
+			[$($defaults)*] [
+				$($arms)*
+				$($pattern)* => { [$($defaults)*] @Dedup [$($pattern)*] $raw $($field : $value ,)* },
+			]
+			$($rest)*
+		}
+	};
+	(
+		// Any other branch of a dedup prop is kept as is
+		@ArmsDedup [$($prefix:tt)*] $dedup:tt $reference:tt $rules:tt [$($entry:tt)*]
+		$defaults:tt $arms:tt $pattern:tt $first:tt $raw:tt $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@ArmsBody [$($prefix)* @Dedup $dedup $reference $rules $($entry)*]
+			$defaults $arms $pattern [] $($rest)*
+		}
+	};
+	(
+		// The value of a named value, given as a plain expression
+		@ArmsLet [$($entry:tt)*] [@Plain] [$($name:tt)*]
+		$value:expr ; $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@ArmsLetDone [$($entry)*] [@Plain] [$($name)* = { [] = $value };]
+			$($rest)*
+		}
+	};
+	(
+		// The value of a named value, given as fields
+		@ArmsLet [$($entry:tt)*] [$($defaults:tt)*] [$($name:tt)*]
+		{ $($fields:tt)* } ; $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@ArmsLetDone [$($entry)*] [$($defaults)*] [$($name)* = { [$($defaults)*] $($fields)* };]
+			$($rest)*
+		}
+	};
+	(
+		// The value of a named value, given as positional values
+		@ArmsLet [$($entry:tt)*] [$($defaults:tt)*] [$($name:tt)*]
+		( $($values:tt)* ) ; $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@ArmsLetDone [$($entry)*] [$($defaults)*] [$($name)* = { [$($defaults)*] ( $($values)* ) };]
+			$($rest)*
+		}
+	};
+	(
+		// The value of a named value, given as an expression
+		@ArmsLet [$($entry:tt)*] [$($defaults:tt)*] [$($name:tt)*]
+		$value:expr ; $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@ArmsLetDone [$($entry)*] [$($defaults)*] [$($name)* = { [$($defaults)*] = $value };]
+			$($rest)*
+		}
+	};
+	(
+		// Adding the named value to the collected ones
+		@ArmsLetDone [@Named [$($lets:tt)*] [] $($entry:tt)*] [$($defaults:tt)*] [$($named:tt)*]
+		$($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Arms [@Named [$($lets)* $($named)*] [] $($entry)*] [$($defaults)*] []
+			$($rest)*
+		}
+	};
+	(
+		// The body of a branch, overriding the modifier of the property, e.g.
+		// `const { ... }` within a `lazy` property
+		@ArmsBody $entry:tt $defaults:tt $arms:tt $branch:tt $next:tt
+		const { $($body:tt)* } $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@ArmsMod (const) $entry $defaults $arms $branch $next
+			{ $($body)* } $($rest)*
+		}
+	};
+	(
+		// The body of a branch, overriding the modifier by `static`
+		@ArmsBody $entry:tt $defaults:tt $arms:tt $branch:tt $next:tt
+		static { $($body:tt)* } $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@ArmsMod (static) $entry $defaults $arms $branch $next
+			{ $($body)* } $($rest)*
+		}
+	};
+	(
+		// The body of a branch, overriding the modifier by `lazy`, optionally
+		// with arguments, e.g. `lazy(once_cell) { ... }`
+		@ArmsBody $entry:tt $defaults:tt $arms:tt $branch:tt $next:tt
+		lazy $(($($args:tt)*))? { $($body:tt)* } $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@ArmsMod (lazy $(($($args)*))?) $entry $defaults $arms $branch $next
+			{ $($body)* } $($rest)*
+		}
+	};
+	(
+		// The overridden body of a branch, given as a plain block
+		@ArmsMod $arm_modifier:tt [$($entry:tt)*] [@Plain] [$($arms:tt)*]
+		[$($branch:tt)*] [$($next:tt)*]
+		{ $($block:tt)* } $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Arms [$($entry)*] [@Plain] [
+				$($arms)*
+				$($branch)* => { @Mod $arm_modifier [] = { $($block)* } },
+			]
+			$($next)* $($rest)*
+		}
+	};
+	(
+		// The overridden body of a branch, given as fields, with the remaining
+		// fields taken from the value of a preceding branch
+		@ArmsMod $arm_modifier:tt [$($entry:tt)*] [$($defaults:tt)*] [$($arms:tt)*]
+		[$($branch:tt)*] [$($next:tt)*]
+		{ $($field:ident $(. $path:ident)* : $value:expr ,)* .. like $($segment:ident)::+ $(,)? }
+		$($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@ArmsLike ($) [$($segment),+] [@Mod $arm_modifier]
+			[$($entry)*] [$($defaults)*] [$($arms)*]
+			[$($branch)*] [$($field $(. $path)* : $value ,)*] [$($next)* $($rest)*]
+		}
+	};
+	(
+		// Looking up the branch referenced via `like` among the preceding
+		// branches, whose value is used as the base of the given fields. Since
+		// variants can not be compared directly, this defines a local macro
+		// (via the passed `$`) matching the referenced variant.
+		@ArmsLike ($d:tt) [$first:ident $(, $segment:ident)*] [$($prefix:tt)*]
+		[$($entry:tt)*] [$($defaults:tt)*] [$($arms:tt)*]
+		[$($branch:tt)*] [$($fields:tt)*] [$($rest:tt)*]
+	) => {
+		macro_rules! internal_props_like_branch {
+			(
+				// The referenced branch, overriding the modifier, which is not
+				// taken over
+				[$d(#[$d attr:meta])* $first $(:: $segment)* => { @Mod $d modifier:tt $d($d body:tt)* } , $d($d others:tt)*]
+			) => {
+				internal_props_like_branch!(@Found $d($d body)*);
+			};
+			(
+				// The referenced branch
+				[$d(#[$d attr:meta])* $first $(:: $segment)* => { $d($d body:tt)* } , $d($d others:tt)*]
+			) => {
+				internal_props_like_branch!(@Found $d($d body)*);
+			};
+			(
+				// Any other branch
+				[$d(#[$d attr:meta])* $d other:pat $d(if $d guard:expr)? => $d body:tt , $d($d others:tt)*]
+			) => {
+				internal_props_like_branch!([$d($d others)*]);
+			};
+			(
+				// No such branch, e.g. it is a later one
+				[]
+			) => {
+				$crate::internal_props_impl_macro!{
+					@Error [$($entry)*]
+					"the branch `",
+					::core::stringify!($first) $(, "::", ::core::stringify!($segment))*,
+					"` referenced via `..like` must be a preceding branch of just that variant"
+				}
+			};
+			(
+				// The referenced branch found, its body becomes the base
+				@Found $d($d body:tt)*
+			) => {
+				$crate::internal_props_impl_macro!{
+					@Arms [$($entry)*] [$($defaults)*] [
+						$($arms)*
+						$($branch)* => { $($prefix)* [] $($fields)* .. @Like { $d($d body)* } },
+					]
+					$($rest)*
+				}
+			};
+		}
+
+		internal_props_like_branch!([$($arms)*]);
+	};
+	(
+		// The overridden body of a branch, given as fields
+		@ArmsMod $arm_modifier:tt [$($entry:tt)*] [$($defaults:tt)*] [$($arms:tt)*]
+		[$($branch:tt)*] [$($next:tt)*]
+		{ $($fields:tt)* } $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Arms [$($entry)*] [$($defaults)*] [
+				$($arms)*
+				$($branch)* => { @Mod $arm_modifier [$($defaults)*] $($fields)* },
+			]
+			$($next)* $($rest)*
+		}
+	};
+	(
+		// The body of a branch, given as a name, which is a named value if it
+		// is one of the collected ones, otherwise it is just an expression.
+		// Since names can not be compared directly, this defines a local macro
+		// (via the passed `$`) matching the collected names.
+		@ArmsBody [@Named [$($lets:tt)*] [$($used:tt)*] $($entry:tt)*] [$($defaults:tt)*] [$($arms:tt)*]
+		[$($branch:tt)*] [$($next:tt)*]
+		$name:ident $(, $($rest:tt)*)?
+	) => {
+		$crate::internal_props_impl_macro!{
+			@ArmsNamed ($) [$($lets)*]
+			[[$($lets)*] [$($used)*] $($entry)*] [$($defaults)*] [$($arms)*]
+			[$($branch)*] [$($next)* $($($rest)*)?]
+			$name
+		}
+	};
+	(
+		// Looking up a name among the named values
+		@ArmsNamed ($d:tt) [$($(#[$attr:meta])* $named:ident = $value:tt ;)*]
+		$entry:tt $defaults:tt $arms:tt $branch:tt $rest:tt
+		$name:ident
+	) => {
+		macro_rules! internal_props_named_value {
+			$(
+				(
+					// A named value, referenced by the branch, thus it is used
+					$named [$d lets:tt [$d($d used:tt)*] $d($d entry:tt)*]
+					[$d($d defaults:tt)*] [$d($d arms:tt)*] [$d($d branch:tt)*] [$d($d rest:tt)*]
+				) => {
+					$crate::internal_props_impl_macro!{
+						@Arms [@Named $d lets [$d($d used)* $named] $d($d entry)*] [$d($d defaults)*] [
+							$d($d arms)*
+							$d($d branch)* => { [$d($d defaults)*] & $named },
+						]
+						$d($d rest)*
+					}
+				};
+			)*
+			(
+				// Any other name, i.e. an expression
+				$d other:ident [$d($d entry:tt)*]
+				[$d($d defaults:tt)*] [$d($d arms:tt)*] [$d($d branch:tt)*] [$d($d rest:tt)*]
+			) => {
+				$crate::internal_props_impl_macro!{
+					@Arms [@Named $d($d entry)*] [$d($d defaults)*] [
+						$d($d arms)*
+						$d($d branch)* => { [$d($d defaults)*] = $d other },
+					]
+					$d($d rest)*
+				}
+			};
+		}
+
+		internal_props_named_value!{ $name $entry $defaults $arms $branch $rest }
+	};
+	(
+		// The body of a branch, delegating to the property of an inner enum,
+		// e.g. `Self::Shape(inner) => delegate inner`
+		@ArmsBody [$($entry:tt)*] [$($defaults:tt)*] [$($arms:tt)*]
+		[$($branch:tt)*] [$($next:tt)*]
+		delegate $inner:expr $(, $($rest:tt)*)?
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Arms [$($entry)*] [$($defaults)*] [
+				$($arms)*
+				$($branch)* => { [$($defaults)*] @Delegate $inner },
+			]
+			$($next)* $($($rest)*)?
+		}
+	};
+	(
+		// The body of a branch, given as a plain block, like a match arm it
+		// needs no comma
+		@ArmsBody [$($entry:tt)*] [@Plain] [$($arms:tt)*]
+		[$($branch:tt)*] [$($next:tt)*]
+		{ $($block:tt)* } $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Arms [$($entry)*] [@Plain] [
+				$($arms)*
+				$($branch)* => { [] = { $($block)* } },
+			]
+			$($next)* $($rest)*
+		}
+	};
+	(
+		// The body of a branch, given as a plain expression, which includes
+		// tuples and references
+		@ArmsBody [$($entry:tt)*] [@Plain] [$($arms:tt)*]
+		[$($branch:tt)*] [$($next:tt)*]
+		$value:expr $(, $($rest:tt)*)?
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Arms [$($entry)*] [@Plain] [
+				$($arms)*
+				$($branch)* => { [] = $value },
+			]
+			$($next)* $($($rest)*)?
+		}
+	};
+	(
+		// The body of a branch, given as fields, with the remaining fields
+		// taken from the value of a preceding branch
+		@ArmsBody [$($entry:tt)*] [$($defaults:tt)*] [$($arms:tt)*]
+		[$($branch:tt)*] [$($next:tt)*]
+		{ $($field:ident $(. $path:ident)* : $value:expr ,)* .. like $($segment:ident)::+ $(,)? }
+		$($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@ArmsLike ($) [$($segment),+] []
+			[$($entry)*] [$($defaults)*] [$($arms)*]
+			[$($branch)*] [$($field $(. $path)* : $value ,)*] [$($next)* $($rest)*]
+		}
+	};
+	(
+		// The body of a branch, given as fields
+		@ArmsBody [$($entry:tt)*] [$($defaults:tt)*] [$($arms:tt)*]
+		[$($branch:tt)*] [$($next:tt)*]
+		{ $($fields:tt)* } $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Arms [$($entry)*] [$($defaults)*] [
+				$($arms)*
+				$($branch)* => { [$($defaults)*] $($fields)* },
+			]
+			$($next)* $($rest)*
+		}
+	};
+	(
+		// The body of a branch, given as positional values of a tuple struct
+		@ArmsBody [$($entry:tt)*] [$($defaults:tt)*] [$($arms:tt)*]
+		[$($branch:tt)*] [$($next:tt)*]
+		( $($values:tt)* ) $(, $($rest:tt)*)?
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Arms [$($entry)*] [$($defaults)*] [
+				$($arms)*
+				$($branch)* => { [$($defaults)*] ( $($values)* ) },
+			]
+			$($next)* $($($rest)*)?
+		}
+	};
+	(
+		// The body of a branch, given as a reference to an existing item,
+		// which is returned as is by `const` and `static` properties
+		@ArmsBody [$($entry:tt)*] [$($defaults:tt)*] [$($arms:tt)*]
+		[$($branch:tt)*] [$($next:tt)*]
+		& $item:path $(, $($rest:tt)*)?
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Arms [$($entry)*] [$($defaults)*] [
+				$($arms)*
+				$($branch)* => { [$($defaults)*] & $item },
+			]
+			$($next)* $($($rest)*)?
+		}
+	};
+	(
+		// The body of a branch, given as an expression, i.e. the entire
+		// property value
+		@ArmsBody [$($entry:tt)*] [$($defaults:tt)*] [$($arms:tt)*]
+		[$($branch:tt)*] [$($next:tt)*]
+		$value:expr $(, $($rest:tt)*)?
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Arms [$($entry)*] [$($defaults)*] [
+				$($arms)*
+				$($branch)* => { [$($defaults)*] = $value },
+			]
+			$($next)* $($($rest)*)?
+		}
+	};
+	(
+		// The named values of a const prop, defined as consts, along with the
+		// impl, within an anonymous const to keep them local
+		@Named [$($(#[$attr:meta])* $name:ident = $value:tt ;)*] [$($used:ident)*] $(@Section $section:tt)?
+		$(@Dedup $dedup:tt $reference:tt $rules:tt)? $(@Consts $consts:tt)? @EnumProp mod(const) ($prop_name:path) for [$($generics:tt)*] $enum_name:ty {
+			$($matching:tt)*
+		}
+	) => {
+		const _: () = {
+			$crate::internal_props_impl_macro!{
+				@NamedUsed ($) [$($used)*] $($name)*
+			}
+
+			$(
+				$(#[$attr])*
+				const $name: $prop_name = $crate::internal_props_impl_macro!(@Value $prop_name $value);
+			)*
+
+			$crate::internal_props_impl_macro!{
+				$(@Dedup $dedup $reference $rules)? $(@Consts $consts)? @EnumProp mod(const) ($prop_name) for [$($generics)*] $enum_name {
+					$($matching)*
+				}
+			}
+		};
+	};
+	(
+		// The named values of a const prop via `EnumPropConst`, defined as
+		// consts as well
+		@Named [$($(#[$attr:meta])* $name:ident = $value:tt ;)*] [$($used:ident)*] $(@Section $section:tt)?
+		$(@Consts $consts:tt)? @EnumPropConst mod(const) ($prop_name:path) for [$($generics:tt)*] $enum_name:ty {
+			$($matching:tt)*
+		}
+	) => {
+		const _: () = {
+			$crate::internal_props_impl_macro!{
+				@NamedUsed ($) [$($used)*] $($name)*
+			}
+
+			$(
+				$(#[$attr])*
+				const $name: $prop_name = $crate::internal_props_impl_macro!(@Value $prop_name $value);
+			)*
+
+			$crate::internal_props_impl_macro!{
+				$(@Consts $consts)? @EnumPropConst mod(const) ($prop_name) for [$($generics)*] $enum_name {
+					$($matching)*
+				}
+			}
+		};
+	};
+	(
+		// The named values of a static prop, defined as statics, thus all
+		// branches using a named value share its address
+		@Named [$($(#[$attr:meta])* $name:ident = $value:tt ;)*] [$($used:ident)*] $(@Section $section:tt)?
+		$(@Dedup $dedup:tt $reference:tt $rules:tt)? $(@Consts $consts:tt)? @EnumProp mod(static) ($prop_name:path) for [$($generics:tt)*] $enum_name:ty {
+			$($matching:tt)*
+		}
+	) => {
+		const _: () = {
+			$crate::internal_props_impl_macro!{
+				@NamedUsed ($) [$($used)*] $($name)*
+			}
+
+			$(
+				$(#[$attr])*
+				static $name: $prop_name = $crate::internal_props_impl_macro!(@Value $prop_name $value);
+			)*
+
+			$crate::internal_props_impl_macro!{
+				$(@Dedup $dedup $reference $rules)? $(@Consts $consts)? @EnumProp mod(static) ($prop_name) for [$($generics)*] $enum_name {
+					$($matching)*
+				}
+			}
+		};
+	};
+	(
+		// The named values of a const prop via `const fn`, defined as consts
+		// as well
+		@Named [$($(#[$attr:meta])* $name:ident = $value:tt ;)*] [$($used:ident)*] $(@Section $section:tt)?
+		$(@Dedup $dedup:tt $reference:tt $rules:tt)? $(@Consts $consts:tt)? @EnumPropConstFn $head:tt $fn_name:ident ($prop_name:path) for [$($generics:tt)*] $enum_name:ty {
+			$($matching:tt)*
+		}
+	) => {
+		const _: () = {
+			$crate::internal_props_impl_macro!{
+				@NamedUsed ($) [$($used)*] $($name)*
+			}
+
+			$(
+				$(#[$attr])*
+				const $name: $prop_name = $crate::internal_props_impl_macro!(@Value $prop_name $value);
+			)*
+
+			$crate::internal_props_impl_macro!{
+				$(@Dedup $dedup $reference $rules)? $(@Consts $consts)? @EnumPropConstFn $head $fn_name ($prop_name) for [$($generics)*] $enum_name {
+					$($matching)*
+				}
+			}
+		};
+	};
+	(
+		// The dedup prop, defining the local macro, which returns for each
+		// branch either its own value or the one of the first identical
+		// branch. The last rule keeps it non-empty, since plain expressions are
+		// not compared.
+		@Dedup ($d:tt) $reference:tt [$($rules:tt)*] $($entry:tt)*
+	) => {
+		const _: () = {
+			#[allow(unused_macros)]
+			macro_rules! internal_props_dedup {
+				$($rules)*
+				($d raw:tt $d pattern:tt $d own:tt) => { $d own };
+			}
+
+			$crate::internal_props_impl_macro!{
+				$($entry)*
+			}
+		};
+	};
+	(
+		// The derived impls, skipping the prefixes of the entry up to the kind
+		// of the property, which must implement `EnumProp`
+		@Derive $derive:tt $entry:tt [
+			@EnumProp mod $modifier:tt ($prop_name:path) for $generics:tt $enum_name:ty
+		]
+	) => {
+		$crate::internal_props_impl_macro!{
+			@DeriveImpl $derive ($prop_name) for $generics $enum_name
+		}
+	};
+	(
+		@Derive $derive:tt $entry:tt [
+			@EnumPropConst mod $modifier:tt ($prop_name:path) for $generics:tt $enum_name:ty
+		]
+	) => {
+		$crate::internal_props_impl_macro!{
+			@DeriveImpl $derive ($prop_name) for $generics $enum_name
+		}
+	};
+	(
+		@Derive $derive:tt $entry:tt [
+			@EnumPropConstFn $head:tt $fn_name:ident ($prop_name:path) for $generics:tt $enum_name:ty
+		]
+	) => {
+		$crate::internal_props_impl_macro!{
+			@DeriveImpl $derive ($prop_name) for $generics $enum_name
+		}
+	};
+	(
+		@Derive $derive:tt $entry:tt [@Dedup $dedup:tt $reference:tt $rules:tt $($rest:tt)*]
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Derive $derive $entry [$($rest)*]
+		}
+	};
+	(
+		@Derive $derive:tt $entry:tt [@ $prefix:ident $value:tt $($rest:tt)*]
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Derive $derive $entry [$($rest)*]
+		}
+	};
+	(
+		@Derive [@Serialize $field:tt] [$($entry:tt)*] [$($rest:tt)*]
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Error [$($entry)*] "the `serialize_as` is only supported for properties implementing `EnumProp`"
+		}
+	};
+	(
+		@Derive [@Defmt $field:tt] [$($entry:tt)*] [$($rest:tt)*]
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Error [$($entry)*] "the `defmt` is only supported for properties implementing `EnumProp`"
+		}
+	};
+	(
+		@Derive $derive:tt [$($entry:tt)*] [$($rest:tt)*]
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Error [$($entry)*] "the `derive` is only supported for properties implementing `EnumProp`"
+		}
+	};
+	(
+		// The derived `Display` impl. The field is bound by destructuring and
+		// kept as a token tree (an `ident` would get the span of the macro),
+		// thus errors about it (e.g. not implementing `Display`) point at the
+		// clause.
+		@DeriveImpl [Display $field:tt] ($prop_name:path) for $generics:tt $enum_name:ty
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Impl $generics [::core::fmt::Display for $enum_name] {
+				fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+					let $prop_name { $field, .. } = <Self as $crate::EnumProp<$prop_name>>::property(self);
+					::core::fmt::Display::fmt($field, f)
+				}
+			}
+		}
+	};
+	(
+		// The derived `PartialEq` impl, comparing the fields, bound like the
+		// one of `Display`
+		@DeriveImpl [PartialEq $field:tt] ($prop_name:path) for $generics:tt $enum_name:ty
+	) => {
+		$crate::internal_props_impl_macro!{
+			@DeriveEq [$crate::internal_partial_eq] $field ($prop_name) for $generics $enum_name
+		}
+	};
+	(
+		// The derived `Eq` impl, along with `PartialEq`, whose comparison
+		// requires the field to be `Eq` as well, since `Eq` has no methods
+		@DeriveImpl [Eq $field:tt] ($prop_name:path) for $generics:tt $enum_name:ty
+	) => {
+		$crate::internal_props_impl_macro!{
+			@DeriveEq [$crate::internal_eq] $field ($prop_name) for $generics $enum_name
+		}
+
+		$crate::internal_props_impl_macro!{
+			@Impl $generics [::core::cmp::Eq for $enum_name] {}
+		}
+	};
+	(
+		@DeriveEq [$($eq:tt)*] $field:tt ($prop_name:path) for $generics:tt $enum_name:ty
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Impl $generics [::core::cmp::PartialEq for $enum_name] {
+				fn eq(&self, other: &Self) -> bool {
+					let $prop_name { $field, .. } = <Self as $crate::EnumProp<$prop_name>>::property(self);
+					let $prop_name { $field: other, .. } = <Self as $crate::EnumProp<$prop_name>>::property(other);
+					$($eq)*($field)(other)
+				}
+			}
+		}
+	};
+	(
+		// The derived `PartialOrd` impl
+		@DeriveImpl [PartialOrd $field:tt] ($prop_name:path) for $generics:tt $enum_name:ty
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Impl $generics [::core::cmp::PartialOrd for $enum_name] {
+				fn partial_cmp(&self, other: &Self) -> ::core::option::Option<::core::cmp::Ordering> {
+					let $prop_name { $field, .. } = <Self as $crate::EnumProp<$prop_name>>::property(self);
+					let $prop_name { $field: other, .. } = <Self as $crate::EnumProp<$prop_name>>::property(other);
+					$crate::internal_partial_cmp($field)(other)
+				}
+			}
+		}
+	};
+	(
+		// The derived `Ord` impl, along with `PartialOrd`
+		@DeriveImpl [Ord $field:tt] ($prop_name:path) for $generics:tt $enum_name:ty
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Impl $generics [::core::cmp::PartialOrd for $enum_name] {
+				fn partial_cmp(&self, other: &Self) -> ::core::option::Option<::core::cmp::Ordering> {
+					::core::option::Option::Some(::core::cmp::Ord::cmp(self, other))
+				}
+			}
+		}
+
+		$crate::internal_props_impl_macro!{
+			@Impl $generics [::core::cmp::Ord for $enum_name] {
+				fn cmp(&self, other: &Self) -> ::core::cmp::Ordering {
+					let $prop_name { $field, .. } = <Self as $crate::EnumProp<$prop_name>>::property(self);
+					let $prop_name { $field: other, .. } = <Self as $crate::EnumProp<$prop_name>>::property(other);
+					$crate::internal_cmp($field)(other)
+				}
+			}
+		}
+	};
+	(
+		// The derived `Debug` impl, the property is written with the same
+		// options, e.g. `{:#?}`
+		@DeriveImpl [Debug $inner:expr] ($prop_name:path) for $generics:tt $enum_name:ty
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Impl $generics [::core::fmt::Debug for $enum_name] {
+				fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+					$inner(self, f)?;
+					f.write_str(" (")?;
+					::core::fmt::Debug::fmt(<Self as $crate::EnumProp<$prop_name>>::property(self), f)?;
+					f.write_str(")")
+				}
+			}
+		}
+	};
+	(
+		// The `Serialize` impl of `serialize_as`, bound like the one of
+		// `Display`, thus variants with data are serialized as the field only
+		@DeriveImpl [@Serialize $field:tt] ($prop_name:path) for $generics:tt $enum_name:ty
+	) => {
+		$crate::internal_serde!{
+			$crate::internal_props_impl_macro!{
+				@Impl $generics [$crate::serde::Serialize for $enum_name] {
+					fn serialize<S: $crate::serde::Serializer>(
+						&self,
+						serializer: S,
+					) -> ::core::result::Result<S::Ok, S::Error> {
+						let $prop_name { $field, .. } = <Self as $crate::EnumProp<$prop_name>>::property(self);
+						$crate::InternalSerializeAs::internal_serialize($field, serializer)
+					}
+				}
+			}
+		}
+	};
+	(
+		// The `defmt::Format` impl of `defmt`, bound like the one of
+		// `Display`, thus variants with data are written as the field only
+		@DeriveImpl [@Defmt $field:tt] ($prop_name:path) for $generics:tt $enum_name:ty
+	) => {
+		$crate::internal_defmt!{
+			$crate::internal_props_impl_macro!{
+				@Impl $generics [$crate::defmt::Format for $enum_name] {
+					fn format(&self, fmt: $crate::defmt::Formatter<'_>) {
+						let $prop_name { $field, .. } = <Self as $crate::EnumProp<$prop_name>>::property(self);
+						$crate::InternalDefmtStr::internal_format($field, fmt)
+					}
+				}
+			}
+		}
+	};
+	(
+		// Any other trait can not be derived
+		@DeriveImpl [$trait:ident $($field:tt)*] $($entry:tt)*
+	) => {
+		::core::compile_error!(::core::concat!(
+			"the `derive` supports `Display`, `PartialEq`, `Eq`, `PartialOrd`, and `Ord` by a field, \
+			 and `Debug`, found `",
+			::core::stringify!($trait),
+			"`"
+		));
+	};
+	(
+		// Each named value must be used by some branch. Since names can not be
+		// compared directly, this defines a local macro (via the passed `$`)
+		// matching the used names.
+		@NamedUsed ($d:tt) [$($used:ident)*] $($name:ident)*
+	) => {
+		macro_rules! internal_props_named_used {
+			$(
+				($used) => {};
+			)*
+			($d other:ident) => {
+				::core::compile_error!(::core::concat!(
+					"the named value `",
+					::core::stringify!($d other),
+					"` is not used by any branch"
+				));
+			};
+		}
+
+		$(
+			internal_props_named_used!($name);
+		)*
+	};
+	(
+		// Any other modifier does not support named values
+		@Named [$($lets:tt)*] $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Error [@Named [$($lets)*] $($rest)*]
+			"named values are only supported for `const` and `static` properties"
+		}
+	};
+	(
+		// Thread local props can not implement `EnumProp`
+		@EnumProp
+		mod(thread_lazy) ($prop_name:path) for [$($generics:tt)*] $enum_name:ty {
+			$($matching:tt)*
+		}
+	) => {
+		::core::compile_error!(
+			"`thread_lazy` properties can not implement `EnumProp`, use \
+			`impl EnumPropLocal for` instead"
+		);
+	};
+	(
+		// The thread local enum prop impl, entry rule
+		@EnumPropLocal
+		mod(thread_lazy) ($prop_name:path) for [$($generics:tt)*] $enum_name:ty {
+			$(
+				$(#[$arm_attr:meta])*
+				$branch:pat $(if $guard:expr)? => {
+					$(
+						$struct_fields:tt
+					)*
+				} $(,)?
+			)*
+		}
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Impl [$($generics)*] [$crate::EnumPropLocal<$prop_name> for $enum_name] {
+				fn with_property<R>(&self, f: impl FnOnce(&$prop_name) -> R) -> R {
+					// Along with the property type, which names it in errors, e.g.
+					// about non-exhaustive patterns
+					#[deny(unreachable_patterns)] // Remember the `Self` prefix
+					match (self, ::core::marker::PhantomData::<$prop_name>) {
+						$(
+							$(#[$arm_attr])*
+							($branch, _) $(if $guard)? => {
+								$crate::internal_props_impl_macro!(
+									@Branch mod(thread_lazy) $prop_name, f {
+										$( $struct_fields )*
+									}
+								)
+							},
+						)*
+					}
+				}
+			}
+		}
+	};
+
+	(
+		// Dynamic props can not implement `EnumProp`
+		@EnumProp
+		mod(dynamic) ($prop_name:path) for [$($generics:tt)*] $enum_name:ty {
+			$($matching:tt)*
+		}
+	) => {
+		::core::compile_error!(
+			"`dynamic` properties can not implement `EnumProp`, use \
+			`impl EnumPropDyn for` instead"
+		);
+	};
+	(
+		// The dynamic enum prop impl, entry rule
+		@EnumPropDyn
+		mod(dynamic) ($prop_name:path) for $generics:tt $enum_name:ty {
+			$(
+				$(#[$arm_attr:meta])*
+				$branch:pat $(if $guard:expr)? => {
+					$(
+						$struct_fields:tt
+					)*
+				} $(,)?
+			)*
+		}
+	) => {
+		$crate::internal_dynamic!{
+			$crate::internal_props_impl_macro!{
+				@Impl $generics [$crate::EnumPropDyn<$prop_name> for $enum_name] {
+					fn property_dyn(&self) -> $crate::Cow<'static, $prop_name> {
+						// Along with the property type, which names it in errors, e.g.
+						// about non-exhaustive patterns
+						#[deny(unreachable_patterns)] // Remember the `Self` prefix
+						match (self, ::core::marker::PhantomData::<$prop_name>) {
+							$(
+								$(#[$arm_attr])*
+								($branch, _) $(if $guard)? => {
+									$crate::internal_props_impl_macro!(
+										@Branch mod(dynamic) $prop_name, $generics $enum_name, $branch => {
+											$( $struct_fields )*
+										}
+									)
+								},
+							)*
+						}
+					}
+				}
+			}
+		}
+	};
+
+	(
+		// The borrowing enum prop impl, entry rule, checking the modifier
+		@EnumPropRef
+		mod(ref lazy(grouped $($args:tt)*)) $($rest:tt)*
+	) => {
+		::core::compile_error!("`ref` properties do not support `lazy(grouped)`");
+	};
+	(
+		// The borrowing enum prop impl, entry rule, a supported modifier
+		@EnumPropRef
+		mod(ref const) $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@EnumPropRef [const] $($rest)*
+		}
+	};
+	(
+		@EnumPropRef
+		mod(ref static) $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@EnumPropRef [static] $($rest)*
+		}
+	};
+	(
+		@EnumPropRef
+		mod(ref lazy $($args:tt)?) $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@EnumPropRef [lazy $($args)?] $($rest)*
+		}
+	};
+	(
+		// The borrowing enum prop impl, entry rule, any other modifier
+		@EnumPropRef
+		mod(ref $modifier:ident $($args:tt)?) $($rest:tt)*
+	) => {
+		::core::compile_error!(::core::concat!(
+			"`ref` properties support only the `const`, `static`, and `lazy` modifiers, not `",
+			::core::stringify!($modifier),
+			"`"
+		));
+	};
+	(
+		// The borrowing enum prop impl, with a `const`, `static`, or `lazy`
+		// modifier for its fields
+		@EnumPropRef
+		$modifier:tt ($prop_name:path) for $generics:tt $enum_name:ty {
+			$(
+				$(#[$arm_attr:meta])*
+				$branch:pat $(if $guard:expr)? => {
+					$(
+						$struct_fields:tt
+					)*
+				} $(,)?
+			)*
+		}
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Impl $generics [$crate::EnumPropRef<$prop_name> for $enum_name] {
+				fn property_ref(&self) -> &$prop_name {
+					// Along with the property type, which names it in errors, e.g.
+					// about non-exhaustive patterns
+					#[deny(unreachable_patterns)] // Remember the `Self` prefix
+					match (self, ::core::marker::PhantomData::<$prop_name>) {
+						$(
+							$(#[$arm_attr])*
+							($branch, _) $(if $guard)? => {
+								$crate::internal_props_impl_macro!(
+									@Branch mod(ref $modifier) $prop_name, $generics $enum_name, $branch => {
+										$( $struct_fields )*
+									}
+								)
+							},
+						)*
+					}
+				}
+			}
+		}
+	};
+	(
+		// The keyed enum prop impl, entry rule, checking the modifier
+		@EnumPropKeyed
+		mod(lazy(grouped $($args:tt)*)) $($rest:tt)*
+	) => {
+		::core::compile_error!("keyed properties do not support `lazy(grouped)`");
+	};
+	(
+		// The keyed enum prop impl, entry rule, a supported modifier
+		@EnumPropKeyed
+		mod(const) $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@EnumPropKeyed [(const)] $($rest)*
+		}
+	};
+	(
+		@EnumPropKeyed
+		mod(static) $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@EnumPropKeyed [(static)] $($rest)*
+		}
+	};
+	(
+		@EnumPropKeyed
+		mod(lazy $($args:tt)?) $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@EnumPropKeyed [(lazy $($args)?)] $($rest)*
+		}
+	};
+	(
+		// The keyed enum prop impl, entry rule, any other modifier
+		@EnumPropKeyed
+		mod($modifier:ident $($args:tt)?) $($rest:tt)*
+	) => {
+		::core::compile_error!(::core::concat!(
+			"keyed properties support only the `const`, `static`, and `lazy` modifiers, not `",
+			::core::stringify!($modifier),
+			"`"
+		));
+	};
+	(
+		// The pairwise enum prop impl, matching both variants
+		@EnumPropKeyed
+		[$modifier:tt] ($prop_name:path) [@Pair] for $generics:tt $enum_name:ty {
+			$(
+				$(#[$arm_attr:meta])*
+				$branch:pat $(if $guard:expr)? => {
+					$(
+						$struct_fields:tt
+					)*
+				} $(,)?
+			)*
+		}
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Impl $generics [$crate::EnumPropPair<$prop_name> for $enum_name] {
+				fn relation(&self, other: &Self) -> &'static $prop_name {
+					// Along with the property type, which names it in errors, e.g.
+					// about non-exhaustive patterns
+					#[deny(unreachable_patterns)] // Remember the `Self` prefix
+					match ((self, other), ::core::marker::PhantomData::<$prop_name>) {
+						$(
+							$(#[$arm_attr])*
+							($branch, _) $(if $guard)? => {
+								$crate::internal_props_impl_macro!(
+									@Branch mod(keyed $modifier) $prop_name, $generics $enum_name, $branch => {
+										$( $struct_fields )*
+									}
+								)
+							},
+						)*
+					}
+				}
+			}
+		}
+	};
+	(
+		// The symmetric pairwise enum prop impl, whose pairs have been mirrored
+		// already. The mirrored pattern of a branch with the same variant on
+		// both sides is unreachable, thus, duplicate branches are not denied
+		// here (but still missing ones).
+		@EnumPropKeyed
+		[$modifier:tt] ($prop_name:path) [@Pair symmetric] for $generics:tt $enum_name:ty {
+			$(
+				$(#[$arm_attr:meta])*
+				$branch:pat $(if $guard:expr)? => {
+					$(
+						$struct_fields:tt
+					)*
+				} $(,)?
+			)*
+		}
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Impl $generics [$crate::EnumPropPair<$prop_name> for $enum_name] {
+				fn relation(&self, other: &Self) -> &'static $prop_name {
+					#[allow(unreachable_patterns)] // Remember the `Self` prefix
+					match ((self, other), ::core::marker::PhantomData::<$prop_name>) {
+						$(
+							$(#[$arm_attr])*
+							($branch, _) $(if $guard)? => {
+								$crate::internal_props_impl_macro!(
+									@Branch mod(keyed $modifier) $prop_name, $generics $enum_name, $branch => {
+										$( $struct_fields )*
+									}
+								)
+							},
+						)*
+					}
+				}
+			}
+		}
+	};
+	(
+		// The tagged enum prop impl, matching only the variant
+		@EnumPropKeyed
+		[$modifier:tt] ($prop_name:path) [@Tag $tag:ty] for $generics:tt $enum_name:ty {
+			$(
+				$(#[$arm_attr:meta])*
+				$branch:pat $(if $guard:expr)? => {
+					$(
+						$struct_fields:tt
+					)*
+				} $(,)?
+			)*
+		}
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Impl $generics [$crate::EnumPropTagged<$tag, $prop_name> for $enum_name] {
+				fn property_tagged(&self) -> &'static $prop_name {
+					// Along with the property type, which names it in errors, e.g.
+					// about non-exhaustive patterns
+					#[deny(unreachable_patterns)] // Remember the `Self` prefix
+					match (self, ::core::marker::PhantomData::<$prop_name>) {
+						$(
+							$(#[$arm_attr])*
+							($branch, _) $(if $guard)? => {
+								$crate::internal_props_impl_macro!(
+									@Branch mod(keyed $modifier) $prop_name, $generics $enum_name, $branch => {
+										$( $struct_fields )*
+									}
+								)
+							},
+						)*
+					}
+				}
+			}
+		}
+	};
+	(
+		// The keyed enum prop impl, matching the variant along with the key,
+		// thus each pair has its own branch
+		@EnumPropKeyed
+		[$modifier:tt] ($prop_name:path) [$key:ty] for $generics:tt $enum_name:ty {
+			$(
+				$(#[$arm_attr:meta])*
+				$branch:pat $(if $guard:expr)? => {
+					$(
+						$struct_fields:tt
+					)*
+				} $(,)?
+			)*
+		}
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Impl $generics [$crate::EnumPropKeyed<$key, $prop_name> for $enum_name] {
+				fn property_for(&self, key: $key) -> &'static $prop_name {
+					// Along with the property type, which names it in errors, e.g.
+					// about non-exhaustive patterns
+					#[deny(unreachable_patterns)] // Remember the `Self` prefix
+					match ((self, key), ::core::marker::PhantomData::<$prop_name>) {
+						$(
+							$(#[$arm_attr])*
+							($branch, _) $(if $guard)? => {
+								$crate::internal_props_impl_macro!(
+									@Branch mod(keyed $modifier) $prop_name, $generics $enum_name, $branch => {
+										$( $struct_fields )*
+									}
+								)
+							},
+						)*
+					}
+				}
+			}
+		}
+	};
+	(
+		// The memoized enum prop impl, entry rule, along with the cache shared
+		// by all branches, within an anonymous const to keep it local
+		@EnumProp
+		mod(memo) ($prop_name:path) for $generics:tt $enum_name:ty {
+			$(
+				$(#[$arm_attr:meta])*
+				$branch:pat $(if $guard:expr)? => {
+					$(
+						$struct_fields:tt
+					)*
+				} $(,)?
+			)*
+		}
+	) => {
+		$crate::internal_memo!{
+			const _: () = {
+				// The computed properties of each value of the enum, which are
+				// leaked to hand out `&'static` references
+				static CACHE: $crate::OnceLock<
+					$crate::Mutex<$crate::HashMap<$enum_name, &'static $prop_name>>
+				> = $crate::OnceLock::new();
+
+				$crate::internal_props_impl_macro!{
+					@Impl $generics [$crate::EnumProp<$prop_name> for $enum_name] {
+						fn property(&self) -> &'static $prop_name {
+							// Along with the property type, which names it in errors, e.g.
+							// about non-exhaustive patterns
+							#[deny(unreachable_patterns)] // Remember the `Self` prefix
+							match (self, ::core::marker::PhantomData::<$prop_name>) {
+								$(
+									$(#[$arm_attr])*
+									($branch, _) $(if $guard)? => {
+										$crate::internal_props_impl_macro!(
+											@Branch mod(memo) $prop_name, CACHE, self, $generics $enum_name, $branch => {
+												$( $struct_fields )*
+											}
+										)
+									},
+								)*
+							}
+						}
+					}
+				}
+
+				$crate::internal_props_impl_macro!{
+					@Impl $generics [$crate::MemoEnumProp<$prop_name> for $enum_name] {
+						fn memo_len() -> usize {
+							match CACHE.get() {
+								::core::option::Option::Some(cache) => cache
+									.lock()
+									.unwrap_or_else(|poisoned| poisoned.into_inner())
+									.len(),
+								::core::option::Option::None => 0,
+							}
+						}
+					}
+				}
+			};
+		}
+	};
+	(
+		// The lazy enum prop impl, entry rule
+		@EnumProp
+		mod (lazy $($backend:tt)?) ($prop_name:path) for [$($generics:tt)*] $enum_name:ty {
+			$($matching:tt)*
+		}
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Impl [$($generics)*] [$crate::EnumProp<$prop_name> for $enum_name] {
+				fn property(&self) -> &'static $prop_name {
+					match $crate::LazyEnumProp::<$prop_name>::internal_lazy_property(self, true) {
+						::core::option::Option::Some(prop) => prop,
+						::core::option::Option::None => ::core::unreachable!(),
+					}
+				}
+			}
+		}
+
+		// The lazy cells are defined in the `LazyEnumProp` impl
+		$crate::internal_props_impl_macro!{
+			@LazyEnumProp
+			mod (lazy $($backend)?) ($prop_name) for [$($generics)*] $enum_name {
+				$($matching)*
+			}
+		}
+	};
+	(
+		// Initializing all variants at once, only for lazy properties
+		@EnumPropInit
+		mod (lazy $($backend:tt)?) ($prop_name:path) for [$($generics:tt)*] $enum_name:ty {
+			$($matching:tt)*
+		}
+	) => {
+		$crate::internal_props_impl_macro!{
+			@EnumPropInit [] ($prop_name) for [$($generics)*] $enum_name {
+				$($matching)*
+			}
+		}
+	};
+	(
+		// Any other modifier has nothing to initialize
+		@EnumPropInit
+		mod $modifier:tt ($prop_name:path) for [$($generics:tt)*] $enum_name:ty {
+			$($matching:tt)*
+		}
+	) => {};
+	(
+		// All branches consist only of fieldless variants, e.g. `Self::A | Self::B`
+		@EnumPropInit [$($(#[$arm_attr:meta])* { $($variant:path,)+ })*] ($prop_name:path) for [$($generics:tt)*] $enum_name:ty {}
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Impl [$($generics)*] [$crate::EnumPropInit<$prop_name> for $enum_name] {
+				fn initialize_all() {
+					$(
+						$(#[$arm_attr])*
+						{
+							$(
+								$crate::EnumProp::<$prop_name>::property(&$variant);
+							)+
+						}
+					)*
+				}
+			}
+		}
+	};
+	(
+		// The comma between the branches
+		@EnumPropInit [$($variants:tt)*] ($prop_name:path) for [$($generics:tt)*] $enum_name:ty {
+			, $($rest:tt)*
+		}
+	) => {
+		$crate::internal_props_impl_macro!{
+			@EnumPropInit [$($variants)*] ($prop_name) for [$($generics)*] $enum_name {
+				$($rest)*
+			}
+		}
+	};
+	(
+		// The marker of plain expressions is no branch
+		@EnumPropInit [$($variants:tt)*] ($prop_name:path) for [$($generics:tt)*] $enum_name:ty {
+			@Plain $($rest:tt)*
+		}
+	) => {
+		$crate::internal_props_impl_macro!{
+			@EnumPropInit [$($variants)*] ($prop_name) for [$($generics)*] $enum_name {
+				$($rest)*
+			}
+		}
+	};
+	(
+		// The section marker is no branch
+		@EnumPropInit [$($variants:tt)*] ($prop_name:path) for [$($generics:tt)*] $enum_name:ty {
+			@Section $section:tt $($rest:tt)*
+		}
+	) => {
+		$crate::internal_props_impl_macro!{
+			@EnumPropInit [$($variants)*] ($prop_name) for [$($generics)*] $enum_name {
+				$($rest)*
+			}
+		}
+	};
+	(
+		// The default fields are no branch
+		@EnumPropInit [$($variants:tt)*] ($prop_name:path) for [$($generics:tt)*] $enum_name:ty {
+			defaults $defaults:tt $($rest:tt)*
+		}
+	) => {
+		$crate::internal_props_impl_macro!{
+			@EnumPropInit [$($variants)*] ($prop_name) for [$($generics)*] $enum_name {
+				$($rest)*
+			}
+		}
+	};
+	(
+		// Neither are the derived impls and the conversions
+		@EnumPropInit [$($variants:tt)*] ($prop_name:path) for [$($generics:tt)*] $enum_name:ty {
+			derive $derive:tt $($rest:tt)*
+		}
+	) => {
+		$crate::internal_props_impl_macro!{
+			@EnumPropInit [$($variants)*] ($prop_name) for [$($generics)*] $enum_name {
+				$($rest)*
+			}
+		}
+	};
+	(
+		@EnumPropInit [$($variants:tt)*] ($prop_name:path) for [$($generics:tt)*] $enum_name:ty {
+			into $into:tt $($rest:tt)*
+		}
+	) => {
+		$crate::internal_props_impl_macro!{
+			@EnumPropInit [$($variants)*] ($prop_name) for [$($generics)*] $enum_name {
+				$($rest)*
+			}
+		}
+	};
+	(
+		@EnumPropInit [$($variants:tt)*] ($prop_name:path) for [$($generics:tt)*] $enum_name:ty {
+			enum_map $clause:tt $($rest:tt)*
+		}
+	) => {
+		$crate::internal_props_impl_macro!{
+			@EnumPropInit [$($variants)*] ($prop_name) for [$($generics)*] $enum_name {
+				$($rest)*
+			}
+		}
+	};
+	(
+		@EnumPropInit [$($variants:tt)*] ($prop_name:path) for [$($generics:tt)*] $enum_name:ty {
+			variants $clause:tt $($rest:tt)*
+		}
+	) => {
+		$crate::internal_props_impl_macro!{
+			@EnumPropInit [$($variants)*] ($prop_name) for [$($generics)*] $enum_name {
+				$($rest)*
+			}
+		}
+	};
+	(
+		@EnumPropInit [$($variants:tt)*] ($prop_name:path) for [$($generics:tt)*] $enum_name:ty {
+			value_enum $clause:tt $($rest:tt)*
+		}
+	) => {
+		$crate::internal_props_impl_macro!{
+			@EnumPropInit [$($variants)*] ($prop_name) for [$($generics)*] $enum_name {
+				$($rest)*
+			}
+		}
+	};
+	(
+		@EnumPropInit [$($variants:tt)*] ($prop_name:path) for [$($generics:tt)*] $enum_name:ty {
+			sample_weighted $clause:tt $($rest:tt)*
+		}
+	) => {
+		$crate::internal_props_impl_macro!{
+			@EnumPropInit [$($variants)*] ($prop_name) for [$($generics)*] $enum_name {
+				$($rest)*
+			}
+		}
+	};
+	(
+		@EnumPropInit [$($variants:tt)*] ($prop_name:path) for [$($generics:tt)*] $enum_name:ty {
+			discriminant $clause:tt $($rest:tt)*
+		}
+	) => {
+		$crate::internal_props_impl_macro!{
+			@EnumPropInit [$($variants)*] ($prop_name) for [$($generics)*] $enum_name {
+				$($rest)*
+			}
+		}
+	};
+	(
+		// The `default` branch does not name its variants
+		@EnumPropInit [$($variants:tt)*] ($prop_name:path) for [$($generics:tt)*] $enum_name:ty {
+			default $($rest:tt)*
+		}
+	) => {};
+	(
+		// Four fieldless branches at once, to reduce the recursion depth
+		@EnumPropInit [$($variants:tt)*] ($prop_name:path) for [$($generics:tt)*] $enum_name:ty {
+			$(#[$arm_attr1:meta])* $(|)? $($($segment1:ident)::+)|+ => { $($fields1:tt)* } $(,)?
+			$(#[$arm_attr2:meta])* $(|)? $($($segment2:ident)::+)|+ => { $($fields2:tt)* } $(,)?
+			$(#[$arm_attr3:meta])* $(|)? $($($segment3:ident)::+)|+ => { $($fields3:tt)* } $(,)?
+			$(#[$arm_attr4:meta])* $(|)? $($($segment4:ident)::+)|+ => { $($fields4:tt)* }
+			$($rest:tt)+
+		}
+	) => {
+		$crate::internal_props_impl_macro!{
+			@EnumPropInit [
+				$($variants)*
+				$(#[$arm_attr1])* { $($($segment1)::+,)+ }
+				$(#[$arm_attr2])* { $($($segment2)::+,)+ }
+				$(#[$arm_attr3])* { $($($segment3)::+,)+ }
+				$(#[$arm_attr4])* { $($($segment4)::+,)+ }
+			] ($prop_name) for [$($generics)*] $enum_name {
+				$($rest)+
+			}
+		}
+	};
+	(
+		// A fieldless branch
+		@EnumPropInit [$($variants:tt)*] ($prop_name:path) for [$($generics:tt)*] $enum_name:ty {
+			$(#[$arm_attr:meta])* $(|)? $($($segment:ident)::+)|+ => { $($fields:tt)* }
+			$($rest:tt)*
+		}
+	) => {
+		$crate::internal_props_impl_macro!{
+			@EnumPropInit [$($variants)* $(#[$arm_attr])* { $($($segment)::+,)+ }] ($prop_name) for [$($generics)*] $enum_name {
+				$($rest)*
+			}
+		}
+	};
+	(
+		// A fieldless branch, overriding the modifier, e.g. `const { ... }`
+		@EnumPropInit [$($variants:tt)*] ($prop_name:path) for [$($generics:tt)*] $enum_name:ty {
+			$(#[$arm_attr:meta])* $(|)? $($($segment:ident)::+)|+
+			=> $arm_modifier:ident $(($($args:tt)*))? { $($fields:tt)* }
+			$($rest:tt)*
+		}
+	) => {
+		$crate::internal_props_impl_macro!{
+			@EnumPropInit [$($variants)* $(#[$arm_attr])* { $($($segment)::+,)+ }] ($prop_name) for [$($generics)*] $enum_name {
+				$($rest)*
+			}
+		}
+	};
+	(
+		// A fieldless branch, given as an expression
+		@EnumPropInit [$($variants:tt)*] ($prop_name:path) for [$($generics:tt)*] $enum_name:ty {
+			$(#[$arm_attr:meta])* $(|)? $($($segment:ident)::+)|+ => $value:expr
+			$(, $($rest:tt)*)?
+		}
+	) => {
+		$crate::internal_props_impl_macro!{
+			@EnumPropInit [$($variants)* $(#[$arm_attr])* { $($($segment)::+,)+ }] ($prop_name) for [$($generics)*] $enum_name {
+				$($($rest)*)?
+			}
+		}
+	};
+	(
+		// A fieldless branch without a body
+		@EnumPropInit [$($variants:tt)*] ($prop_name:path) for [$($generics:tt)*] $enum_name:ty {
+			$(#[$arm_attr:meta])* $(|)? $($($segment:ident)::+)|+
+			$(, $($rest:tt)*)?
+		}
+	) => {
+		$crate::internal_props_impl_macro!{
+			@EnumPropInit [$($variants)* $(#[$arm_attr])* { $($($segment)::+,)+ }] ($prop_name) for [$($generics)*] $enum_name {
+				$($($rest)*)?
+			}
+		}
+	};
+	(
+		// Any other branches, e.g. with variant data or the wildcard, can not
+		// be initialized without a value, thus `EnumPropInit` is not implemented
+		@EnumPropInit [$($variants:tt)*] ($prop_name:path) for [$($generics:tt)*] $enum_name:ty {
+			$($matching:tt)*
+		}
+	) => {};
+	(
+		// The grouped lazy enum prop impl, holding a single lazy table
+		@LazyEnumProp
+		mod (lazy(grouped)) ($prop_name:path) for [$($generics:tt)*] $enum_name:ty {
+			$($matching:tt)*
+		}
+	) => {
+		$crate::internal_props_impl_macro!{
+			@LazyEnumPropGrouped
+			($prop_name) for [$($generics)*] $enum_name, table [] [] []
+			$($matching)*
+		}
+	};
+	(
+		// Collecting the branches of the grouped table, done
+		@LazyEnumPropGrouped
+		($prop_name:path) for [$($generics:tt)*] $enum_name:ty, $table:ident [$($count:tt)*] [$($arms:tt)*] [$($values:tt)*]
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Impl [$($generics)*] [$crate::LazyEnumProp<$prop_name> for $enum_name] {
+				fn internal_lazy_property(&self, init: bool) -> ::core::option::Option<&'static $prop_name> {
+					// All variants are initialized together, in a single table
+					let $table = $crate::internal_lazy_tracked!(
+						init,
+						internal_lazy_with_default,
+						[$prop_name; 0 $($count)*],
+						($enum_name, $prop_name, "grouped"),
+						[ $($values)* ]
+					)?;
+
+					// Along with the property type, which names it in errors
+					#[deny(unreachable_patterns)] // Remember the `Self` prefix
+					let prop = match (self, ::core::marker::PhantomData::<$prop_name>) {
+						$($arms)*
+					};
+
+					::core::option::Option::Some(prop)
+				}
+			}
+		}
+	};
+	(
+		// The grouped table can not hold the properties of inner enums
+		@LazyEnumPropGrouped
+		($prop_name:path) for [$($generics:tt)*] $enum_name:ty, $table:ident $count:tt $arms:tt $values:tt
+		$(#[$arm_attr:meta])*
+		$branch:pat $(if $guard:expr)? => {
+			[$($defaults:tt)*] @Delegate $inner:expr
+		} $($rest:tt)*
+	) => {
+		::core::compile_error!(::core::concat!(
+			"the branch `",
+			::core::stringify!($branch),
+			"` can not delegate the property of a `lazy(grouped)` property"
+		));
+	};
+	(
+		// The grouped table can not hold branches of other modifiers
+		@LazyEnumPropGrouped
+		($prop_name:path) for [$($generics:tt)*] $enum_name:ty, $table:ident $count:tt $arms:tt $values:tt
+		$(#[$arm_attr:meta])*
+		$branch:pat $(if $guard:expr)? => {
+			@Mod $arm_modifier:tt $($struct_fields:tt)*
+		} $($rest:tt)*
+	) => {
+		::core::compile_error!(::core::concat!(
+			"the branch `",
+			::core::stringify!($branch),
+			"` can not override the modifier of a `lazy(grouped)` property"
+		));
+	};
+	(
+		// Collecting the branches of the grouped table, a branch with a comma
+		@LazyEnumPropGrouped
+		($prop_name:path) for [$($generics:tt)*] $enum_name:ty, $table:ident [$($count:tt)*] [$($arms:tt)*] [$($values:tt)*]
+		$(#[$arm_attr:meta])*
+		$branch:pat $(if $guard:expr)? => {
+			$($struct_fields:tt)*
+		} , $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@LazyEnumPropGrouped
+			($prop_name) for [$($generics)*] $enum_name, $table
+			[$($count)* + $crate::internal_props_impl_macro!(@Enabled $(#[$arm_attr])*)]
+			[$($arms)* $(#[$arm_attr])* ($branch, _) $(if $guard)? => &$table[0 $($count)*],]
+			[$($values)* $(#[$arm_attr])* {
+				$crate::internal_lazy_annotated!(
+					$crate::internal_props_impl_macro!(@EnumName [$($generics)*] $enum_name),
+					$prop_name,
+					$branch,
+					{
+						$crate::internal_props_impl_macro!(@Value $prop_name {
+							$($struct_fields)*
+						})
+					}
+				)
+			},]
+			$($rest)*
+		}
+	};
+	(
+		// Collecting the branches of the grouped table, a branch
+		@LazyEnumPropGrouped
+		($prop_name:path) for [$($generics:tt)*] $enum_name:ty, $table:ident [$($count:tt)*] [$($arms:tt)*] [$($values:tt)*]
+		$(#[$arm_attr:meta])*
+		$branch:pat $(if $guard:expr)? => {
+			$($struct_fields:tt)*
+		} $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@LazyEnumPropGrouped
+			($prop_name) for [$($generics)*] $enum_name, $table
+			[$($count)* + $crate::internal_props_impl_macro!(@Enabled $(#[$arm_attr])*)]
+			[$($arms)* $(#[$arm_attr])* ($branch, _) $(if $guard)? => &$table[0 $($count)*],]
+			[$($values)* $(#[$arm_attr])* {
+				$crate::internal_lazy_annotated!(
+					$crate::internal_props_impl_macro!(@EnumName [$($generics)*] $enum_name),
+					$prop_name,
+					$branch,
+					{
+						$crate::internal_props_impl_macro!(@Value $prop_name {
+							$($struct_fields)*
+						})
+					}
+				)
+			},]
+			$($rest)*
+		}
+	};
+	(
+		// The name of the enum, for messages about its lazy properties
+		@EnumName [$([[] $where_clause:tt])?] $enum_name:ty
+	) => {
+		::core::any::type_name::<$enum_name>()
+	};
+	(
+		// The name of a generic enum, which can not be named via `type_name`
+		// within the lazy cells, since they are shared by all instances
+		@EnumName [$($generics:tt)+] $enum_name:ty
+	) => {
+		::core::stringify!($enum_name)
+	};
+	(
+		// Counting a branch of the grouped table, as `1` unless it is removed
+		// by a `cfg` attribute
+		@Enabled $(#[$arm_attr:meta])*
+	) => {{
+		let _enabled = 0;
+		$(#[$arm_attr])*
+		let _enabled = 1;
+		_enabled
+	}};
+	(
+		// The lazy enum prop impl, holding the lazy cells
+		@LazyEnumProp
+		mod $modifier:tt ($prop_name:path) for $generics:tt $enum_name:ty {
+			$(
+				$(#[$arm_attr:meta])*
+				$branch:pat $(if $guard:expr)? => {
+					$(
+						$struct_fields:tt
+					)*
+				} $(,)?
+			)*
+		}
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Impl $generics [$crate::LazyEnumProp<$prop_name> for $enum_name] {
+				fn internal_lazy_property(&self, init: bool) -> ::core::option::Option<&'static $prop_name> {
+					// Along with the property type, which names it in errors, e.g.
+					// about non-exhaustive patterns
+					#[deny(unreachable_patterns)] // Remember the `Self` prefix
+					match (self, ::core::marker::PhantomData::<$prop_name>) {
+						$(
+							$(#[$arm_attr])*
+							($branch, _) $(if $guard)? => {
+								$crate::internal_props_impl_macro!(
+									@Branch mod $modifier $prop_name, init, $generics $enum_name, $branch => {
+										$( $struct_fields )*
+									}
+								)
+							},
+						)*
+					}
+				}
+			}
+		}
+	};
+
+	(
+		// The comma between the clauses and the associated consts
+		@Consts [, $($consts:tt)*] $($entry:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Consts [$($consts)*] $($entry)*
+		}
+	};
+	(
+		// An assertion of a const prop via `const fn`, checking its branches
+		@Consts [@Assert $check:tt $(, $($consts:tt)*)?]
+		@EnumPropConstFn $head:tt $fn_name:ident ($prop_name:path) for $generics:tt $enum_name:ty { $($matching:tt)* }
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Assert $check mod(const) ($prop_name) for $generics $enum_name { $($matching)* }
+		}
+
+		$crate::internal_props_impl_macro!{
+			@Consts [$($($consts)*)?]
+			@EnumPropConstFn $head $fn_name ($prop_name) for $generics $enum_name { $($matching)* }
+		}
+	};
+	(
+		// An assertion of a const prop
+		@Consts [@Assert $check:tt $(, $($consts:tt)*)?]
+		@$kind:ident mod $modifier:tt ($prop_name:path) for $generics:tt $enum_name:ty { $($matching:tt)* }
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Assert $check mod $modifier ($prop_name) for $generics $enum_name { $($matching)* }
+		}
+
+		$crate::internal_props_impl_macro!{
+			@Consts [$($($consts)*)?]
+			@$kind mod $modifier ($prop_name) for $generics $enum_name { $($matching)* }
+		}
+	};
+	(
+		// The unique fields of a const prop via `const fn`, comparing its
+		// branches
+		@Consts [@Unique $fields:tt $(, $($consts:tt)*)?]
+		@EnumPropConstFn $head:tt $fn_name:ident ($prop_name:path) for $generics:tt $enum_name:ty { $($matching:tt)* }
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Unique $fields mod(const) ($prop_name) for $generics $enum_name { $($matching)* }
+		}
+
+		$crate::internal_props_impl_macro!{
+			@Consts [$($($consts)*)?]
+			@EnumPropConstFn $head $fn_name ($prop_name) for $generics $enum_name { $($matching)* }
+		}
+	};
+	(
+		// The unique fields of a const prop
+		@Consts [@Unique $fields:tt $(, $($consts:tt)*)?]
+		@$kind:ident mod $modifier:tt ($prop_name:path) for $generics:tt $enum_name:ty { $($matching:tt)* }
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Unique $fields mod $modifier ($prop_name) for $generics $enum_name { $($matching)* }
+		}
+
+		$crate::internal_props_impl_macro!{
+			@Consts [$($($consts)*)?]
+			@$kind mod $modifier ($prop_name) for $generics $enum_name { $($matching)* }
+		}
+	};
+	(
+		// The aggregates of a const prop via `const fn`, folding its branches
+		@Consts [@Aggregate $aggregates:tt $(, $($consts:tt)*)?]
+		@EnumPropConstFn $head:tt $fn_name:ident ($prop_name:path) for $generics:tt $enum_name:ty { $($matching:tt)* }
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Aggregate $aggregates mod(const) ($prop_name) for $generics $enum_name { $($matching)* }
+		}
+
+		$crate::internal_props_impl_macro!{
+			@Consts [$($($consts)*)?]
+			@EnumPropConstFn $head $fn_name ($prop_name) for $generics $enum_name { $($matching)* }
+		}
+	};
+	(
+		// The aggregates of a const prop
+		@Consts [@Aggregate $aggregates:tt $(, $($consts:tt)*)?]
+		@$kind:ident mod $modifier:tt ($prop_name:path) for $generics:tt $enum_name:ty { $($matching:tt)* }
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Aggregate $aggregates mod $modifier ($prop_name) for $generics $enum_name { $($matching)* }
+		}
+
+		$crate::internal_props_impl_macro!{
+			@Consts [$($($consts)*)?]
+			@$kind mod $modifier ($prop_name) for $generics $enum_name { $($matching)* }
+		}
+	};
+	(
+		// The conversion of a prop via `const fn` into a field
+		@Consts [@Into $d:tt $field:tt $ty:tt $(, $($consts:tt)*)?]
+		@EnumPropConstFn $head:tt $fn_name:ident ($prop_name:path) for $generics:tt $enum_name:ty { $($matching:tt)* }
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Into $d $field $ty [$($($consts)*)?] ($prop_name) for $generics $enum_name
+		}
+
+		$crate::internal_props_impl_macro!{
+			@Consts [$($($consts)*)?]
+			@EnumPropConstFn $head $fn_name ($prop_name) for $generics $enum_name { $($matching)* }
+		}
+	};
+	(
+		// The conversion of a prop into a field
+		@Consts [@Into $d:tt $field:tt $ty:tt $(, $($consts:tt)*)?]
+		@$kind:ident mod $modifier:tt ($prop_name:path) for $generics:tt $enum_name:ty { $($matching:tt)* }
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Into $d $field $ty [$($($consts)*)?] ($prop_name) for $generics $enum_name
+		}
+
+		$crate::internal_props_impl_macro!{
+			@Consts [$($($consts)*)?]
+			@$kind mod $modifier ($prop_name) for $generics $enum_name { $($matching)* }
+		}
+	};
+	(
+		// The list of variants of a const prop via `const fn`
+		@Consts [@All [@VariantList $head:tt] $variants:tt $(, $($consts:tt)*)?]
+		@EnumPropConstFn $fn_head:tt $fn_name:ident ($prop_name:path) for $generics:tt $enum_name:ty { $($matching:tt)* }
+	) => {
+		$crate::internal_props_impl_macro!{
+			@VariantList $head $variants for $generics $enum_name
+		}
+
+		$crate::internal_props_impl_macro!{
+			@Consts [$($($consts)*)?]
+			@EnumPropConstFn $fn_head $fn_name ($prop_name) for $generics $enum_name { $($matching)* }
+		}
+	};
+	(
+		// The list of variants of any other prop
+		@Consts [@All [@VariantList $head:tt] $variants:tt $(, $($consts:tt)*)?]
+		@$kind:ident mod $modifier:tt ($prop_name:path) for $generics:tt $enum_name:ty { $($matching:tt)* }
+	) => {
+		$crate::internal_props_impl_macro!{
+			@VariantList $head $variants for $generics $enum_name
+		}
+
+		$crate::internal_props_impl_macro!{
+			@Consts [$($($consts)*)?]
+			@$kind mod $modifier ($prop_name) for $generics $enum_name { $($matching)* }
+		}
+	};
+	(
+		// The value enum of a const prop via `const fn`
+		@Consts [@All [@ValueEnum $fields:tt $help:tt] $variants:tt $(, $($consts:tt)*)?]
+		@EnumPropConstFn $fn_head:tt $fn_name:ident ($prop_name:path) for $generics:tt $enum_name:ty { $($matching:tt)* }
+	) => {
+		$crate::internal_props_impl_macro!{
+			@ValueEnum $fields $help $variants ($prop_name) for $generics $enum_name
+		}
+
+		$crate::internal_props_impl_macro!{
+			@Consts [$($($consts)*)?]
+			@EnumPropConstFn $fn_head $fn_name ($prop_name) for $generics $enum_name { $($matching)* }
+		}
+	};
+	(
+		// The value enum of any other prop
+		@Consts [@All [@ValueEnum $fields:tt $help:tt] $variants:tt $(, $($consts:tt)*)?]
+		@$kind:ident mod $modifier:tt ($prop_name:path) for $generics:tt $enum_name:ty { $($matching:tt)* }
+	) => {
+		$crate::internal_props_impl_macro!{
+			@ValueEnum $fields $help $variants ($prop_name) for $generics $enum_name
+		}
+
+		$crate::internal_props_impl_macro!{
+			@Consts [$($($consts)*)?]
+			@$kind mod $modifier ($prop_name) for $generics $enum_name { $($matching)* }
+		}
+	};
+	(
+		// The weighted sampling of a const prop via `const fn`
+		@Consts [@All [@SampleWeighted $field:ident] $variants:tt $(, $($consts:tt)*)?]
+		@EnumPropConstFn $fn_head:tt $fn_name:ident ($prop_name:path) for $generics:tt $enum_name:ty { $($matching:tt)* }
+	) => {
+		$crate::internal_props_impl_macro!{
+			@SampleWeighted $field $variants ($prop_name) for $generics $enum_name
+		}
+
+		$crate::internal_props_impl_macro!{
+			@Consts [$($($consts)*)?]
+			@EnumPropConstFn $fn_head $fn_name ($prop_name) for $generics $enum_name { $($matching)* }
+		}
+	};
+	(
+		// The weighted sampling of any other prop
+		@Consts [@All [@SampleWeighted $field:ident] $variants:tt $(, $($consts:tt)*)?]
+		@$kind:ident mod $modifier:tt ($prop_name:path) for $generics:tt $enum_name:ty { $($matching:tt)* }
+	) => {
+		$crate::internal_props_impl_macro!{
+			@SampleWeighted $field $variants ($prop_name) for $generics $enum_name
+		}
+
+		$crate::internal_props_impl_macro!{
+			@Consts [$($($consts)*)?]
+			@$kind mod $modifier ($prop_name) for $generics $enum_name { $($matching)* }
+		}
+	};
+	(
+		// The lookup by discriminant of a const prop via `const fn`
+		@Consts [@All [@Discriminant $repr:ident $name:tt] $variants:tt $(, $($consts:tt)*)?]
+		@EnumPropConstFn $fn_head:tt $fn_name:ident ($prop_name:path) for $generics:tt $enum_name:ty { $($matching:tt)* }
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Discriminant $repr $name $variants ($prop_name) for $generics $enum_name
+		}
+
+		$crate::internal_props_impl_macro!{
+			@Consts [$($($consts)*)?]
+			@EnumPropConstFn $fn_head $fn_name ($prop_name) for $generics $enum_name { $($matching)* }
+		}
+	};
+	(
+		// The lookup by discriminant of any other prop
+		@Consts [@All [@Discriminant $repr:ident $name:tt] $variants:tt $(, $($consts:tt)*)?]
+		@$kind:ident mod $modifier:tt ($prop_name:path) for $generics:tt $enum_name:ty { $($matching:tt)* }
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Discriminant $repr $name $variants ($prop_name) for $generics $enum_name
+		}
+
+		$crate::internal_props_impl_macro!{
+			@Consts [$($($consts)*)?]
+			@$kind mod $modifier ($prop_name) for $generics $enum_name { $($matching)* }
+		}
+	};
+	(
+		// The table replaces the `EnumProp` impl, thus it is moved after the
+		// other consts, which expect the regular entry
+		@Consts [@All [@Table] $variants:tt $(, $($consts:tt)*)?]
+		@EnumProp mod(const) ($prop_name:path) for $generics:tt $enum_name:ty { $($matching:tt)* }
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Consts [$($($consts)*,)? @Table $variants]
+			@EnumProp mod(const) ($prop_name) for $generics $enum_name { $($matching)* }
+		}
+	};
+	(
+		// The table, as the last const, instead of the match
+		@Consts [@Table $variants:tt]
+		@EnumProp mod(const) ($prop_name:path) for $generics:tt $enum_name:ty { $($matching:tt)* }
+	) => {
+		$crate::internal_props_impl_macro!{
+			@EnumPropTable $variants ($prop_name) for $generics $enum_name { $($matching)* }
+		}
+	};
+	(
+		// The reverse lookup of a static prop
+		@Consts [@All [@FromProperty $head:tt] $variants:tt $(, $($consts:tt)*)?]
+		@$kind:ident mod $modifier:tt ($prop_name:path) for $generics:tt $enum_name:ty { $($matching:tt)* }
+	) => {
+		$crate::internal_props_impl_macro!{
+			@FromProperty $head $variants ($prop_name) for $generics $enum_name { $($matching)* }
+		}
+
+		$crate::internal_props_impl_macro!{
+			@Consts [$($($consts)*)?]
+			@$kind mod $modifier ($prop_name) for $generics $enum_name { $($matching)* }
+		}
+	};
+	(
+		// The map of the properties of a const prop via `const fn`
+		@Consts [@EnumMap $head:tt $(, $($consts:tt)*)?]
+		@EnumPropConstFn $fn_head:tt $fn_name:ident ($prop_name:path) for $generics:tt $enum_name:ty { $($matching:tt)* }
+	) => {
+		$crate::internal_props_impl_macro!{
+			@EnumMap $head ($prop_name) for $generics $enum_name
+		}
+
+		$crate::internal_props_impl_macro!{
+			@Consts [$($($consts)*)?]
+			@EnumPropConstFn $fn_head $fn_name ($prop_name) for $generics $enum_name { $($matching)* }
+		}
+	};
+	(
+		// The map of the properties of any other prop
+		@Consts [@EnumMap $head:tt $(, $($consts:tt)*)?]
+		@$kind:ident mod $modifier:tt ($prop_name:path) for $generics:tt $enum_name:ty { $($matching:tt)* }
+	) => {
+		$crate::internal_props_impl_macro!{
+			@EnumMap $head ($prop_name) for $generics $enum_name
+		}
+
+		$crate::internal_props_impl_macro!{
+			@Consts [$($($consts)*)?]
+			@$kind mod $modifier ($prop_name) for $generics $enum_name { $($matching)* }
+		}
+	};
+	(
+		// The setters of a static prop
+		@Consts [@Setters $ordering:tt $setters:tt $(, $($consts:tt)*)?]
+		@$kind:ident mod $modifier:tt ($prop_name:path) for $generics:tt $enum_name:ty { $($matching:tt)* }
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Setters $ordering $setters ($prop_name) for $generics $enum_name { $($matching)* }
+		}
+
+		$crate::internal_props_impl_macro!{
+			@Consts [$($($consts)*)?]
+			@$kind mod $modifier ($prop_name) for $generics $enum_name { $($matching)* }
+		}
+	};
+	(
+		// The identity of a static prop
+		@Consts [@Identity $(, $($consts:tt)*)?]
+		@$kind:ident mod $modifier:tt ($prop_name:path) for $generics:tt $enum_name:ty { $($matching:tt)* }
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Identity ($prop_name) for $generics $enum_name { $($matching)* }
+		}
+
+		$crate::internal_props_impl_macro!{
+			@Consts [$($($consts)*)?]
+			@$kind mod $modifier ($prop_name) for $generics $enum_name { $($matching)* }
+		}
+	};
+	(
+		// The conversion into a const prop via `const fn` by a field
+		@Consts [@All [@TryFrom $field:ident $ty:tt] $variants:tt $(, $($consts:tt)*)?]
+		@EnumPropConstFn $head:tt $fn_name:ident ($prop_name:path) for $generics:tt $enum_name:ty { $($matching:tt)* }
+	) => {
+		$crate::internal_props_impl_macro!{
+			@TryFrom $field $ty $variants ($prop_name) for $generics $enum_name
+		}
+
+		$crate::internal_props_impl_macro!{
+			@Consts [$($($consts)*)?]
+			@EnumPropConstFn $head $fn_name ($prop_name) for $generics $enum_name { $($matching)* }
+		}
+	};
+	(
+		// The conversion into a const prop by a field
+		@Consts [@All [@TryFrom $field:ident $ty:tt] $variants:tt $(, $($consts:tt)*)?]
+		@$kind:ident mod $modifier:tt ($prop_name:path) for $generics:tt $enum_name:ty { $($matching:tt)* }
+	) => {
+		$crate::internal_props_impl_macro!{
+			@TryFrom $field $ty $variants ($prop_name) for $generics $enum_name
+		}
+
+		$crate::internal_props_impl_macro!{
+			@Consts [$($($consts)*)?]
+			@$kind mod $modifier ($prop_name) for $generics $enum_name { $($matching)* }
+		}
+	};
+	(
+		// The parsing of a const prop via `const fn` via a perfect hash map
+		@Consts [@All [@FromStr phf $field:ident $map:tt $pairs:tt] $variants:tt $(, $($consts:tt)*)?]
+		@EnumPropConstFn $head:tt $fn_name:ident ($prop_name:path) for $generics:tt $enum_name:ty { $($matching:tt)* }
+	) => {
+		$crate::internal_props_impl_macro!{
+			@FromStrPhf ($) $field $map $pairs $variants for $generics $enum_name
+		}
+
+		$crate::internal_props_impl_macro!{
+			@Consts [$($($consts)*)?]
+			@EnumPropConstFn $head $fn_name ($prop_name) for $generics $enum_name { $($matching)* }
+		}
+	};
+	(
+		// The parsing of a const prop via a perfect hash map
+		@Consts [@All [@FromStr phf $field:ident $map:tt $pairs:tt] $variants:tt $(, $($consts:tt)*)?]
+		@$kind:ident mod $modifier:tt ($prop_name:path) for $generics:tt $enum_name:ty { $($matching:tt)* }
+	) => {
+		$crate::internal_props_impl_macro!{
+			@FromStrPhf ($) $field $map $pairs $variants for $generics $enum_name
+		}
+
+		$crate::internal_props_impl_macro!{
+			@Consts [$($($consts)*)?]
+			@$kind mod $modifier ($prop_name) for $generics $enum_name { $($matching)* }
+		}
+	};
+	(
+		// The parsing of a const prop via `const fn` by a field
+		@Consts [@All [@FromStr $field:ident] $variants:tt $(, $($consts:tt)*)?]
+		@EnumPropConstFn $head:tt $fn_name:ident ($prop_name:path) for $generics:tt $enum_name:ty { $($matching:tt)* }
+	) => {
+		$crate::internal_props_impl_macro!{
+			@FromStr $field $variants ($prop_name) for $generics $enum_name
+		}
+
+		$crate::internal_props_impl_macro!{
+			@Consts [$($($consts)*)?]
+			@EnumPropConstFn $head $fn_name ($prop_name) for $generics $enum_name { $($matching)* }
+		}
+	};
+	(
+		// The parsing of a const prop by a field
+		@Consts [@All [@FromStr $field:ident] $variants:tt $(, $($consts:tt)*)?]
+		@$kind:ident mod $modifier:tt ($prop_name:path) for $generics:tt $enum_name:ty { $($matching:tt)* }
+	) => {
+		$crate::internal_props_impl_macro!{
+			@FromStr $field $variants ($prop_name) for $generics $enum_name
+		}
+
+		$crate::internal_props_impl_macro!{
+			@Consts [$($($consts)*)?]
+			@$kind mod $modifier ($prop_name) for $generics $enum_name { $($matching)* }
+		}
+	};
+	(
+		// The deserialization of a const prop via `const fn` by a field, the
+		// expected names are taken from the branches
+		@Consts [@All [@Deserialize $field:ident] $variants:tt $(, $($consts:tt)*)?]
+		@EnumPropConstFn $head:tt $fn_name:ident ($prop_name:path) for $generics:tt $enum_name:ty { $($matching:tt)* }
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Deserialize $field $variants mod(const) ($prop_name) for $generics $enum_name { $($matching)* }
+		}
+
+		$crate::internal_props_impl_macro!{
+			@Consts [$($($consts)*)?]
+			@EnumPropConstFn $head $fn_name ($prop_name) for $generics $enum_name { $($matching)* }
+		}
+	};
+	(
+		// The deserialization of a const prop by a field
+		@Consts [@All [@Deserialize $field:ident] $variants:tt $(, $($consts:tt)*)?]
+		@$kind:ident mod $modifier:tt ($prop_name:path) for $generics:tt $enum_name:ty { $($matching:tt)* }
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Deserialize $field $variants mod $modifier ($prop_name) for $generics $enum_name { $($matching)* }
+		}
+
+		$crate::internal_props_impl_macro!{
+			@Consts [$($($consts)*)?]
+			@$kind mod $modifier ($prop_name) for $generics $enum_name { $($matching)* }
+		}
+	};
+	(
+		// The variant sets of a const prop via `const fn`
+		@Consts [@All [@SetWhere $sets:tt] $variants:tt $(, $($consts:tt)*)?]
+		@EnumPropConstFn $head:tt $fn_name:ident ($prop_name:path) for $generics:tt $enum_name:ty { $($matching:tt)* }
+	) => {
+		$crate::internal_props_impl_macro!{
+			@SetWhere $sets $variants mod(const) ($prop_name) for $generics $enum_name { $($matching)* }
+		}
+
+		$crate::internal_props_impl_macro!{
+			@Consts [$($($consts)*)?]
+			@EnumPropConstFn $head $fn_name ($prop_name) for $generics $enum_name { $($matching)* }
+		}
+	};
+	(
+		// The variant sets of a const prop
+		@Consts [@All [@SetWhere $sets:tt] $variants:tt $(, $($consts:tt)*)?]
+		@$kind:ident mod $modifier:tt ($prop_name:path) for $generics:tt $enum_name:ty { $($matching:tt)* }
+	) => {
+		$crate::internal_props_impl_macro!{
+			@SetWhere $sets $variants mod $modifier ($prop_name) for $generics $enum_name { $($matching)* }
+		}
+
+		$crate::internal_props_impl_macro!{
+			@Consts [$($($consts)*)?]
+			@$kind mod $modifier ($prop_name) for $generics $enum_name { $($matching)* }
+		}
+	};
+	(
+		// The sorted variants of a const prop via `const fn`, calling the
+		// `const fn` for each variant
+		@Consts [
+			@All [@Ordered $field:ident [$($head:tt)*]] [$([$(#[$attr:meta])*] $variant:path,)*]
+			$(, $($consts:tt)*)?
+		]
+		@EnumPropConstFn $fn_head:tt $fn_name:ident ($prop_name:path) for $generics:tt $enum_name:ty { $($matching:tt)* }
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Impl $generics [$enum_name] {
+				$($head)* : [Self; <[()]>::len(&[$($(#[$attr])* (),)*])] = $crate::internal_props_impl_macro!(
+					@Sorted $field [$($(#[$attr])* Self::$fn_name(&$variant),)*] [$($(#[$attr])* $variant,)*]
+				);
+			}
+		}
+
+		$crate::internal_props_impl_macro!{
+			@Consts [$($($consts)*)?]
+			@EnumPropConstFn $fn_head $fn_name ($prop_name) for $generics $enum_name { $($matching)* }
+		}
+	};
+	(
+		// The sorted variants of a const or static prop, matching each variant
+		// like the `all` table
+		@Consts [
+			@All [@Ordered $field:ident $head:tt] $variants:tt
+			$(, $($consts:tt)*)?
+		]
+		@$kind:ident mod $modifier:tt ($prop_name:path) for $generics:tt $enum_name:ty { $($matching:tt)* }
+	) => {
+		$crate::internal_props_impl_macro!{
+			@ConstsArray [@Ordered $field $head $variants] [] [] $variants
+			mod $modifier ($prop_name) for $generics $enum_name { $($matching)* }
+		}
+
+		$crate::internal_props_impl_macro!{
+			@Consts [$($($consts)*)?]
+			@$kind mod $modifier ($prop_name) for $generics $enum_name { $($matching)* }
+		}
+	};
+	(
+		// The `all` table of a const prop via `const fn`, calling the
+		// `const fn` for each variant
+		@Consts [
+			@All [$($head:tt)*] [$([$(#[$attr:meta])*] $variant:path,)*]
+			$(, $($consts:tt)*)?
+		]
+		@EnumPropConstFn $fn_head:tt $fn_name:ident ($prop_name:path) for $generics:tt $enum_name:ty { $($matching:tt)* }
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Impl $generics [$enum_name] {
+				$($head)* : [&'static $prop_name; <[()]>::len(&[$($(#[$attr])* (),)*])] = [
+					$(
+						$(#[$attr])*
+						Self::$fn_name(&$variant),
+					)*
+				];
+			}
+		}
+
+		$crate::internal_props_impl_macro!{
+			@Consts [$($($consts)*)?]
+			@EnumPropConstFn $fn_head $fn_name ($prop_name) for $generics $enum_name { $($matching)* }
+		}
+	};
+	(
+		// The `all` table of a static prop holds the statics returned by the
+		// `EnumProp` impl, which it then replaces, thus it is moved after the
+		// other consts, which expect the regular entry
+		@Consts [
+			@All [$(#[$attr:meta])* $vis:vis const $name:ident] $variants:tt
+			$(, $($consts:tt)*)?
+		]
+		@EnumProp mod(static) ($prop_name:path) for $generics:tt $enum_name:ty { $($matching:tt)* }
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Consts [$($($consts)*,)? @StaticAll [$(#[$attr])* $vis const $name] $variants]
+			@EnumProp mod(static) ($prop_name) for $generics $enum_name { $($matching)* }
+		}
+	};
+	(
+		// The `all` table of a static prop, as the last const, along with the
+		// `EnumProp` impl returning its entries
+		@Consts [@StaticAll [$(#[$attr:meta])* $vis:vis const $name:ident] $variants:tt]
+		@EnumProp mod(static) ($prop_name:path) for $generics:tt $enum_name:ty { $($matching:tt)* }
+	) => {
+		$crate::internal_props_impl_macro!{
+			@StaticAll [$(#[$attr])* $vis const $name] { $($matching)* } $variants
+			($prop_name) for $generics $enum_name { $($matching)* }
+		}
+
+		$crate::internal_props_impl_macro!{
+			@StaticAllEnumProp $name $variants ($prop_name) for $generics $enum_name { $($matching)* }
+		}
+	};
+	(
+		// The `all` table of a const or static prop
+		@Consts [
+			@All $head:tt $variants:tt
+			$(, $($consts:tt)*)?
+		]
+		@$kind:ident mod $modifier:tt ($prop_name:path) for $generics:tt $enum_name:ty { $($matching:tt)* }
+	) => {
+		$crate::internal_props_impl_macro!{
+			@ConstsArray $head [] [] $variants
+			mod $modifier ($prop_name) for $generics $enum_name { $($matching)* }
+		}
+
+		$crate::internal_props_impl_macro!{
+			@Consts [$($($consts)*)?]
+			@$kind mod $modifier ($prop_name) for $generics $enum_name { $($matching)* }
+		}
+	};
+	(
+		// An associated const of a const prop via `const fn`, one at a time,
+		// calling the `const fn`
+		@Consts [$(#[$attr:meta])* $vis:vis $name:ident = $variant:expr $(, $($consts:tt)*)?]
+		@EnumPropConstFn $head:tt $fn_name:ident ($prop_name:path) for $generics:tt $enum_name:ty { $($matching:tt)* }
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Impl $generics [$enum_name] {
+				$(#[$attr])*
+				$vis const $name: &'static $prop_name = Self::$fn_name(&$variant);
+			}
+		}
+
+		$crate::internal_props_impl_macro!{
+			@Consts [$($($consts)*)?]
+			@EnumPropConstFn $head $fn_name ($prop_name) for $generics $enum_name { $($matching)* }
+		}
+	};
+	(
+		// An associated const of a const prop, one at a time, with its own
+		// match, since the `EnumProp` impl can not be called in a const
+		@Consts [$(#[$attr:meta])* $vis:vis $name:ident = $variant:expr $(, $($consts:tt)*)?]
+		@EnumProp mod(const) ($prop_name:path) for $generics:tt $enum_name:ty { $($matching:tt)* }
+	) => {
+		$crate::internal_props_impl_macro!{
+			@ConstsItem [$(#[$attr])* $vis const $name] ($variant) ($prop_name) for $generics $enum_name { $($matching)* }
+		}
+
+		$crate::internal_props_impl_macro!{
+			@Consts [$($($consts)*)?]
+			@EnumProp mod(const) ($prop_name) for $generics $enum_name { $($matching)* }
+		}
+	};
+	(
+		// An associated const of a const prop via `EnumPropConst`, like any
+		// other const prop
+		@Consts [$(#[$attr:meta])* $vis:vis $name:ident = $variant:expr $(, $($consts:tt)*)?]
+		@EnumPropConst mod(const) ($prop_name:path) for $generics:tt $enum_name:ty { $($matching:tt)* }
+	) => {
+		$crate::internal_props_impl_macro!{
+			@ConstsItem [$(#[$attr])* $vis const $name] ($variant) ($prop_name) for $generics $enum_name { $($matching)* }
+		}
+
+		$crate::internal_props_impl_macro!{
+			@Consts [$($($consts)*)?]
+			@EnumPropConst mod(const) ($prop_name) for $generics $enum_name { $($matching)* }
+		}
+	};
+	(
+		// The associated consts are done, leaving the prop impl itself
+		@Consts [] $($entry:tt)*
+	) => {
+		$crate::internal_props_impl_macro!{
+			$($entry)*
+		}
+	};
+	(
+		// The `FromStr` impl, comparing the string with the field of each
+		// variant, whose uniqueness is checked at compile time
+		@FromStr $field:ident [$([$(#[$attr:meta])*] $variant:path,)*] ($prop_name:path) for $generics:tt $enum_name:ty
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Impl $generics [::core::str::FromStr for $enum_name] {
+				type Err = $crate::UnknownVariant;
+
+				fn from_str(name: &str) -> ::core::result::Result<Self, Self::Err> {
+					match name {
+						$(
+							$(#[$attr])*
+							name if name == <Self as $crate::EnumProp<$prop_name>>::property(&$variant).$field => {
+								::core::result::Result::Ok($variant)
+							}
+						)*
+						_ => ::core::result::Result::Err($crate::UnknownVariant),
+					}
+				}
+			}
+		}
+	};
+	(
+		// The `Deserialize` impl, reading a string and comparing it like the
+		// `FromStr` impl. The expected names of its error are the fields of
+		// the branches, in a const item like the unique check.
+		@Deserialize $field:ident [$([$(#[$attr:meta])*] $variant:path,)*]
+		mod $modifier:tt ($prop_name:path) for $generics:tt $enum_name:ty {
+			$(
+				$(#[$arm_attr:meta])*
+				$branch:pat $(if $guard:expr)? => {
+					$(
+						$struct_fields:tt
+					)*
+				} $(,)?
+			)*
+		}
+	) => {
+		$crate::internal_serde!{
+			$crate::internal_props_impl_macro!{
+				@DeserializeImpl $generics [$crate::serde::Deserialize<'de> for $enum_name] {
+					fn deserialize<D: $crate::serde::Deserializer<'de>>(
+						deserializer: D,
+					) -> ::core::result::Result<Self, D::Error> {
+						const NAMES: &[&str] = &[
+							$(
+								$(#[$arm_attr])*
+								{
+									$crate::internal_props_impl_macro!(
+										@ArmBranch mod $modifier $prop_name, $generics $enum_name, $branch => {
+											$( $struct_fields )*
+										}
+									)
+								}.$field,
+							)*
+						];
+
+						deserializer.deserialize_str($crate::InternalNameVisitor::new(NAMES, |name: &str| {
+							match name {
+								$(
+									$(#[$attr])*
+									name if name == <Self as $crate::EnumProp<$prop_name>>::property(&$variant).$field => {
+										::core::option::Option::Some($variant)
+									}
+								)*
+								_ => ::core::option::Option::None,
+							}
+						}))
+					}
+				}
+			}
+		}
+	};
+	(
+		// The impl of `Deserialize`, along with its lifetime
+		@DeserializeImpl [] $head:tt $body:tt
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Impl [['de] []] $head $body
+		}
+	};
+	(
+		@DeserializeImpl [[$($generics:tt)*] $where_clause:tt] $head:tt $body:tt
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Impl [['de, $($generics)*] $where_clause] $head $body
+		}
+	};
+	(
+		// The variant sets, one const per field
+		@SetWhere [$name:ident = $field:ident, $($sets:tt)*] $variants:tt
+		mod $modifier:tt ($prop_name:path) for $generics:tt $enum_name:ty { $($matching:tt)* }
+	) => {
+		$crate::internal_props_impl_macro!{
+			@SetWhereConst $name $field $variants mod $modifier ($prop_name) for $generics $enum_name { $($matching)* }
+		}
+
+		$crate::internal_props_impl_macro!{
+			@SetWhere [$($sets)*] $variants mod $modifier ($prop_name) for $generics $enum_name { $($matching)* }
+		}
+	};
+	(
+		@SetWhere [] $variants:tt mod $modifier:tt ($prop_name:path) for $generics:tt $enum_name:ty { $($matching:tt)* }
+	) => {};
+	(
+		// A single variant set, whose variants (as listed by the branches) are
+		// matched against the branches in a loop, since the branches can not
+		// be repeated per variant
+		@SetWhereConst $name:ident $field:ident [$([$(#[$attr:meta])*] $variant:path,)*]
+		mod $modifier:tt ($prop_name:path) for $generics:tt $enum_name:ty {
+			$(
+				$(#[$arm_attr:meta])*
+				$branch:pat $(if $guard:expr)? => {
+					$(
+						$struct_fields:tt
+					)*
+				} $(,)?
+			)*
+		}
+	) => {
+		$crate::internal_enumset!{
+			$crate::internal_props_impl_macro!{
+				@Impl $generics [$enum_name] {
+					/// The variants whose
+					#[doc = ::core::concat!("`", ::core::stringify!($field), "` is `true`")]
+					pub const $name: $crate::enumset::EnumSet<Self> = {
+						let variants = [$($(#[$attr])* $variant,)*];
+						let mut set = $crate::enumset::EnumSet::<Self>::empty();
+						let mut index = 0;
+						while index < variants.len() {
+							let variant = variants[index];
+							let prop = match (&variant, ::core::marker::PhantomData::<$prop_name>) {
+								$(
+									$(#[$arm_attr])*
+									($branch, _) $(if $guard)? => {
+										$crate::internal_props_impl_macro!(
+											@ArmBranch mod $modifier $prop_name, $generics $enum_name, $branch => {
+												$( $struct_fields )*
+											}
+										)
+									},
+								)*
+							};
+							if prop.$field {
+								set = $crate::enumset::enum_set_union!(set, variant);
+							}
+							index += 1;
+						}
+						set
+					};
+				}
+			}
+		}
+	};
+	(
+		// The perfect hash map from the field to the first variant of each
+		// branch, along with the `FromStr` impl looking it up. Since the keys
+		// must be literals, but fields can not be compared directly, this
+		// defines a local macro (via the passed `$`) finding the field in each
+		// branch.
+		@FromStrPhf ($d:tt) $field:ident [$(#[$attr:meta])* $vis:vis const $name:ident]
+		[$($pairs:tt)*] [$([$(#[$variant_attr:meta])*] $variant:path,)*] for $generics:tt $enum_name:ty
+	) => {
+		const _: () = {
+			macro_rules! internal_props_phf {
+				(
+					// The field given as a literal
+					[$d($d entries:tt)*] ($d($d variant:tt)*)
+					{ $field : $d key:literal $d(, $d($d fields:tt)*)? } $d($d rest:tt)*
+				) => {
+					internal_props_phf!([$d($d entries)* $d key => $d($d variant)*,] $d($d rest)*);
+				};
+				(
+					// The field given as anything else
+					[$d($d entries:tt)*] $d variant:tt
+					{ $field : $d value:expr $d(, $d($d fields:tt)*)? } $d($d rest:tt)*
+				) => {
+					::core::compile_error!(::core::concat!(
+						"the `from_str(phf ",
+						::core::stringify!($field),
+						")` requires the field to be given as a literal, not `",
+						::core::stringify!($d value),
+						"`"
+					));
+				};
+				(
+					// Any other field
+					[$d($d entries:tt)*] $d variant:tt
+					{ $d other:ident $d(. $d path:ident)* : $d value:expr $d(, $d($d fields:tt)*)? } $d($d rest:tt)*
+				) => {
+					internal_props_phf!([$d($d entries)*] $d variant { $d($d($d fields)*)? } $d($d rest)*);
+				};
+				(
+					// The field is not given, e.g. taken from the defaults
+					[$d($d entries:tt)*] ($d($d variant:tt)*) { $d($d fields:tt)* } $d($d rest:tt)*
+				) => {
+					::core::compile_error!(::core::concat!(
+						"the `from_str(phf ",
+						::core::stringify!($field),
+						")` requires the field to be given in each branch, but it is missing for `",
+						::core::stringify!($d($d variant)*),
+						"`"
+					));
+				};
+				(
+					// All fields found
+					[$d($d entries:tt)*]
+				) => {
+					$crate::internal_phf!{
+						$crate::internal_props_impl_macro!{
+							@Impl $generics [$enum_name] {
+								$(#[$attr])*
+								$vis const $name: $crate::phf::Map<&'static str, Self> = {
+									use $crate::phf;
+									phf::phf_map! {
+										$d($d entries)*
+									}
+								};
+							}
+						}
+
+						$crate::internal_props_impl_macro!{
+							@Impl $generics [::core::str::FromStr for $enum_name] {
+								type Err = $crate::UnknownVariant;
+
+								fn from_str(name: &str) -> ::core::result::Result<Self, Self::Err> {
+									match Self::$name.get(name) {
+										::core::option::Option::Some(variant) => ::core::result::Result::Ok(match variant {
+											$(
+												$(#[$variant_attr])*
+												$variant => $variant,
+											)*
+										}),
+										::core::option::Option::None => ::core::result::Result::Err($crate::UnknownVariant),
+									}
+								}
+							}
+						}
+					}
+				};
+			}
+
+			internal_props_phf!([] $($pairs)*);
+		};
+	};
+	(
+		// The `TryFrom` impl, comparing the value with the field of each
+		// variant, like the `FromStr` impl
+		@TryFrom $field:ident [$($ty:tt)+] [$([$(#[$attr:meta])*] $variant:path,)*] ($prop_name:path) for $generics:tt $enum_name:ty
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Impl $generics [::core::convert::TryFrom<$($ty)+> for $enum_name] {
+				type Error = $crate::UnknownValue<$($ty)+>;
+
+				fn try_from(value: $($ty)+) -> ::core::result::Result<Self, Self::Error> {
+					match value {
+						$(
+							$(#[$attr])*
+							value if value == <Self as $crate::EnumProp<$prop_name>>::property(&$variant).$field => {
+								::core::result::Result::Ok($variant)
+							}
+						)*
+						_ => ::core::result::Result::Err($crate::UnknownValue(value)),
+					}
+				}
+			}
+		}
+	};
+	(
+		// An assertion of a const prop, checking each branch in a const item,
+		// thus failing to compile, naming the branch
+		@Assert [$kind:ident (| $prop:ident | $condition:expr $(, $message:literal)? $(,)?)]
+		mod $modifier:tt ($prop_name:path) for $generics:tt $enum_name:ty { $($matching:tt)* }
+	) => {
+		$crate::internal_props_impl_macro!{
+			@AssertBranches [$kind ($prop) ($condition) [$($message)?]]
+			mod $modifier ($prop_name) for $generics $enum_name { $($matching)* }
+		}
+	};
+	(
+		// Checking the branches one by one, with the message captured as a
+		// whole, since it may be repeated along with them
+		@AssertBranches [$kind:ident ($prop:ident) ($condition:expr) $message:tt]
+		mod $modifier:tt ($prop_name:path) for $generics:tt $enum_name:ty {
+			$(
+				$(#[$arm_attr:meta])*
+				$branch:pat $(if $guard:expr)? => {
+					$(
+						$struct_fields:tt
+					)*
+				} $(,)?
+			)*
+		}
+	) => {
+		const _: () = {
+			$(
+				$(#[$arm_attr])*
+				{
+					let $prop: &$prop_name = $crate::internal_props_impl_macro!(
+						@ArmBranch mod $modifier $prop_name, $generics $enum_name, $branch => {
+							$( $struct_fields )*
+						}
+					);
+					$crate::internal_props_impl_macro!(
+						@Check $kind ($condition) $message $branch
+					);
+				}
+			)*
+		};
+	};
+	(
+		// Anything else than a closure-like condition
+		@Assert [$kind:ident ($($check:tt)*)]
+		mod $modifier:tt ($prop_name:path) for $generics:tt $enum_name:ty { $($matching:tt)* }
+	) => {
+		::core::compile_error!(::core::concat!(
+			"expected a condition on the property, e.g. `",
+			::core::stringify!($kind),
+			"(|prop| prop.size > 0)`, found `",
+			::core::stringify!($($check)*),
+			"`"
+		));
+	};
+	(
+		// The assertions of a lazy prop, checking the value of each branch
+		// once it is computed
+		@Asserts $checks:tt @EnumProp mod $modifier:tt ($prop_name:path) for $generics:tt $enum_name:ty {
+			$($matching:tt)*
+		}
+	) => {
+		$crate::internal_props_impl_macro!{
+			@AssertsArms $checks ($prop_name) [@EnumProp mod $modifier ($prop_name) for $generics $enum_name] []
+			$($matching)*
+		}
+	};
+	(
+		// The branches of a lazy prop with assertions, done
+		@AssertsArms $checks:tt $prop_name:tt [$($entry:tt)*] [$($arms:tt)*]
+	) => {
+		$crate::internal_props_impl_macro!{
+			$($entry)* {
+				$($arms)*
+			}
+		}
+	};
+	(
+		// A branch overriding the modifier is not checked
+		@AssertsArms $checks:tt $prop_name:tt $entry:tt [$($arms:tt)*]
+		$(#[$arm_attr:meta])*
+		$branch:pat $(if $guard:expr)? => {
+			@Mod $arm_modifier:tt $($struct_fields:tt)*
+		} $(, $($rest:tt)*)?
+	) => {
+		$crate::internal_props_impl_macro!{
+			@AssertsArms $checks $prop_name $entry [
+				$($arms)*
+				$(#[$arm_attr])*
+				$branch $(if $guard)? => {
+					@Mod $arm_modifier $($struct_fields)*
+				},
+			]
+			$($($rest)*)?
+		}
+	};
+	(
+		// Nor is a branch delegating to the property of an inner enum
+		@AssertsArms $checks:tt $prop_name:tt $entry:tt [$($arms:tt)*]
+		$(#[$arm_attr:meta])*
+		$branch:pat $(if $guard:expr)? => {
+			[$($defaults:tt)*] @Delegate $inner:expr
+		} $(, $($rest:tt)*)?
+	) => {
+		$crate::internal_props_impl_macro!{
+			@AssertsArms $checks $prop_name $entry [
+				$($arms)*
+				$(#[$arm_attr])*
+				$branch $(if $guard)? => {
+					[$($defaults)*] @Delegate $inner
+				},
+			]
+			$($($rest)*)?
+		}
+	};
+	(
+		// A branch with a value of its own, which is checked once computed,
+		// thus it is given as an expression
+		@AssertsArms $checks:tt ($prop_name:path) $entry:tt [$($arms:tt)*]
+		$(#[$arm_attr:meta])*
+		$branch:pat $(if $guard:expr)? => {
+			$($struct_fields:tt)*
+		} $(, $($rest:tt)*)?
+	) => {
+		$crate::internal_props_impl_macro!{
+			@AssertsArms $checks ($prop_name) $entry [
+				$($arms)*
+				$(#[$arm_attr])*
+				$branch $(if $guard)? => {
+					[] = $crate::internal_props_impl_macro!(@Checked $checks $prop_name, $branch => {
+						$($struct_fields)*
+					})
+				},
+			]
+			$($($rest)*)?
+		}
+	};
+	(
+		// The value of a property, checked by the given assertions
+		@Checked [$($kind:ident $check:tt)*] $prop_name:path, $branch:pat => {
+			$($struct_fields:tt)*
+		}
+	) => {{
+		let value: $prop_name = $crate::internal_props_impl_macro!(@Value $prop_name {
+			$($struct_fields)*
+		});
+		$(
+			$crate::internal_props_impl_macro!(@CheckValue $kind $check $prop_name, value, $branch);
+		)*
+		value
+	}};
+	(
+		// A single assertion of a value
+		@CheckValue $kind:ident (| $prop:ident | $condition:expr $(, $message:literal)? $(,)?)
+		$prop_name:path, $value:ident, $branch:pat
+	) => {{
+		let $prop: &$prop_name = &$value;
+		$crate::internal_props_impl_macro!(@Check $kind ($condition) [$($message)?] $branch);
+	}};
+	(
+		// Anything else than a closure-like condition
+		@CheckValue $kind:ident ($($check:tt)*) $prop_name:path, $value:ident, $branch:pat
+	) => {
+		::core::compile_error!(::core::concat!(
+			"expected a condition on the property, e.g. `",
+			::core::stringify!($kind),
+			"(|prop| prop.size > 0)`, found `",
+			::core::stringify!($($check)*),
+			"`"
+		))
+	};
+	(
+		// Checking a condition of a branch, with the given message
+		@Check $kind:ident ($condition:expr) [$message:literal] $branch:pat
+	) => {
+		let holds: bool = $condition;
+		if $crate::internal_props_impl_macro!(@CheckEnabled $kind) && !holds {
+			::core::panic!(
+				"{}",
+				::core::concat!("assertion failed for the branch `", ::core::stringify!($branch), "`: ", $message)
+			);
+		}
+	};
+	(
+		// Checking a condition of a branch, with the condition as message
+		@Check $kind:ident ($condition:expr) [] $branch:pat
+	) => {
+		let holds: bool = $condition;
+		if $crate::internal_props_impl_macro!(@CheckEnabled $kind) && !holds {
+			::core::panic!(
+				"{}",
+				::core::concat!(
+					"assertion failed for the branch `",
+					::core::stringify!($branch),
+					"`: ",
+					::core::stringify!($condition)
+				)
+			);
+		}
+	};
+	(
+		// An `assert` is always checked
+		@CheckEnabled assert
+	) => {
+		true
+	};
+	(
+		// A `debug_assert` is checked only with debug assertions enabled
+		@CheckEnabled debug_assert
+	) => {
+		::core::cfg!(debug_assertions)
+	};
+	(
+		// The unique fields, each compared pairwise across all branches in a
+		// const item, thus failing to compile on a duplicate, naming both
+		// branches
+		@Unique [$($field:ident),* $(,)?]
+		mod $modifier:tt ($prop_name:path) for $generics:tt $enum_name:ty {
+			$(
+				$(#[$arm_attr:meta])*
+				$branch:pat $(if $guard:expr)? => {
+					$(
+						$struct_fields:tt
+					)*
+				} $(,)?
+			)*
+		}
+	) => {
+		const _: () = {
+			let branches: &[&$prop_name] = &[
+				$(
+					$(#[$arm_attr])*
+					{
+						$crate::internal_props_impl_macro!(
+							@ArmBranch mod $modifier $prop_name, $generics $enum_name, $branch => {
+								$( $struct_fields )*
+							}
+						)
+					},
+				)*
+			];
+			let names: &[&str] = &[
+				$(
+					$(#[$arm_attr])*
+					{
+						::core::stringify!($branch)
+					},
+				)*
+			];
+
+			$(
+				let mut i = 0;
+				while i < branches.len() {
+					let mut j = i + 1;
+					while j < branches.len() {
+						if $crate::InternalUnique(&branches[i].$field).is_eq(&branches[j].$field) {
+							let message = $crate::InternalMessage::new(&[
+								"duplicate value for field `",
+								::core::stringify!($field),
+								"` in the branches `",
+								names[i],
+								"` and `",
+								names[j],
+								"`",
+							]);
+							::core::panic!("{}", message.as_str());
+						}
+						j += 1;
+					}
+					i += 1;
+				}
+			)*
+		};
+	};
+	(
+		// Anything else than a list of fields
+		@Unique [$($fields:tt)*]
+		mod $modifier:tt ($prop_name:path) for $generics:tt $enum_name:ty { $($matching:tt)* }
+	) => {
+		::core::compile_error!(::core::concat!(
+			"expected a list of fields, e.g. `unique(code)`, found `",
+			::core::stringify!($($fields)*),
+			"`"
+		));
+	};
+	(
+		// The conversions from the enum (and its reference) into a field,
+		// which are only implemented if no later conversion has the same
+		// type, as found by a local macro (via the passed `$`) matching it
+		@Into ($d:tt) $field:tt [$($ty:tt)+] [$($consts:tt)*] ($prop_name:path) for $generics:tt $enum_name:ty
+	) => {
+		const _: () = {
+			macro_rules! internal_props_into {
+				(@Check [$d other:tt] [$($ty)+] $d($d rest:tt)*) => {
+					::core::compile_error!(::core::concat!(
+						"the fields `",
+						::core::stringify!($field),
+						"` and `",
+						::core::stringify!($d other),
+						"` are both converted into `",
+						::core::stringify!($($ty)+),
+						"`, the `into` requires distinct types"
+					));
+				};
+				(@Check [$d other:tt] [$d($d other_ty:tt)+] $d($d rest:tt)*) => {
+					internal_props_into!(@Check $d($d rest)*);
+				};
+				(@Check) => {
+					$crate::internal_props_impl_macro!{
+						@Impl $generics [::core::convert::From<$enum_name> for $($ty)+] {
+							fn from(value: $enum_name) -> Self {
+								let $prop_name { $field, .. } = <$enum_name as $crate::EnumProp<$prop_name>>::property(&value);
+								$crate::internal_copy($field)
+							}
+						}
+					}
+
+					$crate::internal_props_impl_macro!{
+						@Impl $generics [::core::convert::From<&$enum_name> for $($ty)+] {
+							fn from(value: &$enum_name) -> Self {
+								let $prop_name { $field, .. } = <$enum_name as $crate::EnumProp<$prop_name>>::property(value);
+								$crate::internal_copy($field)
+							}
+						}
+					}
+				};
+			}
+
+			$crate::internal_props_impl_macro!{
+				@IntoTypes [] [$($consts)*]
+			}
+		};
+	};
+	(
+		// The fields and types of the later conversions, done
+		@IntoTypes [$($types:tt)*] []
+	) => {
+		internal_props_into!(@Check $($types)*);
+	};
+	(
+		// A later conversion
+		@IntoTypes [$($types:tt)*] [@Into $d:tt $field:tt $ty:tt $($consts:tt)*]
+	) => {
+		$crate::internal_props_impl_macro!{
+			@IntoTypes [$($types)* [$field] $ty] [$($consts)*]
+		}
+	};
+	(
+		// Anything else, e.g. a const
+		@IntoTypes $types:tt [$other:tt $($consts:tt)*]
+	) => {
+		$crate::internal_props_impl_macro!{
+			@IntoTypes $types [$($consts)*]
+		}
+	};
+	(
+		// The identity, implementing the comparison and hash of the variants
+		// by the addresses of their properties, after checking that no branch
+		// is a const one
+		@Identity ($prop_name:path) for $generics:tt $enum_name:ty {
+			$(
+				$(#[$arm_attr:meta])*
+				$branch:pat $(if $guard:expr)? => {
+					$(
+						$struct_fields:tt
+					)*
+				} $(,)?
+			)*
+		}
+	) => {
+		$(
+			$crate::internal_props_impl_macro!{
+				@AddressBranch "identity" $branch => { $($struct_fields)* }
+			}
+		)*
+
+		$crate::internal_props_impl_macro!{
+			@Impl $generics [::core::cmp::PartialEq for $enum_name] {
+				fn eq(&self, other: &Self) -> bool {
+					$crate::internal_address(<Self as $crate::EnumProp<$prop_name>>::property(self))
+						== $crate::internal_address(<Self as $crate::EnumProp<$prop_name>>::property(other))
+				}
+			}
+		}
+
+		$crate::internal_props_impl_macro!{
+			@Impl $generics [::core::cmp::Eq for $enum_name] {}
+		}
+
+		$crate::internal_props_impl_macro!{
+			@Impl $generics [::core::hash::Hash for $enum_name] {
+				fn hash<H: ::core::hash::Hasher>(&self, state: &mut H) {
+					::core::hash::Hash::hash(
+						&$crate::internal_address(<Self as $crate::EnumProp<$prop_name>>::property(self)),
+						state,
+					)
+				}
+			}
+		}
+	};
+	(
+		// A branch overriding the modifier by `const` has no unique address
+		@AddressBranch $clause:literal $branch:pat => { @Mod(const) $($struct_fields:tt)* }
+	) => {
+		::core::compile_error!(::core::concat!(
+			"the `",
+			$clause,
+			"` requires unique addresses, thus the branch `",
+			::core::stringify!($branch),
+			"` can not override the modifier by `const`"
+		));
+	};
+	(
+		@AddressBranch $clause:literal $branch:pat => { $($struct_fields:tt)* }
+	) => {};
+	(
+		// The map of the properties, a `static` deferring to a lazy one, since
+		// an `EnumMap` can not be built in a const
+		@EnumMap [$(#[$attr:meta])* $vis:vis static $name:ident] ($prop_name:path) for [] $enum_name:ty
+	) => {
+		$crate::internal_enum_map!{
+			$(#[$attr])*
+			$vis static $name: $crate::PropMap<$enum_name, $prop_name> = $crate::PropMap::internal_new(|| {
+				$crate::internal_lazy_with_default!(
+					$crate::enum_map::EnumMap<$enum_name, &'static $prop_name>,
+					$crate::enum_map::EnumMap::from(|variant: $enum_name| {
+						$crate::EnumProp::<$prop_name>::property(&variant)
+					})
+				)
+			});
+		}
+	};
+	(
+		// A `static` can not use the generic parameters of the enum
+		@EnumMap [$(#[$attr:meta])* $vis:vis static $name:ident] ($prop_name:path) for $generics:tt $enum_name:ty
+	) => {
+		::core::compile_error!("the `enum_map` is not supported for generic enums");
+	};
+	(
+		// The setters of a const prop
+		@Setters const [$($entry:tt)*]
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Error [$($entry)*]
+			"the `setters` are not supported for `const` properties, since each access may yield a distinct \
+			 copy, thus use a `static` property instead"
+		}
+	};
+	(
+		// The setters, storing into the atomic fields of the properties, after
+		// checking that no branch is a const one, with the default ordering
+		@Setters [] $setters:tt ($prop_name:path) for $generics:tt $enum_name:ty { $($matching:tt)* }
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Setters [SeqCst] $setters ($prop_name) for $generics $enum_name { $($matching)* }
+		}
+	};
+	(
+		@Setters [$ordering:ident] [$($(#[$attr:meta])* $field:ident as $vis:vis fn $name:ident : $ty:ty,)*]
+		($prop_name:path) for $generics:tt $enum_name:ty {
+			$(
+				$(#[$arm_attr:meta])*
+				$branch:pat $(if $guard:expr)? => {
+					$(
+						$struct_fields:tt
+					)*
+				} $(,)?
+			)*
+		}
+	) => {
+		$(
+			$crate::internal_props_impl_macro!{
+				@AddressBranch "setters" $branch => { $($struct_fields)* }
+			}
+		)*
+
+		$crate::internal_props_impl_macro!{
+			@Impl $generics [$enum_name] {
+				$(
+					$(#[$attr])*
+					$vis fn $name(&self, value: $ty) {
+						<Self as $crate::EnumProp<$prop_name>>::property(self)
+							.$field
+							.store(value, ::core::sync::atomic::Ordering::$ordering)
+					}
+				)*
+			}
+		}
+	};
+	(
+		// The reverse lookup of the variants by the addresses of their
+		// properties, after checking that no branch is a const one, in the
+		// order of the branches, thus the first variant sharing an address
+		// wins
+		@FromProperty [$($head:tt)*] [$([$(#[$attr:meta])*] $variant:path,)*]
+		($prop_name:path) for $generics:tt $enum_name:ty {
+			$(
+				$(#[$arm_attr:meta])*
+				$branch:pat $(if $guard:expr)? => {
+					$(
+						$struct_fields:tt
+					)*
+				} $(,)?
+			)*
+		}
+	) => {
+		$(
+			$crate::internal_props_impl_macro!{
+				@AddressBranch "from_property" $branch => { $($struct_fields)* }
+			}
+		)*
+
+		$crate::internal_props_impl_macro!{
+			@Impl $generics [$enum_name] {
+				$($head)*(property: &'static $prop_name) -> ::core::option::Option<Self> {
+					let address = $crate::internal_address(property);
+					$(
+						$(#[$attr])*
+						if address == $crate::internal_address(<Self as $crate::EnumProp<$prop_name>>::property(&$variant)) {
+							return ::core::option::Option::Some($variant);
+						}
+					)*
+					::core::option::Option::None
+				}
+			}
+		}
+	};
+	(
+		// The aggregates, done
+		@Aggregate [$(,)?] mod $modifier:tt ($prop_name:path) for $generics:tt $enum_name:ty { $($matching:tt)* }
+	) => {};
+	(
+		// A single aggregate, folding the field over the properties of all
+		// branches, which must be of a primitive number type, since only
+		// these can be compared and added in a const
+		@Aggregate [
+			$(#[$attr:meta])* $op:ident $field:ident as $vis:vis $name:ident : $field_ty:ty
+			$(, $($aggregates:tt)*)?
+		]
+		mod $modifier:tt ($prop_name:path) for $generics:tt $enum_name:ty {
+			$(
+				$(#[$arm_attr:meta])*
+				$branch:pat $(if $guard:expr)? => {
+					$(
+						$struct_fields:tt
+					)*
+				} $(,)?
+			)*
+		}
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Impl $generics [$enum_name] {
+				$(#[$attr])*
+				$vis const $name: $field_ty = {
+					const fn aggregated<T: $crate::InternalAggregate>() {}
+					aggregated::<$field_ty>();
+
+					let branches: &[&$prop_name] = &[
+						$(
+							$(#[$arm_attr])*
+							{
+								$crate::internal_props_impl_macro!(
+									@ArmBranch mod $modifier $prop_name, $generics $enum_name, $branch => {
+										$( $struct_fields )*
+									}
+								)
+							},
+						)*
+					];
+					let mut value: $field_ty = branches[0].$field;
+					let mut i = 1;
+					while i < branches.len() {
+						value = $crate::internal_props_impl_macro!(@AggregateOp $op value, branches[i].$field);
+						i += 1;
+					}
+					value
+				};
+			}
+		}
+
+		$crate::internal_props_impl_macro!{
+			@Aggregate [$($($aggregates)*)?] mod $modifier ($prop_name) for $generics $enum_name {
+				$(
+					$(#[$arm_attr])*
+					$branch $(if $guard)? => {
+						$( $struct_fields )*
+					}
+				)*
+			}
+		}
+	};
+	(
+		// The maximum of two values
+		@AggregateOp max $value:expr, $next:expr
+	) => {
+		if $next > $value { $next } else { $value }
+	};
+	(
+		// The minimum of two values
+		@AggregateOp min $value:expr, $next:expr
+	) => {
+		if $next < $value { $next } else { $value }
+	};
+	(
+		// The sum of two values
+		@AggregateOp sum $value:expr, $next:expr
+	) => {
+		$value + $next
+	};
+	(
+		// Any other aggregate
+		@AggregateOp $op:ident $value:expr, $next:expr
+	) => {
+		::core::compile_error!(::core::concat!(
+			"unknown aggregate `",
+			::core::stringify!($op),
+			"`, expected `max`, `min`, or `sum`"
+		))
+	};
+	(
+		// The variants as listed by the branches
+		@VariantList [$($head:tt)*] [$([$(#[$attr:meta])*] $variant:path,)*] for $generics:tt $enum_name:ty
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Impl $generics [$enum_name] {
+				$($head)* : [Self; <[()]>::len(&[$($(#[$attr])* (),)*])] = [$($(#[$attr])* $variant,)*];
+			}
+		}
+	};
+	(
+		// The `ValueEnum` impl, with the variants as listed by the branches,
+		// whose slice is promoted to a static, thus the enum can not be
+		// generic
+		@ValueEnum [$name:ident] [$($help:ident)?] [$([$(#[$attr:meta])*] $variant:path,)*]
+		($prop_name:path) for [] $enum_name:ty
+	) => {
+		$crate::internal_clap!{
+			impl $crate::clap::ValueEnum for $enum_name {
+				fn value_variants<'a>() -> &'a [Self] {
+					&[$($(#[$attr])* $variant,)*]
+				}
+
+				fn to_possible_value(&self) -> ::core::option::Option<$crate::clap::builder::PossibleValue> {
+					let prop = <Self as $crate::EnumProp<$prop_name>>::property(self);
+					::core::option::Option::Some(
+						$crate::clap::builder::PossibleValue::new(prop.$name) $(.help(prop.$help))?
+					)
+				}
+			}
+		}
+	};
+	(
+		@ValueEnum $fields:tt $help:tt $variants:tt ($prop_name:path) for $generics:tt $enum_name:ty
+	) => {
+		::core::compile_error!("the `value_enum` is not supported for generic enums");
+	};
+	(
+		// The weighted sampling, whose cumulative weights of the variants (as
+		// listed by the branches) are computed once, in a lazy static. Since
+		// the static can not refer to `Self` (of the variants), it calls an
+		// associated function via the enum instead.
+		@SampleWeighted $field:ident [$([$(#[$attr:meta])*] $variant:path,)*] ($prop_name:path) for [] $enum_name:ty
+	) => {
+		$crate::internal_rand!{
+			impl $enum_name {
+				/// Picks a random variant, with a probability proportional to
+				#[doc = ::core::concat!("its `", ::core::stringify!($field), "`")]
+				pub fn sample_weighted<R: $crate::rand::Rng + ?::core::marker::Sized>(rng: &mut R) -> Self {
+					let cumulative = $crate::internal_lazy_with_default!(
+						[f32; <[()]>::len(&[$($(#[$attr])* (),)*])],
+						$crate::internal_cumulative(<$enum_name>::internal_sample_weights())
+					);
+					let index = $crate::internal_sample_index(cumulative, rng);
+					::core::iter::IntoIterator::into_iter([$($(#[$attr])* $variant,)*])
+						.nth(index)
+						.unwrap()
+				}
+
+				fn internal_sample_weights() -> [f32; <[()]>::len(&[$($(#[$attr])* (),)*])] {
+					[
+						$(
+							$(#[$attr])*
+							$crate::EnumProp::<$prop_name>::property(&$variant).$field,
+						)*
+					]
+				}
+			}
+		}
+	};
+	(
+		// A `static` can not use the generic parameters of the enum
+		@SampleWeighted $field:ident $variants:tt ($prop_name:path) for $generics:tt $enum_name:ty
+	) => {
+		::core::compile_error!("the `sample_weighted` is not supported for generic enums");
+	};
+	(
+		// The lookup by discriminant, named by default
+		@Discriminant $repr:ident [] $variants:tt ($prop_name:path) for [] $enum_name:ty
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Discriminant $repr [property_of_discriminant] $variants ($prop_name) for [] $enum_name
+		}
+	};
+	(
+		// The lookup by discriminant, via a dense table of the variants (as
+		// listed by the branches) indexed by their discriminant, with `None` in
+		// the gaps. The table is computed at compile time, checking that each
+		// discriminant fits the given integer type, and bounding the table.
+		@Discriminant $repr:ident [$name:ident] [$([$(#[$attr:meta])*] $variant:path,)*] ($prop_name:path) for [] $enum_name:ty
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Impl [] [$enum_name] {
+				/// Gets the property of the variant of the given discriminant, or
+				/// `None` if no variant has it
+				pub fn $name(discriminant: $repr) -> ::core::option::Option<&'static $prop_name> {
+					let table: &'static [::core::option::Option<Self>] = const {
+						&{
+							let mut table = [const { ::core::option::Option::None }; {
+								let mut len = 0;
+								$(
+									$(#[$attr])*
+									{
+										let discriminant = $variant as i128;
+										if discriminant < 0 || discriminant != ($variant as $repr) as i128 {
+											::core::panic!(::core::concat!(
+												"the discriminant of `",
+												::core::stringify!($variant),
+												"` must be non-negative and fit in `",
+												::core::stringify!($repr),
+												"`"
+											));
+										}
+										// Checked before the table is sized by it
+										if discriminant >= 4096 {
+											::core::panic!(::core::concat!(
+												"the discriminant of `",
+												::core::stringify!($variant),
+												"` must be less than 4096, which bounds the dense table of `discriminant`"
+											));
+										}
+										if discriminant as usize >= len {
+											len = discriminant as usize + 1;
+										}
+									}
+								)*
+								len
+							}];
+							$(
+								$(#[$attr])*
+								{
+									table[$variant as usize] = ::core::option::Option::Some($variant);
+								}
+							)*
+							table
+						}
+					};
+					match table.get(discriminant as usize) {
+						::core::option::Option::Some(::core::option::Option::Some(variant)) => {
+							::core::option::Option::Some($crate::EnumProp::<$prop_name>::property(variant))
+						}
+						_ => ::core::option::Option::None,
+					}
+				}
+			}
+		}
+	};
+	(
+		// The table can not use the generic parameters of the enum
+		@Discriminant $repr:ident $name:tt $variants:tt ($prop_name:path) for $generics:tt $enum_name:ty
+	) => {
+		::core::compile_error!("the `discriminant` is not supported for generic enums");
+	};
+	(
+		// The enum prop impl via a table of the values of the branches of the
+		// variants (as listed by the branches), indexed by their discriminant
+		// instead of a match. The table is computed at compile time, checking
+		// that the discriminants are dense, i.e. in `0..N`, thus each index is
+		// in bounds, and since they are unique, each entry is set.
+		@EnumPropTable [$([$(#[$attr:meta])*] $variant:path,)*] ($prop_name:path) for $generics:tt $enum_name:ty {
+			$(
+				$(#[$arm_attr:meta])*
+				$branch:pat $(if $guard:expr)? => {
+					$(
+						$struct_fields:tt
+					)*
+				} $(,)?
+			)*
+		}
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Impl $generics [$crate::EnumProp<$prop_name> for $enum_name] {
+				// The entries are replaced and forgotten instead of dropped,
+				// which is not supported in a const if the property has a
+				// destructor
+				#[allow(
+					clippy::forget_non_drop,
+					clippy::mem_replace_option_with_none,
+					clippy::mem_replace_option_with_some
+				)]
+				fn property(&self) -> &'static $prop_name {
+					let table: &'static [$prop_name; <[()]>::len(&[$($(#[$attr])* (),)*])] = const {
+						&{
+							let variants = [$($(#[$attr])* $variant,)*];
+							let discriminants = [$($(#[$attr])* $variant as usize,)*];
+							let mut entries: [::core::option::Option<$prop_name>; <[()]>::len(&[$($(#[$attr])* (),)*])] =
+								[const { ::core::option::Option::None }; <[()]>::len(&[$($(#[$attr])* (),)*])];
+							let mut index = 0;
+							while index < variants.len() {
+								if discriminants[index] >= variants.len() {
+									::core::panic!(
+										"the `table` requires the discriminants to be dense, i.e. `0..N` for `N` variants"
+									);
+								}
+								::core::mem::forget(::core::mem::replace(
+									&mut entries[discriminants[index]],
+									::core::option::Option::Some(
+										match (&variants[index], ::core::marker::PhantomData::<$prop_name>) {
+											$(
+												$(#[$arm_attr])*
+												($branch, _) $(if $guard)? => {
+													$crate::internal_props_impl_macro!(
+														@TableValue $prop_name [$branch] {
+															$( $struct_fields )*
+														}
+													)
+												},
+											)*
+										},
+									),
+								));
+								index += 1;
+							}
+							// The entries in the order of the discriminants, all of
+							// them are set, since the discriminants are unique
+							let mut index = 0;
+							let table = [$(
+								$(#[$attr])*
+								{
+									index += 1;
+									::core::mem::replace(&mut entries[index - 1], ::core::option::Option::None).unwrap()
+								},
+							)*];
+							if index != entries.len() {
+								::core::unreachable!();
+							}
+							::core::mem::forget(entries);
+							table
+						}
+					};
+					&table[$crate::InternalTableCopy::internal_copy(self) as usize]
+				}
+			}
+		}
+	};
+	(
+		// The table holds the values of the branches, thus a branch can not
+		// override the modifier
+		@TableValue $prop_name:path [$branch:pat] {
+			@Mod $arm_modifier:tt $($struct_fields:tt)*
+		}
+	) => {
+		::core::compile_error!(::core::concat!(
+			"the branch `",
+			::core::stringify!($branch),
+			"` can not override the modifier of a property with a `table`"
+		))
+	};
+	(
+		// A reference to an existing item, i.e. a named value, is a const,
+		// thus the table holds a copy of it
+		@TableValue $prop_name:path [$branch:pat] {
+			[$($defaults:tt)*] & $item:path
+		}
+	) => {
+		$item
+	};
+	(
+		// The value of a branch of the table
+		@TableValue $prop_name:path [$branch:pat] {
+			$($struct_fields:tt)*
+		}
+	) => {
+		$crate::internal_props_impl_macro!(@Value $prop_name {
+			$($struct_fields)*
+		})
+	};
+	(
+		// The sorted variants, done
+		@ConstsArray [@Ordered $field:ident [$($head:tt)*] [$([$(#[$attr:meta])*] $variant:path,)*]]
+		[$($(#[$element_attr:meta])* ($element_variant:path) $element:block,)*] [$($count:tt)*] []
+		mod $modifier:tt ($prop_name:path) for $generics:tt $enum_name:ty { $($matching:tt)* }
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Impl $generics [$enum_name] {
+				$($head)* : [Self; <[()]>::len(&[$($count)*])] = $crate::internal_props_impl_macro!(
+					@Sorted $field [$($(#[$element_attr])* $element,)*] [$($(#[$attr])* $variant,)*]
+				);
+			}
+		}
+	};
+	(
+		// The variants sorted by the field of their properties, via a stable
+		// insertion sort, since there is no sorting in const
+		@Sorted $field:ident [$($props:tt)*] [$($variants:tt)*]
+	) => {{
+		let mut props = [$($props)*];
+		let mut variants = [$($variants)*];
+		let mut i = 1;
+		while i < variants.len() {
+			let mut j = i;
+			while j > 0 && props[j].$field < props[j - 1].$field {
+				props.swap(j, j - 1);
+				variants.swap(j, j - 1);
+				j -= 1;
+			}
+			i += 1;
+		}
+		variants
+	}};
+	(
+		// The table of `EnumPropAll`, done, pairing each variant with its
+		// property, within an inline const, which may use the generic
+		// parameters
+		@ConstsArray [@PropAll] [$($(#[$attr:meta])* ($variant:path) $element:block,)*] [$($count:tt)*] []
+		mod $modifier:tt ($prop_name:path) for $generics:tt $enum_name:ty { $($matching:tt)* }
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Impl $generics [$crate::EnumPropAll<$prop_name> for $enum_name] {
+				fn all() -> &'static [(Self, &'static $prop_name)] {
+					const {
+						&[
+							$(
+								$(#[$attr])*
+								($variant, $element),
+							)*
+						]
+					}
+				}
+			}
+		}
+	};
+	(
+		// The `all` table of a static prop, holding the statics of the
+		// branches, each shared by the entries of its variants
+		@StaticAll [$($head:tt)*] $matching:tt [$([$(#[$attr:meta])*] $variant:path,)*]
+		($prop_name:path) for $generics:tt $enum_name:ty {
+			$(
+				$(#[$arm_attr:meta])*
+				$branch:pat $(if $guard:expr)? => {
+					$(
+						$struct_fields:tt
+					)*
+				} $(,)?
+			)*
+		}
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Impl $generics [$enum_name] {
+				$($head)* : [&'static $prop_name; <[()]>::len(&[$($(#[$attr])* (),)*])] = {
+					let branches: [&'static $prop_name; <[()]>::len(&[$($(#[$arm_attr])* (),)*])] = [
+						$(
+							$(#[$arm_attr])*
+							$crate::internal_props_impl_macro!(
+								@ArmBranch mod(static) $prop_name, $generics $enum_name, $branch => {
+									$( $struct_fields )*
+								}
+							),
+						)*
+					];
+					[
+						$(
+							$(#[$attr])*
+							branches[$crate::internal_props_impl_macro!(
+								@StaticAllBranch ($variant) ($prop_name) $matching
+							)],
+						)*
+					]
+				};
+			}
+		}
+	};
+	(
+		// The index of the first branch matching the variant
+		@StaticAllBranch ($variant:path) ($prop_name:path) {
+			$(
+				$(#[$arm_attr:meta])*
+				$branch:pat $(if $guard:expr)? => {
+					$(
+						$struct_fields:tt
+					)*
+				} $(,)?
+			)*
+		}
+	) => {{
+		let matching = [
+			$(
+				$(#[$arm_attr])*
+				::core::matches!(
+					(&$variant, ::core::marker::PhantomData::<$prop_name>),
+					($branch, _) $(if $guard)?
+				),
+			)*
+		];
+		let mut index = 0;
+		while !matching[index] {
+			index += 1;
+		}
+		index
+	}};
+	(
+		// The enum prop impl of a static prop with an `all` table, returning
+		// the entry of the first variant of each branch
+		@StaticAllEnumProp $name:ident $variants:tt ($prop_name:path) for $generics:tt $enum_name:ty {
+			$(
+				$(#[$arm_attr:meta])*
+				$branch:pat $(if $guard:expr)? => {
+					$(
+						$struct_fields:tt
+					)*
+				} $(,)?
+			)*
+		}
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Impl $generics [$crate::EnumProp<$prop_name> for $enum_name] {
+				fn property(&self) -> &'static $prop_name {
+					// Along with the property type, which names it in errors, e.g.
+					// about non-exhaustive patterns
+					#[deny(unreachable_patterns)] // Remember the `Self` prefix
+					match (self, ::core::marker::PhantomData::<$prop_name>) {
+						$(
+							$(#[$arm_attr])*
+							($branch, _) $(if $guard)? => {
+								Self::$name[const {
+									$crate::internal_props_impl_macro!(
+										@StaticAllVariant ($branch) ($prop_name) $variants
+									)
+								}]
+							},
+						)*
+					}
+				}
+			}
+		}
+	};
+	(
+		// The index of the first variant matching the branch
+		@StaticAllVariant ($branch:pat) ($prop_name:path) [$([$(#[$attr:meta])*] $variant:path,)*]
+	) => {{
+		let matching = [
+			$(
+				$(#[$attr])*
+				::core::matches!(
+					(&$variant, ::core::marker::PhantomData::<$prop_name>),
+					($branch, _)
+				),
+			)*
+		];
+		let mut index = 0;
+		while !matching[index] {
+			index += 1;
+		}
+		index
+	}};
+	(
+		// The `all` table, done
+		@ConstsArray [$($head:tt)*] [$($(#[$attr:meta])* ($variant:path) $element:block,)*] [$($count:tt)*] []
+		mod $modifier:tt ($prop_name:path) for $generics:tt $enum_name:ty { $($matching:tt)* }
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Impl $generics [$enum_name] {
+				$($head)* : [&'static $prop_name; <[()]>::len(&[$($count)*])] = [
+					$(
+						$(#[$attr])*
+						$element,
+					)*
+				];
+			}
+		}
+	};
+	(
+		// An element of the `all` table, one at a time, each matching its
+		// variant like a single associated const
+		@ConstsArray [$($head:tt)*] [$($elements:tt)*] [$($count:tt)*]
+		[[$(#[$attr:meta])*] $variant:path, $($variants:tt)*]
+		mod $modifier:tt ($prop_name:path) for $generics:tt $enum_name:ty {
+			$(
+				$(#[$arm_attr:meta])*
+				$branch:pat $(if $guard:expr)? => {
+					$(
+						$struct_fields:tt
+					)*
+				} $(,)?
+			)*
+		}
+	) => {
+		$crate::internal_props_impl_macro!{
+			@ConstsArray [$($head)*] [
+				$($elements)*
+				$(#[$attr])*
+				($variant) {
+					match (&$variant, ::core::marker::PhantomData::<$prop_name>) {
+						$(
+							$(#[$arm_attr])*
+							($branch, _) $(if $guard)? => {
+								$crate::internal_props_impl_macro!(
+									@ArmBranch mod $modifier $prop_name, $generics $enum_name, $branch => {
+										$( $struct_fields )*
+									}
+								)
+							},
+						)*
+					}
+				},
+			] [$($count)* $(#[$attr])* (),] [$($variants)*]
+			mod $modifier ($prop_name) for $generics $enum_name {
+				$(
+					$(#[$arm_attr])*
+					$branch $(if $guard)? => {
+						$( $struct_fields )*
+					}
+				)*
+			}
+		}
+	};
+	(
+		// A single associated const, matching the given variant
+		@ConstsItem [$($head:tt)*] ($variant:expr) ($prop_name:path) for $generics:tt $enum_name:ty {
+			$(
+				$(#[$arm_attr:meta])*
+				$branch:pat $(if $guard:expr)? => {
+					$(
+						$struct_fields:tt
+					)*
+				} $(,)?
+			)*
+		}
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Impl $generics [$enum_name] {
+				$($head)* : &'static $prop_name =
+					match (&$variant, ::core::marker::PhantomData::<$prop_name>) {
+						$(
+							$(#[$arm_attr])*
+							($branch, _) $(if $guard)? => {
+								$crate::internal_props_impl_macro!(
+									@ArmBranch mod(const) $prop_name, $generics $enum_name, $branch => {
+										$( $struct_fields )*
+									}
+								)
+							},
+						)*
+					};
+			}
+		}
+	};
+	(
+		// The const prop impl via `EnumPropConst`, entry rule, the table lists
+		// the branches, since the variants are not known, along with the
+		// regular `EnumProp` impl
+		@EnumPropConst
+		mod $modifier:tt ($prop_name:path) for $generics:tt $enum_name:ty {
+			$(
+				$(#[$arm_attr:meta])*
+				$branch:pat $(if $guard:expr)? => {
+					$(
+						$struct_fields:tt
+					)*
+				} $(,)?
+			)*
+		}
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Impl $generics [$crate::EnumPropConst<$prop_name> for $enum_name] {
+				const PROPERTY_TABLE: &'static [&'static $prop_name] = &[
+					$(
+						// Within a block, since attributes can not be applied to
+						// a macro invocation, e.g. `allow` of the wildcard branch
+						$(#[$arm_attr])*
+						{
+							$crate::internal_props_impl_macro!(
+								@ArmBranch mod $modifier $prop_name, $generics $enum_name, $branch => {
+									$( $struct_fields )*
+								}
+							)
+						},
+					)*
+				];
+			}
+		}
+
+		$crate::internal_props_impl_macro!{
+			@EnumProp mod $modifier ($prop_name) for $generics $enum_name {
+				$(
+					$(#[$arm_attr])*
+					$branch $(if $guard)? => {
+						$( $struct_fields )*
+					}
+				)*
+			}
+		}
+	};
+	(
+		// The const prop impl via inherent `const fn`, entry rule, the match is
+		// in the `const fn` itself, since trait methods can not be `const`
+		@EnumPropConstFn
+		[$($head:tt)*] $fn_name:ident ($prop_name:path) for $generics:tt $enum_name:ty {
+			$(
+				$(#[$arm_attr:meta])*
+				$branch:pat $(if $guard:expr)? => {
+					$(
+						$struct_fields:tt
+					)*
+				} $(,)?
+			)*
+		}
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Impl $generics [$enum_name] {
+				$($head)* const fn $fn_name(&self) -> &'static $prop_name {
+					// Along with the property type, which names it in errors, e.g.
+					// about non-exhaustive patterns
+					#[deny(unreachable_patterns)] // Remember the `Self` prefix
+					match (self, ::core::marker::PhantomData::<$prop_name>) {
+						$(
+							$(#[$arm_attr])*
+							($branch, _) $(if $guard)? => {
+								$crate::internal_props_impl_macro!(
+									@ArmBranch mod(const) $prop_name, $generics $enum_name, $branch => {
+										$( $struct_fields )*
+									}
+								)
+							},
+						)*
+					}
+				}
+			}
+		}
+
+		$crate::internal_props_impl_macro!{
+			@Impl $generics [$crate::EnumProp<$prop_name> for $enum_name] {
+				fn property(&self) -> &'static $prop_name {
+					Self::$fn_name(self)
+				}
+			}
+		}
+	};
+	(
+		// The enum prop impl, entry rule
+		@EnumProp
+		mod $modifier:tt ($prop_name:path) for $generics:tt $enum_name:ty {
+			$(
+				// True match branches, could be simplified to `ident`, but then
+				// one can on longer identify e.g. `Beta(42)` (maybe one shouldn't)
+				$(#[$arm_attr:meta])*
+				$branch:pat $(if $guard:expr)? => {
+					$(
+						$struct_fields:tt
+					)*
+				} $(,)?
+			)*
+		}
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Impl $generics [$crate::EnumProp<$prop_name> for $enum_name] {
+				fn property(&self) -> &'static $prop_name {
+					// Along with the property type, which names it in errors, e.g.
+					// about non-exhaustive patterns
+					#[deny(unreachable_patterns)] // Remember the `Self` prefix
+					match (self, ::core::marker::PhantomData::<$prop_name>) {
+						$(
+							$(#[$arm_attr])*
+							($branch, _) $(if $guard)? => {
+								$crate::internal_props_impl_macro!(
+									@ArmBranch mod $modifier $prop_name, $generics $enum_name, $branch => {
+										$( $struct_fields )*
+									}
+								)
+							},
+						)*
+					}
+				}
+			}
+		}
+	};
+
+	(
+		// The return type of a projection, done, since `as` can not be part of
+		// it
+		@ProjectionRet $generics:tt $enum_name:tt $head:tt [$($ret:tt)+] as $($rest:tt)+
+	) => {
+		$crate::internal_props_impl_macro!{
+			@ProjectionRef $generics $enum_name $head [$($ret)+] $($rest)+
+		}
+	};
+	(
+		// Collecting the return type of a projection
+		@ProjectionRet $generics:tt $enum_name:tt $head:tt [$($ret:tt)*] $next:tt $($rest:tt)+
+	) => {
+		$crate::internal_props_impl_macro!{
+			@ProjectionRet $generics $enum_name $head [$($ret)* $next] $($rest)+
+		}
+	};
+	(
+		// The return type of a projection, without the projected field
+		@ProjectionRet $generics:tt $enum_name:tt $head:tt [$($ret:tt)*] $($last:tt)?
+	) => {
+		::core::compile_error!(
+			"a projection must be followed by its field, e.g. \
+			`fn <NAME> -> <TYPE> as const <PROPERTY>.<FIELD> { ... }`"
+		);
+	};
+	(
+		// A projection returning a reference, with a lifetime
+		@ProjectionRef $generics:tt $enum_name:tt $head:tt [& $lifetime:lifetime $ret:ty] $($rest:tt)+
+	) => {
+		$crate::internal_props_impl_macro!{
+			@ProjectionProp $generics $enum_name $head [&] (& $lifetime $ret) $($rest)+
+		}
+	};
+	(
+		// A projection returning a reference
+		@ProjectionRef $generics:tt $enum_name:tt $head:tt [& $ret:ty] $($rest:tt)+
+	) => {
+		$crate::internal_props_impl_macro!{
+			@ProjectionProp $generics $enum_name $head [&] (& $ret) $($rest)+
+		}
+	};
+	(
+		// A projection returning a value
+		@ProjectionRef $generics:tt $enum_name:tt $head:tt [$ret:ty] $($rest:tt)+
+	) => {
 		$crate::internal_props_impl_macro!{
-			@EnumProp
-			mod($modifier) ($prop_name) for $enum_name {
+			@ProjectionProp $generics $enum_name $head [] ($ret) $($rest)+
+		}
+	};
+	(
+		// A projection along with the branches of its property
+		@ProjectionProp $generics:tt [$enum_name:ty] $head:tt $reference:tt ($ret:ty)
+		$modifier:ident $(($($args:tt)*))? $prop_name:ident $(:: $prop_segment:ident)* . $field:ident { $($matching:tt)* }
+	) => {
+		// Add the EnumProp impl
+		$crate::props!{
+			impl @Generics $generics EnumProp for $enum_name as $modifier $(($($args)*))? $prop_name $(:: $prop_segment)* {
 				$($matching)*
 			}
 		}
-	};
-}
 
-// The internal marco impl, used by `props`, do not use, its API may change
-// at any time
-#[doc(hidden)]
-#[macro_export]
-macro_rules! internal_props_impl_macro {
+		// Add the projecting inherent method
+		$crate::internal_props_impl_macro!{
+			@Projection $generics [$enum_name] $head $reference ($ret) ($prop_name $(:: $prop_segment)*) $field
+		}
+	};
+	(
+		// A further projection of an existing property
+		@ProjectionProp $generics:tt [$enum_name:ty] $head:tt $reference:tt ($ret:ty)
+		$prop_name:ident $(:: $prop_segment:ident)* . $field:ident $(;)?
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Projection $generics [$enum_name] $head $reference ($ret) ($prop_name $(:: $prop_segment)*) $field
+		}
+	};
+	(
+		// Any other projection
+		@ProjectionProp $generics:tt $enum_name:tt $head:tt $reference:tt $ret:tt $($rest:tt)*
+	) => {
+		::core::compile_error!(::core::concat!(
+			"invalid projection `as ",
+			::core::stringify!($($rest)*),
+			"`, expected e.g. `as const <PROPERTY>.<FIELD> { ... }` or `as <PROPERTY>.<FIELD>`"
+		));
+	};
+	(
+		// The function pointers of a branch of a trait implemented per variant,
+		// its methods are defined by a local trait, thus they may use `self`
+		// (which is kept as given, for hygiene)
+		@Vtable ($enum_name:ty) {
+			$(
+				$(#[$method_attr:meta])*
+				fn $method:ident (& $this:tt $(, $arg:ident : $arg_type:ty)* $(,)?) $(-> $ret:ty)? $body:block
+			)*
+		}
+	) => {{
+		trait InternalPropsBranch {
+			$(
+				fn $method(&self $(, $arg: $arg_type)*) $(-> $ret)?;
+			)*
+		}
+
+		impl InternalPropsBranch for $enum_name {
+			$(
+				$(#[$method_attr])*
+				fn $method(& $this $(, $arg: $arg_type)*) $(-> $ret)? $body
+			)*
+		}
+
+		InternalPropsVtable {
+			$(
+				$method: <$enum_name as InternalPropsBranch>::$method,
+			)*
+		}
+	}};
+	(
+		// The calling methods, done
+		@Calls $generics:tt $enum_name:tt [$($attrs:tt)*] [$($vis:tt)*] ($prop_name:path) $(,)?
+	) => {};
+	(
+		// An inherent method calling the function pointer field of a property
+		// with the given arguments
+		@Calls $generics:tt $enum_name:tt [$($attrs:tt)*] [$($vis:tt)*] ($prop_name:path)
+		$(#[$call_attr:meta])* $field:ident ( $($arg:ident : $arg_type:ty),* $(,)? ) $(-> $ret:ty)?
+		$(, $($rest:tt)*)?
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Impl $generics $enum_name {
+				$($attrs)*
+				$(#[$call_attr])*
+				$($vis)* fn $field(&self $(, $arg: $arg_type)*) $(-> $ret)? {
+					($crate::EnumProp::<$prop_name>::property(self).$field)($($arg),*)
+				}
+			}
+		}
+
+		$crate::internal_props_impl_macro!{
+			@Calls $generics $enum_name [$($attrs)*] [$($vis)*] ($prop_name) $($($rest)*)?
+		}
+	};
+	(
+		// The projected fields, done
+		@Fields $generics:tt $enum_name:tt [$($attrs:tt)*] [$($vis:tt)*] ($prop_name:path) $(,)?
+	) => {};
+	(
+		// A projected field returning a reference, with a lifetime
+		@Fields $generics:tt $enum_name:tt [$($attrs:tt)*] [$($vis:tt)*] ($prop_name:path)
+		$(#[$field_attr:meta])* $field:ident : & $lifetime:lifetime $ret:ty $(, $($rest:tt)*)?
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Projection $generics $enum_name [$($attrs)* $(#[$field_attr])* $($vis)* fn $field] [&]
+			(& $lifetime $ret) ($prop_name) $field
+		}
+
+		$crate::internal_props_impl_macro!{
+			@Fields $generics $enum_name [$($attrs)*] [$($vis)*] ($prop_name) $($($rest)*)?
+		}
+	};
+	(
+		// A projected field returning a reference
+		@Fields $generics:tt $enum_name:tt [$($attrs:tt)*] [$($vis:tt)*] ($prop_name:path)
+		$(#[$field_attr:meta])* $field:ident : & $ret:ty $(, $($rest:tt)*)?
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Projection $generics $enum_name [$($attrs)* $(#[$field_attr])* $($vis)* fn $field] [&]
+			(& $ret) ($prop_name) $field
+		}
+
+		$crate::internal_props_impl_macro!{
+			@Fields $generics $enum_name [$($attrs)*] [$($vis)*] ($prop_name) $($($rest)*)?
+		}
+	};
+	(
+		// A projected field returning a value
+		@Fields $generics:tt $enum_name:tt [$($attrs:tt)*] [$($vis:tt)*] ($prop_name:path)
+		$(#[$field_attr:meta])* $field:ident : $ret:ty $(, $($rest:tt)*)?
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Projection $generics $enum_name [$($attrs)* $(#[$field_attr])* $($vis)* fn $field] []
+			($ret) ($prop_name) $field
+		}
+
+		$crate::internal_props_impl_macro!{
+			@Fields $generics $enum_name [$($attrs)*] [$($vis)*] ($prop_name) $($($rest)*)?
+		}
+	};
+	(
+		// The inherent method projecting a single field of a property, either
+		// by reference (which also dereferences a field that is a reference
+		// itself, e.g. `&'static str`) or by value (requiring it to be `Copy`)
+		@Projection $generics:tt [$enum_name:ty] [$($head:tt)*] [$($reference:tt)?]
+		($ret:ty) ($prop_name:path) $field:ident
+	) => {
+		$crate::internal_props_impl_macro!{
+			@Impl $generics [$enum_name] {
+				$($head)*(&self) -> $ret {
+					$($reference)? $crate::EnumProp::<$prop_name>::property(self).$field
+				}
+			}
+		}
+	};
+	(
+		// An impl of a non-generic enum
+		@Impl [] [$($head:tt)*] { $($body:tt)* }
+	) => {
+		impl $($head)* {
+			$($body)*
+		}
+	};
+	(
+		// An impl with the given generic parameters and `where` clause
+		@Impl [[$($generics:tt)*] [$($where_clause:tt)*]] [$($head:tt)*] { $($body:tt)* }
+	) => {
+		impl<$($generics)*> $($head)* $($where_clause)* {
+			$($body)*
+		}
+	};
+
+	(
+		// The value of a property, a struct literal of the given fields
+		@Value $prop_name:path {
+			[]
+			$(
+				$field:ident : $value:expr
+			),* $(,)?
+		}
+	) => {{
+		$prop_name {
+			$(
+				$field : $value ,
+			)*
+		}
+	}};
+	(
+		// The value of a property, given as positional values of a tuple
+		// struct
+		@Value $prop_name:path {
+			[$($defaults:tt)*]
+			( $($value:expr),* $(,)? )
+		}
+	) => {{
+		$prop_name( $($value),* )
+	}};
+	(
+		// The value of a property, given as an expression
+		@Value $prop_name:path {
+			[$($defaults:tt)*]
+			= $value:expr
+		}
+	) => {
+		$value
+	};
+	(
+		// A reference to an existing item is no value, thus it is only
+		// supported by `const` and `static` properties, see `@Branch`
+		@Value $prop_name:path {
+			[$($defaults:tt)*]
+			& $item:path
+		}
+	) => {
+		::core::compile_error!(
+			"a reference to an existing item is only supported for `const` and \
+			`static` properties, use an expression instead"
+		)
+	};
+	(
+		// A delegating branch has no value of its own, see `@Branch`
+		@Value $prop_name:path {
+			[$($defaults:tt)*]
+			@Delegate $inner:expr
+		}
+	) => {
+		::core::compile_error!(
+			"a branch delegating to the property of an inner enum has no value of \
+			its own, e.g. to take the remaining fields from via `..like`"
+		)
+	};
+	(
+		// The value of a property, with the remaining fields taken from the
+		// value of another branch, which is an existing item
+		@Value $prop_name:path {
+			[]
+			$(
+				$field:ident : $value:expr ,
+			)*
+			.. @Like { [$($defaults:tt)*] & $item:path }
+		}
+	) => {
+		$crate::internal_props_impl_macro!(
+			@ValueUpdate $prop_name [$($field : $value ,)*] $item
+		)
+	};
+	(
+		// The value of a property, with the remaining fields taken from the
+		// value of another branch, i.e. evaluating its body again
+		@Value $prop_name:path {
+			[]
+			$(
+				$field:ident : $value:expr ,
+			)*
+			.. @Like { $($body:tt)* }
+		}
+	) => {
+		$crate::internal_props_impl_macro!(
+			@ValueUpdate $prop_name [$($field : $value ,)*]
+			$crate::internal_props_impl_macro!(@Value $prop_name { $($body)* })
+		)
+	};
+	(
+		// The value of a property, with the remaining fields taken from the
+		// base value, i.e. the struct update syntax
+		@Value $prop_name:path {
+			[]
+			$(
+				$field:ident : $value:expr ,
+			)*
+			.. $base:expr
+		}
+	) => {
+		$crate::internal_props_impl_macro!(
+			@ValueUpdate $prop_name [$($field : $value ,)*] $base
+		)
+	};
+	(
+		// The value of a property, given as the base value with the given
+		// fields assigned. Unlike a struct literal, this only requires the
+		// given fields to be accessible, thus it also supports e.g. a
+		// `non_exhaustive` struct of another crate.
+		@ValueUpdate $prop_name:path [$($field:ident $(. $path:ident)* : $value:expr ,)*] $base:expr
+	) => {{
+		#[allow(unused_mut, clippy::field_reassign_with_default)]
+		let mut value: $prop_name = $base;
+		$(
+			value.$field $(. $path)* = $value;
+		)*
+		value
+	}};
+	(
+		// The value of a property, merged with the default fields
+		@Value $prop_name:path {
+			[$($default:ident : $default_value:expr),+ $(,)?]
+			$(
+				$field:ident : $value:expr
+			),* $(,)?
+		}
+	) => {
+		$crate::internal_props_impl_macro!(
+			@ValueDefaults ($) $prop_name [$($default : $default_value ,)+] [$($field : $value ,)*] []
+		)
+	};
+	(
+		// The value of a property, merged with the default fields, with the
+		// remaining fields taken from the base value
+		@Value $prop_name:path {
+			[$($default:ident : $default_value:expr),+ $(,)?]
+			$(
+				$field:ident : $value:expr ,
+			)*
+			.. $base:expr
+		}
+	) => {
+		$crate::internal_props_impl_macro!(
+			@ValueDefaults ($) $prop_name [$($default : $default_value ,)+] [$($field : $value ,)*] [$base]
+		)
+	};
+	(
+		// Dotted field paths are assigned onto a base value, since the types
+		// of the nested structs are unknown, their literals can not be built
+		@Value $prop_name:path {
+			[]
+			$(
+				$field:ident $(. $path:ident)* : $value:expr
+			),* $(,)?
+		}
+	) => {
+		::core::compile_error!(
+			"dotted field paths require a base value holding the nested structs, \
+			i.e. `defaults`, `..<BASE>`, or `..like <VARIANT>`"
+		)
+	};
+	(
+		// The value of a property, with dotted field paths, which are assigned
+		// onto the value of the other fields
+		@Value $prop_name:path {
+			[$($defaults:tt)*]
+			$(
+				$field:ident $(. $path:ident)* : $value:expr
+			),* $(,)?
+		}
+	) => {
+		$crate::internal_props_impl_macro!(
+			@ValueNested ($) $prop_name [$($defaults)*] [] [] [] $($field $(. $path)* : $value ,)*
+		)
+	};
+	(
+		// The value of a property, with dotted field paths, and the remaining
+		// fields taken from the base value
+		@Value $prop_name:path {
+			[$($defaults:tt)*]
+			$(
+				$field:ident $(. $path:ident)* : $value:expr ,
+			)*
+			.. $($base:tt)+
+		}
+	) => {
+		$crate::internal_props_impl_macro!(
+			@ValueNested ($) $prop_name [$($defaults)*] [.. $($base)+] [] []
+			$($field $(. $path)* : $value ,)*
+		)
+	};
+	(
+		// Splitting the fields from the dotted field paths, a field
+		@ValueNested ($d:tt) $prop_name:path [$($defaults:tt)*] [$($base:tt)*]
+		[$($fields:tt)*] [$($paths:tt)*]
+		$field:ident : $value:expr , $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!(
+			@ValueNested ($d) $prop_name [$($defaults)*] [$($base)*]
+			[$($fields)* $field : $value ,] [$($paths)*] $($rest)*
+		)
+	};
+	(
+		// Splitting the fields from the dotted field paths, a dotted field path
+		@ValueNested ($d:tt) $prop_name:path [$($defaults:tt)*] [$($base:tt)*]
+		[$($fields:tt)*] [$($paths:tt)*]
+		$field:ident $(. $path:ident)+ : $value:expr , $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!(
+			@ValueNested ($d) $prop_name [$($defaults)*] [$($base)*]
+			[$($fields)*] [$($paths)* $field $(. $path)+ : $value ,] $($rest)*
+		)
+	};
+	(
+		// Splitting the fields from the dotted field paths, done. No field may
+		// be assigned both as a whole and via dotted field paths, since fields
+		// can not be compared directly, this defines a local macro (via the
+		// passed `$`) matching the dotted field paths of each field.
+		@ValueNested ($d:tt) $prop_name:path [$($defaults:tt)*] [$($base:tt)*]
+		[$($field:ident : $value:expr ,)*]
+		[$($head:ident $(. $path:ident)+ : $path_value:expr ,)*]
+	) => {{
+		macro_rules! internal_props_whole_field {
+			$(
+				($field . $d($d path:tt)+) => {
+					::core::compile_error!(::core::concat!(
+						"the field `",
+						::core::stringify!($field),
+						"` is assigned both as a whole and via dotted field paths"
+					))
+				};
+			)*
+			($d($d path:tt)+) => {};
+		}
+
+		$(
+			internal_props_whole_field!($head $(. $path)+);
+		)*
+
+		$crate::internal_props_impl_macro!(
+			@ValueUpdate $prop_name [$($head $(. $path)+ : $path_value ,)*]
+			$crate::internal_props_impl_macro!(
+				@Value $prop_name { [$($defaults)*] $($field : $value ,)* $($base)* }
+			)
+		)
+	}};
+	(
+		// Merging the default fields into the given fields, skipping those
+		// that are given explicitly. Since fields can not be compared
+		// directly, this defines a local macro (via the passed `$`) matching
+		// the given fields.
+		@ValueDefaults ($d:tt) $prop_name:path
+		[$($default:ident : $default_value:expr ,)+]
+		[$($field:ident : $value:expr ,)*]
+		[$($base:expr)?]
+	) => {{
+		macro_rules! internal_props_merge_defaults {
+			(
+				// All defaults merged
+				[$d($d merged:tt)*]
+			) => {
+				$crate::internal_props_impl_macro!(
+					@ValueMerged $prop_name [$($field : $value ,)* $d($d merged)*] [$($base)?]
+				)
+			};
+			$(
+				(
+					// A default that is given explicitly, thus it is skipped
+					[$d($d merged:tt)*] $field : $d value:expr , $d($d rest:tt)*
+				) => {
+					internal_props_merge_defaults!([$d($d merged)*] $d($d rest)*)
+				};
+			)*
+			(
+				// A default that is not given explicitly
+				[$d($d merged:tt)*] $d default:ident : $d value:expr , $d($d rest:tt)*
+			) => {
+				internal_props_merge_defaults!([$d($d merged)* $d default : $d value ,] $d($d rest)*)
+			};
+		}
+
+		internal_props_merge_defaults!([] $($default : $default_value ,)+)
+	}};
+
 	(
-		// The enum prop impl, entry rule
-		@EnumProp
-		mod($modifier:ident) ($prop_name:path) for $enum_name:ty {
+		// The value of a property, of the merged fields
+		@ValueMerged $prop_name:path [$($field:ident : $value:expr ,)*] []
+	) => {{
+		$prop_name {
 			$(
-				// True match branches, could be simplified to `ident`, but then
-				// one can on longer identify e.g. `Beta(42)` (maybe one shouldn't)
-				$branch:pat => {
-					$(
-						$struct_fields:tt
-					)*
-				} $(,)?
+				$field : $value ,
 			)*
 		}
+	}};
+	(
+		// The value of a property, of the merged fields assigned to the base
+		// value
+		@ValueMerged $prop_name:path [$($field:ident : $value:expr ,)*] [$base:expr]
 	) => {
-		impl $crate::EnumProp<$prop_name> for $enum_name {
-			fn property(&self) -> &'static $prop_name {
-				#[deny(unreachable_patterns)] // Remember the `Self` prefix
-				match self {
-					$(
-						$branch => {
-							$crate::internal_props_impl_macro!(
-								@Branch mod($modifier) $prop_name {
-									$( $struct_fields )*
-								}
-							)
-						},
-					)*
+		$crate::internal_props_impl_macro!(
+			@ValueUpdate $prop_name [$($field : $value ,)*] $base
+		)
+	};
+
+	(
+		// A branch of a dedup prop, asking the local macro for its value
+		@ArmBranch
+		mod $modifier:tt $prop_name:path, $generics:tt $enum_name:ty, $branch:pat => {
+			[$($defaults:tt)*] @Dedup $pattern:tt $raw:tt $($struct_fields:tt)*
+		}
+	) => {
+		internal_props_dedup!($raw $pattern {
+			$crate::internal_props_impl_macro!(
+				@ArmBranch mod $modifier $prop_name, $generics $enum_name, $branch => {
+					[$($defaults)*] $($struct_fields)*
 				}
+			)
+		})
+	};
+	(
+		// A branch overriding the modifier by `lazy`, which is initialized at
+		// its first access, like the branches of `lazy` properties
+		@ArmBranch
+		mod $modifier:tt $prop_name:path, $generics:tt $enum_name:ty, $branch:pat => {
+			@Mod(lazy $($args:tt)?) $($struct_fields:tt)*
+		}
+	) => {{
+		let init = true;
+		match $crate::internal_props_impl_macro!(
+			@Branch mod(lazy $($args)?) $prop_name, init, $generics $enum_name, $branch => {
+				$($struct_fields)*
+			}
+		) {
+			::core::option::Option::Some(prop) => prop,
+			::core::option::Option::None => ::core::unreachable!(),
+		}
+	}};
+	(
+		// A branch overriding the modifier, e.g. by `static`
+		@ArmBranch
+		mod $modifier:tt $prop_name:path, $generics:tt $enum_name:ty, $branch:pat => {
+			@Mod $arm_modifier:tt $($struct_fields:tt)*
+		}
+	) => {
+		$crate::internal_props_impl_macro!(
+			@Branch mod $arm_modifier $prop_name {
+				$($struct_fields)*
+			}
+		)
+	};
+	(
+		// A branch with the modifier of the property
+		@ArmBranch
+		mod $modifier:tt $prop_name:path, $generics:tt $enum_name:ty, $branch:pat => {
+			$($struct_fields:tt)*
+		}
+	) => {
+		$crate::internal_props_impl_macro!(
+			@Branch mod $modifier $prop_name {
+				$($struct_fields)*
+			}
+		)
+	};
+
+	(
+		// A branch of a *keyed* prop overriding the modifier, e.g. by `static`
+		@Branch
+		mod(keyed $modifier:tt) $prop_name:path, $generics:tt $enum_name:ty, $branch:pat => {
+			@Mod $arm_modifier:tt $($struct_fields:tt)*
+		}
+	) => {
+		$crate::internal_props_impl_macro!(
+			@ArmBranch mod $arm_modifier $prop_name, $generics $enum_name, $branch => {
+				@Mod $arm_modifier $($struct_fields)*
+			}
+		)
+	};
+	(
+		// A branch of a *keyed* prop with the modifier of the property, as if
+		// overridden by it, since `lazy` branches get no `init` flag here
+		@Branch
+		mod(keyed $modifier:tt) $prop_name:path, $generics:tt $enum_name:ty, $branch:pat => {
+			$($struct_fields:tt)*
+		}
+	) => {
+		$crate::internal_props_impl_macro!(
+			@ArmBranch mod $modifier $prop_name, $generics $enum_name, $branch => {
+				@Mod $modifier $($struct_fields)*
+			}
+		)
+	};
+
+	(
+		// A branch of a *ref* prop delegating to the property of an inner
+		// enum, which may be borrowed as well
+		@Branch
+		mod(ref $modifier:tt) $prop_name:path, $generics:tt $enum_name:ty, $branch:pat => {
+			[$($defaults:tt)*] @Delegate $inner:expr
+		}
+	) => {
+		$crate::EnumPropRef::<$prop_name>::property_ref($inner)
+	};
+	(
+		// A branch of a *ref* prop given as an expression, which is the
+		// reference itself, thus it may borrow from the data of the variant
+		@Branch
+		mod(ref $modifier:tt) $prop_name:path, $generics:tt $enum_name:ty, $branch:pat => {
+			[$($defaults:tt)*] = $value:expr
+		}
+	) => {
+		$value
+	};
+	(
+		// A branch of a *ref* prop referencing an existing item
+		@Branch
+		mod(ref $modifier:tt) $prop_name:path, $generics:tt $enum_name:ty, $branch:pat => {
+			[$($defaults:tt)*] & $item:path
+		}
+	) => {
+		& $item
+	};
+	(
+		// A `const` branch of a *ref* prop, as an inline const, which unlike
+		// a const item may use the generic parameters, e.g. the lifetimes of a
+		// borrowing property
+		@Branch
+		mod(ref $modifier:tt) $prop_name:path, $generics:tt $enum_name:ty, $branch:pat => {
+			@Mod(const) $($struct_fields:tt)*
+		}
+	) => {
+		& const {
+			$crate::internal_props_impl_macro!(@Value $prop_name {
+				$($struct_fields)*
+			})
+		}
+	};
+	(
+		// A branch of a *ref* prop overriding the modifier, e.g. by `static`
+		@Branch
+		mod(ref $modifier:tt) $prop_name:path, $generics:tt $enum_name:ty, $branch:pat => {
+			@Mod $arm_modifier:tt $($struct_fields:tt)*
+		}
+	) => {
+		$crate::internal_props_impl_macro!(
+			@ArmBranch mod $arm_modifier $prop_name, $generics $enum_name, $branch => {
+				@Mod $arm_modifier $($struct_fields)*
+			}
+		)
+	};
+	(
+		// A branch of a *ref* prop given as fields, with the modifier of the
+		// property
+		@Branch
+		mod(ref [$($modifier:tt)+]) $prop_name:path, $generics:tt $enum_name:ty, $branch:pat => {
+			$($struct_fields:tt)*
+		}
+	) => {
+		$crate::internal_props_impl_macro!(
+			@Branch mod(ref [$($modifier)+]) $prop_name, $generics $enum_name, $branch => {
+				@Mod($($modifier)+) $($struct_fields)*
+			}
+		)
+	};
+
+	(
+		// A branch of a *memo* prop delegating to the property of an inner
+		// enum, which is not cached
+		@Branch
+		mod(memo) $prop_name:path, $cache:ident, $this:tt, $generics:tt $enum_name:ty, $branch:pat => {
+			[$($defaults:tt)*] @Delegate $inner:expr
+		}
+	) => {
+		$crate::internal_props_impl_macro!(@Delegate $prop_name, $inner)
+	};
+	(
+		// A branch of a *memo* prop overriding the modifier, e.g. by `const`,
+		// which is not cached
+		@Branch
+		mod(memo) $prop_name:path, $cache:ident, $this:tt, $generics:tt $enum_name:ty, $branch:pat => {
+			@Mod $arm_modifier:tt $($struct_fields:tt)*
+		}
+	) => {
+		$crate::internal_props_impl_macro!(
+			@ArmBranch mod(memo) $prop_name, $generics $enum_name, $branch => {
+				@Mod $arm_modifier $($struct_fields)*
+			}
+		)
+	};
+	(
+		// A single *memo* prop value, computed once for each value of the enum,
+		// e.g. from the data of the variant bound by the branch
+		@Branch
+		mod(memo) $prop_name:path, $cache:ident, $this:tt, $generics:tt $enum_name:ty, $branch:pat => {
+			$($struct_fields:tt)*
+		}
+	) => {{
+		let cache = $cache.get_or_init(::core::default::Default::default);
+		let cached = cache
+			.lock()
+			.unwrap_or_else(|poisoned| poisoned.into_inner())
+			.get($this)
+			.copied();
+
+		match cached {
+			::core::option::Option::Some(prop) => prop,
+			::core::option::Option::None => {
+				// Computed without holding the lock, thus it may access the
+				// property of other values
+				let value = $crate::internal_props_impl_macro!(@Value $prop_name {
+					$($struct_fields)*
+				});
+
+				// If another thread was faster, its value is kept, thus all
+				// accesses of the same value share the same reference
+				*cache
+					.lock()
+					.unwrap_or_else(|poisoned| poisoned.into_inner())
+					.entry(::core::clone::Clone::clone($this))
+					.or_insert_with(|| $crate::Box::leak($crate::Box::new(value)))
+			}
+		}
+	}};
+
+	(
+		// A branch of a `lazy` property delegating to the property of an inner
+		// enum, which has no lazy cell of its own, thus it is always
+		// initialized
+		@Branch
+		mod $modifier:tt $prop_name:path, $init:ident, $generics:tt $enum_name:ty, $branch:pat => {
+			[$($defaults:tt)*] @Delegate $inner:expr
+		}
+	) => {
+		::core::option::Option::Some($crate::internal_props_impl_macro!(@Delegate $prop_name, $inner))
+	};
+	(
+		// A branch of a `lazy` property overriding the modifier by another
+		// `lazy` one, e.g. with a different backend
+		@Branch
+		mod $modifier:tt $prop_name:path, $init:ident, $generics:tt $enum_name:ty, $branch:pat => {
+			@Mod(lazy $($args:tt)?) $($struct_fields:tt)*
+		}
+	) => {
+		$crate::internal_props_impl_macro!(
+			@Branch mod(lazy $($args)?) $prop_name, $init, $generics $enum_name, $branch => {
+				$($struct_fields)*
+			}
+		)
+	};
+	(
+		// A branch of a `lazy` property overriding the modifier, e.g. by
+		// `const`, which is always initialized
+		@Branch
+		mod $modifier:tt $prop_name:path, $init:ident, $generics:tt $enum_name:ty, $branch:pat => {
+			@Mod $arm_modifier:tt $($struct_fields:tt)*
+		}
+	) => {
+		::core::option::Option::Some($crate::internal_props_impl_macro!(
+			@Branch mod $arm_modifier $prop_name {
+				$($struct_fields)*
 			}
+		))
+	};
+
+	(
+		// A branch delegating to the property of an inner enum, e.g. of the
+		// data of the variant
+		@Branch
+		mod $modifier:tt $prop_name:path {
+			[$($defaults:tt)*] @Delegate $inner:expr
 		}
+	) => {
+		$crate::internal_props_impl_macro!(@Delegate $prop_name, $inner)
+	};
+	(
+		// The property of an inner enum, named in errors if it is missing
+		@Delegate $prop_name:path, $inner:expr
+	) => {
+		$crate::EnumProp::<$prop_name>::property($inner)
+	};
+
+	(
+		// A reference to an existing const or static, returned as is, thus
+		// it keeps its address
+		@Branch
+		mod(const) $prop_name:path {
+			[$($defaults:tt)*] & $item:path
+		}
+	) => {
+		& $item
 	};
+	(
+		// A single *const* trait object, the value is a reference to its
+		// implementor
+		@Branch
+		mod(const(dyn)) $prop_name:path {
+			[] = $value:expr
+		}
+	) => {{
+		const BAR : &'static $prop_name = $value;
 
+		BAR
+	}};
 	(
 		// A single *const* prop value
 		@Branch
 		mod(const) $prop_name:path {
-			$(
-				$field:ident : $value:expr
-			),* $(,)?
+			$($struct_fields:tt)*
 		}
 	) => {{
 		// A const reference, given that all `$value`s are const-init
@@ -361,69 +13104,336 @@ macro_rules! internal_props_impl_macro {
 
 		// `BAR` is rather arbitrary here, maybe different name would be better
 		const BAR : $prop_name = {
-			$prop_name {
-				$(
-					$field : $value ,
-				)*
-			}
+			$crate::internal_props_impl_macro!(@Value $prop_name {
+				$($struct_fields)*
+			})
 		};
 
 		& BAR
 	}};
 
+	(
+		// A reference to an existing static, returned as is, thus it keeps
+		// its address
+		@Branch
+		mod(static) $prop_name:path {
+			[$($defaults:tt)*] & $item:path
+		}
+	) => {
+		& $item
+	};
+	(
+		// A single *static* trait object, the value is a reference to its
+		// implementor, which thus keeps its address
+		@Branch
+		mod(static(dyn)) $prop_name:path {
+			[] = $value:expr
+		}
+	) => {{
+		static BAZ : &'static $prop_name = $value;
+
+		BAZ
+	}};
 	(
 		// A single *static* prop value
 		@Branch
 		mod(static) $prop_name:path {
-			$(
-				$field:ident : $value:expr
-			),* $(,)?
+			$($struct_fields:tt)*
 		}
 	) => {{
 		// A static reference given, that all `$value`s are const-init
 
 		// `BAZ` is rather arbitrary here, maybe different name would be better
 		static BAZ : $prop_name = {
-			$prop_name {
-				$(
-					$field : $value ,
-				)*
-			}
+			$crate::internal_props_impl_macro!(@Value $prop_name {
+				$($struct_fields)*
+			})
 		};
 
 		& BAZ
 	}};
 
 	(
-		// A single *const* prop value
+		// A single *lazy* prop value
 		@Branch
-		mod(lazy) $prop_name:path {
-			$(
-				$field:ident : $value:expr
-			),* $(,)?
+		mod(lazy) $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!(
+			@LazyBranch [internal_lazy_with_default] [] $($rest)*
+		)
+	};
+	(
+		// A single *lazy* prop value, with an explicitly selected backend
+		@Branch
+		mod(lazy($backend:ident)) $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!(
+			@LazyBranch [internal_lazy_with_backend($backend)] [] $($rest)*
+		)
+	};
+	(
+		// A single *lazy* prop value, with an initialization hook
+		@Branch
+		mod(lazy(on_init = $hook:path)) $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!(
+			@LazyBranch [internal_lazy_with_default] [$hook] $($rest)*
+		)
+	};
+	(
+		// A single *lazy* prop value, with an explicitly selected backend and
+		// an initialization hook
+		@Branch
+		mod(lazy($backend:ident, on_init = $hook:path)) $($rest:tt)*
+	) => {
+		$crate::internal_props_impl_macro!(
+			@LazyBranch [internal_lazy_with_backend($backend)] [$hook] $($rest)*
+		)
+	};
+	(
+		// A single *lazy* prop value, via the given backend
+		@LazyBranch
+		[$($backend:tt)+] [$($hook:path)?]
+		$prop_name:path, $init:ident, $generics:tt $enum_name:ty, $branch:pat => {
+			$($struct_fields:tt)*
 		}
 	) => {{
-		// A static reference via lazy_static.
+		// A static reference via the lazy backend, if initialized or `$init`
+		// is set.
+		$crate::internal_lazy_tracked!(
+			$init,
+			$($backend)+,
+			$prop_name,
+			($enum_name, $prop_name, ::core::concat!("branch `", ::core::stringify!($branch), "`")),
+			{
+				// The enum is named here, since a generic enum can not be
+				// named within the lazy cell
+				let value = $crate::internal_lazy_traced!(
+					$crate::internal_props_impl_macro!(@EnumName $generics $enum_name),
+					$prop_name,
+					$branch,
+					{
+						$crate::internal_lazy_annotated!(
+							$crate::internal_props_impl_macro!(@EnumName $generics $enum_name),
+							$prop_name,
+							$branch,
+							{
+								$crate::internal_props_impl_macro!(@Value $prop_name {
+									$($struct_fields)*
+								})
+							}
+						)
+					}
+				);
 
-		// `FOO` is rather arbitrary here, maybe different name would be better
-		$crate::lazy_static::lazy_static!{
-			static ref FOO: $prop_name = {
-				$prop_name {
-					$(
-						$field : $value ,
-					)*
-				}
-			};
+				// The hook is called only once, by the initializing thread
+				$(
+					$hook(::core::any::type_name::<$prop_name>(), ::core::stringify!($branch));
+				)?
+
+				value
+			}
+		)
+	}};
+
+	(
+		// A single *eager* prop value
+		@Branch
+		mod(eager) $prop_name:path {
+			$($struct_fields:tt)*
+		}
+	) => {{
+		// A static reference, initialized before `main`
+		$crate::internal_eager!(
+			$prop_name,
+			{
+				$crate::internal_props_impl_macro!(@Value $prop_name {
+					$($struct_fields)*
+				})
+			}
+		)
+	}};
+
+	(
+		// A branch of a *dynamic* prop overriding the modifier, e.g. by
+		// `const`, which is borrowed instead of computed
+		@Branch
+		mod(dynamic) $prop_name:path, $generics:tt $enum_name:ty, $branch:pat => {
+			@Mod $arm_modifier:tt $($struct_fields:tt)*
+		}
+	) => {
+		$crate::Cow::Borrowed($crate::internal_props_impl_macro!(
+			@ArmBranch mod(dynamic) $prop_name, $generics $enum_name, $branch => {
+				@Mod $arm_modifier $($struct_fields)*
+			}
+		))
+	};
+	(
+		// A branch of a *dynamic* prop delegating to the property of an inner
+		// enum, which is borrowed
+		@Branch
+		mod(dynamic) $prop_name:path, $generics:tt $enum_name:ty, $branch:pat => {
+			[$($defaults:tt)*] @Delegate $inner:expr
+		}
+	) => {
+		$crate::Cow::Borrowed($crate::internal_props_impl_macro!(@Delegate $prop_name, $inner))
+	};
+	(
+		// A reference to an existing const or static of a *dynamic* prop,
+		// which is borrowed
+		@Branch
+		mod(dynamic) $prop_name:path, $generics:tt $enum_name:ty, $branch:pat => {
+			[$($defaults:tt)*] & $item:path
 		}
+	) => {
+		$crate::Cow::Borrowed(& $item)
+	};
+	(
+		// A single *dynamic* prop value, computed at each access, e.g. from the
+		// data of the variant bound by the branch
+		@Branch
+		mod(dynamic) $prop_name:path, $generics:tt $enum_name:ty, $branch:pat => {
+			$($struct_fields:tt)*
+		}
+	) => {
+		$crate::Cow::Owned($crate::internal_props_impl_macro!(@Value $prop_name {
+			$($struct_fields)*
+		}))
+	};
 
-		&*FOO
+	(
+		// A branch of a *thread_lazy* prop delegating to the property of an
+		// inner enum, which is thread local as well
+		@Branch
+		mod(thread_lazy) $prop_name:path, $f:ident {
+			[$($defaults:tt)*] @Delegate $inner:expr
+		}
+	) => {
+		$crate::EnumPropLocal::<$prop_name>::with_property($inner, $f)
+	};
+	(
+		// A thread local can not be overridden by a shared one, nor vice versa
+		@Branch
+		mod(thread_lazy) $prop_name:path, $f:ident {
+			@Mod $arm_modifier:tt $($struct_fields:tt)*
+		}
+	) => {
+		::core::compile_error!("the branches of `thread_lazy` properties can not override the modifier")
+	};
+	(
+		// A single *thread_lazy* prop value, passed to the closure `$f`
+		@Branch
+		mod(thread_lazy) $prop_name:path, $f:ident {
+			$($struct_fields:tt)*
+		}
+	) => {{
+		// A thread local, lazily initialized for each thread
+		$crate::internal_thread_lazy!(
+			$prop_name,
+			$f,
+			{
+				$crate::internal_props_impl_macro!(@Value $prop_name {
+					$($struct_fields)*
+				})
+			}
+		)
 	}};
 }
 
+// The lazy backends
+
+mod lazy;
+
 // Some testing modules
 
 mod benchs;
+mod test_address;
+mod test_aggregate;
+mod test_all;
+mod test_all_trait;
+mod test_as_ref;
+mod test_assert;
+mod test_attrs;
+mod test_calls;
+mod test_cfg;
+mod test_combined;
+mod test_compare;
+mod test_const_context;
+mod test_const_fn;
+mod test_const_generic;
+mod test_consts;
+mod test_debug;
+mod test_dedup;
+mod test_defaults;
+mod test_defmt;
+mod test_delegate;
+mod test_deserialize;
+mod test_discriminant;
+mod test_display;
+mod test_dyn;
+mod test_dynamic;
+mod test_enum_def;
+mod test_enum_map;
+mod test_enums;
+mod test_error;
+mod test_expression;
+mod test_fields;
+mod test_find;
+mod test_fn_attrs;
+mod test_from_property;
+mod test_from_ref;
+mod test_from_str;
+mod test_from_str_phf;
+mod test_generic;
+mod test_generic_prop;
+mod test_guard;
+mod test_identity;
+mod test_inline_struct;
+mod test_into;
+mod test_keyed;
+mod test_lazy;
+mod test_lazy_backend;
+mod test_lazy_cross;
+mod test_lazy_init;
+mod test_lifetime;
+mod test_like;
+mod test_marker;
+mod test_memo;
+mod test_mixed;
+mod test_named;
+mod test_nested;
+mod test_no_lazy;
+mod test_non_exhaustive;
+mod test_or_pattern;
+mod test_ordered;
+mod test_pair;
+mod test_primitive;
+mod test_projection;
+mod test_prop_const;
+mod test_prop_ext;
+mod test_props_tuple;
+mod test_ref;
+mod test_reference;
+mod test_sample_weighted;
+mod test_sections;
+mod test_serialize;
+mod test_set_where;
+mod test_setters;
 mod test_static;
+mod test_struct_update;
+mod test_strum;
+mod test_table;
+mod test_tagged;
+mod test_thread_lazy;
+mod test_trait_impl;
+mod test_try_from;
+mod test_tuple;
+mod test_unique;
+mod test_util;
+mod test_value_enum;
+mod test_variants;
+mod test_where;
+mod test_wildcard;
 
 
 #[cfg(test)]
@@ -0,0 +1,255 @@
+// This file tests the addresses of the properties for each modifier, i.e.
+// which variants share a single property instance
+#![cfg(any(test, doctest))]
+#![allow(dead_code)]
+
+use super::prop_ptr_eq;
+
+
+// Not zero-sized, since distinct zero-sized statics may share their address
+struct Prop {
+	name: &'static str,
+}
+
+static SHARED: Prop = Prop {
+	name: "shared",
+};
+
+#[derive(Clone, Copy)]
+enum Foo {
+	A,
+	B,
+	C,
+	D(u8),
+	E,
+	F,
+}
+
+props! {
+	impl EnumProp for Foo as static Prop {
+		Self::A => {
+			name: "a",
+		}
+		Self::B | Self::C => {
+			name: "either",
+		}
+		Self::D(_) => {
+			name: "data",
+		}
+		Self::E => &SHARED,
+		Self::F => &SHARED,
+	}
+}
+
+#[test]
+fn static_same_variant() {
+	assert_shared_props!(Prop: Foo::A, Foo::A);
+	assert!(prop_ptr_eq::<Prop>(&Foo::A, &Foo::A));
+}
+
+#[test]
+fn static_distinct_branches() {
+	assert_distinct_props!(Prop: Foo::A, Foo::B, Foo::D(0), Foo::E);
+	assert!(!prop_ptr_eq::<Prop>(&Foo::A, &Foo::B));
+}
+
+#[test]
+fn static_or_pattern() {
+	assert_shared_props!(Prop: Foo::B, Foo::C);
+}
+
+#[test]
+fn static_variant_data() {
+	assert_shared_props!(Prop: Foo::D(0), Foo::D(1), Foo::D(255));
+}
+
+#[test]
+fn static_referenced_item() {
+	assert_shared_props!(Prop: Foo::E, Foo::F);
+	assert!(core::ptr::eq(
+		super::EnumProp::<Prop>::property(&Foo::E),
+		&SHARED
+	));
+}
+
+// Branches overriding the modifier of a const property
+
+enum Bar {
+	A,
+	B,
+	C,
+}
+
+props! {
+	impl EnumProp for Bar as const Prop {
+		Self::A => static {
+			name: "a",
+		}
+		Self::B | Self::C => static {
+			name: "either",
+		}
+	}
+}
+
+#[test]
+fn static_branch_of_const() {
+	assert_shared_props!(Prop: Bar::A, Bar::A);
+	assert_shared_props!(Prop: Bar::B, Bar::C);
+	assert_distinct_props!(Prop: Bar::A, Bar::B);
+}
+
+// Distinct enums referring to the same item
+
+enum Baz {
+	X,
+	Y,
+}
+
+props! {
+	impl EnumProp for Baz as const Prop {
+		Self::X => &SHARED,
+		Self::Y => {
+			name: "y",
+		}
+	}
+}
+
+#[test]
+fn across_enums() {
+	assert_shared_props!(Prop: Foo::E, Baz::X);
+	assert!(prop_ptr_eq::<Prop>(&Foo::E, &Baz::X));
+	assert_distinct_props!(Prop: Foo::A, Baz::X);
+}
+
+// Identical branches merged by `dedup`
+
+enum Qux {
+	A,
+	B,
+	C,
+}
+
+props! {
+	impl EnumProp for Qux as static Prop {
+		dedup
+		Self::A => {
+			name: "same",
+		}
+		Self::B => {
+			name: "same",
+		}
+		Self::C => {
+			name: "other",
+		}
+	}
+}
+
+#[test]
+fn static_dedup() {
+	assert_shared_props!(Prop: Qux::A, Qux::B);
+	assert_distinct_props!(Prop: Qux::A, Qux::C);
+}
+
+// Unsized properties are compared without their metadata
+
+trait Named {
+	fn name(&self) -> &'static str;
+}
+
+impl Named for Prop {
+	fn name(&self) -> &'static str {
+		self.name
+	}
+}
+
+static OTHER: Prop = Prop {
+	name: "other",
+};
+
+props! {
+	impl Foo : fn named as static dyn Named + Sync {
+		Self::A => &SHARED,
+		Self::B | Self::C => &SHARED,
+		_ => &OTHER,
+	}
+}
+
+#[test]
+fn static_dyn() {
+	assert_shared_props!(dyn Named + Sync: Foo::A, Foo::B, Foo::C);
+	assert_distinct_props!(dyn Named + Sync: Foo::A, Foo::D(0));
+}
+
+#[test]
+#[should_panic(expected = "the `Prop` of `Foo::A` and `Foo::B` are not shared")]
+fn assert_shared_fails() {
+	assert_shared_props!(Prop: Foo::A, Foo::B);
+}
+
+#[test]
+#[should_panic(expected = "the `Prop` of `Foo::B` and `Foo::C` are shared")]
+fn assert_distinct_fails() {
+	assert_distinct_props!(Prop: Foo::A, Foo::B, Foo::C);
+}
+
+#[cfg(lazy_backend)]
+mod lazy {
+	use super::Prop;
+
+	enum Foo {
+		A,
+		B,
+		C,
+	}
+
+	props! {
+		impl EnumProp for Foo as lazy Prop {
+			Self::A => {
+				name: "a",
+			}
+			Self::B | Self::C => {
+				name: "either",
+			}
+		}
+	}
+
+	#[test]
+	fn lazy_or_pattern() {
+		assert_shared_props!(Prop: Foo::A, Foo::A);
+		assert_shared_props!(Prop: Foo::B, Foo::C);
+		assert_distinct_props!(Prop: Foo::A, Foo::B);
+	}
+}
+
+#[cfg(feature = "std")]
+mod memo {
+	use std::format;
+	use std::string::String;
+
+	struct Label {
+		text: String,
+	}
+
+	#[derive(Clone, PartialEq, Eq, Hash)]
+	enum Foo {
+		A,
+		B(u8),
+	}
+
+	props! {
+		impl EnumProp for Foo as memo Label {
+			Self::A => {
+				text: String::from("a"),
+			}
+			Self::B(n) => {
+				text: format!("b{n}"),
+			}
+		}
+	}
+
+	#[test]
+	fn memo_by_value() {
+		assert_shared_props!(Label: Foo::B(1), Foo::B(1));
+		assert_distinct_props!(Label: Foo::A, Foo::B(1), Foo::B(2));
+	}
+}
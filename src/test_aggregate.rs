@@ -0,0 +1,187 @@
+// This file tests the aggregates of const properties, folding a field over all
+// branches at compile time
+#![cfg(any(test, doctest))]
+#![allow(dead_code)]
+
+
+struct ShapeDef {
+	name: &'static str,
+	vertices: u32,
+	weight: f64,
+}
+
+enum Shape {
+	Triangle,
+	Square,
+	Rhombus,
+	Polygon(u32),
+}
+
+props! {
+	impl Deref for Shape as const ShapeDef {
+		defaults {
+			weight: 1.0,
+		}
+		aggregate {
+			/// The most vertices of any shape
+			max vertices as pub MAX_VERTICES: u32,
+			min vertices as MIN_VERTICES: u32,
+		}
+		aggregate {
+			sum weight as TOTAL_WEIGHT: f64,
+		}
+		let QUAD = {
+			name: "Quad",
+			vertices: 4,
+		};
+		Self::Triangle => {
+			name: "Triangle",
+			vertices: 3,
+			weight: 0.5,
+		}
+		Self::Square | Self::Rhombus => QUAD,
+		_ => {
+			name: "Polygon",
+			vertices: 12,
+			weight: 2.0,
+		}
+	}
+}
+
+// Usable in const contexts, e.g. as an array length
+const BUFFER: [u8; Shape::MAX_VERTICES as usize] = [0; Shape::MAX_VERTICES as usize];
+
+#[test]
+fn aggregate_const() {
+	assert_eq!(BUFFER.len(), 12);
+	assert_eq!(Shape::MIN_VERTICES, 3);
+	assert_eq!(Shape::TOTAL_WEIGHT, 3.5);
+	assert_eq!(Shape::Polygon(5).name, "Polygon");
+}
+
+// Via `const fn` and `EnumPropConst`
+
+struct Cost {
+	value: u8,
+}
+
+props! {
+	impl Shape {
+		const fn cost as const Cost {
+			aggregate {
+				sum value as TOTAL_COST: u8,
+			}
+			Self::Triangle => {
+				value: 1,
+			}
+			Self::Square | Self::Rhombus => {
+				value: 2,
+			}
+			Self::Polygon(_) => {
+				value: 3,
+			}
+		}
+	}
+}
+
+struct Rank {
+	value: i8,
+}
+
+props! {
+	impl EnumPropConst for Shape as const Rank {
+		aggregate {
+			min value as LOWEST_RANK: i8,
+		}
+		Self::Triangle => {
+			value: -1,
+		}
+		_ => {
+			value: 5,
+		}
+	}
+}
+
+#[test]
+fn aggregate_const_fn() {
+	assert_eq!(Shape::TOTAL_COST, 6);
+	assert_eq!(Shape::LOWEST_RANK, -1);
+}
+
+// Only primitive number fields
+
+/// ```compile_fail
+/// use enumeraties::props;
+///
+/// struct Props {
+///     name: &'static str,
+/// }
+///
+/// enum Foo {
+///     A,
+/// }
+///
+/// props! {
+///     impl Foo : fn getter as const Props {
+///         aggregate {
+///             max name as MAX_NAME: &'static str,
+///         }
+///         Self::A => {
+///             name: "a",
+///         }
+///     }
+/// }
+/// ```
+struct NonNumber;
+
+// Only the known operations
+
+/// ```compile_fail
+/// use enumeraties::props;
+///
+/// struct Props {
+///     foo: u32,
+/// }
+///
+/// enum Foo {
+///     A,
+/// }
+///
+/// props! {
+///     impl Foo : fn getter as const Props {
+///         aggregate {
+///             avg foo as AVG_FOO: u32,
+///         }
+///         Self::A => {
+///             foo: 1,
+///         }
+///     }
+/// }
+/// ```
+struct UnknownOp;
+
+// Only `const` properties
+
+/// ```compile_fail
+/// use enumeraties::props;
+///
+/// struct Props {
+///     foo: u32,
+/// }
+///
+/// enum Foo {
+///     A,
+/// }
+///
+/// props! {
+///     impl Foo : fn getter as static Props {
+///         aggregate {
+///             max foo as MAX_FOO: u32,
+///         }
+///         Self::A => {
+///             foo: 1,
+///         }
+///     }
+/// }
+/// ```
+struct StaticAggregate;
@@ -0,0 +1,300 @@
+// This file tests the `all` table of const and static properties, one entry
+// per variant, in the order of the branches
+#![cfg(any(test, doctest))]
+#![allow(dead_code)]
+
+use core::sync::atomic::AtomicU32;
+use core::sync::atomic::Ordering;
+
+use super::EnumProp;
+
+
+struct ShapeDef {
+	name: &'static str,
+	vertices: u32,
+}
+
+enum Shape {
+	Triangle,
+	Square,
+	Rhombus,
+	Circle,
+}
+
+props! {
+	impl Deref for Shape as const ShapeDef {
+		defaults {
+			vertices: 0,
+		}
+		all {
+			/// All shapes
+			pub ALL_SHAPE_DEFS
+		}
+		Self::Triangle => {
+			name: "Triangle",
+			vertices: 3,
+		}
+		Self::Square | Self::Rhombus => {
+			name: "Quad",
+			vertices: 4,
+		}
+		Self::Circle => {
+			name: "Circle",
+		}
+	}
+}
+
+// Another table, built from the first one at compile time
+const VERTICES: [u32; Shape::ALL_SHAPE_DEFS.len()] = {
+	let mut vertices = [0; Shape::ALL_SHAPE_DEFS.len()];
+	let mut i = 0;
+	while i < vertices.len() {
+		vertices[i] = Shape::ALL_SHAPE_DEFS[i].vertices;
+		i += 1;
+	}
+	vertices
+};
+
+#[test]
+fn all_const() {
+	assert_eq!(Shape::ALL_SHAPE_DEFS.len(), 4);
+	assert_eq!(VERTICES, [3, 4, 4, 0]);
+
+	// The same properties as via `EnumProp`
+	let names = Shape::ALL_SHAPE_DEFS.map(|def| def.name);
+	assert_eq!(names, ["Triangle", "Quad", "Quad", "Circle"]);
+	assert_eq!(Shape::Rhombus.name, Shape::ALL_SHAPE_DEFS[2].name);
+}
+
+// Static props, with named values, arm attributes, associated consts, and a
+// section
+
+struct Cost {
+	value: u32,
+}
+
+static EXPENSIVE: Cost = Cost {
+	value: 100,
+};
+
+props! {
+	impl Shape {
+		EnumProp as static Cost {
+			all {
+				ALL_COSTS
+			}
+			let CHEAP = {
+				value: 1,
+			};
+			Self::Triangle => CHEAP,
+			#[cfg(all())]
+			Self::Square | Self::Rhombus => &EXPENSIVE,
+			#[cfg(any())]
+			Self::Square | Self::Rhombus => {
+				value: 0,
+			}
+			Self::Circle => const {
+				value: 10,
+			}
+		}
+	}
+}
+
+#[test]
+fn all_static() {
+	let values = Shape::ALL_COSTS.map(|cost| cost.value);
+	assert_eq!(values, [1, 100, 100, 10]);
+
+	// The entries are the statics of `EnumProp`, including named values and
+	// referenced items
+	let prop = |shape: Shape| EnumProp::<Cost>::property(&shape);
+	assert!(core::ptr::eq(Shape::ALL_COSTS[0], prop(Shape::Triangle)));
+	assert!(core::ptr::eq(Shape::ALL_COSTS[1], &EXPENSIVE));
+	assert!(core::ptr::eq(Shape::ALL_COSTS[2], prop(Shape::Rhombus)));
+}
+
+// The statics of the branches, shared by the variants of a branch, with
+// interior mutability
+
+struct Counter {
+	count: AtomicU32,
+}
+
+props! {
+	impl Shape {
+		EnumProp as static Counter {
+			all {
+				ALL_COUNTERS
+			}
+			Self::Triangle | Self::Circle => {
+				count: AtomicU32::new(0),
+			}
+			Self::Square => {
+				count: AtomicU32::new(0),
+			}
+			Self::Rhombus => {
+				count: AtomicU32::new(0),
+			}
+		}
+	}
+}
+
+#[test]
+fn all_static_address() {
+	let prop = |shape: Shape| EnumProp::<Counter>::property(&shape);
+	let shapes = [
+		Shape::Triangle,
+		Shape::Circle,
+		Shape::Square,
+		Shape::Rhombus,
+	];
+	for (entry, shape) in Shape::ALL_COUNTERS.into_iter().zip(shapes) {
+		assert!(core::ptr::eq(entry, prop(shape)));
+	}
+	assert!(core::ptr::eq(
+		Shape::ALL_COUNTERS[0],
+		Shape::ALL_COUNTERS[1]
+	));
+	assert!(!core::ptr::eq(
+		Shape::ALL_COUNTERS[2],
+		Shape::ALL_COUNTERS[3]
+	));
+
+	// Thus, changes via either are seen by both
+	prop(Shape::Circle).count.fetch_add(1, Ordering::SeqCst);
+	assert_eq!(Shape::ALL_COUNTERS[0].count.load(Ordering::SeqCst), 1);
+	assert_eq!(prop(Shape::Triangle).count.load(Ordering::SeqCst), 1);
+}
+
+struct Tier {
+	level: u8,
+}
+
+props! {
+	impl Shape {
+		const fn tier as const Tier {
+			consts {
+				pub CIRCLE_TIER = Self::Circle,
+			}
+			all {
+				pub(crate) ALL_TIERS
+			}
+			Self::Triangle | Self::Square => {
+				level: 1,
+			}
+			Self::Rhombus => {
+				level: 2,
+			}
+			Self::Circle => {
+				level: 3,
+			}
+		}
+	}
+}
+
+const MAX_TIER: u8 = {
+	let mut max = 0;
+	let mut i = 0;
+	while i < Shape::ALL_TIERS.len() {
+		if Shape::ALL_TIERS[i].level > max {
+			max = Shape::ALL_TIERS[i].level;
+		}
+		i += 1;
+	}
+	max
+};
+
+#[test]
+fn all_const_fn() {
+	assert_eq!(MAX_TIER, 3);
+	assert_eq!(Shape::CIRCLE_TIER.level, 3);
+	assert!(core::ptr::eq(Shape::ALL_TIERS[3], Shape::Circle.tier()));
+}
+
+
+
+// No variants with data
+
+/// ```compile_fail
+/// use enumeraties::props;
+///
+/// struct Props {
+///     foo: u32,
+/// }
+///
+/// enum Foo {
+///     A,
+///     B(u8),
+/// }
+///
+/// props! {
+///     impl Foo : fn getter as const Props {
+///         all {
+///             ALL_PROPS
+///         }
+///         Self::A => {
+///             foo: 1,
+///         }
+///         Self::B(_) => {
+///             foo: 2,
+///         }
+///     }
+/// }
+/// ```
+struct DataVariant;
+
+// No wildcard
+
+/// ```compile_fail
+/// use enumeraties::props;
+///
+/// struct Props {
+///     foo: u32,
+/// }
+///
+/// enum Foo {
+///     A,
+///     B,
+/// }
+///
+/// props! {
+///     impl Foo : fn getter as const Props {
+///         all {
+///             ALL_PROPS
+///         }
+///         Self::A => {
+///             foo: 1,
+///         }
+///         _ => {
+///             foo: 2,
+///         }
+///     }
+/// }
+/// ```
+struct Wildcard;
+
+// Only `const` and `static` properties
+
+/// ```compile_fail
+/// use enumeraties::props;
+///
+/// struct Props {
+///     foo: u32,
+/// }
+///
+/// enum Foo {
+///     A,
+/// }
+///
+/// props! {
+///     impl Foo : fn getter as lazy Props {
+///         all {
+///             ALL_PROPS
+///         }
+///         Self::A => {
+///             foo: 1,
+///         }
+///     }
+/// }
+/// ```
+struct LazyAll;
@@ -0,0 +1,145 @@
+// This file tests the `EnumPropAll` trait, listing all variants along with
+// their properties
+#![cfg(any(test, doctest))]
+#![allow(dead_code)]
+
+use crate::EnumPropAll;
+
+// Like the `basic_usage` example
+struct ShapeDef {
+	name: &'static str,
+	vertices: u32,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum Shape {
+	Triangle,
+	Square,
+	Rhombus,
+	Hexagon,
+}
+
+props! {
+	impl EnumPropAll for Shape as const ShapeDef {
+		Self::Triangle => {
+			name: "Triangle",
+			vertices: 3,
+		}
+		Self::Square | Self::Rhombus => {
+			name: "Quad",
+			vertices: 4,
+		}
+		Self::Hexagon => {
+			name: "Hexagon",
+			vertices: 6,
+		}
+	}
+}
+
+#[test]
+fn all_const() {
+	let all = Shape::all();
+	assert_eq!(all.len(), 4);
+
+	let variants = all.iter().map(|(variant, _)| variant);
+	assert!(variants.eq([
+		&Shape::Triangle,
+		&Shape::Square,
+		&Shape::Rhombus,
+		&Shape::Hexagon
+	]));
+
+	let names = all.iter().map(|(_, def)| def.name);
+	assert!(names.eq(["Triangle", "Quad", "Quad", "Hexagon"]));
+
+	// The same properties as via `EnumProp`
+	for (variant, def) in all {
+		assert!(core::ptr::eq(
+			*def,
+			crate::EnumProp::<ShapeDef>::property(variant)
+		));
+	}
+}
+
+// Generic code
+
+fn total_vertices<E: EnumPropAll<ShapeDef>>() -> u32 {
+	E::all().iter().map(|(_, def)| def.vertices).sum()
+}
+
+#[test]
+fn generic() {
+	assert_eq!(total_vertices::<Shape>(), 3 + 4 + 4 + 6);
+}
+
+// Static properties, with attributes on the branches
+
+struct Color(&'static str);
+
+enum Light {
+	Red,
+	Green,
+	#[cfg(any())]
+	Blue,
+}
+
+props! {
+	impl EnumPropAll for Light as static Color {
+		Self::Red => Color("red"),
+		Self::Green => Color("green"),
+		#[cfg(any())]
+		Self::Blue => Color("blue"),
+	}
+}
+
+#[test]
+fn all_static() {
+	let colors = Light::all().iter().map(|(_, color)| color.0);
+	assert!(colors.eq(["red", "green"]));
+}
+
+/// ```compile_fail
+/// use enumeraties::props;
+/// struct Color(&'static str);
+/// enum Light {
+///     Red,
+///     Green,
+/// }
+/// props! {
+///     impl EnumPropAll for Light as const Color {
+///         Self::Red => Color("red"),
+///         _ => Color("other"),
+///     }
+/// }
+/// ```
+struct Wildcard;
+
+/// ```compile_fail
+/// use enumeraties::props;
+/// struct Color(&'static str);
+/// enum Light {
+///     Red,
+///     Custom(&'static str),
+/// }
+/// props! {
+///     impl EnumPropAll for Light as const Color {
+///         Self::Red => Color("red"),
+///         Self::Custom(_) => Color("custom"),
+///     }
+/// }
+/// ```
+struct VariantData;
+
+/// ```compile_fail
+/// use enumeraties::props;
+/// struct Color(&'static str);
+/// enum Light {
+///     Red,
+/// }
+/// props! {
+///     impl EnumPropAll for Light as lazy Color {
+///         Self::Red => Color("red"),
+///     }
+/// }
+/// ```
+struct Lazy;
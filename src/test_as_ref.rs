@@ -0,0 +1,84 @@
+// This file tests the `AsRef` and `Borrow` impls of properties
+#![cfg(any(test, doctest))]
+#![allow(dead_code)]
+
+use core::borrow::Borrow;
+
+struct RenderInfo {
+	color: &'static str,
+	layer: u8,
+}
+
+#[derive(Debug, PartialEq, Eq, Hash)]
+struct Name(&'static str);
+
+enum Tile {
+	Grass,
+	Water,
+	Rock,
+}
+
+props! {
+	impl Deref for Tile as const Name {
+		Self::Grass => Name("grass"),
+		Self::Water => Name("water"),
+		Self::Rock => Name("rock"),
+	}
+}
+
+props! {
+	impl AsRef for Tile as static RenderInfo {
+		Self::Grass => {
+			color: "green",
+			layer: 0,
+		}
+		Self::Water => {
+			color: "blue",
+			layer: 0,
+		}
+		Self::Rock => {
+			color: "gray",
+			layer: 1,
+		}
+	}
+}
+
+fn color(info: impl AsRef<RenderInfo>) -> &'static str {
+	info.as_ref().color
+}
+
+#[test]
+fn as_ref_generic() {
+	assert_eq!(color(Tile::Water), "blue");
+	assert_eq!(color(Tile::Rock), "gray");
+	assert_eq!(Tile::Rock.as_ref().layer, 1);
+	// Along with the primary property
+	assert_eq!(Tile::Grass.0, "grass");
+}
+
+// Borrowing the property, which is fine, since the enum neither implements
+// `Eq` nor `Hash`
+
+enum Key {
+	Alpha,
+	Beta,
+}
+
+props! {
+	impl Borrow for Key as const Name {
+		Self::Alpha => Name("alpha"),
+		Self::Beta => Name("beta"),
+	}
+}
+
+fn name<T: Borrow<Name>>(value: &T) -> &'static str {
+	value.borrow().0
+}
+
+#[test]
+fn borrow_generic() {
+	assert_eq!(name(&Key::Beta), "beta");
+	assert_eq!(name(&Key::Alpha), "alpha");
+	// Like any type borrowing itself
+	assert_eq!(name(&Name("gamma")), "gamma");
+}
@@ -0,0 +1,205 @@
+// This file tests the assertions of properties, checked at compile time for
+// const properties, and at the initialization for lazy ones
+#![cfg(any(test, doctest))]
+#![allow(dead_code)]
+
+
+struct ShapeDef {
+	vertices: u32,
+	internal_angle: f64,
+}
+
+enum Shape {
+	Triangle,
+	Square,
+	Rhombus,
+	Polygon(u32),
+}
+
+props! {
+	impl Deref for Shape as const ShapeDef {
+		assert(|def| def.vertices >= 3)
+		debug_assert(|def| def.internal_angle > 0.0, "the internal angle must be positive")
+		let QUAD = {
+			vertices: 4,
+			internal_angle: 90.0,
+		};
+		Self::Triangle => {
+			vertices: 3,
+			internal_angle: 60.0,
+		}
+		Self::Square | Self::Rhombus => QUAD,
+		_ => {
+			vertices: 6,
+			internal_angle: 120.0,
+		}
+	}
+}
+
+#[test]
+fn assert_const() {
+	assert_eq!(Shape::Triangle.vertices, 3);
+	assert_eq!(Shape::Rhombus.internal_angle, 90.0);
+	assert_eq!(Shape::Polygon(6).vertices, 6);
+}
+
+// Via `const fn`, along with further clauses
+
+struct Cost {
+	value: u8,
+}
+
+props! {
+	impl Shape {
+		const fn cost as const Cost {
+			unique(value)
+			assert(|cost| cost.value < 10, "too expensive")
+			Self::Triangle => {
+				value: 1,
+			}
+			Self::Square => {
+				value: 2,
+			}
+			Self::Rhombus => {
+				value: 3,
+			}
+			Self::Polygon(_) => {
+				value: 4,
+			}
+		}
+	}
+}
+
+#[test]
+fn assert_const_fn() {
+	const COST: u8 = Shape::Rhombus.cost().value;
+	assert_eq!(COST, 3);
+}
+
+// Lazy props are checked at the initialization of each branch, with std to
+// catch the panic
+#[cfg(all(test, feature = "lazy", feature = "std"))]
+mod lazy {
+	use std::string::String;
+
+	use crate::EnumProp;
+
+	struct Limit {
+		value: u32,
+	}
+
+	#[derive(Copy, Clone)]
+	enum Size {
+		Small,
+		Large,
+		Huge,
+	}
+
+	props! {
+		impl EnumProp for Size as lazy Limit {
+			assert(|limit| limit.value <= 100, "the limit is capped at 100")
+			Self::Small => {
+				value: 10,
+			}
+			Self::Large => {
+				value: 10 * 100,
+			}
+			// Not checked, since it overrides the modifier
+			Self::Huge => static {
+				value: 1000,
+			}
+		}
+	}
+
+	#[test]
+	fn assert_lazy() {
+		assert_eq!(Size::Small.property().value, 10);
+		assert_eq!(Size::Huge.property().value, 1000);
+
+		let payload = std::panic::catch_unwind(|| Size::Large.property().value).unwrap_err();
+		let message = payload.downcast_ref::<String>().unwrap();
+		assert!(
+			message.contains(
+				"assertion failed for the branch `Self::Large`: the limit is capped at 100"
+			),
+			"{}",
+			message
+		);
+	}
+}
+
+// A failing assertion of a const prop does not compile
+
+/// ```compile_fail,E0080
+/// use enumeraties::props;
+///
+/// struct Props {
+///     vertices: u32,
+/// }
+///
+/// enum Foo {
+///     A,
+///     B,
+/// }
+///
+/// props! {
+///     impl Foo : fn getter as const Props {
+///         assert(|props| props.vertices >= 3)
+///         Self::A => {
+///             vertices: 3,
+///         }
+///         Self::B => {
+///             vertices: 2,
+///         }
+///     }
+/// }
+/// ```
+struct FailingConst;
+
+// Only `const` and `lazy` properties
+
+/// ```compile_fail
+/// use enumeraties::props;
+///
+/// struct Props {
+///     vertices: u32,
+/// }
+///
+/// enum Foo {
+///     A,
+/// }
+///
+/// props! {
+///     impl Foo : fn getter as static Props {
+///         assert(|props| props.vertices >= 3)
+///         Self::A => {
+///             vertices: 3,
+///         }
+///     }
+/// }
+/// ```
+struct StaticAssert;
+
+// The assertions must come first
+
+/// ```compile_fail
+/// use enumeraties::props;
+///
+/// struct Props {
+///     vertices: u32,
+/// }
+///
+/// enum Foo {
+///     A,
+/// }
+///
+/// props! {
+///     impl Foo : fn getter as const Props {
+///         Self::A => {
+///             vertices: 3,
+///         }
+///         assert(|props| props.vertices >= 3)
+///     }
+/// }
+/// ```
+struct LateAssert;
@@ -0,0 +1,95 @@
+// This file tests attributes on the entire props invocation
+#![cfg(any(test, doctest))]
+#![allow(dead_code)]
+
+use super::EnumProp;
+
+
+struct Prop {
+	name: &'static str,
+}
+
+enum Foo {
+	A,
+	B,
+}
+
+props! {
+	#[cfg(any())]
+	impl Deref for Foo as const Prop {
+		Self::A => {
+			name: "never",
+		}
+		Self::B => {
+			name: "never",
+		}
+	}
+}
+
+// Would conflict with the above, unless it is configured away
+props! {
+	#[cfg(all())]
+	impl Deref for Foo as const Prop {
+		Self::A => {
+			name: "A",
+		}
+		Self::B => {
+			name: "B",
+		}
+	}
+}
+
+#[test]
+fn attrs_cfg() {
+	assert_eq!(Foo::A.name, "A");
+	assert_eq!(EnumProp::<Prop>::property(&Foo::B).name, "B");
+}
+
+// The attributes also apply to the inherent method
+props! {
+	#[allow(non_snake_case)]
+	#[cfg(feature = "std")]
+	impl Foo : pub fn Weight as const u32 {
+		Self::A => 1,
+		Self::B => 2,
+	}
+}
+
+props! {
+	#[allow(non_snake_case)]
+	#[cfg(not(feature = "std"))]
+	impl Foo : pub fn Weight as const u32 {
+		Self::A => 10,
+		Self::B => 20,
+	}
+}
+
+#[test]
+fn attrs_inherent() {
+	#[cfg(feature = "std")]
+	assert_eq!(*Foo::A.Weight(), 1);
+	#[cfg(not(feature = "std"))]
+	assert_eq!(*Foo::A.Weight(), 10);
+}
+
+#[cfg(lazy_backend)]
+mod lazy {
+	use super::Foo;
+	use crate::test_util::compute;
+	use crate::EnumPropInit;
+
+	props! {
+		#[allow(non_snake_case)]
+		impl Foo : fn Squares as lazy [u32; 2] {
+			Self::A => [compute(1), compute(2)],
+			Self::B => [compute(3), compute(4)],
+		}
+	}
+
+	#[test]
+	fn attrs_lazy() {
+		// The `EnumPropInit` impl is generated as well
+		<Foo as EnumPropInit<[u32; 2]>>::initialize_all();
+		assert_eq!(Foo::B.Squares(), &[9, 16]);
+	}
+}
@@ -0,0 +1,145 @@
+// This file tests call-through methods of function pointer fields
+#![cfg(any(test, doctest))]
+#![allow(dead_code)]
+
+use super::EnumProp;
+
+
+#[derive(Default)]
+struct World {
+	log: [u8; 4],
+	len: usize,
+}
+
+impl World {
+	fn push(&mut self, value: u8) {
+		self.log[self.len] = value;
+		self.len += 1;
+	}
+}
+
+struct Handler {
+	on_enter: fn(&mut World),
+	score: fn(u32, u32) -> u32,
+	name: &'static str,
+}
+
+fn enter_menu(world: &mut World) {
+	world.push(1);
+}
+
+fn enter_game(world: &mut World) {
+	world.push(2);
+}
+
+enum State {
+	Menu,
+	Game,
+	Paused,
+}
+
+props! {
+	impl State : pub calls(on_enter(world: &mut World), score(base: u32, bonus: u32) -> u32) as const Handler {
+		Self::Menu => {
+			on_enter: enter_menu,
+			score: |_, _| 0,
+			name: "menu",
+		}
+		Self::Game => {
+			on_enter: enter_game,
+			score: |base, bonus| base + bonus,
+			name: "game",
+		}
+		Self::Paused => {
+			on_enter: |world| world.push(3),
+			score: |base, _| base,
+			name: "paused",
+		}
+	}
+}
+
+#[test]
+fn calls_dispatch() {
+	let mut world = World::default();
+	State::Game.on_enter(&mut world);
+	State::Menu.on_enter(&mut world);
+	State::Paused.on_enter(&mut world);
+	State::Game.on_enter(&mut world);
+	assert_eq!(world.log, [2, 1, 3, 2]);
+
+	assert_eq!(State::Menu.score(10, 5), 0);
+	assert_eq!(State::Game.score(10, 5), 15);
+	assert_eq!(State::Paused.score(10, 5), 10);
+
+	// The property itself is still available
+	assert_eq!(EnumProp::<Handler>::property(&State::Paused).name, "paused");
+}
+
+// Calls of an existing property, with attributes
+struct Ops {
+	apply: fn(i32) -> i32,
+	check: fn(&str) -> bool,
+}
+
+props! {
+	impl EnumProp for State as static Ops {
+		Self::Menu => {
+			apply: |x| x,
+			check: str::is_empty,
+		}
+		Self::Game => {
+			apply: |x| x * 2,
+			check: |text| text.len() > 3,
+		}
+		Self::Paused => {
+			apply: i32::wrapping_neg,
+			check: |_| false,
+		}
+	}
+}
+
+props! {
+	impl State :
+		#[must_use]
+		pub(crate) calls(
+			/// Applies the operation of the state
+			apply(value: i32) -> i32,
+			#[inline]
+			check(text: &str) -> bool,
+		) as Ops;
+}
+
+#[test]
+fn calls_existing() {
+	assert_eq!(State::Menu.apply(7), 7);
+	assert_eq!(State::Game.apply(7), 14);
+	assert_eq!(State::Paused.apply(7), -7);
+	assert!(State::Menu.check(""));
+	assert!(State::Game.check("long"));
+	assert!(!State::Paused.check("long"));
+}
+
+
+
+// The arguments must match the function pointer
+
+/// ```compile_fail,E0308
+/// use enumeraties::props;
+///
+/// struct Props {
+///     run: fn(u32) -> u32,
+/// }
+///
+/// enum Foo {
+///     A,
+/// }
+///
+/// props! {
+///     impl Foo : calls(run(value: &str) -> u32) as const Props {
+///         Self::A => {
+///             run: |x| x,
+///         }
+///     }
+/// }
+/// ```
+struct WrongArgument;
@@ -0,0 +1,154 @@
+// This file tests branches with `cfg` attributes, e.g. for feature gated
+// variants
+#![cfg(any(test, doctest))]
+#![allow(dead_code)]
+
+use super::EnumProp;
+
+
+struct Prop {
+	name: &'static str,
+}
+
+#[derive(Clone, Copy)]
+enum Foo {
+	A,
+	#[cfg(feature = "std")]
+	Std,
+	#[cfg(not(feature = "std"))]
+	NoStd,
+	C(u8),
+}
+
+props! {
+	impl Deref for Foo as const Prop {
+		Self::A => {
+			name: "A",
+		}
+		#[cfg(feature = "std")]
+		Self::Std => {
+			name: "std",
+		}
+		#[cfg(not(feature = "std"))]
+		Self::NoStd => {
+			name: "no_std",
+		}
+		Self::C(_) => {
+			name: "C",
+		}
+	}
+}
+
+#[test]
+fn cfg_const() {
+	assert_eq!(Foo::A.name, "A");
+	#[cfg(feature = "std")]
+	assert_eq!(Foo::Std.name, "std");
+	#[cfg(not(feature = "std"))]
+	assert_eq!(Foo::NoStd.name, "no_std");
+}
+
+props! {
+	impl EnumProp for Foo as static u32 {
+		let STD = 1;
+		Self::A => 0,
+		// A `cfg` via `cfg_attr`
+		#[cfg_attr(not(feature = "std"), cfg(any()))]
+		Self::Std => STD,
+		#[cfg(not(feature = "std"))]
+		Self::NoStd => 2,
+		_ => 3,
+	}
+}
+
+#[test]
+fn cfg_static() {
+	let number = |variant: Foo| *EnumProp::<u32>::property(&variant);
+
+	assert_eq!(number(Foo::A), 0);
+	#[cfg(feature = "std")]
+	assert_eq!(number(Foo::Std), 1);
+	#[cfg(not(feature = "std"))]
+	assert_eq!(number(Foo::NoStd), 2);
+	assert_eq!(number(Foo::C(42)), 3);
+}
+
+#[cfg(lazy_backend)]
+mod lazy {
+	use super::Foo;
+	use crate::test_util::compute;
+	use crate::EnumPropInit;
+	use crate::LazyEnumProp;
+
+	struct Stats {
+		value: u32,
+	}
+
+	struct GroupedStats {
+		value: u32,
+	}
+
+	#[derive(Clone, Copy)]
+	enum Bar {
+		A,
+		#[cfg(feature = "std")]
+		Std,
+		#[cfg(not(feature = "std"))]
+		NoStd,
+	}
+
+	props! {
+		impl Bar : fn stats as lazy Stats {
+			Self::A => {
+				value: compute(2),
+			}
+			#[cfg(feature = "std")]
+			Self::Std => {
+				value: compute(3),
+			}
+			#[cfg(not(feature = "std"))]
+			Self::NoStd => {
+				value: compute(4),
+			}
+		}
+	}
+
+	props! {
+		impl Foo : fn grouped_stats as lazy(grouped) GroupedStats {
+			#[cfg(feature = "std")]
+			Self::Std => {
+				value: compute(3),
+			}
+			Self::A => {
+				value: compute(2),
+			}
+			#[cfg(not(feature = "std"))]
+			Self::NoStd => {
+				value: compute(4),
+			}
+			_ => {
+				value: compute(5),
+			}
+		}
+	}
+
+	#[test]
+	fn cfg_lazy() {
+		// Only the present variants are initialized
+		<Bar as EnumPropInit<Stats>>::initialize_all();
+		assert!(LazyEnumProp::<Stats>::is_property_initialized(&Bar::A));
+		assert_eq!(Bar::A.stats().value, 4);
+		#[cfg(feature = "std")]
+		assert_eq!(Bar::Std.stats().value, 9);
+		#[cfg(not(feature = "std"))]
+		assert_eq!(Bar::NoStd.stats().value, 16);
+
+		// The table of the grouped property skips the absent variants
+		assert_eq!(Foo::A.grouped_stats().value, 4);
+		assert_eq!(Foo::C(42).grouped_stats().value, 25);
+		#[cfg(feature = "std")]
+		assert_eq!(Foo::Std.grouped_stats().value, 9);
+		#[cfg(not(feature = "std"))]
+		assert_eq!(Foo::NoStd.grouped_stats().value, 16);
+	}
+}
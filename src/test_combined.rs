@@ -0,0 +1,93 @@
+// This file tests the combined syntax, implementing `Deref` and an inherent
+// method at once
+#![cfg(any(test, doctest))]
+#![allow(dead_code)]
+
+mod other {
+	pub struct Stats {
+		pub health: u32,
+	}
+
+	pub enum Foo {
+		A,
+		B,
+	}
+
+	props! {
+		impl Deref + pub fn stats for Foo as static Stats {
+			Self::A => {
+				health: 1,
+			}
+			Self::B => {
+				health: 2,
+			}
+		}
+	}
+}
+
+use other::Foo;
+
+#[test]
+fn combined_static() {
+	assert_eq!(Foo::A.health, 1);
+	assert_eq!(Foo::B.stats().health, 2);
+
+	// Both access paths return the same reference
+	assert!(core::ptr::eq(&*Foo::A, Foo::A.stats()));
+	assert!(core::ptr::eq(&*Foo::B, Foo::B.stats()));
+}
+
+enum Bar {
+	A,
+	B,
+}
+
+props! {
+	impl #[inline] Deref +
+		/// Returns the weight of the variant
+		#[must_use]
+		fn weight
+	for Bar as const u32 {
+		Self::A => 3,
+		Self::B => 5,
+	}
+}
+
+#[test]
+fn combined_const() {
+	assert_eq!(*Bar::A + 1, 4);
+	assert_eq!(*Bar::B.weight(), 5);
+}
+
+#[cfg(lazy_backend)]
+mod lazy {
+	use crate::test_util::compute;
+	use crate::EnumPropInit;
+
+	struct Stats {
+		value: u32,
+	}
+
+	enum Baz {
+		A,
+		B,
+	}
+
+	props! {
+		impl Deref + fn stats for Baz as lazy Stats {
+			Self::A => {
+				value: compute(2),
+			}
+			Self::B => {
+				value: compute(3),
+			}
+		}
+	}
+
+	#[test]
+	fn combined_lazy() {
+		<Baz as EnumPropInit<Stats>>::initialize_all();
+		assert_eq!(Baz::A.value, 4);
+		assert!(core::ptr::eq(&*Baz::B, Baz::B.stats()));
+	}
+}
@@ -0,0 +1,149 @@
+// This file tests the comparison traits derived by a field of the property
+#![cfg(any(test, doctest))]
+#![allow(dead_code)]
+
+use core::cmp::Ordering;
+#[cfg(test)]
+use std::vec::Vec;
+
+struct PrioDef {
+	name: &'static str,
+	rank: u8,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Prio {
+	Urgent,
+	Low,
+	Normal,
+	Also,
+	High,
+}
+
+props! {
+	impl Deref for Prio as const PrioDef {
+		derive(Eq = rank)
+		derive(Ord = rank)
+		Self::Urgent => {
+			name: "urgent",
+			rank: 10,
+		}
+		Self::Low => {
+			name: "low",
+			rank: 1,
+		}
+		Self::Normal | Self::Also => {
+			name: "normal",
+			rank: 3,
+		}
+		Self::High => {
+			name: "high",
+			rank: 5,
+		}
+	}
+}
+
+#[test]
+fn sort_by_rank() {
+	let mut prios = Vec::from([Prio::Urgent, Prio::High, Prio::Normal, Prio::Low]);
+	prios.sort();
+	let ranks: Vec<u8> = prios.iter().map(|p| p.rank).collect();
+	assert_eq!(ranks, [1, 3, 5, 10]);
+	assert_eq!(prios[0].name, "low");
+	assert_eq!(prios[3].name, "urgent");
+}
+
+#[test]
+fn equal_rank() {
+	assert_eq!(Prio::Normal, Prio::Also);
+	assert_eq!(Prio::Normal.cmp(&Prio::Also), Ordering::Equal);
+	assert_ne!(Prio::Normal, Prio::High);
+	assert!(Prio::Low < Prio::Urgent);
+}
+
+// A static property, only partially ordered
+
+struct Weight {
+	value: f32,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Item {
+	Feather,
+	Stone,
+	Nothing,
+}
+
+props! {
+	impl Deref for Item as static Weight {
+		derive(PartialEq = value)
+		derive(PartialOrd = value)
+		Self::Feather => {
+			value: 0.1,
+		}
+		Self::Stone => {
+			value: 2.0,
+		}
+		Self::Nothing => {
+			value: f32::NAN,
+		}
+	}
+}
+
+#[test]
+fn partial_cmp_static() {
+	let mut items = Vec::from([Item::Stone, Item::Feather]);
+	items.sort_by(|a, b| a.partial_cmp(b).unwrap());
+	assert_eq!(items[0], Item::Feather);
+	assert!(Item::Stone > Item::Feather);
+	assert_eq!(Item::Nothing.partial_cmp(&Item::Stone), None);
+	assert_ne!(Item::Nothing, Item::Nothing);
+}
+
+// The field must implement the trait
+
+/// ```compile_fail,E0277
+/// use enumeraties::props;
+///
+/// struct Props {
+///     weight: f32,
+/// }
+///
+/// enum Foo {
+///     A,
+/// }
+///
+/// props! {
+///     impl Deref for Foo as const Props {
+///         derive(Eq = weight)
+///         Self::A => {
+///             weight: 1.0,
+///         }
+///     }
+/// }
+/// ```
+struct NotEq;
+
+// Not a supported trait
+
+/// ```compile_fail
+/// use enumeraties::props;
+///
+/// struct Props {
+///     rank: u8,
+/// }
+///
+/// enum Foo {
+///     A,
+/// }
+///
+/// props! {
+///     impl Deref for Foo as const Props {
+///         derive(Hash = rank)
+///         Self::A => {
+///             rank: 1,
+///         }
+///     }
+/// }
+/// ```
+struct UnknownTrait;
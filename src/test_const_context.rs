@@ -0,0 +1,195 @@
+// This file tests that const properties stay usable in const contexts, i.e. to
+// initialize consts and statics, and as array lengths, for each kind of branch
+// and each const accessor. Any failure here is a compile error.
+#![cfg(any(test, doctest))]
+#![allow(dead_code)]
+
+use super::EnumPropConst;
+
+
+struct ShapeDef {
+	name: &'static str,
+	vertices: u32,
+	weight: f32,
+}
+
+const EXPENSIVE: ShapeDef = ShapeDef {
+	name: "Expensive",
+	vertices: 100,
+	weight: 100.0,
+};
+
+#[derive(Clone, Copy)]
+enum Shape {
+	Triangle,
+	Square,
+	Rhombus,
+	Pentagon,
+	Hexagon,
+	Polygon(u32),
+}
+
+// Each kind of branch, via the `const fn` getter
+props! {
+	impl Shape : pub const fn getter as const ShapeDef {
+		consts {
+			TRIANGLE = Self::Triangle,
+			POLYGON = Self::Polygon(7),
+		}
+		aggregate {
+			max vertices as MAX_VERTICES: u32,
+		}
+		defaults {
+			weight: 1.0,
+		}
+		let QUAD = {
+			name: "Quad",
+			vertices: 4,
+		};
+		Self::Triangle => {
+			name: "Triangle",
+			vertices: 3,
+		}
+		Self::Square | Self::Rhombus => QUAD,
+		Self::Pentagon => {
+			name: "Pentagon",
+			..like Self::Triangle
+		}
+		Self::Hexagon => &EXPENSIVE,
+		Self::Polygon(_) => {
+			name: "Polygon",
+			vertices: 0,
+			weight: 2.0,
+		}
+	}
+}
+
+// Initializing further consts
+const TRIANGLE_VERTICES: u32 = Shape::Triangle.getter().vertices;
+const QUAD_NAME: &str = Shape::Rhombus.getter().name;
+const PENTAGON_VERTICES: u32 = Shape::Pentagon.getter().vertices;
+const HEXAGON_WEIGHT: f32 = Shape::Hexagon.getter().weight;
+const POLYGON_NAME: &str = Shape::POLYGON.name;
+
+// As array lengths
+const CORNERS: [u8; Shape::Square.getter().vertices as usize] = [0; 4];
+const LARGEST: [u8; Shape::MAX_VERTICES as usize] = [0; 100];
+
+// In static initializers
+static TRIANGLE_DEF: &ShapeDef = Shape::TRIANGLE;
+static SQUARE_VERTICES: u32 = Shape::Square.getter().vertices;
+
+// Within const blocks
+const _: () = {
+	assert!(TRIANGLE_VERTICES == 3);
+	assert!(PENTAGON_VERTICES == 3);
+	assert!(HEXAGON_WEIGHT == 100.0);
+	assert!(Shape::Polygon(5).getter().weight == 2.0);
+	assert!(CORNERS.len() == 4);
+	assert!(LARGEST.len() == 100);
+	assert!(Shape::Square.getter().weight == 1.0);
+};
+
+#[test]
+fn const_context_const_fn() {
+	assert_eq!(QUAD_NAME, "Quad");
+	assert_eq!(POLYGON_NAME, "Polygon");
+	assert_eq!(TRIANGLE_DEF.name, "Triangle");
+	assert_eq!(SQUARE_VERTICES, 4);
+}
+
+// Via `EnumPropConst`, with the `all` table and the sorted variants
+
+struct Cost {
+	value: u32,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Tier {
+	Low,
+	Mid,
+	High,
+	Top,
+}
+
+props! {
+	impl EnumPropConst for Tier as const Cost {
+		all {
+			ALL_COSTS
+		}
+		ordered_by(value as BY_COST)
+		Self::Low => {
+			value: 3,
+		}
+		Self::Mid | Self::High => {
+			value: 2,
+		}
+		Self::Top => {
+			value: 1,
+		}
+	}
+}
+
+// A generic `const fn` over any enum with the property
+const fn max_cost<E: EnumPropConst<Cost>>() -> u32 {
+	let table = E::PROPERTY_TABLE;
+	let mut max = 0;
+	let mut i = 0;
+	while i < table.len() {
+		if table[i].value > max {
+			max = table[i].value;
+		}
+		i += 1;
+	}
+	max
+}
+
+const MAX_COST: u32 = max_cost::<Tier>();
+const COST_BUFFER: [u8; Tier::ALL_COSTS[0].value as usize] = [0; 3];
+static CHEAPEST: Tier = Tier::BY_COST[0];
+
+const _: () = {
+	assert!(MAX_COST == 3);
+	assert!(Tier::ALL_COSTS.len() == 4);
+	assert!(Tier::ALL_COSTS[3].value == 1);
+	assert!(COST_BUFFER.len() == 3);
+	assert!(Tier::BY_COST.len() == 4);
+};
+
+#[test]
+fn const_context_prop_const() {
+	assert_eq!(CHEAPEST, Tier::Top);
+	assert_eq!(<Tier as EnumPropConst<Cost>>::PROPERTY_TABLE.len(), 3);
+}
+
+// Via the associated consts of a `Deref` property
+
+struct Meta {
+	id: u8,
+}
+
+props! {
+	impl Deref for Shape as const Meta {
+		consts {
+			pub SQUARE_META = Self::Square,
+		}
+		Self::Triangle => {
+			id: 1,
+		}
+		_ => {
+			id: 2,
+		}
+	}
+}
+
+const SQUARE_ID: u8 = Shape::SQUARE_META.id;
+static IDS: [u8; 2] = [SQUARE_ID, Shape::SQUARE_META.id + 1];
+
+const _: () = {
+	assert!(SQUARE_ID == 2);
+};
+
+#[test]
+fn const_context_consts() {
+	assert_eq!(IDS, [2, 3]);
+}
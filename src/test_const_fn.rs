@@ -0,0 +1,153 @@
+// This file tests getters of const properties via `const fn`, usable in const
+// contexts
+#![cfg(any(test, doctest))]
+#![allow(dead_code)]
+
+use super::EnumProp;
+
+
+struct ShapeDef {
+	name: &'static str,
+	vertices: u32,
+}
+
+#[derive(Clone, Copy)]
+enum Shape {
+	Triangle,
+	Square,
+	Circle,
+}
+
+props! {
+	impl Shape : pub const fn getter as const ShapeDef {
+		defaults {
+			vertices: 0,
+		}
+		Self::Triangle => {
+			name: "Triangle",
+			vertices: 3,
+		}
+		Self::Square => {
+			name: "Square",
+			vertices: 4,
+		}
+		Self::Circle => {
+			name: "Circle",
+		}
+	}
+}
+
+const N: usize = Shape::Square.getter().vertices as usize;
+const NAME: &str = Shape::Triangle.getter().name;
+
+#[test]
+fn const_fn_const_context() {
+	assert_eq!(N, 4);
+	assert_eq!(NAME, "Triangle");
+
+	// As an array length
+	let corners = [0u8; Shape::Triangle.getter().vertices as usize];
+	assert_eq!(corners.len(), 3);
+
+	// Within another `const fn`
+	const fn total(shapes: &[Shape]) -> u32 {
+		let mut sum = 0;
+		let mut i = 0;
+		while i < shapes.len() {
+			sum += shapes[i].getter().vertices;
+			i += 1;
+		}
+		sum
+	}
+	const TOTAL: u32 = total(&[Shape::Triangle, Shape::Square, Shape::Circle]);
+	assert_eq!(TOTAL, 7);
+}
+
+#[test]
+fn const_fn_enum_prop() {
+	// Also implements `EnumProp`, sharing the same table
+	let props = EnumProp::<ShapeDef>::property(&Shape::Circle);
+	assert_eq!(props.name, "Circle");
+	assert!(core::ptr::eq(props, Shape::Circle.getter()));
+}
+
+// Named values, overriding branches, and a section
+struct Cost {
+	value: u32,
+}
+
+static EXPENSIVE: Cost = Cost {
+	value: 100,
+};
+
+props! {
+	impl Shape {
+		const fn cost as const Cost {
+			let CHEAP = {
+				value: 1,
+			};
+			Self::Triangle => CHEAP,
+			Self::Square => &EXPENSIVE,
+			Self::Circle => static {
+				value: 10,
+			}
+		}
+	}
+}
+
+const CIRCLE_COST: u32 = Shape::Circle.cost().value;
+
+#[test]
+fn const_fn_section() {
+	assert_eq!(Shape::Triangle.cost().value, 1);
+	assert_eq!(CIRCLE_COST, 10);
+	assert!(core::ptr::eq(Shape::Square.cost(), &EXPENSIVE));
+}
+
+
+
+// Only `const` properties
+
+/// ```compile_fail
+/// use enumeraties::props;
+///
+/// struct Props {
+///     foo: u32,
+/// }
+///
+/// enum Foo {
+///     A,
+/// }
+///
+/// props! {
+///     impl Foo : const fn getter as static Props {
+///         Self::A => {
+///             foo: 1,
+///         }
+///     }
+/// }
+/// ```
+struct StaticConstFn;
+
+// And no `lazy` branches
+
+/// ```compile_fail
+/// use enumeraties::props;
+///
+/// struct Props {
+///     foo: u32,
+/// }
+///
+/// enum Foo {
+///     A,
+/// }
+///
+/// props! {
+///     impl Foo : const fn getter as const Props {
+///         Self::A => lazy {
+///             foo: 1,
+///         }
+///     }
+/// }
+/// ```
+struct LazyBranch;
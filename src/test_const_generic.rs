@@ -0,0 +1,129 @@
+// This file tests properties of enums with const generic parameters
+#![cfg(any(test, doctest))]
+#![allow(dead_code)]
+
+use super::EnumProp;
+
+
+struct Meta {
+	name: &'static str,
+	growable: bool,
+}
+
+enum Buffer<const N: usize> {
+	Small,
+	Large,
+}
+
+props! {
+	impl<const N: usize> Deref for Buffer<N> as static Meta {
+		Self::Small => {
+			name: "small",
+			growable: false,
+		}
+		Self::Large => {
+			name: "large",
+			growable: true,
+		}
+	}
+}
+
+props! {
+	impl<const N: usize> Buffer<N> : fn factor as const u8 {
+		Self::Small => 1,
+		Self::Large => 4,
+	}
+}
+
+#[test]
+fn const_generic_enum() {
+	assert_eq!(Buffer::<8>::Small.name, "small");
+	assert!(Buffer::<8>::Large.growable);
+	assert_eq!(*Buffer::<16>::Large.factor(), 4);
+
+	// The same `static` property for all instantiations
+	assert!(core::ptr::eq(&*Buffer::<8>::Small, &*Buffer::<16>::Small));
+	assert!(core::ptr::eq(&*Buffer::<8>::Large, &*Buffer::<16>::Large));
+}
+
+// Mixed with lifetimes and type parameters
+enum Chunk<'a, T, const N: usize> {
+	Full(&'a [T; N]),
+	Partial(&'a [T]),
+}
+
+props! {
+	impl<'a, T, const N: usize> EnumProp for Chunk<'a, T, N> as static &'static str {
+		Self::Full(_) => "full",
+		Self::Partial(_) => "partial",
+	}
+}
+
+#[test]
+fn const_generic_enum_mixed() {
+	let data = [1, 2, 3];
+	assert_eq!(*Chunk::Full(&data).property(), "full");
+	assert_eq!(*Chunk::<_, 3>::Partial(&data[1..]).property(), "partial");
+	assert!(core::ptr::eq(
+		Chunk::Full(&data).property(),
+		Chunk::Full(&[1u8; 8]).property()
+	));
+}
+
+#[cfg(lazy_backend)]
+mod lazy {
+	use super::Buffer;
+	use crate::test_util::compute;
+
+	struct Stats {
+		value: u32,
+	}
+
+	props! {
+		impl<const N: usize> Buffer<N> : fn stats as lazy Stats {
+			Self::Small => {
+				value: compute(2),
+			}
+			Self::Large => {
+				value: compute(3),
+			}
+		}
+	}
+
+	#[test]
+	fn const_generic_enum_lazy() {
+		assert_eq!(Buffer::<8>::Small.stats().value, 4);
+		assert_eq!(Buffer::<16>::Large.stats().value, 9);
+
+		// Initialized only once for all instantiations
+		assert!(core::ptr::eq(
+			Buffer::<8>::Large.stats(),
+			Buffer::<16>::Large.stats()
+		));
+	}
+}
+
+
+
+// The values can not depend on the const parameter
+
+/// ```compile_fail,E0401
+/// use enumeraties::props;
+///
+/// struct Props {
+///     size: usize,
+/// }
+///
+/// enum Buffer<const N: usize> {
+///     Small,
+/// }
+///
+/// props! {
+///     impl<const N: usize> EnumProp for Buffer<N> as static Props {
+///         Self::Small => {
+///             size: N,
+///         }
+///     }
+/// }
+/// ```
+struct ConstParamInValue;
@@ -0,0 +1,203 @@
+// This file tests the associated consts of const properties, one per listed
+// variant
+#![cfg(any(test, doctest))]
+#![allow(dead_code)]
+
+use super::EnumProp;
+
+
+struct ShapeDef {
+	name: &'static str,
+	vertices: u32,
+}
+
+struct RenderInfo {
+	layer: u8,
+}
+
+enum Shape {
+	Triangle,
+	Square,
+	Polygon(u32),
+}
+
+props! {
+	impl Deref for Shape as const ShapeDef {
+		defaults {
+			vertices: 0,
+		}
+		consts {
+			/// The triangle
+			pub TRIANGLE_SHAPE_DEF = Self::Triangle,
+			pub(crate) SQUARE_SHAPE_DEF = Shape::Square,
+			PENTAGON_SHAPE_DEF = Self::Polygon(5),
+		}
+		Self::Triangle => {
+			name: "Triangle",
+			vertices: 3,
+		}
+		Self::Square => {
+			name: "Square",
+			vertices: 4,
+		}
+		Self::Polygon(5) => {
+			name: "Pentagon",
+			vertices: 5,
+		}
+		Self::Polygon(_) => {
+			name: "Polygon",
+		}
+	}
+}
+
+// A second property of the same enum, with its own suffix
+props! {
+	impl EnumProp for Shape as const RenderInfo {
+		consts {
+			TRIANGLE_RENDER_INFO = Self::Triangle,
+		}
+		Self::Triangle => {
+			layer: 1,
+		}
+		_ => {
+			layer: 0,
+		}
+	}
+}
+
+const VERTICES: u32 = Shape::SQUARE_SHAPE_DEF.vertices;
+
+#[test]
+fn consts_const_context() {
+	assert_eq!(VERTICES, 4);
+	assert_eq!(Shape::TRIANGLE_SHAPE_DEF.name, "Triangle");
+	assert_eq!(Shape::PENTAGON_SHAPE_DEF.name, "Pentagon");
+	assert_eq!(Shape::TRIANGLE_RENDER_INFO.layer, 1);
+
+	// As an array length
+	let corners = [0u8; Shape::TRIANGLE_SHAPE_DEF.vertices as usize];
+	assert_eq!(corners.len(), 3);
+}
+
+#[test]
+fn consts_match_property() {
+	assert_eq!(Shape::Square.name, Shape::SQUARE_SHAPE_DEF.name);
+	assert_eq!(EnumProp::<RenderInfo>::property(&Shape::Square).layer, 0);
+	assert_eq!(Shape::Polygon(7).name, "Polygon");
+}
+
+// Named values, a `const fn`, and a section
+
+struct Cost {
+	value: u32,
+}
+
+props! {
+	impl Shape {
+		const fn cost as const Cost {
+			consts {
+				pub TRIANGLE_COST = Self::Triangle,
+				pub SQUARE_COST = Self::Square,
+			}
+			let CHEAP = {
+				value: 1,
+			};
+			Self::Triangle | Self::Square => CHEAP,
+			Self::Polygon(_) => static {
+				value: 10,
+			}
+		}
+	}
+}
+
+const TOTAL: u32 = Shape::TRIANGLE_COST.value + Shape::SQUARE_COST.value;
+
+#[test]
+fn consts_section() {
+	assert_eq!(TOTAL, 2);
+	assert!(core::ptr::eq(Shape::TRIANGLE_COST, Shape::Triangle.cost()));
+}
+
+
+
+// Only `const` properties
+
+/// ```compile_fail
+/// use enumeraties::props;
+///
+/// struct Props {
+///     foo: u32,
+/// }
+///
+/// enum Foo {
+///     A,
+/// }
+///
+/// props! {
+///     impl Foo : fn getter as static Props {
+///         consts {
+///             A_PROPS = Self::A,
+///         }
+///         Self::A => {
+///             foo: 1,
+///         }
+///     }
+/// }
+/// ```
+struct StaticConsts;
+
+// Before all branches
+
+/// ```compile_fail
+/// use enumeraties::props;
+///
+/// struct Props {
+///     foo: u32,
+/// }
+///
+/// enum Foo {
+///     A,
+///     B,
+/// }
+///
+/// props! {
+///     impl Foo : fn getter as const Props {
+///         Self::A => {
+///             foo: 1,
+///         }
+///         consts {
+///             B_PROPS = Self::B,
+///         }
+///         Self::B => {
+///             foo: 2,
+///         }
+///     }
+/// }
+/// ```
+struct ConstsAfterBranch;
+
+// A variant with fields needs its values
+
+/// ```compile_fail,E0308
+/// use enumeraties::props;
+///
+/// struct Props {
+///     foo: u32,
+/// }
+///
+/// enum Foo {
+///     A(u8),
+/// }
+///
+/// props! {
+///     impl Foo : fn getter as const Props {
+///         consts {
+///             A_PROPS = Self::A,
+///         }
+///         Self::A(_) => {
+///             foo: 1,
+///         }
+///     }
+/// }
+/// ```
+struct DataVariant;
@@ -0,0 +1,138 @@
+// This file tests the derived `Debug` writing the variant along with its
+// property
+#![cfg(any(test, doctest))]
+#![allow(dead_code)]
+
+use core::fmt;
+#[cfg(test)]
+use std::format;
+
+#[derive(Debug)]
+struct ShapeDef {
+	name: &'static str,
+	vertices: u8,
+}
+
+enum Shape {
+	Triangle,
+	Polygon(u8),
+}
+
+impl Shape {
+	fn variant(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::Triangle => f.write_str("Triangle"),
+			Self::Polygon(n) => f.debug_tuple("Polygon").field(n).finish(),
+		}
+	}
+}
+
+props! {
+	impl Deref for Shape as const ShapeDef {
+		derive(Debug = Self::variant)
+		derive(Display = name)
+		Self::Triangle => {
+			name: "Triangle",
+			vertices: 3,
+		}
+		Self::Polygon(_) => {
+			name: "Polygon",
+			vertices: 0,
+		}
+	}
+}
+
+#[test]
+fn debug_fieldless() {
+	assert_eq!(
+		format!("{:?}", Shape::Triangle),
+		r#"Triangle (ShapeDef { name: "Triangle", vertices: 3 })"#
+	);
+	assert_eq!(format!("{}", Shape::Triangle), "Triangle");
+}
+
+#[test]
+fn debug_data() {
+	assert_eq!(
+		format!("{:?}", Shape::Polygon(5)),
+		r#"Polygon(5) (ShapeDef { name: "Polygon", vertices: 0 })"#
+	);
+	// The alternate form applies to both
+	assert_eq!(
+		format!("{:#?}", Shape::Polygon(5)),
+		"Polygon(\n    5,\n) (ShapeDef {\n    name: \"Polygon\",\n    vertices: 0,\n})"
+	);
+}
+
+// A plain property, via a closure
+
+enum Level {
+	Low,
+	High,
+}
+
+props! {
+	impl EnumProp for Level as static u8 {
+		derive(Debug = |level: &Level, f: &mut fmt::Formatter<'_>| match level {
+			Level::Low => f.write_str("Low"),
+			Level::High => f.write_str("High"),
+		})
+		Self::Low => 1,
+		Self::High => 10,
+	}
+}
+
+#[test]
+fn debug_plain() {
+	assert_eq!(format!("{:?}", Level::High), "High (10)");
+}
+
+// The property must implement `Debug`
+
+/// ```compile_fail,E0277
+/// use enumeraties::props;
+///
+/// struct Props {
+///     name: &'static str,
+/// }
+///
+/// enum Foo {
+///     A,
+/// }
+///
+/// props! {
+///     impl Deref for Foo as const Props {
+///         derive(Debug = |_: &Foo, f: &mut core::fmt::Formatter<'_>| f.write_str("A"))
+///         Self::A => {
+///             name: "a",
+///         }
+///     }
+/// }
+/// ```
+struct NotDebug;
+
+// Conflicts with a derived `Debug` of the enum
+
+/// ```compile_fail,E0119
+/// use enumeraties::props;
+///
+/// #[derive(Debug)]
+/// struct Props {
+///     name: &'static str,
+/// }
+///
+/// #[derive(Debug)]
+/// enum Foo {
+///     A,
+/// }
+///
+/// props! {
+///     impl Deref for Foo as const Props {
+///         derive(Debug = |_: &Foo, f: &mut core::fmt::Formatter<'_>| f.write_str("A"))
+///         Self::A => {
+///             name: "a",
+///         }
+///     }
+/// }
+/// ```
+struct DerivedDebug;
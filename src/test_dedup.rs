@@ -0,0 +1,189 @@
+// This file tests the dedup of identical branches of const and static props
+#![cfg(any(test, doctest))]
+#![allow(dead_code)]
+
+use super::EnumProp;
+
+struct Style {
+	color: &'static str,
+	width: u8,
+}
+
+enum Line {
+	Solid,
+	Dashed,
+	Dotted,
+	Double,
+	Hidden,
+	Custom(u8),
+}
+
+props! {
+	impl EnumProp for Line as static Style {
+		dedup
+		defaults {
+			width: 1,
+		}
+		Self::Solid => {
+			color: "black",
+		}
+		Self::Dashed | Self::Dotted => {
+			color: "black",
+		}
+		Self::Double => {
+			color: "black",
+			width: 2,
+		}
+		Self::Hidden => {
+			color: "none",
+		}
+		Self::Custom(_) => {
+			color: "black",
+		}
+	}
+}
+
+fn style(line: Line) -> &'static Style {
+	EnumProp::<Style>::property(&line)
+}
+
+#[test]
+fn dedup_static() {
+	assert!(core::ptr::eq(style(Line::Solid), style(Line::Dashed)));
+	assert!(core::ptr::eq(style(Line::Solid), style(Line::Dotted)));
+	assert_eq!(style(Line::Dotted).width, 1);
+
+	// Different fields, even if only via the defaults
+	assert!(!core::ptr::eq(style(Line::Solid), style(Line::Double)));
+	assert!(!core::ptr::eq(style(Line::Solid), style(Line::Hidden)));
+	assert_eq!(style(Line::Double).width, 2);
+	assert_eq!(style(Line::Hidden).color, "none");
+
+	// Branches with variant data are kept as they are
+	assert!(!core::ptr::eq(style(Line::Solid), style(Line::Custom(3))));
+	assert_eq!(style(Line::Custom(3)).color, "black");
+}
+
+// Via `const fn`, along with named values, usable in a const
+
+struct Stroke {
+	color: &'static str,
+	width: u8,
+}
+
+props! {
+	impl Line {
+		const fn stroke as const Stroke {
+			dedup
+			let THICK = {
+				color: "gray",
+				width: 3,
+			};
+			Self::Solid => {
+				color: "gray",
+				width: 1,
+			}
+			Self::Double => THICK,
+			Self::Hidden => {
+				color: "gray",
+				width: 1,
+			}
+			_ => {
+				color: "gray",
+				width: 1,
+			}
+		}
+	}
+}
+
+#[test]
+fn dedup_const_fn() {
+	const HIDDEN: &Stroke = Line::Hidden.stroke();
+	assert_eq!(HIDDEN.width, 1);
+	assert_eq!(Line::Double.stroke().width, 3);
+	assert_eq!(Line::Custom(1).stroke().color, "gray");
+}
+
+// Only `const` and `static` properties
+
+/// ```compile_fail
+/// use enumeraties::props;
+///
+/// struct Props {
+///     name: &'static str,
+/// }
+///
+/// enum Foo {
+///     A,
+///     B,
+/// }
+///
+/// props! {
+///     impl Deref for Foo as lazy Props {
+///         dedup
+///         Self::A => {
+///             name: "a",
+///         }
+///         Self::B => {
+///             name: "a",
+///         }
+///     }
+/// }
+/// ```
+struct LazyDedup;
+
+// Not along with the clauses evaluated in consts
+
+/// ```compile_fail
+/// use enumeraties::props;
+///
+/// struct Props {
+///     code: u16,
+/// }
+///
+/// enum Foo {
+///     A,
+///     B,
+/// }
+///
+/// props! {
+///     impl Foo : fn getter as const Props {
+///         dedup
+///         unique(code)
+///         Self::A => {
+///             code: 1,
+///         }
+///         Self::B => {
+///             code: 2,
+///         }
+///     }
+/// }
+/// ```
+struct DedupUnique;
+
+/// ```compile_fail
+/// use enumeraties::props;
+///
+/// struct Props {
+///     name: &'static str,
+/// }
+///
+/// enum Foo {
+///     A,
+///     B,
+/// }
+///
+/// props! {
+///     impl Foo : fn getter as const Props {
+///         dedup
+///         from_str(name)
+///         Self::A => {
+///             name: "a",
+///         }
+///         Self::B => {
+///             name: "b",
+///         }
+///     }
+/// }
+/// ```
+struct DedupFromStr;
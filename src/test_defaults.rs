@@ -0,0 +1,220 @@
+// This file tests the default fields, given once for all branches
+#![cfg(any(test, doctest))]
+#![allow(dead_code)]
+
+use super::EnumProp;
+
+
+struct Unit {
+	name: &'static str,
+	dangerous: bool,
+	speed: f32,
+}
+
+enum Foo {
+	A,
+	B,
+	C(u8),
+}
+
+props! {
+	impl Deref for Foo as const Unit {
+		defaults {
+			dangerous: false,
+			speed: 1.0,
+		}
+		Self::A => {
+			name: "A",
+		}
+		Self::B => {
+			name: "B",
+			speed: 2.0,
+		}
+		_ => {
+			name: "other",
+			dangerous: true,
+			speed: 0.5,
+		}
+	}
+}
+
+#[test]
+fn defaults_const() {
+	assert_eq!(Foo::A.name, "A");
+	assert!(!Foo::A.dangerous);
+	assert_eq!(Foo::A.speed, 1.0);
+
+	// Explicit fields override the defaults
+	assert!(!Foo::B.dangerous);
+	assert_eq!(Foo::B.speed, 2.0);
+
+	assert!(Foo::C(42).dangerous);
+	assert_eq!(Foo::C(42).speed, 0.5);
+}
+
+struct StaticUnit {
+	name: &'static str,
+	dangerous: bool,
+	speed: f32,
+}
+
+const STATIC_BASE: StaticUnit = StaticUnit {
+	name: "base",
+	dangerous: false,
+	speed: 0.0,
+};
+
+props! {
+	impl EnumProp for Foo as static StaticUnit {
+		defaults { speed: 3.0 },
+		Self::A => {
+			name: "A",
+			dangerous: false,
+		},
+		Self::B => {
+			dangerous: true,
+			..STATIC_BASE
+		},
+		Self::C(_) => {
+			..STATIC_BASE
+		},
+	}
+}
+
+#[test]
+fn defaults_static() {
+	let unit = |variant: Foo| EnumProp::<StaticUnit>::property(&variant);
+
+	assert_eq!(unit(Foo::A).name, "A");
+	assert_eq!(unit(Foo::A).speed, 3.0);
+
+	// The defaults take precedence over the base value
+	assert_eq!(unit(Foo::B).name, "base");
+	assert!(unit(Foo::B).dangerous);
+	assert_eq!(unit(Foo::B).speed, 3.0);
+	assert!(!unit(Foo::C(42)).dangerous);
+	assert_eq!(unit(Foo::C(42)).speed, 3.0);
+}
+
+#[cfg(lazy_backend)]
+mod lazy {
+	use super::Foo;
+	use crate::test_util::compute;
+
+	struct Stats {
+		health: u32,
+		armor: u32,
+	}
+
+	struct GroupedStats {
+		health: u32,
+		armor: u32,
+	}
+
+	props! {
+		impl Foo : fn stats as lazy Stats {
+			defaults {
+				health: compute(10),
+				armor: compute(2),
+			}
+			Self::A => {}
+			Self::B => {
+				armor: compute(3),
+			}
+			Self::C(_) => {
+				health: 1,
+				armor: 0,
+			}
+		}
+	}
+
+	props! {
+		impl Foo : fn grouped_stats as lazy(grouped) GroupedStats {
+			defaults {
+				health: compute(10),
+				armor: compute(2),
+			}
+			Self::A => {},
+			Self::B => {
+				armor: compute(3),
+			},
+			Self::C(_) => {
+				health: 1,
+			},
+		}
+	}
+
+	#[test]
+	fn defaults_lazy() {
+		assert_eq!(Foo::A.stats().health, 100);
+		assert_eq!(Foo::A.stats().armor, 4);
+		assert_eq!(Foo::B.stats().armor, 9);
+		assert_eq!(Foo::C(42).stats().health, 1);
+
+		assert_eq!(Foo::A.grouped_stats().health, 100);
+		assert_eq!(Foo::B.grouped_stats().armor, 9);
+		assert_eq!(Foo::C(42).grouped_stats().health, 1);
+		assert_eq!(Foo::C(42).grouped_stats().armor, 4);
+	}
+}
+
+
+
+// Fields that are neither given nor defaulted are still missing
+
+/// ```compile_fail,E0063
+/// use enumeraties::props;
+///
+/// struct Props {
+///     foo: u8,
+///     bar: u8,
+///     baz: u8,
+/// }
+///
+/// enum Foo {
+///     A,
+/// }
+///
+/// props! {
+///     impl Deref for Foo as const Props {
+///         defaults {
+///             foo: 42,
+///         }
+///         Self::A => {
+///             bar: 43,
+///         }
+///     }
+/// }
+/// ```
+struct MissingField;
+
+// The defaults must come first
+
+/// ```compile_fail
+/// use enumeraties::props;
+///
+/// struct Props {
+///     foo: u8,
+///     bar: u8,
+/// }
+///
+/// enum Foo {
+///     A,
+///     B,
+/// }
+///
+/// props! {
+///     impl Deref for Foo as const Props {
+///         Self::A => {
+///             bar: 43,
+///         }
+///         defaults {
+///             foo: 42,
+///         }
+///         Self::B => {
+///             bar: 44,
+///         }
+///     }
+/// }
+/// ```
+struct DefaultsFirst;
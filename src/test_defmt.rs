@@ -0,0 +1,155 @@
+// This file tests the `defmt::Format` impl writing a field of the property.
+// Since the log frames are only decoded on the host of an embedded target,
+// this checks that the impls exist and compile.
+#![cfg(any(test, doctest))]
+#![cfg(feature = "defmt")]
+#![allow(dead_code)]
+
+#[cfg(test)]
+fn assert_format<T: defmt::Format>(_value: &T) {}
+
+
+struct StateDef {
+	name: &'static str,
+	code: u8,
+}
+
+enum State {
+	Idle,
+	Busy(u16),
+}
+
+props! {
+	impl Deref for State as const StateDef {
+		defmt(name)
+		Self::Idle => {
+			name: "idle",
+			code: 0,
+		}
+		Self::Busy(_) => {
+			name: "busy",
+			code: 1,
+		}
+	}
+}
+
+#[test]
+fn format_deref() {
+	assert_format(&State::Idle);
+	assert_format(&State::Busy(3));
+	assert_eq!(State::Busy(3).code, 1);
+}
+
+// Along with a static prop via `fn`, and a derived `Display` of the same field
+
+struct Level {
+	label: &'static str,
+}
+
+enum Priority {
+	Low,
+	High,
+}
+
+props! {
+	impl Priority {
+		fn level as static Level {
+			defmt(label)
+			derive(Display = label)
+			Self::Low => {
+				label: "low",
+			}
+			Self::High => {
+				label: "high",
+			}
+		}
+	}
+}
+
+#[test]
+fn format_static_fn() {
+	use std::string::ToString;
+
+	assert_format(&Priority::High);
+	assert_eq!(Priority::Low.to_string(), "low");
+}
+
+// Generic enums
+
+struct Label {
+	text: &'static str,
+}
+
+enum Wrapper<T> {
+	Empty,
+	Value(T),
+}
+
+props! {
+	impl<T> EnumProp for Wrapper<T> as const Label {
+		defmt(text)
+		Self::Empty => {
+			text: "none",
+		}
+		Self::Value(_) => {
+			text: "some",
+		}
+	}
+}
+
+#[test]
+fn format_generic() {
+	// The value needs not implement `Format`
+	struct NoFormat;
+
+	assert_format(&Wrapper::Value(NoFormat));
+	assert_format(&Wrapper::<NoFormat>::Empty);
+}
+
+// The field must be a `&'static str`
+
+/// ```compile_fail,E0277
+/// use enumeraties::props;
+///
+/// struct Props {
+///     code: u32,
+/// }
+///
+/// enum Foo {
+///     A,
+/// }
+///
+/// props! {
+///     impl Deref for Foo as const Props {
+///         defmt(code)
+///         Self::A => {
+///             code: 1,
+///         }
+///     }
+/// }
+/// ```
+struct NotStr;
+
+// The clause must come before all branches
+
+/// ```compile_fail
+/// use enumeraties::props;
+///
+/// struct Props {
+///     name: &'static str,
+/// }
+///
+/// enum Foo {
+///     A,
+/// }
+///
+/// props! {
+///     impl Deref for Foo as const Props {
+///         Self::A => {
+///             name: "a",
+///         }
+///         defmt(name)
+///     }
+/// }
+/// ```
+struct AfterBranches;
@@ -0,0 +1,320 @@
+// This file tests branches delegating to the property of an inner enum
+#![cfg(any(test, doctest))]
+#![allow(dead_code)]
+
+use super::EnumProp;
+
+
+struct ShapeDef {
+	name: &'static str,
+	sides: u8,
+}
+
+#[derive(Clone, Copy)]
+enum Shape {
+	Triangle,
+	Square,
+}
+
+props! {
+	impl Deref for Shape as static ShapeDef {
+		Self::Triangle => {
+			name: "Triangle",
+			sides: 3,
+		}
+		Self::Square => {
+			name: "Square",
+			sides: 4,
+		}
+	}
+}
+
+enum Outer {
+	Shape(Shape),
+	Named { shape: Shape, label: &'static str },
+	Misc,
+	Circle,
+}
+
+props! {
+	impl Deref for Outer as const ShapeDef {
+		defaults {
+			sides: 0,
+		}
+		Self::Shape(inner) => delegate inner,
+		Self::Named { shape, .. } => delegate shape,
+		Self::Misc => {
+			name: "Misc",
+		}
+		Self::Circle => {
+			name: "Circle",
+			sides: 1,
+		}
+	}
+}
+
+#[test]
+fn delegate_mixed() {
+	assert_eq!(Outer::Shape(Shape::Triangle).name, "Triangle");
+	assert_eq!(Outer::Shape(Shape::Square).sides, 4);
+	assert_eq!(
+		Outer::Named {
+			shape: Shape::Square,
+			label: "box",
+		}
+		.name,
+		"Square"
+	);
+	assert_eq!(Outer::Misc.name, "Misc");
+	assert_eq!(Outer::Misc.sides, 0);
+	assert_eq!(Outer::Circle.sides, 1);
+
+	// The very property of the inner enum
+	assert!(core::ptr::eq(
+		&*Outer::Shape(Shape::Triangle),
+		&*Shape::Triangle
+	));
+}
+
+// Delegating through several levels
+enum Outermost {
+	Outer(Outer),
+	Empty,
+}
+
+props! {
+	impl EnumProp for Outermost as static ShapeDef {
+		Self::Outer(outer) => delegate outer,
+		Self::Empty => {
+			name: "Empty",
+			sides: 0,
+		}
+	}
+}
+
+#[test]
+fn delegate_nested() {
+	let props = |value: Outermost| EnumProp::<ShapeDef>::property(&value);
+
+	assert_eq!(
+		props(Outermost::Outer(Outer::Shape(Shape::Square))).name,
+		"Square"
+	);
+	assert_eq!(props(Outermost::Outer(Outer::Misc)).name, "Misc");
+	assert_eq!(props(Outermost::Empty).name, "Empty");
+}
+
+// Delegating a guarded branch, and an expression of the bound data
+enum Wrapper {
+	Pair(Shape, Shape),
+	Boxed(&'static Shape),
+}
+
+props! {
+	impl Wrapper : fn shape as const ShapeDef {
+		Self::Pair(first, _) if first.sides > 3 => delegate first,
+		Self::Pair(_, second) => delegate second,
+		Self::Boxed(inner) => delegate *inner,
+	}
+}
+
+#[test]
+fn delegate_expression() {
+	assert_eq!(
+		Wrapper::Pair(Shape::Square, Shape::Triangle).shape().name,
+		"Square"
+	);
+	assert_eq!(
+		Wrapper::Pair(Shape::Triangle, Shape::Square).shape().name,
+		"Square"
+	);
+	assert_eq!(
+		Wrapper::Pair(Shape::Triangle, Shape::Triangle).shape().name,
+		"Triangle"
+	);
+	assert_eq!(Wrapper::Boxed(&Shape::Triangle).shape().sides, 3);
+}
+
+#[cfg(lazy_backend)]
+mod lazy {
+	use super::Shape;
+	use super::ShapeDef;
+	use crate::test_util::compute;
+	use crate::LazyEnumProp;
+
+	enum Tiled {
+		Shape(Shape),
+		Grid,
+	}
+
+	props! {
+		impl Tiled : fn def as lazy ShapeDef {
+			Self::Shape(inner) => delegate inner,
+			Self::Grid => {
+				name: "Grid",
+				sides: compute(2),
+			}
+		}
+	}
+
+	#[test]
+	fn delegate_lazy() {
+		// Without a lazy cell of its own
+		assert!(Tiled::Shape(Shape::Square).is_property_initialized());
+		assert_eq!(Tiled::Shape(Shape::Square).def().sides, 4);
+
+		assert!(!Tiled::Grid.is_property_initialized());
+		assert_eq!(Tiled::Grid.def().sides, 4);
+	}
+}
+
+#[cfg(feature = "alloc")]
+mod dynamic {
+	use alloc::borrow::Cow;
+	use alloc::string::String;
+
+	use super::Shape;
+
+	#[derive(Clone)]
+	struct Def {
+		name: Cow<'static, str>,
+	}
+
+	props! {
+		impl EnumProp for Shape as const Def {
+			Self::Triangle => {
+				name: Cow::Borrowed("Triangle"),
+			}
+			Self::Square => {
+				name: Cow::Borrowed("Square"),
+			}
+		}
+	}
+
+	enum Custom {
+		Shape(Shape),
+		Named(&'static str),
+	}
+
+	props! {
+		impl Custom : fn def as dynamic Def {
+			Self::Shape(inner) => delegate inner,
+			Self::Named(name) => {
+				name: Cow::Owned(String::from(*name)),
+			}
+		}
+	}
+
+	#[test]
+	fn delegate_dynamic() {
+		let def = Custom::Shape(Shape::Triangle).def();
+		assert!(matches!(def, Cow::Borrowed(_)));
+		assert_eq!(def.name, "Triangle");
+
+		assert_eq!(Custom::Named("Hexagon").def().name, "Hexagon");
+	}
+}
+
+
+
+// The inner enum must have the property
+
+/// ```compile_fail,E0277
+/// use enumeraties::props;
+///
+/// struct Props {
+///     foo: u32,
+/// }
+///
+/// enum Inner {
+///     A,
+/// }
+///
+/// enum Outer {
+///     Inner(Inner),
+/// }
+///
+/// props! {
+///     impl Deref for Outer as const Props {
+///         Self::Inner(inner) => delegate inner,
+///     }
+/// }
+/// ```
+struct MissingProperty;
+
+// A grouped table can not delegate
+
+/// ```compile_fail
+/// use enumeraties::props;
+///
+/// struct Props {
+///     foo: u32,
+/// }
+///
+/// enum Inner {
+///     A,
+/// }
+///
+/// props! {
+///     impl Deref for Inner as const Props {
+///         Self::A => {
+///             foo: 1,
+///         }
+///     }
+/// }
+///
+/// enum Outer {
+///     Inner(Inner),
+///     B,
+/// }
+///
+/// props! {
+///     impl EnumProp for Outer as lazy(grouped) Props {
+///         Self::Inner(inner) => delegate inner,
+///         Self::B => {
+///             foo: 2,
+///         }
+///     }
+/// }
+/// ```
+struct Grouped;
+
+// Nor be the base of `..like`
+
+/// ```compile_fail
+/// use enumeraties::props;
+///
+/// struct Props {
+///     foo: u32,
+///     bar: u32,
+/// }
+///
+/// enum Inner {
+///     A,
+/// }
+///
+/// props! {
+///     impl Deref for Inner as const Props {
+///         Self::A => {
+///             foo: 1,
+///             bar: 1,
+///         }
+///     }
+/// }
+///
+/// enum Outer {
+///     Inner(Inner),
+///     B,
+/// }
+///
+/// props! {
+///     impl Deref for Outer as const Props {
+///         Self::Inner(inner) => delegate inner,
+///         Self::B => {
+///             foo: 2,
+///             ..like Self::Inner
+///         }
+///     }
+/// }
+/// ```
+struct LikeDelegate;
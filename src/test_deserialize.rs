@@ -0,0 +1,294 @@
+// This file tests deserializing enums from a string field of their property
+#![cfg(any(test, doctest))]
+#![cfg(feature = "serde")]
+#![allow(dead_code)]
+
+use std::string::ToString;
+use std::vec::Vec;
+
+use serde_json::from_str;
+use serde_json::to_string;
+
+
+struct ColorDef {
+	name: &'static str,
+	hex: u32,
+}
+
+#[derive(Debug, PartialEq)]
+enum Color {
+	Red,
+	Green,
+	Blue,
+}
+
+props! {
+	impl Deref for Color as const ColorDef {
+		serialize_as(name)
+		deserialize_from(name)
+		Self::Red => {
+			name: "red",
+			hex: 0xff0000,
+		}
+		Self::Green => {
+			name: "green",
+			hex: 0x00ff00,
+		}
+		Self::Blue => {
+			name: "blue",
+			hex: 0x0000ff,
+		}
+	}
+}
+
+#[test]
+fn deserialize_name() {
+	assert_eq!(from_str::<Color>(r#""green""#).unwrap(), Color::Green);
+	assert_eq!(
+		from_str::<Vec<Color>>(r#"["blue","red"]"#).unwrap(),
+		[Color::Blue, Color::Red]
+	);
+}
+
+#[test]
+fn round_trip() {
+	for color in [Color::Red, Color::Green, Color::Blue] {
+		let json = to_string(&color).unwrap();
+		assert_eq!(from_str::<Color>(&json).unwrap(), color);
+	}
+}
+
+#[test]
+fn deserialize_unknown() {
+	let error = from_str::<Color>(r#""yellow""#).unwrap_err();
+	assert_eq!(
+		error.to_string(),
+		"unknown variant `yellow`, expected one of `red`, `green`, `blue` at line 1 column 8"
+	);
+}
+
+#[test]
+fn deserialize_not_str() {
+	let error = from_str::<Color>("3").unwrap_err();
+	assert_eq!(
+		error.to_string(),
+		"invalid type: integer `3`, expected the name of a variant at line 1 column 1"
+	);
+}
+
+// Along with a `const fn`, defaults, or-patterns, and named values
+
+struct Level {
+	label: &'static str,
+	rank: u8,
+}
+
+#[derive(Debug, PartialEq)]
+enum Priority {
+	Low,
+	Medium,
+	Normal,
+	High,
+}
+
+props! {
+	impl Priority {
+		pub const fn level as const Level {
+			deserialize_from(label)
+			defaults {
+				rank: 0,
+			}
+			let HIGH = {
+				label: "high",
+				rank: 10,
+			};
+			Self::Low => {
+				label: "low",
+			}
+			Self::Medium | Self::Normal => {
+				label: "normal",
+				rank: 5,
+			}
+			Self::High => HIGH,
+		}
+	}
+}
+
+#[test]
+fn deserialize_const_fn() {
+	assert_eq!(Priority::High.level().rank, 10);
+	assert_eq!(from_str::<Priority>(r#""low""#).unwrap(), Priority::Low);
+	assert_eq!(from_str::<Priority>(r#""high""#).unwrap(), Priority::High);
+	// The first variant of the branch
+	assert_eq!(
+		from_str::<Priority>(r#""normal""#).unwrap(),
+		Priority::Medium
+	);
+	// Each branch is expected once
+	assert_eq!(
+		from_str::<Priority>(r#""urgent""#).unwrap_err().to_string(),
+		"unknown variant `urgent`, expected one of `low`, `normal`, `high` at line 1 column 8"
+	);
+}
+
+// Generic enums, whose variants can not have data, e.g. by a const parameter
+
+struct Label {
+	text: &'static str,
+}
+
+#[derive(Debug, PartialEq)]
+enum Side<const N: usize> {
+	Left,
+	Right,
+}
+
+props! {
+	impl<const N: usize> EnumProp for Side<N> as const Label {
+		deserialize_from(text)
+		Self::Left => {
+			text: "left",
+		}
+		Self::Right => {
+			text: "right",
+		}
+	}
+}
+
+#[test]
+fn deserialize_generic() {
+	assert_eq!(from_str::<Side<3>>(r#""right""#).unwrap(), Side::Right);
+	assert!(from_str::<Side<3>>(r#""up""#).is_err());
+}
+
+// Variants with data can not be deserialized
+
+/// ```compile_fail
+/// use enumeraties::props;
+///
+/// struct Props {
+///     name: &'static str,
+/// }
+///
+/// enum Foo {
+///     A,
+///     B(u8),
+/// }
+///
+/// props! {
+///     impl Deref for Foo as const Props {
+///         deserialize_from(name)
+///         Self::A => {
+///             name: "a",
+///         }
+///         Self::B(_) => {
+///             name: "b",
+///         }
+///     }
+/// }
+/// ```
+struct DataVariant;
+
+// Neither a wildcard branch
+
+/// ```compile_fail
+/// use enumeraties::props;
+///
+/// struct Props {
+///     name: &'static str,
+/// }
+///
+/// enum Foo {
+///     A,
+///     B,
+/// }
+///
+/// props! {
+///     impl Deref for Foo as const Props {
+///         deserialize_from(name)
+///         Self::A => {
+///             name: "a",
+///         }
+///         _ => {
+///             name: "b",
+///         }
+///     }
+/// }
+/// ```
+struct Wildcard;
+
+// The names must be unique
+
+/// ```compile_fail,E0080
+/// use enumeraties::props;
+///
+/// struct Props {
+///     name: &'static str,
+/// }
+///
+/// enum Foo {
+///     A,
+///     B,
+/// }
+///
+/// props! {
+///     impl Deref for Foo as const Props {
+///         deserialize_from(name)
+///         Self::A => {
+///             name: "a",
+///         }
+///         Self::B => {
+///             name: "a",
+///         }
+///     }
+/// }
+/// ```
+struct DuplicateName;
+
+// The field must be a string
+
+/// ```compile_fail
+/// use enumeraties::props;
+///
+/// struct Props {
+///     code: u32,
+/// }
+///
+/// enum Foo {
+///     A,
+/// }
+///
+/// props! {
+///     impl Deref for Foo as const Props {
+///         deserialize_from(code)
+///         Self::A => {
+///             code: 1,
+///         }
+///     }
+/// }
+/// ```
+struct NotStr;
+
+// Only const properties are supported
+
+/// ```compile_fail
+/// use enumeraties::props;
+///
+/// struct Props {
+///     name: &'static str,
+/// }
+///
+/// enum Foo {
+///     A,
+/// }
+///
+/// props! {
+///     impl Deref for Foo as static Props {
+///         deserialize_from(name)
+///         Self::A => {
+///             name: "a",
+///         }
+///     }
+/// }
+/// ```
+struct Static;
@@ -0,0 +1,327 @@
+// This file tests looking up the properties by the discriminant of the variants
+#![cfg(any(test, doctest))]
+#![allow(dead_code)]
+
+
+struct OpcodeDef {
+	mnemonic: &'static str,
+	operands: u8,
+}
+
+// Contiguous discriminants, implicitly assigned
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[repr(u8)]
+enum Opcode {
+	Nop,
+	Load,
+	Store,
+	Jump,
+}
+
+props! {
+	impl Deref for Opcode as const OpcodeDef {
+		discriminant(u8)
+		Self::Nop => {
+			mnemonic: "nop",
+			operands: 0,
+		}
+		Self::Load | Self::Store => {
+			mnemonic: "mov",
+			operands: 2,
+		}
+		Self::Jump => {
+			mnemonic: "jmp",
+			operands: 1,
+		}
+	}
+}
+
+#[test]
+fn contiguous() {
+	for opcode in [Opcode::Nop, Opcode::Load, Opcode::Store, Opcode::Jump] {
+		let discriminant = opcode as u8;
+		let prop = Opcode::property_of_discriminant(discriminant).unwrap();
+		assert_eq!(prop.mnemonic, opcode.mnemonic);
+	}
+	assert_eq!(Opcode::property_of_discriminant(2).unwrap().operands, 2);
+	assert!(Opcode::property_of_discriminant(4).is_none());
+	assert!(Opcode::property_of_discriminant(u8::MAX).is_none());
+}
+
+// Sparse discriminants, explicitly assigned, along with a `static` property
+
+#[derive(Debug, PartialEq)]
+#[repr(u16)]
+enum Status {
+	Ok = 200,
+	NotFound = 404,
+	Teapot = 418,
+	Error = 500,
+}
+
+props! {
+	impl Status : pub fn reason as static &'static str {
+		discriminant(u16)
+		Self::Ok => "OK",
+		Self::NotFound => "Not Found",
+		Self::Teapot => "I'm a teapot",
+		Self::Error => "Internal Server Error",
+	}
+}
+
+#[test]
+fn sparse() {
+	assert_eq!(Status::property_of_discriminant(200), Some(&"OK"));
+	assert_eq!(Status::property_of_discriminant(418), Some(&"I'm a teapot"));
+	assert_eq!(
+		Status::property_of_discriminant(500),
+		Some(&"Internal Server Error")
+	);
+	// The gaps and beyond the table
+	assert_eq!(Status::property_of_discriminant(0), None);
+	assert_eq!(Status::property_of_discriminant(201), None);
+	assert_eq!(Status::property_of_discriminant(501), None);
+	assert_eq!(Status::property_of_discriminant(u16::MAX), None);
+}
+
+// Several lookups on the same enum, named explicitly
+
+props! {
+	impl Status : pub fn is_success as static bool {
+		discriminant(u16 as is_success_of_discriminant)
+		Self::Ok => true,
+		Self::NotFound | Self::Teapot | Self::Error => false,
+	}
+}
+
+props! {
+	impl Status : pub fn class as const u8 {
+		discriminant(u16 as class_of_discriminant)
+		Self::Ok => 2,
+		Self::NotFound | Self::Teapot => 4,
+		Self::Error => 5,
+	}
+}
+
+#[test]
+fn named() {
+	assert_eq!(Status::is_success_of_discriminant(200), Some(&true));
+	assert_eq!(Status::is_success_of_discriminant(404), Some(&false));
+	assert_eq!(Status::class_of_discriminant(418), Some(&4));
+	assert_eq!(Status::class_of_discriminant(201), None);
+	// Along with the default name
+	assert_eq!(Status::property_of_discriminant(200), Some(&"OK"));
+}
+
+// Along with a `const fn`, in any order of the branches
+
+struct Level {
+	rank: u8,
+}
+
+#[repr(u8)]
+enum Priority {
+	Low = 1,
+	High = 3,
+	Medium = 2,
+}
+
+props! {
+	impl Priority {
+		pub const fn level as const Level {
+			discriminant(u8)
+			Self::High => {
+				rank: 10,
+			}
+			Self::Low => {
+				rank: 0,
+			}
+			Self::Medium => {
+				rank: 5,
+			}
+		}
+	}
+}
+
+#[test]
+fn const_fn() {
+	let ranks: [Option<u8>; 5] = core::array::from_fn(|i| {
+		Priority::property_of_discriminant(i as u8).map(|level| level.rank)
+	});
+	assert_eq!(ranks, [None, Some(0), Some(5), Some(10), None]);
+}
+
+// Variants with data have no discriminant to look up
+
+/// ```compile_fail
+/// use enumeraties::props;
+///
+/// struct Props {
+///     name: &'static str,
+/// }
+///
+/// #[repr(u8)]
+/// enum Foo {
+///     A,
+///     B(u8),
+/// }
+///
+/// props! {
+///     impl Deref for Foo as const Props {
+///         discriminant(u8)
+///         Self::A => {
+///             name: "a",
+///         }
+///         Self::B(_) => {
+///             name: "b",
+///         }
+///     }
+/// }
+/// ```
+struct DataVariant;
+
+// Neither a wildcard branch
+
+/// ```compile_fail
+/// use enumeraties::props;
+///
+/// struct Props {
+///     name: &'static str,
+/// }
+///
+/// #[repr(u8)]
+/// enum Foo {
+///     A,
+///     B,
+/// }
+///
+/// props! {
+///     impl Deref for Foo as const Props {
+///         discriminant(u8)
+///         Self::A => {
+///             name: "a",
+///         }
+///         _ => {
+///             name: "b",
+///         }
+///     }
+/// }
+/// ```
+struct Wildcard;
+
+// The discriminants must fit in the given type
+
+/// ```compile_fail,E0080
+/// use enumeraties::props;
+///
+/// struct Props {
+///     name: &'static str,
+/// }
+///
+/// #[repr(u16)]
+/// enum Foo {
+///     A = 1,
+///     B = 256,
+/// }
+///
+/// props! {
+///     impl Deref for Foo as const Props {
+///         discriminant(u8)
+///         Self::A => {
+///             name: "a",
+///         }
+///         Self::B => {
+///             name: "b",
+///         }
+///     }
+/// }
+/// ```
+struct TooLarge;
+
+// Nor exceed the bound of the dense table
+
+/// ```compile_fail,E0080
+/// use enumeraties::props;
+///
+/// struct Props {
+///     name: &'static str,
+/// }
+///
+/// #[repr(u32)]
+/// enum Foo {
+///     A = 1,
+///     B = 0xDEAD_BEEF,
+/// }
+///
+/// props! {
+///     impl Deref for Foo as const Props {
+///         discriminant(u32)
+///         Self::A => {
+///             name: "a",
+///         }
+///         Self::B => {
+///             name: "b",
+///         }
+///     }
+/// }
+/// ```
+struct Unbounded;
+
+// Neither be negative
+
+/// ```compile_fail,E0080
+/// use enumeraties::props;
+///
+/// struct Props {
+///     name: &'static str,
+/// }
+///
+/// #[repr(i8)]
+/// enum Foo {
+///     A = -1,
+///     B = 1,
+/// }
+///
+/// props! {
+///     impl Deref for Foo as const Props {
+///         discriminant(i8)
+///         Self::A => {
+///             name: "a",
+///         }
+///         Self::B => {
+///             name: "b",
+///         }
+///     }
+/// }
+/// ```
+struct Negative;
+
+// Generic enums are not supported
+
+/// ```compile_fail
+/// use enumeraties::props;
+///
+/// struct Props {
+///     name: &'static str,
+/// }
+///
+/// #[repr(u8)]
+/// enum Foo<const N: usize> {
+///     A,
+///     B,
+/// }
+///
+/// props! {
+///     impl<const N: usize> EnumProp for Foo<N> as const Props {
+///         discriminant(u8)
+///         Self::A => {
+///             name: "a",
+///         }
+///         Self::B => {
+///             name: "b",
+///         }
+///     }
+/// }
+/// ```
+struct Generic;
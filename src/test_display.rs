@@ -0,0 +1,180 @@
+// This file tests the derived `Display` writing a field of the property
+#![cfg(any(test, doctest))]
+#![allow(dead_code)]
+
+#[cfg(test)]
+use std::format;
+
+struct ShapeDef {
+	name: &'static str,
+	sides: u8,
+}
+
+enum Shape {
+	Triangle,
+	Square,
+	Polygon(u8),
+}
+
+props! {
+	impl Deref for Shape as const ShapeDef {
+		derive(Display = name)
+		defaults {
+			name: "polygon",
+		}
+		Self::Triangle => {
+			name: "triangle",
+			sides: 3,
+		}
+		Self::Square => {
+			name: "square",
+			sides: 4,
+		}
+		Self::Polygon(_) => {
+			sides: 0,
+		}
+	}
+}
+
+#[test]
+fn display_deref() {
+	assert_eq!(Shape::Square.sides, 4);
+	assert_eq!(format!("{}", Shape::Triangle), "triangle");
+	assert_eq!(format!("{}", Shape::Polygon(7)), "polygon");
+	// Along with the formatting options of the field
+	assert_eq!(format!("{:>8}", Shape::Square), "  square");
+}
+
+// Along with `Deref` and an inherent method, via a non-str field
+
+struct Level {
+	rank: u32,
+}
+
+enum Priority {
+	Low,
+	High,
+}
+
+props! {
+	impl Deref + pub fn level for Priority as static Level {
+		derive(Display = rank)
+		Self::Low => {
+			rank: 1,
+		}
+		Self::High => {
+			rank: 10,
+		}
+	}
+}
+
+#[test]
+fn display_deref_fn() {
+	assert_eq!(Priority::High.rank, 10);
+	assert_eq!(Priority::Low.level().rank, 1);
+	assert_eq!(format!("{}", Priority::High), "10");
+}
+
+// Generic enums, along with further clauses, within a section
+
+struct Label {
+	text: &'static str,
+}
+
+enum Wrapper<T> {
+	Empty,
+	Value(T),
+}
+
+props! {
+	impl<T> Wrapper<T> {
+		const fn label as const Label {
+			dedup
+			derive(Display = text)
+			Self::Empty => {
+				text: "none",
+			}
+			Self::Value(_) => {
+				text: "some",
+			}
+		}
+	}
+}
+
+#[test]
+fn display_generic() {
+	assert_eq!(format!("{}", Wrapper::Value(3)), "some");
+	assert_eq!(format!("{}", Wrapper::<u8>::Empty), "none");
+}
+
+// The field must implement `Display`
+
+/// ```compile_fail,E0277
+/// use enumeraties::props;
+///
+/// struct Props {
+///     sides: (u8, u8),
+/// }
+///
+/// enum Foo {
+///     A,
+/// }
+///
+/// props! {
+///     impl Deref for Foo as const Props {
+///         derive(Display = sides)
+///         Self::A => {
+///             sides: (1, 2),
+///         }
+///     }
+/// }
+/// ```
+struct NotDisplay;
+
+// The field must exist
+
+/// ```compile_fail,E0026
+/// use enumeraties::props;
+///
+/// struct Props {
+///     name: &'static str,
+/// }
+///
+/// enum Foo {
+///     A,
+/// }
+///
+/// props! {
+///     impl Deref for Foo as const Props {
+///         derive(Display = title)
+///         Self::A => {
+///             name: "a",
+///         }
+///     }
+/// }
+/// ```
+struct UnknownField;
+
+// Only `Display` can be derived
+
+/// ```compile_fail
+/// use enumeraties::props;
+///
+/// struct Props {
+///     name: &'static str,
+/// }
+///
+/// enum Foo {
+///     A,
+/// }
+///
+/// props! {
+///     impl Deref for Foo as const Props {
+///         derive(Debug = name)
+///         Self::A => {
+///             name: "a",
+///         }
+///     }
+/// }
+/// ```
+struct OtherTrait;
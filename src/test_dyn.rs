@@ -0,0 +1,137 @@
+// This file tests trait object properties
+#![cfg(any(test, doctest))]
+#![allow(dead_code)]
+
+use super::EnumProp;
+
+
+trait Renderer {
+	fn render(&self, size: u32) -> u32;
+}
+
+struct SpriteRenderer;
+
+impl Renderer for SpriteRenderer {
+	fn render(&self, size: u32) -> u32 {
+		size
+	}
+}
+
+struct MeshRenderer {
+	triangles: u32,
+}
+
+impl Renderer for MeshRenderer {
+	fn render(&self, size: u32) -> u32 {
+		size * self.triangles
+	}
+}
+
+const SPRITE_RENDERER: SpriteRenderer = SpriteRenderer;
+
+static MESH_RENDERER: MeshRenderer = MeshRenderer {
+	triangles: 12,
+};
+
+#[derive(Clone, Copy)]
+enum Foo {
+	A,
+	B,
+	C,
+}
+
+props! {
+	impl Foo : fn renderer as const dyn Renderer {
+		Self::A => &SPRITE_RENDERER,
+		Self::B => &MESH_RENDERER,
+		Self::C => &MeshRenderer {
+			triangles: 2,
+		},
+	}
+}
+
+#[test]
+fn dyn_const() {
+	// Dispatched to the implementor of each variant
+	assert_eq!(Foo::A.renderer().render(3), 3);
+	assert_eq!(Foo::B.renderer().render(3), 36);
+	assert_eq!(Foo::C.renderer().render(3), 6);
+}
+
+props! {
+	impl Deref for Foo as static dyn Renderer + Sync {
+		Self::A => &SPRITE_RENDERER,
+		Self::B | Self::C => &MESH_RENDERER,
+	}
+}
+
+#[test]
+fn dyn_static() {
+	assert_eq!(Foo::A.render(2), 2);
+	assert_eq!(Foo::C.render(2), 24);
+
+	// The property is the referenced static itself
+	assert!(core::ptr::eq(
+		&*Foo::B as *const (dyn Renderer + Sync) as *const u8,
+		&MESH_RENDERER as *const MeshRenderer as *const u8
+	));
+}
+
+// Generic code can use it via `EnumProp`
+fn render_with<E: EnumProp<dyn Renderer>>(variant: E, size: u32) -> u32 {
+	variant.property().render(size)
+}
+
+#[test]
+fn dyn_generic_code() {
+	assert_eq!(render_with(Foo::A, 5), 5);
+	assert_eq!(render_with(Foo::B, 5), 60);
+}
+
+
+
+// Trait objects need a `const` or `static` property
+
+/// ```compile_fail
+/// use enumeraties::props;
+///
+/// trait Renderer {}
+///
+/// struct SpriteRenderer;
+///
+/// impl Renderer for SpriteRenderer {}
+///
+/// enum Foo {
+///     A,
+/// }
+///
+/// props! {
+///     impl Foo : fn renderer as lazy dyn Renderer {
+///         Self::A => &SpriteRenderer,
+///     }
+/// }
+/// ```
+struct DynLazy;
+
+// The branches are references to the implementors
+
+/// ```compile_fail,E0308
+/// use enumeraties::props;
+///
+/// trait Renderer {}
+///
+/// struct SpriteRenderer;
+///
+/// impl Renderer for SpriteRenderer {}
+///
+/// enum Foo {
+///     A,
+/// }
+///
+/// props! {
+///     impl Foo : fn renderer as const dyn Renderer {
+///         Self::A => SpriteRenderer,
+///     }
+/// }
+/// ```
+struct DynValue;
@@ -0,0 +1,250 @@
+// This file tests dynamic properties, computed from the data of the variants
+#![cfg(any(test, doctest))]
+#![cfg(feature = "alloc")]
+#![allow(dead_code)]
+
+use alloc::borrow::Cow;
+use alloc::format;
+use alloc::string::ToString;
+
+use super::EnumPropDyn;
+
+
+#[derive(Debug, Clone, PartialEq)]
+struct Label {
+	text: Cow<'static, str>,
+	size: usize,
+}
+
+const UNKNOWN: Label = Label {
+	text: Cow::Borrowed("unknown"),
+	size: 0,
+};
+
+enum Foo {
+	Alpha,
+	Beta(u32),
+	Gamma { name: &'static str },
+	Delta,
+}
+
+props! {
+	impl Foo : fn label as dynamic Label {
+		defaults {
+			size: 1,
+		}
+		Self::Alpha => const {
+			text: Cow::Borrowed("alpha"),
+		}
+		Self::Beta(n) if *n >= 100 => {
+			text: Cow::Owned(format!("beta-{n}")),
+			size: 3,
+		}
+		Self::Beta(n) => {
+			text: Cow::Owned(format!("beta-{n}")),
+		}
+		Self::Gamma { name } => Label {
+			text: Cow::Owned(name.to_string()),
+			size: name.len(),
+		},
+		Self::Delta => &UNKNOWN,
+	}
+}
+
+#[test]
+fn dynamic_owned() {
+	let label = Foo::Beta(42).label();
+	assert!(matches!(label, Cow::Owned(_)));
+	assert_eq!(label.text, "beta-42");
+	assert_eq!(label.size, 1);
+
+	assert_eq!(Foo::Beta(420).label().size, 3);
+
+	let label = Foo::Gamma {
+		name: "gamma",
+	}
+	.label();
+	assert!(matches!(label, Cow::Owned(_)));
+	assert_eq!(label.text, "gamma");
+	assert_eq!(label.size, 5);
+}
+
+#[test]
+fn dynamic_borrowed() {
+	let label = Foo::Alpha.label();
+	assert!(matches!(label, Cow::Borrowed(_)));
+	assert_eq!(label.text, "alpha");
+	assert_eq!(label.size, 1);
+
+	let label = EnumPropDyn::<Label>::property_dyn(&Foo::Delta);
+	assert!(matches!(label, Cow::Borrowed(_)));
+	assert_eq!(*label, UNKNOWN);
+}
+
+// Implementing only `EnumPropDyn`, e.g. for generic code
+#[derive(Debug, Clone, PartialEq)]
+struct Weight(u32);
+
+props! {
+	impl EnumPropDyn for Foo as dynamic Weight {
+		Self::Beta(n) => (*n * 2),
+		Self::Alpha | Self::Delta => static { (0) }
+		_ => (1),
+	}
+}
+
+fn weight(value: &impl EnumPropDyn<Weight>) -> u32 {
+	value.property_dyn().0
+}
+
+#[test]
+fn dynamic_trait() {
+	assert_eq!(weight(&Foo::Beta(21)), 42);
+	assert_eq!(
+		weight(&Foo::Gamma {
+			name: ""
+		}),
+		1
+	);
+	assert_eq!(weight(&Foo::Alpha), 0);
+
+	// The `static` branch is always borrowed from the same address
+	let (a, b) = (
+		EnumPropDyn::<Weight>::property_dyn(&Foo::Alpha),
+		EnumPropDyn::<Weight>::property_dyn(&Foo::Delta),
+	);
+	match (a, b) {
+		(Cow::Borrowed(a), Cow::Borrowed(b)) => assert!(core::ptr::eq(a, b)),
+		_ => panic!("expected borrowed properties"),
+	}
+}
+
+#[cfg(lazy_backend)]
+mod lazy {
+	use alloc::borrow::Cow;
+
+	use super::Foo;
+	use crate::test_util::compute;
+
+	#[derive(Clone)]
+	struct Stats {
+		value: u32,
+	}
+
+	// A `lazy` branch is initialized once and then borrowed
+	props! {
+		impl Foo : fn stats as dynamic Stats {
+			Self::Beta(n) => {
+				value: compute(*n),
+			}
+			_ => lazy {
+				value: compute(5),
+			}
+		}
+	}
+
+	#[test]
+	fn dynamic_lazy() {
+		assert_eq!(Foo::Beta(3).stats().value, 9);
+
+		let stats = Foo::Alpha.stats();
+		assert!(matches!(stats, Cow::Borrowed(_)));
+		assert_eq!(stats.value, 25);
+	}
+}
+
+
+
+// Dynamic properties can not be used via `Deref`
+
+/// ```compile_fail
+/// use enumeraties::props;
+///
+/// #[derive(Clone)]
+/// struct Props {
+///     foo: u32,
+/// }
+///
+/// enum Foo {
+///     A(u32),
+/// }
+///
+/// props! {
+///     impl Deref for Foo as dynamic Props {
+///         Self::A(n) => {
+///             foo: *n,
+///         }
+///     }
+/// }
+/// ```
+struct DynamicDeref;
+
+// Nor via `EnumProp`
+
+/// ```compile_fail
+/// use enumeraties::props;
+///
+/// #[derive(Clone)]
+/// struct Props {
+///     foo: u32,
+/// }
+///
+/// enum Foo {
+///     A(u32),
+/// }
+///
+/// props! {
+///     impl EnumProp for Foo as dynamic Props {
+///         Self::A(n) => {
+///             foo: *n,
+///         }
+///     }
+/// }
+/// ```
+struct DynamicEnumProp;
+
+// Other modifiers can not use the data of the variant
+
+/// ```compile_fail,E0435
+/// use enumeraties::props;
+///
+/// struct Props {
+///     foo: u32,
+/// }
+///
+/// enum Foo {
+///     A(u32),
+/// }
+///
+/// props! {
+///     impl EnumProp for Foo as const Props {
+///         Self::A(n) => {
+///             foo: *n,
+///         }
+///     }
+/// }
+/// ```
+struct ConstWithData;
+
+// The property must be `Clone`
+
+/// ```compile_fail,E0277
+/// use enumeraties::props;
+///
+/// struct Props {
+///     foo: u32,
+/// }
+///
+/// enum Foo {
+///     A(u32),
+/// }
+///
+/// props! {
+///     impl EnumPropDyn for Foo as dynamic Props {
+///         Self::A(n) => {
+///             foo: *n,
+///         }
+///     }
+/// }
+/// ```
+struct NotClone;
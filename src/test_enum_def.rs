@@ -0,0 +1,142 @@
+// This file tests enums defined by the macro along with their property
+#![cfg(any(test, doctest))]
+#![allow(dead_code)]
+
+use super::EnumProp;
+
+
+mod other {
+	pub struct Meta {
+		pub name: &'static str,
+		pub vertices: u32,
+	}
+
+	props! {
+		/// The shapes
+		#[derive(Debug, Clone, Copy, PartialEq)]
+		pub enum Shape as const Meta {
+			defaults {
+				vertices: 0,
+			}
+			let ROUND = {
+				name: "round",
+			};
+			/// A triangle
+			Triangle => {
+				name: "triangle",
+				vertices: 3,
+			}
+			Square => {
+				name: "square",
+				vertices: 4,
+			},
+			Circle(f32) => ROUND,
+			Ellipse {
+				width: f32,
+				height: f32,
+			} => ROUND,
+		}
+	}
+}
+
+use other::Shape;
+
+#[test]
+fn enum_def() {
+	// The derives are applied
+	let shape = Shape::Circle(1.0);
+	assert_eq!(shape, shape.clone());
+
+	assert_eq!(Shape::Triangle.name, "triangle");
+	assert_eq!(Shape::Square.vertices, 4);
+	assert_eq!(Shape::Circle(1.0).name, "round");
+	assert_eq!(
+		Shape::Ellipse {
+			width: 1.0,
+			height: 2.0
+		}
+		.vertices,
+		0
+	);
+}
+
+// Further properties can be added as usual
+props! {
+	impl Shape : pub fn weight as static u32 {
+		Self::Triangle => 1,
+		_ => 2,
+	}
+}
+
+props! {
+	enum Plain as static &'static str {
+		A => "A",
+		B => {
+			"B"
+		}
+		C => "C",
+		// Configured away, both the variant and its branch
+		#[cfg(any())]
+		D => "D",
+	}
+}
+
+#[test]
+fn enum_def_more() {
+	assert_eq!(*Shape::Triangle.weight(), 1);
+	assert_eq!(*Plain::B, "B");
+	assert_eq!(*EnumProp::<&str>::property(&Plain::C), "C");
+}
+
+#[cfg(lazy_backend)]
+mod lazy {
+	use crate::test_util::compute;
+	use crate::EnumPropInit;
+
+	struct Stats {
+		value: u32,
+	}
+
+	props! {
+		#[derive(Clone, Copy)]
+		enum Foo as lazy Stats {
+			A => {
+				value: compute(2),
+			}
+			B => {
+				value: compute(3),
+			}
+		}
+	}
+
+	#[test]
+	fn enum_def_lazy() {
+		<Foo as EnumPropInit<Stats>>::initialize_all();
+		assert_eq!(Foo::A.value, 4);
+		assert_eq!(Foo::B.value, 9);
+	}
+}
+
+
+
+// The branches must be variants
+
+/// ```compile_fail
+/// use enumeraties::props;
+///
+/// struct Props {
+///     foo: u8,
+/// }
+///
+/// props! {
+///     enum Foo as const Props {
+///         A => {
+///             foo: 1,
+///         }
+///         _ => {
+///             foo: 2,
+///         }
+///     }
+/// }
+/// ```
+struct WildcardBranch;
@@ -0,0 +1,151 @@
+// This file tests the `EnumMap` of the properties of all variants
+#![cfg(any(test, doctest))]
+#![cfg(feature = "enum_map")]
+#![cfg(lazy_backend)]
+#![allow(dead_code)]
+
+use enum_map::Enum;
+
+use super::EnumProp;
+
+
+struct ShapeDef {
+	name: &'static str,
+	vertices: u8,
+}
+
+#[derive(Enum, Clone, Copy)]
+enum Shape {
+	Triangle,
+	Square,
+	Rhombus,
+}
+
+props! {
+	impl Deref for Shape as static ShapeDef {
+		enum_map {
+			/// All shape definitions
+			static SHAPE_DEFS
+		}
+		Self::Triangle => {
+			name: "triangle",
+			vertices: 3,
+		}
+		Self::Square | Self::Rhombus => {
+			name: "quad",
+			vertices: 4,
+		}
+	}
+}
+
+// Each map is accessed by a single test only, since the `unsync-lazy` backend
+// supports a single thread only
+#[test]
+fn map_static() {
+	assert_eq!(SHAPE_DEFS[Shape::Triangle].name, "triangle");
+	assert_eq!(SHAPE_DEFS[Shape::Rhombus].vertices, 4);
+	assert_eq!(SHAPE_DEFS.len(), 3);
+	for (shape, def) in SHAPE_DEFS.iter() {
+		assert!(core::ptr::eq(*def, &*shape));
+	}
+	// The same map at each access
+	assert!(core::ptr::eq(&*SHAPE_DEFS, &*SHAPE_DEFS));
+	// The same address for the variants of a branch
+	assert!(core::ptr::eq(
+		SHAPE_DEFS[Shape::Square],
+		SHAPE_DEFS[Shape::Rhombus]
+	));
+}
+
+// A const prop, along with other clauses
+
+#[derive(Enum, Clone, Copy)]
+enum Level {
+	Low,
+	High,
+}
+
+props! {
+	impl Level : fn weight as const u32 {
+		variants { LEVELS }
+		enum_map { static LEVEL_WEIGHTS }
+		Self::Low => 1,
+		Self::High => 10,
+	}
+}
+
+#[test]
+fn map_const() {
+	assert_eq!(*LEVEL_WEIGHTS[Level::Low], 1);
+	assert_eq!(LEVEL_WEIGHTS.values().copied().sum::<u32>(), 11);
+	assert_eq!(Level::LEVELS.len(), 2);
+}
+
+// A lazy prop, which is initialized at the first access of the map
+
+struct Label {
+	text: &'static str,
+}
+
+#[derive(Enum, Clone, Copy)]
+enum Lazy {
+	A,
+	B,
+}
+
+props! {
+	impl EnumProp for Lazy as lazy Label {
+		enum_map { static LABELS }
+		Self::A => {
+			text: "a",
+		}
+		Self::B => {
+			text: "b",
+		}
+	}
+}
+
+#[test]
+fn map_lazy() {
+	assert_eq!(LABELS[Lazy::B].text, "b");
+	assert!(core::ptr::eq(
+		LABELS[Lazy::A],
+		EnumProp::<Label>::property(&Lazy::A)
+	));
+}
+
+/// ```compile_fail
+/// use enumeraties::props;
+/// struct Prop { size: u8 }
+/// #[derive(enum_map::Enum)]
+/// enum Foo {A, B}
+/// props! {
+///     impl EnumProp for Foo as static Prop {
+///         Self::A => {
+///             size: 1,
+///         }
+///         enum_map { static FOOS }
+///         Self::B => {
+///             size: 2,
+///         }
+///     }
+/// }
+/// ```
+struct AfterBranches;
+
+/// ```compile_fail
+/// use enumeraties::props;
+/// use enumeraties::EnumPropKeyed;
+/// struct Prop { size: u8 }
+/// #[derive(enum_map::Enum)]
+/// enum Foo {A, B}
+/// props! {
+///     impl EnumPropKeyed<u8> for Foo as const Prop {
+///         enum_map { static FOOS }
+///         (_, _) => {
+///             size: 1,
+///         }
+///     }
+/// }
+/// ```
+struct Keyed;
@@ -0,0 +1,158 @@
+// This file tests the same properties applied to several enums at once
+#![cfg(any(test, doctest))]
+#![allow(dead_code)]
+
+use super::EnumProp;
+
+
+struct Meta {
+	name: &'static str,
+	vertices: u32,
+}
+
+#[derive(Clone, Copy)]
+enum Planar {
+	Triangle,
+	Square,
+}
+
+#[derive(Clone, Copy)]
+enum Solid {
+	Triangle,
+	Square,
+	Cube,
+}
+
+props! {
+	impl Deref for Planar, Solid as const Meta {
+		Self::Triangle => {
+			name: "Triangle",
+			vertices: 3,
+		}
+		Self::Square => {
+			name: "Square",
+			vertices: 4,
+		}
+		_ => {
+			name: "Other",
+			vertices: 0,
+		}
+	}
+}
+
+// A generic function, working for all the enums
+fn vertices<E: EnumProp<Meta>>(variant: E) -> u32 {
+	variant.property().vertices
+}
+
+#[test]
+fn enums_deref() {
+	assert_eq!(Planar::Triangle.name, "Triangle");
+	assert_eq!(Solid::Triangle.name, "Triangle");
+	assert_eq!(vertices(Planar::Square), 4);
+	assert_eq!(vertices(Solid::Square), 4);
+	assert_eq!(Solid::Cube.name, "Other");
+}
+
+props! {
+	impl Planar, Solid : pub fn weight as static u32 {
+		Self::Triangle => 1,
+		_ => 2,
+	}
+}
+
+props! {
+	impl EnumProp for Planar, Solid as const &'static str {
+		Self::Triangle => "tri",
+		Self::Square => "quad",
+		_ => "?",
+	}
+}
+
+props! {
+	impl Planar, Solid {
+		as static u8 {
+			Self::Triangle => 3,
+			_ => 4,
+		}
+	}
+}
+
+#[test]
+fn enums_other_syntax() {
+	assert_eq!(*Planar::Triangle.weight(), 1);
+	assert_eq!(*Solid::Cube.weight(), 2);
+	assert_eq!(*EnumProp::<&str>::property(&Solid::Square), "quad");
+	assert_eq!(*EnumProp::<u8>::property(&Planar::Square), 4);
+
+	// Each enum has its own statics
+	assert!(!core::ptr::eq(
+		Planar::Triangle.weight(),
+		Solid::Triangle.weight()
+	));
+}
+
+#[cfg(lazy_backend)]
+mod lazy {
+	use super::Planar;
+	use super::Solid;
+	use crate::test_util::compute;
+
+	struct Stats {
+		value: u32,
+	}
+
+	props! {
+		impl Planar, Solid : fn stats as lazy Stats {
+			Self::Triangle => {
+				value: compute(3),
+			}
+			Self::Square => {
+				value: compute(4),
+			}
+			_ => {
+				value: compute(5),
+			}
+		}
+	}
+
+	#[test]
+	fn enums_lazy() {
+		assert_eq!(Planar::Triangle.stats().value, 9);
+		assert_eq!(Solid::Square.stats().value, 16);
+		assert_eq!(Solid::Cube.stats().value, 25);
+	}
+}
+
+
+
+// All enums need the variants of all branches
+
+/// ```compile_fail,E0599
+/// use enumeraties::props;
+///
+/// struct Props {
+///     foo: u8,
+/// }
+///
+/// enum Foo {
+///     A,
+///     B,
+/// }
+///
+/// enum Bar {
+///     A,
+/// }
+///
+/// props! {
+///     impl Deref for Foo, Bar as const Props {
+///         Self::A => {
+///             foo: 1,
+///         }
+///         Self::B => {
+///             foo: 2,
+///         }
+///     }
+/// }
+/// ```
+struct MissingVariant;
@@ -0,0 +1,133 @@
+// This file tests error enums, whose message is a field of their property
+#![cfg(any(test, doctest))]
+#![allow(dead_code)]
+
+#[cfg(test)]
+use std::boxed::Box;
+#[cfg(test)]
+use std::string::ToString;
+
+use super::EnumProp;
+
+struct ErrMeta {
+	message: &'static str,
+	code: u16,
+	retry: bool,
+}
+
+#[derive(Debug)]
+enum MyError {
+	NotFound,
+	Timeout(u32),
+	Busy,
+}
+
+props! {
+	impl Error(message) for MyError as const ErrMeta {
+		Self::NotFound => {
+			message: "the resource was not found",
+			code: 404,
+			retry: false,
+		}
+		Self::Timeout(_) => {
+			message: "the request timed out",
+			code: 504,
+			retry: true,
+		}
+		Self::Busy => {
+			message: "the server is busy",
+			code: 503,
+			retry: true,
+		}
+	}
+}
+
+props! {
+	impl MyError : pub fn code -> u16 as ErrMeta.code;
+}
+
+#[cfg(test)]
+fn fail(retried: bool) -> Result<(), Box<dyn std::error::Error>> {
+	if retried {
+		Err(MyError::Busy)?
+	} else {
+		Err(MyError::Timeout(30))?
+	}
+}
+
+#[test]
+fn display_message() {
+	assert_eq!(MyError::NotFound.to_string(), "the resource was not found");
+	assert_eq!(MyError::Timeout(10).to_string(), "the request timed out");
+}
+
+#[test]
+fn boxed_error() {
+	let err = fail(true).unwrap_err();
+	assert_eq!(err.to_string(), "the server is busy");
+	assert!(err.source().is_none());
+
+	let err = fail(false).unwrap_err();
+	let err = err.downcast_ref::<MyError>().unwrap();
+	assert!(matches!(err, MyError::Timeout(30)));
+	assert_eq!(err.code(), 504);
+}
+
+#[test]
+fn other_fields() {
+	assert_eq!(MyError::Busy.code(), 503);
+	assert!(EnumProp::<ErrMeta>::property(&MyError::Busy).retry);
+	assert!(!EnumProp::<ErrMeta>::property(&MyError::NotFound).retry);
+}
+
+// A static property of a generic error
+
+#[derive(Debug)]
+enum Wrapped<T: 'static> {
+	Inner(T),
+	Other,
+}
+
+props! {
+	impl<T: core::fmt::Debug> Error(message) for Wrapped<T> as static ErrMeta {
+		Self::Inner(_) => {
+			message: "an inner error",
+			code: 500,
+			retry: false,
+		}
+		Self::Other => {
+			message: "another error",
+			code: 400,
+			retry: false,
+		}
+	}
+}
+
+#[test]
+fn generic_error() {
+	let err: Box<dyn std::error::Error> = Box::new(Wrapped::Inner(1));
+	assert_eq!(err.to_string(), "an inner error");
+}
+
+// The enum must implement `Debug`
+
+/// ```compile_fail,E0277
+/// use enumeraties::props;
+///
+/// struct ErrMeta {
+///     message: &'static str,
+/// }
+///
+/// enum MyError {
+///     A,
+/// }
+///
+/// props! {
+///     impl Error(message) for MyError as const ErrMeta {
+///         Self::A => {
+///             message: "a",
+///         }
+///     }
+/// }
+/// ```
+struct NotDebug;
@@ -0,0 +1,128 @@
+// This file tests branches given as expressions instead of fields
+#![cfg(any(test, doctest))]
+#![allow(dead_code)]
+
+use super::EnumProp;
+
+
+// A property with private fields, as if defined in another crate
+mod other {
+	pub struct Prop {
+		name: &'static str,
+		sides: u8,
+	}
+
+	impl Prop {
+		pub const fn new(name: &'static str) -> Self {
+			Self {
+				name,
+				sides: 0,
+			}
+		}
+
+		pub const fn with_sides(self, sides: u8) -> Self {
+			Self {
+				sides,
+				..self
+			}
+		}
+
+		pub fn name(&self) -> &'static str {
+			self.name
+		}
+
+		pub fn sides(&self) -> u8 {
+			self.sides
+		}
+	}
+
+	pub struct Pair(pub &'static str, pub u8);
+}
+
+use other::Pair;
+use other::Prop;
+
+enum Foo {
+	A,
+	B,
+	C(u8),
+}
+
+props! {
+	impl Deref for Foo as const Prop {
+		Self::A => Prop::new("Triangle").with_sides(3),
+		Self::B => Prop::new("Square").with_sides(4),
+		_ => Prop::new("Circle")
+	}
+}
+
+#[test]
+fn expression_const() {
+	assert_eq!(Foo::A.name(), "Triangle");
+	assert_eq!(Foo::A.sides(), 3);
+	assert_eq!(Foo::B.sides(), 4);
+	assert_eq!(Foo::C(42).name(), "Circle");
+}
+
+props! {
+	impl EnumProp for Foo as static Pair {
+		Self::A => Pair("A", 1),
+		Self::B | Self::C(0) => Pair("B", 2),
+		Self::C(_) => Pair("C", 3),
+	}
+}
+
+#[test]
+fn expression_static() {
+	let pair = |variant: Foo| EnumProp::<Pair>::property(&variant);
+
+	assert_eq!(pair(Foo::A).0, "A");
+	assert_eq!(pair(Foo::C(0)).1, 2);
+	assert_eq!(pair(Foo::C(42)).0, "C");
+}
+
+#[cfg(lazy_backend)]
+mod lazy {
+	use super::Pair;
+	use super::Prop;
+	use crate::test_util::compute;
+	use crate::EnumPropInit;
+
+	enum Bar {
+		A,
+		B,
+		C(u8),
+	}
+
+	props! {
+		impl Bar : fn lazy_prop as lazy Prop {
+			Self::A => Prop::new("Triangle").with_sides(compute(2)),
+			Self::B => Prop::new("Square"),
+			Self::C(_) => Prop::new("Circle").with_sides(compute(3)),
+		}
+	}
+
+	enum Baz {
+		A,
+		B,
+	}
+
+	props! {
+		impl Baz : fn lazy_pair as lazy Pair {
+			Self::A => Pair("A", compute(3)),
+			Self::B => Pair("B", compute(4))
+		}
+	}
+
+	#[test]
+	fn expression_lazy() {
+		assert_eq!(Bar::A.lazy_prop().sides(), 4);
+		assert_eq!(Bar::B.lazy_prop().name(), "Square");
+		assert_eq!(Bar::C(42).lazy_prop().sides(), 9);
+
+		// Fieldless variants can still be initialized all at once
+		<Baz as EnumPropInit<Pair>>::initialize_all();
+		assert_eq!(Baz::A.lazy_pair().1, 9);
+		assert_eq!(Baz::B.lazy_pair().1, 16);
+	}
+}
@@ -0,0 +1,156 @@
+// This file tests the inherent methods of several fields of a property at once
+#![cfg(any(test, doctest))]
+#![allow(dead_code)]
+
+use super::EnumProp;
+
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Tier {
+	Low,
+	High,
+}
+
+struct Meta {
+	name: &'static str,
+	cost: u32,
+	tier: Tier,
+}
+
+enum Foo {
+	Alpha,
+	Beta,
+}
+
+props! {
+	impl Foo : pub fields(name: &'static str, cost: u32, tier: Tier) as const Meta {
+		Self::Alpha => {
+			name: "alpha",
+			cost: 1,
+			tier: Tier::Low,
+		}
+		Self::Beta => {
+			name: "beta",
+			cost: 20,
+			tier: Tier::High,
+		}
+	}
+}
+
+#[test]
+fn fields_accessors() {
+	let name: &'static str = Foo::Alpha.name();
+	assert_eq!(name, "alpha");
+	assert_eq!(Foo::Beta.name(), "beta");
+	assert_eq!(Foo::Alpha.cost(), 1);
+	assert_eq!(Foo::Beta.cost(), 20);
+	assert_eq!(Foo::Beta.tier(), Tier::High);
+}
+
+// Further fields of the same property, with attributes for all of them and for
+// single ones
+struct Label {
+	text: &'static str,
+	width: usize,
+	height: usize,
+}
+
+props! {
+	impl Foo :
+		#[must_use]
+		pub(crate) fields(
+			/// The text of the label
+			text: &str,
+			#[inline]
+			width: usize,
+		) as static Label {
+			Self::Alpha => {
+				text: "A",
+				width: 1,
+				height: 1,
+			}
+			Self::Beta => {
+				text: "B",
+				width: 2,
+				height: 1,
+			}
+		}
+}
+
+props! {
+	impl Foo : fields(height: &'static usize) as Label;
+}
+
+#[test]
+fn fields_existing() {
+	assert_eq!(Foo::Alpha.text(), "A");
+	assert_eq!(Foo::Beta.width(), 2);
+	assert!(core::ptr::eq(
+		Foo::Beta.height(),
+		&EnumProp::<Label>::property(&Foo::Beta).height
+	));
+}
+
+#[cfg(lazy_backend)]
+mod lazy {
+	use crate::test_util::compute;
+	struct Stats {
+		value: u32,
+		double: u32,
+	}
+
+	enum Foo {
+		A,
+		B,
+	}
+
+	props! {
+		impl Foo : fields(value: u32, double: u32) as lazy Stats {
+			Self::A => {
+				value: compute(2),
+				double: compute(2) * 2,
+			}
+			Self::B => {
+				value: compute(3),
+				double: compute(3) * 2,
+			}
+		}
+	}
+
+	#[test]
+	fn fields_lazy() {
+		assert_eq!(Foo::A.value(), 4);
+		assert_eq!(Foo::B.double(), 18);
+	}
+}
+
+
+
+// A name collision with an existing inherent method is a normal error
+
+/// ```compile_fail,E0592
+/// use enumeraties::props;
+///
+/// struct Props {
+///     name: &'static str,
+/// }
+///
+/// enum Foo {
+///     A,
+/// }
+///
+/// impl Foo {
+///     fn name(&self) -> &'static str {
+///         "foo"
+///     }
+/// }
+///
+/// props! {
+///     impl Foo : fields(name: &'static str) as const Props {
+///         Self::A => {
+///             name: "a",
+///         }
+///     }
+/// }
+/// ```
+struct Collision;
@@ -0,0 +1,101 @@
+// This file tests the lookup of variants by a predicate on their properties,
+// via `EnumPropAll`
+#![cfg(any(test, doctest))]
+#![allow(dead_code)]
+
+use crate::EnumPropAll;
+
+struct ShapeDef {
+	name: &'static str,
+	vertices: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Shape {
+	Triangle,
+	Square,
+	Pentagon,
+	Rhombus,
+	Kite,
+}
+
+props! {
+	impl EnumPropAll for Shape as const ShapeDef {
+		Self::Triangle => {
+			name: "triangle",
+			vertices: 3,
+		}
+		Self::Square => {
+			name: "square",
+			vertices: 4,
+		}
+		Self::Pentagon => {
+			name: "pentagon",
+			vertices: 5,
+		}
+		Self::Rhombus | Self::Kite => {
+			name: "quad",
+			vertices: 4,
+		}
+	}
+}
+
+fn with_vertices(n: u32) -> Option<Shape> {
+	Shape::find_by(|def| def.vertices == n)
+}
+
+#[test]
+fn no_match() {
+	assert_eq!(with_vertices(6), None);
+	assert_eq!(Shape::find_all_by(|def| def.name.is_empty()).count(), 0);
+}
+
+#[test]
+fn single_match() {
+	assert_eq!(with_vertices(3), Some(Shape::Triangle));
+	assert!(Shape::find_all_by(|def| def.name == "pentagon").eq([Shape::Pentagon]));
+}
+
+#[test]
+fn multiple_matches() {
+	// The first in the order of the branches
+	assert_eq!(with_vertices(4), Some(Shape::Square));
+	assert!(Shape::find_all_by(|def| def.vertices == 4).eq([
+		Shape::Square,
+		Shape::Rhombus,
+		Shape::Kite
+	]));
+	assert!(Shape::find_all_by(|def| def.name == "quad").eq([Shape::Rhombus, Shape::Kite]));
+}
+
+// Static properties, without `Copy`
+
+struct Color(&'static str);
+
+#[derive(Debug, Clone, PartialEq)]
+enum Light {
+	Red,
+	Green,
+	Blue,
+}
+
+props! {
+	impl EnumPropAll for Light as static Color {
+		Self::Red => Color("red"),
+		Self::Green => Color("green"),
+		Self::Blue => Color("blue"),
+	}
+}
+
+#[test]
+fn clone_static() {
+	assert_eq!(
+		Light::find_by(|color| color.0.starts_with('g')),
+		Some(Light::Green)
+	);
+	assert!(Light::find_all_by(|color| color.0.contains('e')).eq([
+		Light::Red,
+		Light::Green,
+		Light::Blue
+	]));
+}
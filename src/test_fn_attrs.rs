@@ -0,0 +1,218 @@
+// This file tests doc comments and attributes on the generated methods
+#![cfg(any(test, doctest))]
+#![allow(dead_code)]
+
+struct Prop {
+	name: &'static str,
+}
+
+enum Foo {
+	A,
+	B,
+}
+
+props! {
+	impl #[inline] Deref for Foo as const Prop {
+		Self::A => {
+			name: "A",
+		}
+		Self::B => {
+			name: "B",
+		}
+	}
+}
+
+props! {
+	impl Foo :
+		/// Returns the weight of the variant
+		#[inline]
+		#[must_use]
+		pub fn weight as static u32 {
+		Self::A => 1,
+		Self::B => 2,
+	}
+}
+
+#[test]
+fn fn_attrs() {
+	assert_eq!(Foo::A.name, "A");
+	assert_eq!(*Foo::B.weight(), 2);
+}
+
+#[cfg(lazy_backend)]
+mod lazy {
+	use super::Foo;
+	use crate::test_util::compute;
+
+	struct LazyProp {
+		value: u32,
+	}
+
+	props! {
+		impl Foo :
+			/// Returns the lazily computed property
+			#[must_use]
+			fn lazy_prop as lazy LazyProp {
+			Self::A => {
+				value: compute(2),
+			}
+			Self::B => {
+				value: compute(3),
+			}
+		}
+	}
+
+	#[test]
+	fn fn_attrs_lazy() {
+		assert_eq!(Foo::B.lazy_prop().value, 9);
+	}
+}
+
+
+
+// The doc comment is forwarded, thus it is not missing
+
+/// ```
+/// #![deny(missing_docs)]
+/// #![doc = "A crate"]
+/// use enumeraties::props;
+///
+/// /// A property
+/// pub struct Props {
+///     /// A field
+///     pub foo: u8,
+/// }
+///
+/// /// An enum
+/// pub enum Foo {
+///     /// A variant
+///     A,
+/// }
+///
+/// props! {
+///     impl Foo :
+///         /// Returns the property
+///         pub fn props as const Props {
+///         Self::A => {
+///             foo: 42,
+///         }
+///     }
+/// }
+///
+/// fn main() {
+///     assert_eq!(Foo::A.props().foo, 42);
+/// }
+/// ```
+struct Documented;
+
+/// ```compile_fail
+/// #![deny(missing_docs)]
+/// #![doc = "A crate"]
+/// use enumeraties::props;
+///
+/// /// A property
+/// pub struct Props {
+///     /// A field
+///     pub foo: u8,
+/// }
+///
+/// /// An enum
+/// pub enum Foo {
+///     /// A variant
+///     A,
+/// }
+///
+/// props! {
+///     impl Foo : pub fn props as const Props {
+///         Self::A => {
+///             foo: 42,
+///         }
+///     }
+/// }
+///
+/// fn main() {
+///     assert_eq!(Foo::A.props().foo, 42);
+/// }
+/// ```
+struct Undocumented;
+
+// The attributes are forwarded as well, e.g. `must_use`
+
+/// ```compile_fail
+/// #![deny(unused_must_use)]
+/// use enumeraties::props;
+///
+/// struct Props {
+///     foo: u8,
+/// }
+///
+/// enum Foo {
+///     A,
+/// }
+///
+/// props! {
+///     impl Foo : #[must_use] fn props as const Props {
+///         Self::A => {
+///             foo: 42,
+///         }
+///     }
+/// }
+///
+/// fn main() {
+///     Foo::A.props();
+/// }
+/// ```
+struct MustUse;
+
+// Including `inline`, thus an invalid one is rejected on the method, e.g. of
+// `Deref`, while a valid one is accepted, see `Foo` above
+
+/// ```compile_fail
+/// use enumeraties::props;
+///
+/// struct Props {
+///     foo: u8,
+/// }
+///
+/// enum Foo {
+///     A,
+/// }
+///
+/// props! {
+///     impl #[inline(sometimes)] Deref for Foo as const Props {
+///         Self::A => {
+///             foo: 42,
+///         }
+///     }
+/// }
+///
+/// fn main() {
+///     assert_eq!(Foo::A.foo, 42);
+/// }
+/// ```
+struct InlineDeref;
+
+/// ```compile_fail
+/// use enumeraties::props;
+///
+/// struct Props {
+///     foo: u8,
+/// }
+///
+/// enum Foo {
+///     A,
+/// }
+///
+/// props! {
+///     impl Foo : #[inline(sometimes)] fn props as const Props {
+///         Self::A => {
+///             foo: 42,
+///         }
+///     }
+/// }
+///
+/// fn main() {
+///     assert_eq!(Foo::A.props().foo, 42);
+/// }
+/// ```
+struct InlineMethod;
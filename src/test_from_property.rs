@@ -0,0 +1,176 @@
+// This file tests the reverse lookup of variants by the addresses of their
+// static properties
+#![cfg(any(test, doctest))]
+#![allow(dead_code)]
+
+use super::EnumProp;
+
+#[derive(Debug, PartialEq)]
+struct PluginDef {
+	name: &'static str,
+	version: u32,
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+enum Plugin {
+	Audio,
+	Video,
+	Network,
+	Storage,
+	Legacy,
+}
+
+props! {
+	impl Deref for Plugin as static PluginDef {
+		from_property(
+			/// The plugin of a handle
+			pub fn from_def
+		)
+		Self::Audio => {
+			name: "audio",
+			version: 1,
+		}
+		Self::Video => {
+			name: "video",
+			version: 2,
+		}
+		Self::Network => {
+			name: "network",
+			version: 1,
+		}
+		// Sharing their property
+		Self::Storage | Self::Legacy => {
+			name: "storage",
+			version: 3,
+		}
+	}
+}
+
+// An unrelated static, equal to the property of a variant
+static AUDIO: PluginDef = PluginDef {
+	name: "audio",
+	version: 1,
+};
+
+#[test]
+fn round_trip() {
+	for plugin in [
+		Plugin::Audio,
+		Plugin::Video,
+		Plugin::Network,
+		Plugin::Storage,
+	] {
+		let handle: &'static PluginDef = plugin.property();
+		assert_eq!(Plugin::from_def(handle), Some(plugin));
+	}
+	// The first of the shared branch
+	assert_eq!(
+		Plugin::from_def(Plugin::Legacy.property()),
+		Some(Plugin::Storage)
+	);
+}
+
+#[test]
+fn unrelated() {
+	assert_eq!(*Plugin::Audio, AUDIO);
+	assert_eq!(Plugin::from_def(&AUDIO), None);
+}
+
+// Via the method form, along with named values and referenced items
+
+static SHARED: PluginDef = PluginDef {
+	name: "shared",
+	version: 0,
+};
+
+enum Other {
+	First,
+	Second,
+	Third,
+	Fourth,
+}
+
+props! {
+	impl Other : fn def as static PluginDef {
+		from_property(fn from_def)
+		let NAMED = {
+			name: "named",
+			version: 4,
+		};
+		Self::First => &SHARED,
+		Self::Second => NAMED,
+		Self::Third => NAMED,
+		Self::Fourth => {
+			name: "fourth",
+			version: 5,
+		}
+	}
+}
+
+#[test]
+fn shared() {
+	assert!(matches!(Other::from_def(&SHARED), Some(Other::First)));
+	assert!(matches!(
+		Other::from_def(Other::Third.def()),
+		Some(Other::Second)
+	));
+	assert!(matches!(
+		Other::from_def(Other::Fourth.def()),
+		Some(Other::Fourth)
+	));
+	assert!(Other::from_def(&AUDIO).is_none());
+}
+
+/// ```compile_fail
+/// use enumeraties::props;
+/// struct Def { name: &'static str }
+/// enum Foo { A, B }
+/// props! {
+///     impl EnumProp for Foo as const Def {
+///         from_property(fn from_def)
+///         Self::A => {
+///             name: "a",
+///         }
+///         Self::B => {
+///             name: "b",
+///         }
+///     }
+/// }
+/// ```
+struct NotStatic;
+
+/// ```compile_fail
+/// use enumeraties::props;
+/// struct Def { name: &'static str }
+/// enum Foo { A, B(u32) }
+/// props! {
+///     impl EnumProp for Foo as static Def {
+///         from_property(fn from_def)
+///         Self::A => {
+///             name: "a",
+///         }
+///         Self::B(_) => {
+///             name: "b",
+///         }
+///     }
+/// }
+/// ```
+struct VariantData;
+
+/// ```compile_fail
+/// use enumeraties::props;
+/// struct Def { name: &'static str }
+/// enum Foo { A, B }
+/// props! {
+///     impl EnumProp for Foo as static Def {
+///         from_property(fn from_def)
+///         Self::A => {
+///             name: "a",
+///         }
+///         Self::B => const {
+///             name: "b",
+///         }
+///     }
+/// }
+/// ```
+struct ConstBranch;
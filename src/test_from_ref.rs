@@ -0,0 +1,92 @@
+// This file tests the `From` impls of static references to properties
+#![cfg(any(test, doctest))]
+#![allow(dead_code)]
+
+struct ShapeDef {
+	name: &'static str,
+	corners: u8,
+}
+
+struct Color(&'static str);
+
+// Not `Copy`, the by-value conversion just drops the enum
+#[derive(Debug)]
+enum Shape {
+	Triangle,
+	Square,
+	Circle,
+}
+
+props! {
+	impl From for Shape as static ShapeDef {
+		Self::Triangle => {
+			name: "triangle",
+			corners: 3,
+		}
+		Self::Square => {
+			name: "square",
+			corners: 4,
+		}
+		Self::Circle => {
+			name: "circle",
+			corners: 0,
+		}
+	}
+}
+
+// A second property of the same enum
+props! {
+	impl From for Shape as const Color {
+		Self::Triangle => Color("red"),
+		Self::Square => Color("green"),
+		Self::Circle => Color("blue"),
+	}
+}
+
+// Like some third-party API
+fn describe(def: impl Into<&'static ShapeDef>) -> (&'static str, u8) {
+	let def = def.into();
+	(def.name, def.corners)
+}
+
+fn color(color: impl Into<&'static Color>) -> &'static str {
+	color.into().0
+}
+
+#[test]
+fn from_ref() {
+	let shape = Shape::Square;
+	assert_eq!(describe(&shape), ("square", 4));
+	assert_eq!(color(&shape), "green");
+	// Still usable
+	assert_eq!(describe(&shape), ("square", 4));
+}
+
+#[test]
+fn from_value() {
+	assert_eq!(describe(Shape::Triangle), ("triangle", 3));
+	assert_eq!(color(Shape::Circle), "blue");
+	let def: &'static ShapeDef = Shape::Circle.into();
+	assert_eq!(def.corners, 0);
+}
+
+// Generic enums
+
+enum Wrapped<T> {
+	Some(T),
+	None,
+}
+
+props! {
+	impl<T> From for Wrapped<T> as const Color {
+		Self::Some(_) => Color("full"),
+		Self::None => Color("empty"),
+	}
+}
+
+#[test]
+fn from_generic() {
+	let wrapped = Wrapped::Some(42);
+	assert_eq!(color(&wrapped), "full");
+	assert_eq!(color(Wrapped::<&str>::None), "empty");
+}
@@ -0,0 +1,174 @@
+// This file tests parsing the enum by a string field of a const property
+#![cfg(any(test, doctest))]
+#![allow(dead_code)]
+
+use super::UnknownVariant;
+
+struct ShapeDef {
+	name: &'static str,
+	vertices: u8,
+}
+
+#[derive(Debug, PartialEq)]
+enum Shape {
+	Triangle,
+	Square,
+	Pentagon,
+	Hexagon,
+}
+
+props! {
+	impl Deref for Shape as const ShapeDef {
+		from_str(name)
+		defaults {
+			vertices: 0,
+		}
+		Self::Triangle => {
+			name: "triangle",
+			vertices: 3,
+		}
+		Self::Square => {
+			name: "square",
+			vertices: 4,
+		}
+		// Parses as the first variant
+		Self::Pentagon | Self::Hexagon => {
+			name: "polygon",
+		}
+	}
+}
+
+#[test]
+fn from_str_const() {
+	assert_eq!("triangle".parse(), Ok(Shape::Triangle));
+	assert_eq!("square".parse(), Ok(Shape::Square));
+	assert_eq!("polygon".parse(), Ok(Shape::Pentagon));
+	assert_eq!("circle".parse::<Shape>(), Err(UnknownVariant));
+	// Exact matches only
+	assert_eq!("Square".parse::<Shape>(), Err(UnknownVariant));
+	assert_eq!("".parse::<Shape>(), Err(UnknownVariant));
+}
+
+// Via `const fn`, along with further clauses and `cfg` attributes
+
+struct Code {
+	text: &'static str,
+	value: u16,
+}
+
+#[derive(Debug, PartialEq)]
+enum Status {
+	Ok,
+	NotFound,
+	#[cfg(any())]
+	Gone,
+}
+
+props! {
+	impl Status {
+		pub const fn code as const Code {
+			unique(value)
+			from_str(text)
+			Self::Ok => {
+				text: "OK",
+				value: 200,
+			}
+			Self::NotFound => {
+				text: "Not Found",
+				value: 404,
+			}
+			#[cfg(any())]
+			Self::Gone => {
+				text: "Gone",
+				value: 410,
+			}
+		}
+	}
+}
+
+#[test]
+fn from_str_const_fn() {
+	assert_eq!("Not Found".parse(), Ok(Status::NotFound));
+	assert_eq!("Gone".parse::<Status>(), Err(UnknownVariant));
+	assert_eq!(Status::Ok.code().value, 200);
+}
+
+// Duplicate names
+
+/// ```compile_fail,E0080
+/// use enumeraties::props;
+///
+/// struct Props {
+///     name: &'static str,
+/// }
+///
+/// enum Foo {
+///     A,
+///     B,
+/// }
+///
+/// props! {
+///     impl Deref for Foo as const Props {
+///         from_str(name)
+///         Self::A => {
+///             name: "a",
+///         }
+///         Self::B => {
+///             name: "a",
+///         }
+///     }
+/// }
+/// ```
+struct DuplicateName;
+
+// Only fieldless variants
+
+/// ```compile_fail
+/// use enumeraties::props;
+///
+/// struct Props {
+///     name: &'static str,
+/// }
+///
+/// enum Foo {
+///     A,
+///     B(u8),
+/// }
+///
+/// props! {
+///     impl Deref for Foo as const Props {
+///         from_str(name)
+///         Self::A => {
+///             name: "a",
+///         }
+///         Self::B(_) => {
+///             name: "b",
+///         }
+///     }
+/// }
+/// ```
+struct DataVariant;
+
+// Only `const` properties
+
+/// ```compile_fail
+/// use enumeraties::props;
+///
+/// struct Props {
+///     name: &'static str,
+/// }
+///
+/// enum Foo {
+///     A,
+/// }
+///
+/// props! {
+///     impl Deref for Foo as static Props {
+///         from_str(name)
+///         Self::A => {
+///             name: "a",
+///         }
+///     }
+/// }
+/// ```
+struct StaticFromStr;
@@ -0,0 +1,217 @@
+// This file tests parsing the enum via a perfect hash map of a string field
+#![cfg(any(test, doctest))]
+#![cfg(feature = "phf")]
+#![allow(dead_code)]
+
+use super::UnknownVariant;
+
+struct ShapeDef {
+	name: &'static str,
+	vertices: u8,
+}
+
+#[derive(Debug, PartialEq)]
+enum Shape {
+	Triangle,
+	Square,
+	Pentagon,
+	Hexagon,
+	Circle,
+}
+
+props! {
+	impl Deref for Shape as const ShapeDef {
+		from_str(phf name as pub NAMES)
+		defaults {
+			vertices: 0,
+		}
+		Self::Triangle => {
+			name: "triangle",
+			vertices: 3,
+		}
+		// The field in any position
+		Self::Square => {
+			vertices: 4,
+			name: "square"
+		}
+		// Parses as the first variant
+		Self::Pentagon | Self::Hexagon => {
+			name: "polygon",
+		}
+		Self::Circle => static {
+			name: "circle",
+		}
+	}
+}
+
+#[test]
+fn from_str_phf() {
+	assert_eq!("triangle".parse(), Ok(Shape::Triangle));
+	assert_eq!("square".parse(), Ok(Shape::Square));
+	assert_eq!("polygon".parse(), Ok(Shape::Pentagon));
+	assert_eq!("circle".parse(), Ok(Shape::Circle));
+	assert_eq!("ellipse".parse::<Shape>(), Err(UnknownVariant));
+	// Exact matches only
+	assert_eq!("Square".parse::<Shape>(), Err(UnknownVariant));
+	assert_eq!("".parse::<Shape>(), Err(UnknownVariant));
+}
+
+#[test]
+fn phf_map() {
+	assert_eq!(Shape::NAMES.len(), 4);
+	assert_eq!(Shape::NAMES.get("polygon"), Some(&Shape::Pentagon));
+	assert!(Shape::NAMES.get("hexagon").is_none());
+	// Each key names a variant with that field
+	for (name, shape) in Shape::NAMES.entries() {
+		assert_eq!(shape.name, *name);
+	}
+}
+
+// Via `const fn`, along with further clauses
+
+struct Code {
+	text: &'static str,
+	value: u16,
+}
+
+#[derive(Debug, PartialEq)]
+enum Status {
+	Ok,
+	NotFound,
+}
+
+props! {
+	impl Status {
+		pub const fn code as const Code {
+			unique(value)
+			from_str(phf text as STATUS_TEXTS)
+			Self::Ok => {
+				value: 200,
+				text: "OK",
+			}
+			Self::NotFound => {
+				text: "Not Found",
+				value: 404,
+			}
+		}
+	}
+}
+
+#[test]
+fn from_str_phf_const_fn() {
+	assert_eq!("Not Found".parse(), Ok(Status::NotFound));
+	assert_eq!("Gone".parse::<Status>(), Err(UnknownVariant));
+	assert_eq!(Status::STATUS_TEXTS.len(), 2);
+}
+
+// Duplicate names
+
+/// ```compile_fail
+/// use enumeraties::props;
+///
+/// struct Props {
+///     name: &'static str,
+/// }
+///
+/// enum Foo {
+///     A,
+///     B,
+/// }
+///
+/// props! {
+///     impl Deref for Foo as const Props {
+///         from_str(phf name as NAMES)
+///         Self::A => {
+///             name: "a",
+///         }
+///         Self::B => {
+///             name: "a",
+///         }
+///     }
+/// }
+/// ```
+struct DuplicateName;
+
+// Only literals
+
+/// ```compile_fail
+/// use enumeraties::props;
+///
+/// struct Props {
+///     name: &'static str,
+/// }
+///
+/// const A: &str = "a";
+///
+/// enum Foo {
+///     A,
+/// }
+///
+/// props! {
+///     impl Deref for Foo as const Props {
+///         from_str(phf name as NAMES)
+///         Self::A => {
+///             name: A,
+///         }
+///     }
+/// }
+/// ```
+struct NotLiteral;
+
+// The field must be given in each branch
+
+/// ```compile_fail
+/// use enumeraties::props;
+///
+/// struct Props {
+///     name: &'static str,
+/// }
+///
+/// enum Foo {
+///     A,
+///     B,
+/// }
+///
+/// props! {
+///     impl Deref for Foo as const Props {
+///         from_str(phf name as NAMES)
+///         defaults {
+///             name: "b",
+///         }
+///         Self::A => {
+///             name: "a",
+///         }
+///         Self::B => {}
+///     }
+/// }
+/// ```
+struct DefaultField;
+
+// No attributes on branches
+
+/// ```compile_fail
+/// use enumeraties::props;
+///
+/// struct Props {
+///     name: &'static str,
+/// }
+///
+/// enum Foo {
+///     A,
+///     B,
+/// }
+///
+/// props! {
+///     impl Deref for Foo as const Props {
+///         from_str(phf name as NAMES)
+///         Self::A => {
+///             name: "a",
+///         }
+///         #[cfg(all())]
+///         Self::B => {
+///             name: "b",
+///         }
+///     }
+/// }
+/// ```
+struct BranchAttribute;
@@ -0,0 +1,181 @@
+// This file tests properties of generic enums
+#![cfg(any(test, doctest))]
+#![allow(dead_code)]
+
+use super::EnumProp;
+
+
+struct Meta {
+	name: &'static str,
+	urgent: bool,
+}
+
+enum Message<T> {
+	Text(T),
+	Alert(T),
+	Empty,
+}
+
+props! {
+	impl<T: Clone + 'static> Deref for Message<T> as const Meta {
+		Self::Text(_) => {
+			name: "text",
+			urgent: false,
+		}
+		Self::Alert(_) => {
+			name: "alert",
+			urgent: true,
+		}
+		Self::Empty => {
+			name: "empty",
+			urgent: false,
+		}
+	}
+}
+
+props! {
+	impl<T> Message<T> : pub fn priority as static u8 {
+		Self::Alert(_) => 9,
+		_ => 1,
+	}
+}
+
+#[test]
+fn generic_enum() {
+	assert_eq!(Message::Text("hi").name, "text");
+	assert!(Message::Alert(42).urgent);
+	assert!(!Message::<()>::Empty.urgent);
+
+	assert_eq!(*Message::Alert(1.0).priority(), 9);
+	assert_eq!(*Message::<u8>::Empty.priority(), 1);
+
+	// The same property for all instantiations
+	assert!(core::ptr::eq(
+		Message::Text(1).priority(),
+		Message::Text("one").priority()
+	));
+}
+
+// With a default parameter, which is not repeated in the impl, and nested
+// angle brackets in the bounds
+enum Packet<T = Option<u8>> {
+	Data(T),
+	Ack,
+}
+
+props! {
+	impl<T: Into<Option<u8>>> EnumProp for Packet<T> as const &'static str {
+		Self::Data(_) => "data",
+		Self::Ack => "ack",
+	}
+}
+
+props! {
+	impl<T> Packet<T> {
+		fn size as const usize {
+			Self::Data(_) => 8,
+			Self::Ack => 1,
+		}
+	}
+}
+
+#[test]
+fn generic_enum_default() {
+	let ack: Packet = Packet::Ack;
+	assert_eq!(*EnumProp::<&str>::property(&ack), "ack");
+	assert_eq!(*EnumProp::<&str>::property(&Packet::Data(1u8)), "data");
+	assert_eq!(*ack.size(), 1);
+}
+
+// Several generic enums at once
+enum Reply<T> {
+	Text(T),
+	Error,
+}
+
+props! {
+	impl<T> Message<T>, Reply<T> : fn is_text as const bool {
+		Self::Text(_) => true,
+		_ => false,
+	}
+}
+
+#[test]
+fn generic_enums() {
+	assert!(*Reply::Text(1).is_text());
+	assert!(!*Reply::<()>::Error.is_text());
+	assert!(!*Message::Alert(1).is_text());
+}
+
+#[cfg(lazy_backend)]
+mod lazy {
+	use super::Message;
+	use crate::test_util::compute;
+
+	struct Stats {
+		value: u32,
+	}
+
+	props! {
+		impl<T> Message<T> : fn stats as lazy Stats {
+			Self::Text(_) => {
+				value: compute(2),
+			}
+			Self::Alert(_) => {
+				value: compute(3),
+			}
+			Self::Empty => {
+				value: compute(4),
+			}
+		}
+	}
+
+	struct Grouped {
+		value: u32,
+	}
+
+	props! {
+		impl<T> EnumProp for Message<T> as lazy(grouped) Grouped {
+			Self::Text(_) => {
+				value: compute(5),
+			}
+			_ => {
+				value: compute(6),
+			}
+		}
+	}
+
+	struct Mixed {
+		value: u32,
+	}
+
+	props! {
+		impl<T> Message<T> : fn mixed as const Mixed {
+			Self::Text(_) => lazy {
+				value: compute(7),
+			}
+			_ => {
+				value: 0,
+			}
+		}
+	}
+
+	#[test]
+	fn generic_enum_lazy() {
+		assert_eq!(Message::Text(1).stats().value, 4);
+		assert_eq!(Message::<()>::Empty.stats().value, 16);
+
+		// Initialized only once for all instantiations
+		assert!(core::ptr::eq(
+			Message::Alert(1).stats(),
+			Message::Alert("one").stats()
+		));
+
+		let grouped = |variant: Message<u8>| crate::EnumProp::<Grouped>::property(&variant).value;
+		assert_eq!(grouped(Message::Text(1)), 25);
+		assert_eq!(grouped(Message::Empty), 36);
+
+		assert_eq!(Message::Text(1).mixed().value, 49);
+		assert_eq!(Message::<()>::Empty.mixed().value, 0);
+	}
+}
@@ -0,0 +1,136 @@
+// This file tests generic property types
+#![cfg(any(test, doctest))]
+#![allow(dead_code)]
+
+use super::EnumProp;
+
+
+#[derive(Debug, PartialEq)]
+struct Limits<T> {
+	min: T,
+	max: T,
+}
+
+struct Pair<T>(T, T);
+
+#[derive(Clone, Copy)]
+enum Foo {
+	A,
+	B,
+}
+
+// Several instantiations of the same generic property type
+props! {
+	impl EnumProp for Foo as const Limits<u32> {
+		Self::A => {
+			min: 1,
+			max: 10,
+		}
+		Self::B => {
+			min: 5,
+			max: 50,
+		}
+	}
+}
+
+props! {
+	impl EnumProp for Foo as static Limits<&'static str> {
+		defaults {
+			max: "z",
+		}
+		Self::A => {
+			min: "a",
+		}
+		Self::B => {
+			min: "b",
+			max: "y",
+		}
+	}
+}
+
+#[test]
+fn generic_prop() {
+	assert_eq!(
+		*EnumProp::<Limits<u32>>::property(&Foo::A),
+		Limits {
+			min: 1,
+			max: 10
+		}
+	);
+	assert_eq!(EnumProp::<Limits<u32>>::property(&Foo::B).max, 50);
+	assert_eq!(EnumProp::<Limits<&str>>::property(&Foo::A).max, "z");
+	assert_eq!(EnumProp::<Limits<&str>>::property(&Foo::B).min, "b");
+}
+
+// Given with a turbofish, and nested generic arguments
+props! {
+	impl Foo {
+		fn small as const Limits::<u8> {
+			_ => {
+				min: 0,
+				max: 1,
+			}
+		}
+
+		fn optional as static Limits<Option<u8>> {
+			let NONE = {
+				min: None,
+				max: None,
+			};
+			Self::A => NONE,
+			Self::B => {
+				min: Some(2),
+				max: None,
+			}
+		}
+
+		fn pair as const Pair<i8> {
+			Self::A => (1, -1),
+			Self::B => (2, -2),
+		}
+	}
+}
+
+#[test]
+fn generic_prop_syntaxes() {
+	assert_eq!(Foo::B.small().max, 1);
+	assert_eq!(Foo::A.optional().min, None);
+	assert_eq!(Foo::B.optional().min, Some(2));
+	assert_eq!(Foo::B.pair().1, -2);
+}
+
+#[cfg(lazy_backend)]
+mod lazy {
+	use super::Foo;
+	use super::Limits;
+	use crate::test_util::compute;
+
+	props! {
+		impl Deref + fn limits for Foo as lazy Limits<u64> {
+			Self::A => {
+				min: 0,
+				max: compute(2u32).into(),
+			}
+			Self::B => {
+				min: 0,
+				max: compute(3u32).into(),
+			}
+		}
+	}
+
+	props! {
+		impl Foo : fn grouped as lazy(grouped) Limits<u16> {
+			_ => {
+				min: 0,
+				max: compute(4) as u16,
+			}
+		}
+	}
+
+	#[test]
+	fn generic_prop_lazy() {
+		assert_eq!(Foo::A.max, 4);
+		assert_eq!(Foo::B.limits().max, 9);
+		assert_eq!(Foo::A.grouped().max, 16);
+	}
+}
@@ -0,0 +1,129 @@
+// This file tests branches with match guards
+#![cfg(any(test, doctest))]
+#![allow(dead_code)]
+
+use super::EnumProp;
+
+
+struct Size {
+	name: &'static str,
+}
+
+enum Foo {
+	Alpha,
+	Beta(u32),
+}
+
+props! {
+	impl Deref for Foo as const Size {
+		Self::Alpha => {
+			name: "none",
+		}
+		Self::Beta(n) if *n < 100 => {
+			name: "small",
+		}
+		Self::Beta(_) => {
+			name: "large",
+		}
+	}
+}
+
+#[test]
+fn guard_const() {
+	assert_eq!(Foo::Alpha.name, "none");
+	assert_eq!(Foo::Beta(0).name, "small");
+	assert_eq!(Foo::Beta(99).name, "small");
+	assert_eq!(Foo::Beta(100).name, "large");
+}
+
+props! {
+	impl EnumProp for Foo as static u32 {
+		Self::Beta(n) if n % 2 == 0 => 2,
+		Self::Beta(n) if n % 3 == 0 => 3,
+		_ => 1,
+	}
+}
+
+#[test]
+fn guard_static() {
+	let divisor = |variant: Foo| *EnumProp::<u32>::property(&variant);
+
+	assert_eq!(divisor(Foo::Alpha), 1);
+	assert_eq!(divisor(Foo::Beta(4)), 2);
+	assert_eq!(divisor(Foo::Beta(9)), 3);
+	assert_eq!(divisor(Foo::Beta(7)), 1);
+}
+
+#[cfg(lazy_backend)]
+mod lazy {
+	use super::Foo;
+	use crate::test_util::compute;
+
+	struct Limit {
+		value: u32,
+	}
+
+	struct GroupedLimit {
+		value: u32,
+	}
+
+	props! {
+		impl Foo : fn limit as lazy Limit {
+			Self::Beta(n) if *n < 100 => {
+				value: compute(10),
+			}
+			Self::Beta(_) => {
+				value: compute(100),
+			}
+			Self::Alpha => {
+				value: 0,
+			}
+		}
+	}
+
+	props! {
+		impl Foo : fn grouped_limit as lazy(grouped) GroupedLimit {
+			Self::Beta(n) if *n < 100 => {
+				value: compute(10),
+			}
+			_ => {
+				value: compute(100),
+			}
+		}
+	}
+
+	#[test]
+	fn guard_lazy() {
+		assert_eq!(Foo::Beta(42).limit().value, 100);
+		assert_eq!(Foo::Beta(420).limit().value, 10_000);
+		assert_eq!(Foo::Alpha.limit().value, 0);
+
+		assert_eq!(Foo::Beta(42).grouped_limit().value, 100);
+		assert_eq!(Foo::Alpha.grouped_limit().value, 10_000);
+	}
+}
+
+
+
+// Guarded branches are still required to be exhaustive
+
+/// ```compile_fail,E0004
+/// use enumeraties::props;
+///
+/// struct Props {
+///     foo: u8,
+/// }
+///
+/// enum Foo {
+///     A(u8),
+/// }
+///
+/// props! {
+///     impl Deref for Foo as const Props {
+///         Self::A(n) if *n < 100 => {
+///             foo: 42,
+///         }
+///     }
+/// }
+/// ```
+struct NonExhaustiveGuard;
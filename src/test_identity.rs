@@ -0,0 +1,153 @@
+// This file tests the identity of static properties, comparing and hashing
+// the variants by the address of their property
+#![cfg(any(test, doctest))]
+#![allow(dead_code)]
+
+#[cfg(test)]
+use std::collections::HashSet;
+
+struct Meta {
+	name: &'static str,
+}
+
+#[derive(Debug)]
+enum Foo {
+	Alpha,
+	Beta(u32),
+	Gamma { id: u8 },
+}
+
+props! {
+	impl Deref for Foo as static Meta {
+		identity
+		Self::Alpha => {
+			name: "alpha",
+		}
+		Self::Beta(_) => {
+			name: "beta",
+		}
+		Self::Gamma { .. } => {
+			name: "gamma",
+		}
+	}
+}
+
+#[test]
+fn equal_regardless_of_data() {
+	assert_eq!(Foo::Beta(1), Foo::Beta(2));
+	assert_eq!(
+		Foo::Gamma {
+			id: 1
+		},
+		Foo::Gamma {
+			id: 2
+		}
+	);
+	assert_eq!(Foo::Alpha, Foo::Alpha);
+}
+
+#[test]
+fn distinct_variants() {
+	assert_ne!(Foo::Alpha, Foo::Beta(1));
+	assert_ne!(
+		Foo::Beta(1),
+		Foo::Gamma {
+			id: 1
+		}
+	);
+}
+
+#[test]
+fn hash_by_identity() {
+	let set: HashSet<Foo> = [
+		Foo::Beta(1),
+		Foo::Beta(2),
+		Foo::Alpha,
+		Foo::Gamma {
+			id: 3,
+		},
+	]
+	.into_iter()
+	.collect();
+	assert_eq!(set.len(), 3);
+	assert!(set.contains(&Foo::Beta(7)));
+}
+
+// Variants of a shared branch, and of a branch overriding the modifier
+
+#[derive(Debug)]
+enum Bar {
+	A,
+	B,
+	C,
+}
+
+props! {
+	impl EnumProp for Bar as static Meta {
+		identity
+		Self::A | Self::B => {
+			name: "ab",
+		}
+		Self::C => static {
+			name: "c",
+		}
+	}
+}
+
+#[test]
+fn shared_branch() {
+	assert_eq!(Bar::A, Bar::B);
+	assert_ne!(Bar::A, Bar::C);
+}
+
+// Const properties have no unique addresses
+
+/// ```compile_fail
+/// use enumeraties::props;
+///
+/// struct Meta {
+///     name: &'static str,
+/// }
+///
+/// enum Foo {
+///     A,
+/// }
+///
+/// props! {
+///     impl Deref for Foo as const Meta {
+///         identity
+///         Self::A => {
+///             name: "a",
+///         }
+///     }
+/// }
+/// ```
+struct ConstProp;
+
+// Neither have const branches
+
+/// ```compile_fail
+/// use enumeraties::props;
+///
+/// struct Meta {
+///     name: &'static str,
+/// }
+///
+/// enum Foo {
+///     A,
+///     B,
+/// }
+///
+/// props! {
+///     impl Deref for Foo as static Meta {
+///         identity
+///         Self::A => {
+///             name: "a",
+///         }
+///         Self::B => const {
+///             name: "b",
+///         }
+///     }
+/// }
+/// ```
+struct ConstBranch;
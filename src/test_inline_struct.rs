@@ -0,0 +1,143 @@
+// This file tests property structs defined inline, along with the property
+#![cfg(any(test, doctest))]
+#![allow(dead_code)]
+
+use super::EnumProp;
+
+
+mod other {
+	#[derive(Clone, Copy)]
+	pub enum Shape {
+		Triangle,
+		Square,
+	}
+
+	props! {
+		impl Deref for Shape as const #[derive(Debug, PartialEq)] pub struct ShapeDef {
+			pub name: &'static str,
+			pub vertices: u32,
+		} {
+			Self::Triangle => {
+				name: "Triangle",
+				vertices: 3,
+			}
+			// The fields may be given in any order
+			Self::Square => {
+				vertices: 4,
+				name: "Square",
+			}
+		}
+	}
+}
+
+// The inline struct is a regular struct, e.g. it can be re-exported
+use other::Shape;
+pub use other::ShapeDef;
+
+#[test]
+fn inline_struct() {
+	assert_eq!(Shape::Triangle.name, "Triangle");
+	assert_eq!(
+		*Shape::Square,
+		ShapeDef {
+			name: "Square",
+			vertices: 4,
+		}
+	);
+}
+
+// And it can be used by further properties, e.g. of other enums
+enum Solid {
+	Tetrahedron,
+	Cube,
+}
+
+props! {
+	impl EnumProp for Solid as static ShapeDef {
+		Self::Tetrahedron => {
+			name: "Tetrahedron",
+			vertices: 4,
+		}
+		Self::Cube => {
+			name: "Cube",
+			vertices: 8,
+		}
+	}
+}
+
+props! {
+	impl Solid : fn faces as static struct Faces {
+		count: u32,
+	} {
+		Self::Tetrahedron => {
+			count: 4,
+		}
+		Self::Cube => {
+			count: 6,
+		}
+	}
+}
+
+#[test]
+fn inline_struct_reused() {
+	assert_eq!(EnumProp::<ShapeDef>::property(&Solid::Cube).vertices, 8);
+	assert_eq!(Solid::Cube.faces().count, 6);
+}
+
+// Also for the other syntaxes
+props! {
+	impl Shape {
+		as const struct Edges {
+			count: u32,
+		} {
+			Self::Triangle => {
+				count: 3,
+			}
+			Self::Square => {
+				count: 4,
+			}
+		}
+	}
+}
+
+props! {
+	impl Shape, Solid : fn kind as const struct Kind {
+		solid: bool,
+	} {
+		_ => {
+			solid: false,
+		}
+	}
+}
+
+#[test]
+fn inline_struct_syntaxes() {
+	assert_eq!(EnumProp::<Edges>::property(&Shape::Square).count, 4);
+	assert!(!Shape::Triangle.kind().solid);
+	assert!(!Solid::Cube.kind().solid);
+}
+
+#[cfg(lazy_backend)]
+mod lazy {
+	use super::Solid;
+	use crate::test_util::compute;
+
+	props! {
+		impl Solid : fn stats as lazy(grouped) struct Stats {
+			value: u32,
+		} {
+			Self::Tetrahedron => {
+				value: compute(2),
+			}
+			Self::Cube => {
+				value: compute(3),
+			}
+		}
+	}
+
+	#[test]
+	fn inline_struct_lazy() {
+		assert_eq!(Solid::Tetrahedron.stats().value, 4);
+		assert_eq!(Solid::Cube.stats().value, 9);
+	}
+}
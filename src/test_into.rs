@@ -0,0 +1,156 @@
+// This file tests the conversions of the enum into a field of its property
+#![cfg(any(test, doctest))]
+#![allow(dead_code)]
+
+struct StatusDef {
+	code: u16,
+	reason: &'static str,
+	retry: bool,
+}
+
+#[derive(Clone, Copy)]
+enum Status {
+	Ok,
+	NotFound,
+	Unavailable,
+}
+
+props! {
+	impl Deref for Status as const StatusDef {
+		into(code: u16)
+		into(reason: &'static str)
+		Self::Ok => {
+			code: 200,
+			reason: "OK",
+			retry: false,
+		}
+		Self::NotFound => {
+			code: 404,
+			reason: "Not Found",
+			retry: false,
+		}
+		Self::Unavailable => {
+			code: 503,
+			reason: "Service Unavailable",
+			retry: true,
+		}
+	}
+}
+
+#[test]
+fn into_number() {
+	let code: u16 = Status::NotFound.into();
+	assert_eq!(code, 404);
+	assert_eq!(u16::from(&Status::Unavailable), 503);
+}
+
+#[test]
+fn into_str() {
+	let reason: &'static str = Status::Unavailable.into();
+	assert_eq!(reason, "Service Unavailable");
+	assert_eq!(<&str>::from(&Status::Ok), "OK");
+}
+
+// Other modifiers and kinds
+
+enum Level {
+	Low,
+	High,
+}
+
+props! {
+	impl Level : const fn level as const StatusDef {
+		into(retry: bool)
+		Self::Low => {
+			code: 1,
+			reason: "low",
+			retry: false,
+		}
+		Self::High => {
+			code: 2,
+			reason: "high",
+			retry: true,
+		}
+	}
+}
+
+props! {
+	impl EnumProp for Level as static u8 {
+		Self::Low => 1,
+		Self::High => 2,
+	}
+}
+
+struct Weight {
+	grams: u32,
+}
+
+props! {
+	impl EnumProp for Level as static Weight {
+		into(grams: u32)
+		Self::Low => {
+			grams: 10,
+		}
+		Self::High => {
+			grams: 1000,
+		}
+	}
+}
+
+#[test]
+fn into_other_kinds() {
+	assert!(bool::from(Level::High));
+	assert!(!bool::from(&Level::Low));
+	assert_eq!(u32::from(Level::High), 1000);
+}
+
+// The types must be distinct
+
+/// ```compile_fail
+/// use enumeraties::props;
+///
+/// struct Props {
+///     code: u16,
+///     port: u16,
+/// }
+///
+/// enum Foo {
+///     A,
+/// }
+///
+/// props! {
+///     impl Deref for Foo as const Props {
+///         into(code: u16)
+///         into(port: u16)
+///         Self::A => {
+///             code: 1,
+///             port: 2,
+///         }
+///     }
+/// }
+/// ```
+struct SameType;
+
+// The field must be `Copy`
+
+/// ```compile_fail,E0277
+/// use enumeraties::props;
+///
+/// struct Props {
+///     name: [std::string::String; 1],
+/// }
+///
+/// enum Foo {
+///     A,
+/// }
+///
+/// props! {
+///     impl Deref for Foo as static Props {
+///         into(name: [std::string::String; 1])
+///         Self::A => {
+///             name: [std::string::String::new()],
+///         }
+///     }
+/// }
+/// ```
+struct NotCopy;
@@ -0,0 +1,272 @@
+// This file tests keyed properties, depending on a runtime key as well
+#![cfg(any(test, doctest))]
+#![allow(dead_code)]
+
+use super::EnumPropKeyed;
+
+
+struct Text {
+	text: &'static str,
+	len: usize,
+}
+
+#[derive(Clone, Copy)]
+enum Locale {
+	En,
+	De,
+	Fr,
+}
+
+enum Shape {
+	Triangle,
+	Square,
+	Circle,
+}
+
+props! {
+	impl EnumPropKeyed<Locale> for Shape as const Text {
+		defaults {
+			len: 0,
+		}
+		(Self::Triangle, Locale::En) => {
+			text: "triangle",
+			len: 8,
+		}
+		(Self::Triangle, Locale::De) => {
+			text: "Dreieck",
+			len: 7,
+		}
+		(Self::Triangle, Locale::Fr) => {
+			text: "triangle",
+			len: 8,
+		}
+		(Self::Square, Locale::De) => {
+			text: "Quadrat",
+		}
+		// Any other key of a variant
+		(Self::Square, _) => {
+			text: "square",
+		}
+		_ => {
+			text: "?",
+		}
+	}
+}
+
+#[test]
+fn keyed_const() {
+	let props =
+		|value: Shape, locale: Locale| EnumPropKeyed::<Locale, Text>::property_for(&value, locale);
+
+	assert_eq!(props(Shape::Triangle, Locale::En).text, "triangle");
+	assert_eq!(props(Shape::Triangle, Locale::De).text, "Dreieck");
+	assert_eq!(props(Shape::Triangle, Locale::De).len, 7);
+	assert_eq!(props(Shape::Square, Locale::De).text, "Quadrat");
+	assert_eq!(props(Shape::Square, Locale::Fr).text, "square");
+	assert_eq!(props(Shape::Square, Locale::Fr).len, 0);
+	assert_eq!(props(Shape::Circle, Locale::En).text, "?");
+}
+
+// Via inherent method, with a key that is not `Copy`
+struct Name {
+	name: &'static str,
+}
+
+enum Scope {
+	Local,
+	Named(&'static str),
+}
+
+props! {
+	impl Shape : fn name(scope: Scope) as static Name {
+		(Self::Triangle, Scope::Local) => {
+			name: "local triangle",
+		}
+		(Self::Triangle, Scope::Named(_)) => {
+			name: "named triangle",
+		}
+		(_, Scope::Named(name)) if name.is_empty() => {
+			name: "anonymous",
+		}
+		(_, _) => {
+			name: "shape",
+		}
+	}
+}
+
+#[test]
+fn keyed_method() {
+	assert_eq!(Shape::Triangle.name(Scope::Local).name, "local triangle");
+	assert_eq!(
+		Shape::Triangle.name(Scope::Named("x")).name,
+		"named triangle"
+	);
+	assert_eq!(Shape::Square.name(Scope::Named("")).name, "anonymous");
+	assert_eq!(Shape::Circle.name(Scope::Local).name, "shape");
+
+	// Each branch is a unique static
+	assert!(core::ptr::eq(
+		Shape::Square.name(Scope::Local),
+		Shape::Circle.name(Scope::Named("y"))
+	));
+}
+
+// Generic code over the key
+fn text_of<K>(value: &impl EnumPropKeyed<K, Text>, key: K) -> &'static str {
+	value.property_for(key).text
+}
+
+#[test]
+fn keyed_generic() {
+	assert_eq!(text_of(&Shape::Triangle, Locale::Fr), "triangle");
+}
+
+// Keyed by a primitive, as a section
+enum Size {
+	Small,
+	Large,
+}
+
+props! {
+	impl Size {
+		fn scaled(factor: u8) as const Text {
+			(Self::Small, 0) => {
+				text: "none",
+				len: 0,
+			}
+			(Self::Small, 1..=9) => {
+				text: "small",
+				len: 1,
+			}
+			(Self::Small, _) | (Self::Large, _) => {
+				text: "large",
+				len: 2,
+			}
+		}
+	}
+}
+
+#[test]
+fn keyed_section() {
+	assert_eq!(Size::Small.scaled(0).text, "none");
+	assert_eq!(Size::Small.scaled(5).text, "small");
+	assert_eq!(Size::Small.scaled(10).len, 2);
+	assert_eq!(Size::Large.scaled(0).text, "large");
+}
+
+#[cfg(lazy_backend)]
+mod lazy {
+	use super::Locale;
+	use super::Shape;
+	use crate::test_util::compute;
+
+	struct Stats {
+		value: u32,
+	}
+
+	props! {
+		impl Shape : fn stats(locale: Locale) as lazy Stats {
+			(Self::Triangle, Locale::En) => {
+				value: compute(3),
+			}
+			(Self::Triangle, _) => const {
+				value: 0,
+			}
+			_ => {
+				value: compute(4),
+			}
+		}
+	}
+
+	#[test]
+	fn keyed_lazy() {
+		assert_eq!(Shape::Triangle.stats(Locale::En).value, 9);
+		assert_eq!(Shape::Triangle.stats(Locale::De).value, 0);
+		assert_eq!(Shape::Square.stats(Locale::Fr).value, 16);
+		assert!(core::ptr::eq(
+			Shape::Square.stats(Locale::Fr),
+			Shape::Circle.stats(Locale::En)
+		));
+	}
+}
+
+
+
+// All pairs of variant and key must be covered
+
+/// ```compile_fail,E0004
+/// use enumeraties::props;
+///
+/// struct Props {
+///     foo: u32,
+/// }
+///
+/// enum Key {
+///     X,
+///     Y,
+/// }
+///
+/// enum Foo {
+///     A,
+///     B,
+/// }
+///
+/// props! {
+///     impl EnumPropKeyed<Key> for Foo as const Props {
+///         (Self::A, _) => {
+///             foo: 1,
+///         }
+///         (Self::B, Key::X) => {
+///             foo: 2,
+///         }
+///     }
+/// }
+/// ```
+struct NotExhaustive;
+
+// Only `const`, `static`, and `lazy` are supported
+
+/// ```compile_fail
+/// use enumeraties::props;
+///
+/// #[derive(Clone)]
+/// struct Props {
+///     foo: u32,
+/// }
+///
+/// enum Foo {
+///     A,
+/// }
+///
+/// props! {
+///     impl EnumPropKeyed<u8> for Foo as dynamic Props {
+///         (Self::A, _) => {
+///             foo: 1,
+///         }
+///     }
+/// }
+/// ```
+struct KeyedDynamic;
+
+// Without `lazy(grouped)`
+
+/// ```compile_fail
+/// use enumeraties::props;
+///
+/// struct Props {
+///     foo: u32,
+/// }
+///
+/// enum Foo {
+///     A,
+/// }
+///
+/// props! {
+///     impl EnumPropKeyed<u8> for Foo as lazy(grouped) Props {
+///         (Self::A, _) => {
+///             foo: 1,
+///         }
+///     }
+/// }
+/// ```
+struct KeyedGrouped;
@@ -0,0 +1,648 @@
+// This file tests the semantics of lazy properties, which must be the same
+// regardless of the lazy backend selected via the crate features.
+#![cfg(test)]
+#![cfg(lazy_backend)]
+
+use core::sync::atomic::AtomicUsize;
+use core::sync::atomic::Ordering;
+use std::format;
+use std::string::String;
+use std::vec::Vec;
+
+use super::EnumProp;
+use super::EnumPropInit;
+use super::LazyEnumProp;
+
+
+// Counts how often the lazy properties got initialized
+static INIT_COUNT: AtomicUsize = AtomicUsize::new(0);
+
+struct Counted {
+	value: usize,
+}
+
+#[derive(Copy, Clone)]
+enum Foo {
+	A,
+	B,
+}
+
+props! {
+	impl EnumProp for Foo as lazy Counted {
+		Self::A => {
+			value: INIT_COUNT.fetch_add(1, Ordering::SeqCst) + 100,
+		}
+		Self::B => {
+			value: INIT_COUNT.fetch_add(1, Ordering::SeqCst) + 200,
+		}
+	}
+}
+
+#[test]
+fn lazy_init_once_with_stable_address() {
+	let first: &'static Counted = Foo::A.property();
+	let second: &'static Counted = Foo::A.property();
+
+	// Same value, same address, and only a single initialization of `A`
+	assert!(core::ptr::eq(first, second));
+	assert_eq!(first.value, 100);
+	assert_eq!(INIT_COUNT.load(Ordering::SeqCst), 1);
+
+	// The other variant gets its own value, initialized once as well
+	let other: &'static Counted = Foo::B.property();
+	assert!(!core::ptr::eq(first, other));
+	assert!(core::ptr::eq(other, Foo::B.property()));
+	assert_eq!(other.value, 201);
+	assert_eq!(INIT_COUNT.load(Ordering::SeqCst), 2);
+}
+
+
+
+// Counts the initializations of `Quad`'s properties
+static QUAD_COUNT: AtomicUsize = AtomicUsize::new(0);
+
+struct Tracked {
+	value: usize,
+}
+
+#[derive(Copy, Clone)]
+enum Quad {
+	A,
+	B,
+	C,
+	D,
+}
+
+props! {
+	impl EnumProp for Quad as lazy Tracked {
+		Self::A => {
+			value: QUAD_COUNT.fetch_add(1, Ordering::SeqCst),
+		}
+		Self::B => {
+			value: QUAD_COUNT.fetch_add(1, Ordering::SeqCst),
+		}
+		Self::C | Self::D => {
+			value: QUAD_COUNT.fetch_add(1, Ordering::SeqCst),
+		}
+	}
+}
+
+#[test]
+fn lazy_is_initialized() {
+	// Querying does not trigger the initialization
+	assert!(!LazyEnumProp::<Tracked>::is_property_initialized(&Quad::A));
+	assert!(LazyEnumProp::<Tracked>::try_property(&Quad::A).is_none());
+	assert_eq!(QUAD_COUNT.load(Ordering::SeqCst), 0);
+
+	let a: &'static Tracked = Quad::A.property();
+	assert!(LazyEnumProp::<Tracked>::is_property_initialized(&Quad::A));
+	assert!(core::ptr::eq(
+		LazyEnumProp::<Tracked>::try_property(&Quad::A).unwrap(),
+		a
+	));
+	assert_eq!(a.value, 0);
+
+	// The other variants are independent
+	assert!(!LazyEnumProp::<Tracked>::is_property_initialized(&Quad::B));
+	assert!(!LazyEnumProp::<Tracked>::is_property_initialized(&Quad::C));
+
+	// Unless they share the same match branch
+	let _: &'static Tracked = Quad::C.property();
+	assert!(!LazyEnumProp::<Tracked>::is_property_initialized(&Quad::B));
+	assert!(LazyEnumProp::<Tracked>::is_property_initialized(&Quad::C));
+	assert!(LazyEnumProp::<Tracked>::is_property_initialized(&Quad::D));
+	assert_eq!(QUAD_COUNT.load(Ordering::SeqCst), 2);
+}
+
+
+
+// Another enum, which is initialized all at once
+#[derive(Copy, Clone)]
+enum Warm {
+	A,
+	B,
+	C,
+}
+
+props! {
+	impl EnumProp for Warm as lazy Tracked {
+		Self::A => {
+			value: 1,
+		}
+		Warm::B | Self::C => {
+			value: 2,
+		}
+	}
+}
+
+#[test]
+fn lazy_initialize_all() {
+	assert!(!LazyEnumProp::<Tracked>::is_property_initialized(&Warm::A));
+
+	<Warm as EnumPropInit<Tracked>>::initialize_all();
+
+	for variant in [Warm::A, Warm::B, Warm::C] {
+		assert!(LazyEnumProp::<Tracked>::is_property_initialized(&variant));
+	}
+	assert_eq!(
+		LazyEnumProp::<Tracked>::try_property(&Warm::C)
+			.unwrap()
+			.value,
+		2
+	);
+}
+
+
+
+// Records the calls of the initialization hook
+static HOOKED: std::sync::Mutex<Vec<(&str, &str)>> = std::sync::Mutex::new(Vec::new());
+
+fn record_hook(prop: &'static str, branch: &'static str) {
+	HOOKED.lock().unwrap().push((prop, branch));
+}
+
+#[derive(Copy, Clone)]
+enum Hooked {
+	A,
+	B,
+}
+
+props! {
+	impl EnumProp for Hooked as lazy(on_init = record_hook) Tracked {
+		Self::A => {
+			value: 1,
+		}
+		Self::B => {
+			value: 2,
+		}
+	}
+}
+
+#[test]
+fn lazy_init_hook() {
+	assert!(HOOKED.lock().unwrap().is_empty());
+
+	assert_eq!(Hooked::B.property().value, 2);
+	assert_eq!(Hooked::B.property().value, 2);
+	assert_eq!(Hooked::A.property().value, 1);
+
+	// Only called on the first access of each branch
+	let prop = core::any::type_name::<Tracked>();
+	assert_eq!(
+		*HOOKED.lock().unwrap(),
+		[(prop, "Self::B"), (prop, "Self::A")]
+	);
+}
+
+
+
+// The initializer is outlined into a function of its own, thus, the items
+// defined within it are nested in that function
+struct Located {
+	path: &'static str,
+}
+
+#[derive(Copy, Clone)]
+enum Outlined {
+	A,
+}
+
+props! {
+	impl EnumProp for Outlined as lazy Located {
+		Self::A => {
+			path: {
+				fn item() {}
+				core::any::type_name_of_val(&item)
+			},
+		}
+	}
+}
+
+#[test]
+fn lazy_init_outlined() {
+	let path = Outlined::A.property().path;
+	assert!(path.contains("::internal_lazy_init::"), "{}", path);
+}
+
+
+
+// Initializing on a background thread, which is not supported with the
+// `unsync-lazy` backend
+#[cfg(all(feature = "std", not(feature = "unsync-lazy")))]
+mod warmup {
+	use std::sync::Barrier;
+
+	use super::Tracked;
+	use crate::EnumProp;
+	use crate::EnumPropWarmup;
+	use crate::LazyEnumProp;
+
+	#[derive(Copy, Clone)]
+	enum Background {
+		A,
+		B,
+	}
+
+	props! {
+		impl EnumProp for Background as lazy Tracked {
+			Self::A => {
+				value: 1,
+			}
+			Self::B => {
+				value: 2,
+			}
+		}
+	}
+
+	#[test]
+	fn lazy_spawn_property_warmup() {
+		<Background as EnumPropWarmup<Tracked>>::spawn_property_warmup()
+			.join()
+			.unwrap();
+
+		for variant in [Background::A, Background::B] {
+			assert!(LazyEnumProp::<Tracked>::is_property_initialized(&variant));
+		}
+		assert_eq!(Background::B.property().value, 2);
+	}
+
+
+	// Synchronizes the slow initialization with the foreground
+	static STARTED: Barrier = Barrier::new(2);
+	static RELEASED: Barrier = Barrier::new(2);
+
+	fn slow() -> usize {
+		STARTED.wait();
+		RELEASED.wait();
+		1
+	}
+
+	#[derive(Copy, Clone)]
+	enum Slow {
+		A,
+		B,
+	}
+
+	props! {
+		impl EnumProp for Slow as lazy Tracked {
+			Self::A => {
+				value: slow(),
+			}
+			Self::B => {
+				value: 2,
+			}
+		}
+	}
+
+	#[test]
+	fn lazy_warmup_does_not_block_foreground() {
+		let warmup = <Slow as EnumPropWarmup<Tracked>>::spawn_property_warmup();
+
+		// While `A` is being initialized on the background thread, `B` is
+		// accessible on this one
+		STARTED.wait();
+		assert_eq!(Slow::B.property().value, 2);
+		assert!(!LazyEnumProp::<Tracked>::is_property_initialized(&Slow::A));
+
+		RELEASED.wait();
+		warmup.join().unwrap();
+		assert_eq!(Slow::A.property().value, 1);
+	}
+}
+
+
+
+// Concurrent access is not supported with the `unsync-lazy` backend
+#[cfg(not(feature = "unsync-lazy"))]
+mod concurrent {
+	use core::sync::atomic::AtomicUsize;
+	use core::sync::atomic::Ordering;
+	use std::vec::Vec;
+
+	use crate::EnumProp;
+
+
+	// Counts the initializations racing on the first access
+	static RACE_COUNT: AtomicUsize = AtomicUsize::new(0);
+
+	struct Raced {
+		value: usize,
+	}
+
+	#[derive(Copy, Clone)]
+	enum Bar {
+		A,
+	}
+
+	props! {
+		impl EnumProp for Bar as lazy Raced {
+			Self::A => {
+				value: {
+					RACE_COUNT.fetch_add(1, Ordering::SeqCst);
+					// Give the other threads a chance to pile up on the init
+					std::thread::sleep(std::time::Duration::from_millis(10));
+					42
+				},
+			}
+		}
+	}
+
+	#[test]
+	fn lazy_init_once_under_concurrent_access() {
+		const THREADS: usize = 8;
+		let barrier = std::sync::Barrier::new(THREADS);
+
+		let addresses: Vec<usize> = std::thread::scope(|s| {
+			let handles: Vec<_> = (0..THREADS)
+				.map(|_| {
+					s.spawn(|| {
+						barrier.wait();
+						let prop: &'static Raced = Bar::A.property();
+						assert_eq!(prop.value, 42);
+						prop as *const Raced as usize
+					})
+				})
+				.collect();
+			handles.into_iter().map(|h| h.join().unwrap()).collect()
+		});
+
+		assert_eq!(RACE_COUNT.load(Ordering::SeqCst), 1);
+		assert!(addresses.iter().all(|&a| a == addresses[0]));
+	}
+
+
+	// Counts the calls of the initialization hook
+	static HOOK_COUNT: AtomicUsize = AtomicUsize::new(0);
+
+	fn count_hook(_prop: &'static str, _branch: &'static str) {
+		HOOK_COUNT.fetch_add(1, Ordering::SeqCst);
+	}
+
+	#[derive(Copy, Clone)]
+	enum Hooked {
+		A,
+	}
+
+	props! {
+		impl EnumProp for Hooked as lazy(on_init = count_hook) Raced {
+			Self::A => {
+				value: {
+					// Give the other threads a chance to pile up on the init
+					std::thread::sleep(std::time::Duration::from_millis(10));
+					42
+				},
+			}
+		}
+	}
+
+	#[test]
+	fn lazy_init_hook_once_under_concurrent_access() {
+		const THREADS: usize = 8;
+		let barrier = std::sync::Barrier::new(THREADS);
+
+		std::thread::scope(|s| {
+			for _ in 0..THREADS {
+				s.spawn(|| {
+					barrier.wait();
+					let prop: &'static Raced = Hooked::A.property();
+					assert_eq!(prop.value, 42);
+				});
+			}
+		});
+
+		assert_eq!(HOOK_COUNT.load(Ordering::SeqCst), 1);
+	}
+}
+
+
+
+// The props can also be defined within a generic context, as long as the
+// property values do not depend on the generic parameters.
+fn generic_context<T: AsRef<str>>(suffix: T) -> String {
+	struct Named {
+		name: &'static str,
+	}
+
+	enum Baz {
+		A,
+	}
+
+	props! {
+		impl Baz : fn named as lazy Named {
+			Self::A => {
+				name: "Baz",
+			}
+		}
+	}
+
+	format!("{}{}", Baz::A.named().name, suffix.as_ref())
+}
+
+#[test]
+fn lazy_in_generic_context() {
+	assert_eq!(generic_context("!"), "Baz!");
+	assert_eq!(generic_context(String::from("?")), "Baz?");
+}
+
+
+
+// All variants in a single table
+static GROUPED_COUNT: AtomicUsize = AtomicUsize::new(0);
+
+#[derive(Copy, Clone)]
+enum Grouped {
+	A,
+	B,
+	C,
+}
+
+props! {
+	impl Grouped : fn grouped as lazy(grouped) Tracked {
+		Self::A => {
+			value: GROUPED_COUNT.fetch_add(1, Ordering::SeqCst),
+		}
+		Self::B | Self::C => {
+			value: GROUPED_COUNT.fetch_add(1, Ordering::SeqCst) + 10,
+		},
+	}
+}
+
+#[test]
+fn lazy_grouped_init_together() {
+	assert!(!LazyEnumProp::<Tracked>::is_property_initialized(
+		&Grouped::C
+	));
+
+	// The first access initializes all variants, in the order of the branches
+	let b: &'static Tracked = Grouped::B.grouped();
+	assert_eq!(b.value, 11);
+	assert_eq!(GROUPED_COUNT.load(Ordering::SeqCst), 2);
+	for variant in [Grouped::A, Grouped::B, Grouped::C] {
+		assert!(LazyEnumProp::<Tracked>::is_property_initialized(&variant));
+	}
+
+	// Each branch has its own value with a stable address
+	assert_eq!(Grouped::A.grouped().value, 0);
+	assert!(core::ptr::eq(Grouped::B.grouped(), Grouped::C.grouped()));
+	assert!(core::ptr::eq(b, Grouped::B.grouped()));
+	assert!(!core::ptr::eq(b, Grouped::A.grouped()));
+	assert_eq!(GROUPED_COUNT.load(Ordering::SeqCst), 2);
+}
+
+
+
+// Panics during the initialization are annotated with the context, with std
+#[cfg(feature = "std")]
+mod annotated {
+	use core::any::Any;
+	use core::cell::RefCell;
+	use std::boxed::Box;
+	use std::panic::UnwindSafe;
+	use std::string::String;
+	use std::sync::Once;
+	use std::vec::Vec;
+
+	use super::Tracked;
+	use crate::EnumProp;
+
+	std::thread_local! {
+		static RECORDED: RefCell<Vec<String>> = const { RefCell::new(Vec::new()) };
+	}
+
+	// Catches the panic of `f`, along with the messages of all panics on this
+	// thread as seen by the panic hook, which is installed once, passing them
+	// on to the previous hook
+	fn catch_recorded<R>(f: impl FnOnce() -> R + UnwindSafe) -> (Box<dyn Any + Send>, Vec<String>) {
+		static HOOK: Once = Once::new();
+		HOOK.call_once(|| {
+			let previous = std::panic::take_hook();
+			std::panic::set_hook(Box::new(move |info| {
+				if let Some(message) = info.payload_as_str() {
+					RECORDED.with(|recorded| recorded.borrow_mut().push(message.into()));
+				}
+				previous(info)
+			}));
+		});
+
+		RECORDED.with(|recorded| recorded.borrow_mut().clear());
+		let payload = std::panic::catch_unwind(f).err().unwrap();
+		(payload, RECORDED.with(RefCell::take))
+	}
+
+	#[derive(Copy, Clone)]
+	enum Failing {
+		A,
+		B,
+	}
+
+	fn fail() -> usize {
+		panic!("boom")
+	}
+
+	props! {
+		impl EnumProp for Failing as lazy Tracked {
+			Self::A => {
+				value: 1,
+			}
+			Self::B => {
+				value: fail(),
+			}
+		}
+	}
+
+	#[test]
+	fn lazy_init_panic_annotated() {
+		let (payload, messages) = catch_recorded(|| Failing::B.property().value);
+
+		// The payload is kept, while the context follows the original panic
+		assert_eq!(payload.downcast_ref::<&str>(), Some(&"boom"));
+		assert_eq!(messages.len(), 2, "{:?}", messages);
+		assert_eq!(messages[0], "boom");
+		let context = &messages[1];
+		assert!(context.contains("Tracked"), "{}", context);
+		assert!(context.contains("Failing"), "{}", context);
+		assert!(
+			context.ends_with("(branch `Self::B`) panicked"),
+			"{}",
+			context
+		);
+
+		// Other branches are unaffected
+		assert_eq!(Failing::A.property().value, 1);
+	}
+
+	// A panic with a non-message payload
+	#[derive(Debug, PartialEq)]
+	struct FailedInit(u32);
+
+	fn fail_typed() -> usize {
+		std::panic::panic_any(FailedInit(42))
+	}
+
+	#[derive(Copy, Clone)]
+	enum Typed {
+		A,
+	}
+
+	props! {
+		impl EnumProp for Typed as lazy Tracked {
+			Self::A => {
+				value: fail_typed(),
+			}
+		}
+	}
+
+	#[test]
+	fn lazy_init_panic_payload_kept() {
+		let (payload, messages) = catch_recorded(|| Typed::A.property().value);
+
+		assert_eq!(payload.downcast_ref::<FailedInit>(), Some(&FailedInit(42)));
+		assert_eq!(messages.len(), 1, "{:?}", messages);
+		assert!(messages[0].contains("Typed"), "{}", messages[0]);
+	}
+
+	// Two properties referencing each other can not be initialized
+	#[derive(Copy, Clone)]
+	enum Ping {
+		A,
+	}
+
+	#[derive(Copy, Clone)]
+	enum Pong {
+		A,
+	}
+
+	props! {
+		impl EnumProp for Ping as lazy Tracked {
+			Self::A => {
+				value: Pong::A.property().value + 1,
+			}
+		}
+	}
+
+	props! {
+		impl EnumProp for Pong as lazy Tracked {
+			Self::A => {
+				value: Ping::A.property().value + 1,
+			}
+		}
+	}
+
+	#[test]
+	fn lazy_recursive_init_panics() {
+		let (payload, messages) = catch_recorded(|| Ping::A.property().value);
+		let message = payload.downcast_ref::<String>().unwrap();
+
+		// The detected recursion, followed by the annotations along the cycle
+		assert!(
+			message.starts_with("recursive initialization of `lazy` property"),
+			"{}",
+			message
+		);
+		assert!(message.ends_with("Ping` (branch `Self::A`)"), "{}", message);
+		assert_eq!(messages.len(), 3, "{:?}", messages);
+		assert_eq!(&messages[0], message);
+		assert!(messages[1].contains("Pong"), "{}", messages[1]);
+		assert!(messages[2].contains("Ping"), "{}", messages[2]);
+	}
+}
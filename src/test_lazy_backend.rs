@@ -0,0 +1,84 @@
+// This file tests the explicit selection of lazy backends, i.e. `lazy(<BACKEND>)`
+#![cfg(any(test, doctest))]
+#![allow(dead_code)]
+
+#[cfg(all(test, feature = "lazy", feature = "once_cell"))]
+mod mixed {
+	use crate::EnumProp;
+
+	struct Name {
+		name: &'static str,
+	}
+
+	struct Id {
+		id: u32,
+	}
+
+	#[derive(Copy, Clone)]
+	enum Foo {
+		A,
+		B,
+	}
+
+	// Two properties on the same enum, each with its own lazy backend
+	props! {
+		impl Deref for Foo as lazy(lazy_static) Name {
+			Self::A => {
+				name: "A",
+			}
+			Self::B => {
+				name: "B",
+			}
+		}
+	}
+	props! {
+		impl Foo : fn id as lazy(once_cell) Id {
+			Self::A => {
+				id: 1,
+			}
+			Self::B => {
+				id: 2,
+			}
+		}
+	}
+
+	#[test]
+	fn mixed_lazy_backends() {
+		assert_eq!(Foo::A.name, "A");
+		assert_eq!(Foo::B.name, "B");
+		assert_eq!(Foo::A.id().id, 1);
+		assert_eq!(Foo::B.id().id, 2);
+
+		// Both backends return stable addresses
+		assert!(core::ptr::eq(
+			EnumProp::<Name>::property(&Foo::A),
+			EnumProp::<Name>::property(&Foo::A)
+		));
+		assert!(core::ptr::eq(Foo::B.id(), Foo::B.id()));
+	}
+}
+
+
+
+// Unknown backends are rejected
+
+/// ```compile_fail
+/// use enumeraties::props;
+///
+/// struct Props {
+///     foo: u8,
+/// }
+///
+/// enum Foo {
+///     A,
+/// }
+///
+/// props! {
+///     impl Deref for Foo as lazy(no_such_backend) Props {
+///         Self::A => {
+///             foo: 42,
+///         }
+///     }
+/// }
+/// ```
+struct UnknownLazyBackend;
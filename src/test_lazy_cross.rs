@@ -0,0 +1,288 @@
+// This file tests lazy properties computed from the properties of other
+// variants, which are initialized on demand, in the order of the references.
+#![cfg(test)]
+#![cfg(lazy_backend)]
+
+use super::EnumProp;
+use super::LazyEnumProp;
+
+
+struct Props {
+	area: f64,
+}
+
+#[derive(Copy, Clone)]
+enum Shape {
+	Triangle,
+	Hexagon,
+}
+
+props! {
+	impl Shape : fn props as lazy Props {
+		Self::Triangle => {
+			area: 3f64.sqrt() / 4.0,
+		}
+		Self::Hexagon => {
+			area: Shape::Triangle.props().area * 6.0,
+		}
+	}
+}
+
+#[test]
+fn lazy_cross_reference() {
+	assert_eq!(Shape::Hexagon.props().area, 1.5 * 3f64.sqrt());
+	assert_eq!(Shape::Triangle.props().area, 3f64.sqrt() / 4.0);
+}
+
+// The chain `A -> B -> C`, each touched first by one of the tests below, which
+// is why there is a separate enum for each test
+struct Linked {
+	value: u32,
+}
+
+macro_rules! chain {
+	($name:ident) => {
+		#[derive(Copy, Clone)]
+		enum $name {
+			A,
+			B,
+			C,
+		}
+
+		props! {
+			impl EnumProp for $name as lazy Linked {
+				Self::A => {
+					value: $name::B.property().value + 1,
+				}
+				Self::B => {
+					value: $name::C.property().value + 1,
+				}
+				Self::C => {
+					value: 1,
+				}
+			}
+		}
+	};
+}
+
+chain!(ChainA);
+chain!(ChainB);
+chain!(ChainC);
+
+#[test]
+fn lazy_chain_from_head() {
+	assert_eq!(ChainA::A.property().value, 3);
+
+	// The whole chain got initialized
+	assert!(ChainA::B.is_property_initialized());
+	assert!(ChainA::C.is_property_initialized());
+	assert_eq!(ChainA::B.property().value, 2);
+	assert_eq!(ChainA::C.property().value, 1);
+}
+
+#[test]
+fn lazy_chain_from_middle() {
+	assert_eq!(ChainB::B.property().value, 2);
+
+	// Only the referenced part of the chain got initialized
+	assert!(!ChainB::A.is_property_initialized());
+	assert!(ChainB::C.is_property_initialized());
+	assert_eq!(ChainB::A.property().value, 3);
+	assert_eq!(ChainB::C.property().value, 1);
+}
+
+#[test]
+fn lazy_chain_from_tail() {
+	assert_eq!(ChainC::C.property().value, 1);
+
+	assert!(!ChainC::A.is_property_initialized());
+	assert!(!ChainC::B.is_property_initialized());
+	assert_eq!(ChainC::A.property().value, 3);
+	assert_eq!(ChainC::B.property().value, 2);
+}
+
+// The same chain touched from multiple threads at once
+#[cfg(not(feature = "unsync-lazy"))]
+mod concurrent {
+	use std::vec::Vec;
+
+	use super::Linked;
+	use crate::EnumProp;
+
+	chain!(Raced);
+
+	#[test]
+	fn lazy_chain_concurrent() {
+		let values: Vec<u32> = std::thread::scope(|s| {
+			let handles: Vec<_> = [Raced::C, Raced::A, Raced::B, Raced::A]
+				.into_iter()
+				.map(|variant| s.spawn(move || variant.property().value))
+				.collect();
+			handles.into_iter().map(|h| h.join().unwrap()).collect()
+		});
+
+		assert_eq!(values, [1, 3, 2, 3]);
+	}
+}
+
+// References forming a cycle panic instead of deadlocking, with std
+#[cfg(feature = "std")]
+mod cycle {
+	use std::string::String;
+
+	use super::Linked;
+	use crate::EnumProp;
+
+	#[derive(Copy, Clone)]
+	enum Loop {
+		A,
+		B,
+	}
+
+	props! {
+		impl EnumProp for Loop as lazy Linked {
+			Self::A => {
+				value: Loop::A.property().value + 1,
+			}
+			Self::B => {
+				value: 1,
+			}
+		}
+	}
+
+	#[test]
+	fn lazy_self_reference_panics() {
+		let payload = std::panic::catch_unwind(|| Loop::A.property().value).unwrap_err();
+		let message = payload.downcast_ref::<String>().unwrap();
+
+		assert!(
+			message.contains("recursive initialization of `lazy` property"),
+			"{}",
+			message
+		);
+		assert!(message.ends_with("Loop` (branch `Self::A`)"), "{}", message);
+
+		// Other branches are unaffected
+		assert_eq!(Loop::B.property().value, 1);
+	}
+
+	// A cycle over several variants
+	#[derive(Copy, Clone)]
+	enum Ring {
+		A,
+		B,
+		C,
+	}
+
+	props! {
+		impl EnumProp for Ring as lazy Linked {
+			Self::A => {
+				value: Ring::B.property().value + 1,
+			}
+			Self::B => {
+				value: Ring::C.property().value + 1,
+			}
+			Self::C => {
+				value: Ring::A.property().value + 1,
+			}
+		}
+	}
+
+	#[test]
+	fn lazy_cycle_panics() {
+		let payload = std::panic::catch_unwind(|| Ring::B.property().value).unwrap_err();
+		let message = payload.downcast_ref::<String>().unwrap();
+
+		// The cycle `B -> C -> A -> B` is detected at the second access of `B`
+		assert!(
+			message.starts_with("recursive initialization of `lazy` property"),
+			"{}",
+			message
+		);
+		assert!(message.ends_with("Ring` (branch `Self::B`)"), "{}", message);
+	}
+
+	// The variants of a grouped table are initialized together, thus, they can
+	// not reference each other at all
+	#[derive(Copy, Clone)]
+	enum Grouped {
+		A,
+		B,
+	}
+
+	props! {
+		impl EnumProp for Grouped as lazy(grouped) Linked {
+			Self::A => {
+				value: Grouped::B.property().value + 1,
+			}
+			Self::B => {
+				value: 1,
+			}
+		}
+	}
+
+	#[test]
+	fn lazy_grouped_reference_panics() {
+		let payload = std::panic::catch_unwind(|| Grouped::B.property().value).unwrap_err();
+		let message = payload.downcast_ref::<String>().unwrap();
+
+		assert!(message.ends_with("Grouped` (grouped)"), "{}", message);
+	}
+}
+
+// A cycle entered from several threads at once is not detected, since the
+// initialization is tracked per thread, thus, it deadlocks
+#[cfg(all(feature = "std", not(feature = "unsync-lazy")))]
+mod cross_thread {
+	use std::sync::atomic::AtomicUsize;
+	use std::sync::atomic::Ordering;
+	use std::sync::Barrier;
+	use std::time::Duration;
+
+	use super::Linked;
+	use crate::EnumProp;
+
+	// Both threads and the test enter the initialization of the cycle at once
+	static ENTERED: Barrier = Barrier::new(3);
+	// Counts the threads returning from the access, by a value or a panic
+	static RETURNED: AtomicUsize = AtomicUsize::new(0);
+
+	#[derive(Copy, Clone)]
+	enum Cross {
+		A,
+		B,
+	}
+
+	props! {
+		impl EnumProp for Cross as lazy Linked {
+			Self::A => {
+				value: {
+					ENTERED.wait();
+					Cross::B.property().value + 1
+				},
+			}
+			Self::B => {
+				value: {
+					ENTERED.wait();
+					Cross::A.property().value + 1
+				},
+			}
+		}
+	}
+
+	#[test]
+	fn lazy_cross_thread_cycle_deadlocks() {
+		for variant in [Cross::A, Cross::B] {
+			std::thread::spawn(move || {
+				let _ = std::panic::catch_unwind(|| variant.property().value);
+				RETURNED.fetch_add(1, Ordering::SeqCst);
+			});
+		}
+
+		// Once both are initializing, each waits for the other forever, thus
+		// the threads are left blocked
+		ENTERED.wait();
+		std::thread::sleep(Duration::from_millis(100));
+		assert_eq!(RETURNED.load(Ordering::SeqCst), 0);
+	}
+}
@@ -0,0 +1,60 @@
+// This file tests the error when initializing all variants of lazy properties
+// with variant data
+#![cfg(doctest)]
+#![cfg(lazy_backend)]
+
+/// ```compile_fail
+/// use enumeraties::props;
+/// use enumeraties::EnumPropInit;
+///
+/// struct Props {
+///     foo: u8,
+/// }
+///
+/// enum Foo {
+///     A,
+///     B(u8),
+/// }
+///
+/// props! {
+///     impl Deref for Foo as lazy Props {
+///         Self::A => {
+///             foo: 42,
+///         }
+///         Self::B(_) => {
+///             foo: 43,
+///         }
+///     }
+/// }
+///
+/// <Foo as EnumPropInit<Props>>::initialize_all();
+/// ```
+struct NoInitWithVariantData;
+
+/// ```compile_fail
+/// use enumeraties::props;
+/// use enumeraties::EnumPropInit;
+///
+/// struct Props {
+///     foo: u8,
+/// }
+///
+/// enum Foo {
+///     A,
+///     B,
+/// }
+///
+/// props! {
+///     impl Deref for Foo as lazy Props {
+///         Self::A => {
+///             foo: 42,
+///         }
+///         default => {
+///             foo: 43,
+///         }
+///     }
+/// }
+///
+/// <Foo as EnumPropInit<Props>>::initialize_all();
+/// ```
+struct NoInitWithWildcard;
@@ -0,0 +1,112 @@
+// This file tests properties of enums with lifetime parameters
+#![cfg(any(test, doctest))]
+#![allow(dead_code)]
+
+use super::EnumProp;
+
+
+struct TokenMeta {
+	kind: &'static str,
+	keyword: bool,
+}
+
+#[derive(Clone, Copy)]
+enum Token<'src> {
+	Ident(&'src str),
+	Number(&'src str),
+	Let,
+}
+
+props! {
+	impl<'src> Deref for Token<'src> as const TokenMeta {
+		Self::Ident(_) => {
+			kind: "identifier",
+			keyword: false,
+		}
+		Self::Number(_) => {
+			kind: "number",
+			keyword: false,
+		}
+		Self::Let => {
+			kind: "let",
+			keyword: true,
+		}
+	}
+}
+
+props! {
+	impl<'src> Token<'src> : fn precedence as static u8 {
+		Self::Number(digits) if digits.len() > 9 => 2,
+		_ => 1,
+	}
+}
+
+// The property is `'static`, regardless of the borrowed payload
+fn kind(source: &str) -> &'static str {
+	let token = Token::Ident(source.trim());
+	token.kind
+}
+
+#[test]
+fn lifetime_enum() {
+	// A borrow which does not outlive the test
+	let buffer = *b"  foo ";
+	let source = core::str::from_utf8(&buffer).unwrap();
+	assert_eq!(kind(source), "identifier");
+
+	let number = Token::Number(&source[2..5]);
+	assert!(!number.keyword);
+	assert_eq!(*number.precedence(), 1);
+	assert_eq!(*Token::Number("1234567890").precedence(), 2);
+	assert!(Token::Let.keyword);
+}
+
+// Along with type parameters and bounds
+enum Node<'a, T: 'a> {
+	Leaf(&'a T),
+	Branch(&'a [Node<'a, T>]),
+}
+
+props! {
+	impl<'a, T: 'a> EnumProp for Node<'a, T> as const &'static str where T: Clone {
+		Self::Leaf(_) => "leaf",
+		Self::Branch(_) => "branch",
+	}
+}
+
+#[test]
+fn lifetime_enum_generic() {
+	let value = 42;
+	let leaf = Node::Leaf(&value);
+	assert_eq!(*leaf.property(), "leaf");
+	assert_eq!(*Node::Branch(&[leaf]).property(), "branch");
+}
+
+#[cfg(lazy_backend)]
+mod lazy {
+	use super::Token;
+	use crate::test_util::compute;
+
+	struct Stats {
+		value: u32,
+	}
+
+	props! {
+		impl<'src> Token<'src> : fn stats as lazy Stats {
+			Self::Let => {
+				value: compute(2),
+			}
+			_ => {
+				value: compute(3),
+			}
+		}
+	}
+
+	#[test]
+	fn lifetime_enum_lazy() {
+		let buffer = *b"bar";
+		let source = core::str::from_utf8(&buffer).unwrap();
+		assert_eq!(Token::Ident(source).stats().value, 9);
+		assert_eq!(Token::Let.stats().value, 4);
+	}
+}
@@ -0,0 +1,249 @@
+// This file tests branches taking the remaining fields from another branch
+#![cfg(any(test, doctest))]
+#![allow(dead_code)]
+
+use super::EnumProp;
+
+
+struct Unit {
+	name: &'static str,
+	speed: f32,
+	armor: u32,
+}
+
+#[derive(Clone, Copy)]
+enum Foo {
+	Scout,
+	FastScout,
+	ArmoredScout,
+	Tank,
+	Heavy(u8),
+}
+
+props! {
+	impl Deref for Foo as const Unit {
+		Self::Scout => {
+			name: "scout",
+			speed: 1.0,
+			armor: 1,
+		}
+		Self::FastScout => {
+			speed: 2.0,
+			..like Self::Scout
+		}
+		// A chain of references
+		Self::ArmoredScout => {
+			armor: 3,
+			..like Self::FastScout
+		}
+		Self::Tank => {
+			name: "tank",
+			speed: 0.5,
+			armor: 10,
+		}
+		Self::Heavy(_) => {
+			..like Self::Tank
+		}
+	}
+}
+
+#[test]
+fn like_const() {
+	assert_eq!(Foo::FastScout.name, "scout");
+	assert_eq!(Foo::FastScout.speed, 2.0);
+	assert_eq!(Foo::FastScout.armor, 1);
+
+	assert_eq!(Foo::ArmoredScout.speed, 2.0);
+	assert_eq!(Foo::ArmoredScout.armor, 3);
+
+	assert_eq!(Foo::Heavy(1).name, "tank");
+}
+
+#[derive(Clone, Copy)]
+enum Bar {
+	Scout,
+	FastScout,
+	ArmoredScout,
+	Tank,
+	Heavy(u8),
+}
+
+props! {
+	impl Bar : fn stats as static Unit {
+		defaults {
+			armor: 0,
+		}
+		Self::Scout => {
+			name: "scout",
+			speed: 1.0,
+		}
+		// The defaults do not override the fields of the referenced branch
+		Self::Tank => {
+			name: "tank",
+			speed: 0.5,
+			armor: 10,
+		},
+		Self::FastScout => {
+			speed: 2.0,
+			..like Self::Scout
+		},
+		Self::ArmoredScout => {
+			..like Self::Tank
+		},
+		// Along with overriding the modifier
+		Self::Heavy(_) => const {
+			name: "heavy",
+			..like Self::Tank
+		}
+	}
+}
+
+#[test]
+fn like_static_defaults() {
+	assert_eq!(Bar::FastScout.stats().armor, 0);
+	assert_eq!(Bar::ArmoredScout.stats().armor, 10);
+	assert_eq!(Bar::Heavy(1).stats().name, "heavy");
+	assert_eq!(Bar::Heavy(1).stats().speed, 0.5);
+
+	// Each branch has its own value
+	assert!(!core::ptr::eq(Bar::ArmoredScout.stats(), Bar::Tank.stats()));
+}
+
+// Also with named values and values given as an expression
+const SCOUT: Unit = Unit {
+	name: "scout",
+	speed: 1.0,
+	armor: 1,
+};
+
+#[derive(Clone, Copy)]
+enum Baz {
+	Scout,
+	FastScout,
+	ArmoredScout,
+	Tank,
+	Heavy(u8),
+}
+
+props! {
+	impl EnumProp for Baz as const Unit {
+		let TANK = {
+			name: "tank",
+			speed: 0.5,
+			armor: 10,
+		};
+		Self::Scout => SCOUT,
+		Self::Tank => TANK,
+		Self::FastScout => {
+			speed: 2.0,
+			..like Self::Scout
+		}
+		Self::ArmoredScout | Self::Heavy(_) => {
+			armor: 20,
+			..like Self::Tank
+		}
+	}
+}
+
+#[test]
+fn like_named() {
+	let prop = |variant: Baz| EnumProp::<Unit>::property(&variant);
+	assert_eq!(prop(Baz::FastScout).speed, 2.0);
+	assert_eq!(prop(Baz::FastScout).name, "scout");
+	assert_eq!(prop(Baz::Heavy(1)).name, "tank");
+	assert_eq!(prop(Baz::Heavy(1)).armor, 20);
+}
+
+#[cfg(lazy_backend)]
+mod lazy {
+	use super::Foo;
+	use crate::test_util::compute;
+
+	struct Stats {
+		value: u32,
+		twice: u32,
+	}
+
+	// The referenced branch is evaluated again
+	props! {
+		impl Foo : fn lazy_stats as lazy Stats {
+			Self::Scout => {
+				value: compute(2),
+				twice: compute(2) * 2,
+			}
+			Self::FastScout => {
+				value: compute(3),
+				..like Self::Scout
+			}
+			_ => {
+				..like Self::FastScout
+			}
+		}
+	}
+
+	#[test]
+	fn like_lazy() {
+		assert_eq!(Foo::FastScout.lazy_stats().value, 9);
+		assert_eq!(Foo::FastScout.lazy_stats().twice, 8);
+		assert_eq!(Foo::Tank.lazy_stats().value, 9);
+		assert!(!core::ptr::eq(
+			Foo::FastScout.lazy_stats(),
+			Foo::Tank.lazy_stats()
+		));
+	}
+}
+
+
+
+// Only preceding branches can be referenced, thus there can be no cycles
+
+/// ```compile_fail
+/// use enumeraties::props;
+///
+/// struct Props {
+///     foo: u8,
+///     bar: u8,
+/// }
+///
+/// enum Foo {
+///     A,
+///     B,
+/// }
+///
+/// props! {
+///     impl Deref for Foo as const Props {
+///         Self::A => {
+///             foo: 1,
+///             ..like Self::B
+///         }
+///         Self::B => {
+///             foo: 2,
+///             bar: 2,
+///         }
+///     }
+/// }
+/// ```
+struct LikeLater;
+
+// Neither the branch itself
+
+/// ```compile_fail
+/// use enumeraties::props;
+///
+/// struct Props {
+///     foo: u8,
+/// }
+///
+/// enum Foo {
+///     A,
+/// }
+///
+/// props! {
+///     impl Deref for Foo as const Props {
+///         Self::A => {
+///             ..like Self::A
+///         }
+///     }
+/// }
+/// ```
+struct LikeItself;
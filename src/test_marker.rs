@@ -0,0 +1,97 @@
+// This file tests unit struct properties, e.g. used as markers in generic code
+#![cfg(any(test, doctest))]
+#![deny(warnings)]
+#![allow(dead_code)]
+
+use super::EnumProp;
+
+
+// A marker, which can be required by generic code
+struct Deprecated;
+
+struct Stable;
+
+#[derive(Clone, Copy)]
+enum Foo {
+	A,
+	B,
+	C(u8),
+}
+
+props! {
+	impl EnumProp for Foo as const Deprecated {
+		Self::A => {}
+		Self::B,
+		_
+	}
+}
+
+props! {
+	impl EnumProp for Foo as static Stable {
+		Self::A | Self::B,
+		Self::C(_) => {},
+	}
+}
+
+// Generic code requiring the marker
+fn deprecated<E: EnumProp<Deprecated>>(variant: E) -> &'static Deprecated {
+	variant.property()
+}
+
+#[test]
+fn marker_const() {
+	let _: &Deprecated = deprecated(Foo::A);
+	let _: &Deprecated = deprecated(Foo::C(42));
+}
+
+#[test]
+fn marker_static() {
+	// Being a `static`, there is a unique address per branch
+	let a: *const Stable = EnumProp::<Stable>::property(&Foo::A);
+	let b: *const Stable = EnumProp::<Stable>::property(&Foo::B);
+	assert_eq!(a, b);
+}
+
+#[cfg(lazy_backend)]
+mod lazy {
+	use super::Foo;
+	use crate::EnumPropInit;
+	use crate::LazyEnumProp;
+
+	struct Experimental;
+
+	#[derive(Clone, Copy)]
+	enum Bar {
+		A,
+		B,
+	}
+
+	props! {
+		impl EnumProp for Foo as lazy Experimental {
+			Self::A,
+			Self::B,
+			Self::C(_),
+		}
+	}
+
+	props! {
+		impl EnumProp for Bar as lazy Experimental {
+			Self::A,
+			Self::B => {}
+		}
+	}
+
+	#[test]
+	fn marker_lazy() {
+		let _: &Experimental = super::EnumProp::property(&Foo::C(42));
+
+		// Markers without bodies can also be initialized all at once
+		<Bar as EnumPropInit<Experimental>>::initialize_all();
+		assert!(LazyEnumProp::<Experimental>::is_property_initialized(
+			&Bar::A
+		));
+		assert!(LazyEnumProp::<Experimental>::is_property_initialized(
+			&Bar::B
+		));
+	}
+}
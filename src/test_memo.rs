@@ -0,0 +1,212 @@
+// This file tests memoized properties, computed once for each value of the enum
+#![cfg(any(test, doctest))]
+#![cfg(feature = "std")]
+#![allow(dead_code)]
+
+use std::format;
+use std::string::String;
+
+use super::EnumProp;
+use super::MemoEnumProp;
+
+
+struct Props {
+	name: String,
+	size: u32,
+}
+
+#[derive(Clone, PartialEq, Eq, Hash)]
+enum Foo {
+	Alpha,
+	Beta(u32),
+	Gamma { name: &'static str },
+}
+
+props! {
+	impl Deref for Foo as memo Props {
+		defaults {
+			size: 1,
+		}
+		Self::Alpha => static {
+			name: String::new(),
+		}
+		Self::Beta(n) => {
+			name: format!("beta-{n}"),
+			size: *n,
+		}
+		Self::Gamma { name } => {
+			name: String::from(*name),
+		}
+	}
+}
+
+#[test]
+fn memo_same_value() {
+	let a = Foo::Beta(42);
+	let b = Foo::Beta(42);
+
+	assert_eq!(a.name, "beta-42");
+	assert_eq!(b.size, 42);
+	assert!(core::ptr::eq(&*a, &*b));
+
+	let gamma = Foo::Gamma {
+		name: "gamma",
+	};
+	assert_eq!(gamma.name, "gamma");
+	assert_eq!(gamma.size, 1);
+	assert!(core::ptr::eq(&*gamma, &*gamma.clone()));
+}
+
+#[test]
+fn memo_different_values() {
+	let a = Foo::Beta(1);
+	let b = Foo::Beta(2);
+
+	assert_eq!(a.size, 1);
+	assert_eq!(b.size, 2);
+	assert!(!core::ptr::eq(&*a, &*b));
+}
+
+// Each test has its own enum, to count the cached values
+#[derive(Clone, PartialEq, Eq, Hash)]
+enum Bar {
+	A(u8),
+	B,
+}
+
+props! {
+	impl EnumProp for Bar as memo Props {
+		Self::A(n) => {
+			name: format!("a-{n}"),
+			size: u32::from(*n),
+		}
+		// Not cached at all
+		Self::B => static {
+			name: String::new(),
+			size: 0,
+		}
+	}
+}
+
+#[test]
+fn memo_len() {
+	let props = |value: Bar| EnumProp::<Props>::property(&value);
+
+	assert_eq!(<Bar as MemoEnumProp<Props>>::memo_len(), 0);
+
+	assert_eq!(props(Bar::A(1)).size, 1);
+	assert_eq!(props(Bar::A(2)).size, 2);
+	assert_eq!(props(Bar::A(1)).name, "a-1");
+	assert_eq!(<Bar as MemoEnumProp<Props>>::memo_len(), 2);
+
+	assert_eq!(props(Bar::B).size, 0);
+	assert!(core::ptr::eq(props(Bar::B), props(Bar::B)));
+	assert_eq!(<Bar as MemoEnumProp<Props>>::memo_len(), 2);
+}
+
+// Racing threads all get the same reference
+#[derive(Clone, PartialEq, Eq, Hash)]
+enum Baz {
+	A(u32),
+}
+
+props! {
+	impl EnumProp for Baz as memo Props {
+		Self::A(n) => {
+			name: format!("{n}"),
+			size: *n,
+		}
+	}
+}
+
+#[test]
+fn memo_concurrent() {
+	let addresses: std::vec::Vec<usize> = std::thread::scope(|s| {
+		let handles: std::vec::Vec<_> = (0..4)
+			.map(|_| {
+				s.spawn(|| {
+					let props: &'static Props = Baz::A(7).property();
+					props as *const Props as usize
+				})
+			})
+			.collect();
+		handles.into_iter().map(|h| h.join().unwrap()).collect()
+	});
+
+	assert!(addresses.iter().all(|&address| address == addresses[0]));
+	assert_eq!(<Baz as MemoEnumProp<Props>>::memo_len(), 1);
+}
+
+
+
+// The enum must be hashable, to be used as the key of the cache
+
+/// ```compile_fail
+/// use enumeraties::props;
+///
+/// struct Props {
+///     foo: u32,
+/// }
+///
+/// #[derive(Clone, PartialEq, Eq)]
+/// enum Foo {
+///     A(u32),
+/// }
+///
+/// props! {
+///     impl EnumProp for Foo as memo Props {
+///         Self::A(n) => {
+///             foo: *n,
+///         }
+///     }
+/// }
+/// ```
+struct NotHash;
+
+// And `Clone`, to store a copy of it
+
+/// ```compile_fail,E0277
+/// use enumeraties::props;
+///
+/// struct Props {
+///     foo: u32,
+/// }
+///
+/// #[derive(PartialEq, Eq, Hash)]
+/// enum Foo {
+///     A(u32),
+/// }
+///
+/// props! {
+///     impl EnumProp for Foo as memo Props {
+///         Self::A(n) => {
+///             foo: *n,
+///         }
+///     }
+/// }
+/// ```
+struct NotClone;
+
+// Generic enums are not supported, the cache can not use their parameters
+
+/// ```compile_fail
+/// use enumeraties::props;
+///
+/// struct Props {
+///     foo: u32,
+/// }
+///
+/// #[derive(Clone, PartialEq, Eq, Hash)]
+/// enum Foo<T> {
+///     A(T),
+/// }
+///
+/// props! {
+///     impl<T: Clone + Eq + core::hash::Hash + Send + Sync + 'static> EnumProp for Foo<T> as memo Props {
+///         Self::A(_) => {
+///             foo: 1,
+///         }
+///     }
+/// }
+/// ```
+struct Generic;
@@ -0,0 +1,197 @@
+// This file tests branches overriding the modifier of their property
+#![cfg(any(test, doctest))]
+#![allow(dead_code)]
+
+use super::EnumProp;
+
+
+struct Meta {
+	name: &'static str,
+	value: u32,
+}
+
+#[derive(Clone, Copy)]
+enum Foo {
+	A,
+	B,
+	C,
+}
+
+props! {
+	impl Deref for Foo as const Meta {
+		defaults {
+			value: 0,
+		}
+		Self::A => {
+			name: "A",
+		}
+		Self::B => static {
+			name: "B",
+			value: 2,
+		}
+		Self::C => const {
+			name: "C",
+		}
+	}
+}
+
+#[test]
+fn mixed_const_static() {
+	assert_eq!(Foo::A.name, "A");
+	assert_eq!(Foo::B.value, 2);
+	assert_eq!(Foo::C.value, 0);
+
+	// The `static` branch has a unique address
+	assert!(core::ptr::eq(&*Foo::B, &*Foo::B));
+}
+
+props! {
+	impl Foo : fn label as static &'static str {
+		Self::A => const { "a" }
+		_ => "other",
+	}
+}
+
+#[test]
+fn mixed_plain() {
+	assert_eq!(*Foo::A.label(), "a");
+	assert_eq!(*Foo::C.label(), "other");
+}
+
+props! {
+	enum Bar as static &'static str {
+		A => "A",
+		B => const { "B" }
+		C => "C",
+	}
+}
+
+#[test]
+fn mixed_enum_def() {
+	assert_eq!(*Bar::B, "B");
+	assert_eq!(*EnumProp::<&str>::property(&Bar::C), "C");
+}
+
+#[cfg(lazy_backend)]
+mod lazy {
+	use super::Foo;
+	use crate::test_util::compute;
+	use crate::EnumPropInit;
+	use crate::LazyEnumProp;
+
+	struct Stats {
+		value: u32,
+	}
+
+	struct Info {
+		value: u32,
+	}
+
+	#[derive(Clone, Copy)]
+	enum Baz {
+		A,
+		B,
+		C,
+	}
+
+	// Only the `lazy` branches are initialized at runtime
+	props! {
+		impl Foo : fn stats as const Stats {
+			Self::A => {
+				value: 1,
+			}
+			Self::B => lazy {
+				value: compute(2),
+			}
+			Self::C => lazy {
+				value: compute(3),
+			}
+		}
+	}
+
+	#[test]
+	fn mixed_lazy_in_const() {
+		assert_eq!(Foo::A.stats().value, 1);
+		assert_eq!(Foo::B.stats().value, 4);
+		assert_eq!(Foo::C.stats().value, 9);
+		assert!(core::ptr::eq(Foo::C.stats(), Foo::C.stats()));
+	}
+
+	// The `const` branches of a `lazy` property are always initialized
+	props! {
+		impl Deref + fn info for Baz as lazy Info {
+			Self::A => const {
+				value: 1,
+			}
+			Self::B => static {
+				value: 2,
+			}
+			Self::C => {
+				value: compute(3),
+			}
+		}
+	}
+
+	#[test]
+	fn mixed_const_in_lazy() {
+		assert!(Baz::A.try_property().is_some());
+		assert!(Baz::B.try_property().is_some());
+
+		<Baz as EnumPropInit<Info>>::initialize_all();
+		assert_eq!(Baz::A.value, 1);
+		assert_eq!(Baz::B.info().value, 2);
+		assert_eq!(Baz::C.value, 9);
+	}
+}
+
+
+
+// A grouped lazy table can not hold other branches
+
+/// ```compile_fail
+/// use enumeraties::props;
+///
+/// struct Props {
+///     foo: u8,
+/// }
+///
+/// enum Foo {
+///     A,
+///     B,
+/// }
+///
+/// props! {
+///     impl EnumProp for Foo as lazy(grouped) Props {
+///         Self::A => const {
+///             foo: 1,
+///         }
+///         Self::B => {
+///             foo: 2,
+///         }
+///     }
+/// }
+/// ```
+struct GroupedOverride;
+
+// Neither can thread locals
+
+/// ```compile_fail
+/// use enumeraties::props;
+///
+/// struct Props {
+///     foo: u8,
+/// }
+///
+/// enum Foo {
+///     A,
+/// }
+///
+/// props! {
+///     impl EnumPropLocal for Foo as thread_lazy Props {
+///         Self::A => const {
+///             foo: 1,
+///         }
+///     }
+/// }
+/// ```
+struct ThreadLazyOverride;
@@ -0,0 +1,201 @@
+// This file tests named values, shared by several branches
+#![cfg(any(test, doctest))]
+#![allow(dead_code)]
+
+use super::EnumProp;
+
+
+struct Prop {
+	name: &'static str,
+	tier: u8,
+}
+
+#[derive(Clone, Copy)]
+enum Foo {
+	A,
+	B,
+	C(u8),
+	D,
+}
+
+props! {
+	impl EnumProp for Foo as static Prop {
+		let COMMON = {
+			name: "common",
+			tier: 1,
+		};
+		let RARE = {
+			name: "rare",
+			tier: 2,
+		};
+		Self::A => COMMON,
+		Self::B => RARE,
+		Self::C(0) => {
+			name: "zero",
+			tier: 0,
+		}
+		Self::C(_) => RARE,
+		Self::D => COMMON,
+	}
+}
+
+#[test]
+fn named_static() {
+	let prop = |variant: Foo| EnumProp::<Prop>::property(&variant);
+
+	assert_eq!(prop(Foo::A).name, "common");
+	assert_eq!(prop(Foo::B).tier, 2);
+	assert_eq!(prop(Foo::C(0)).name, "zero");
+	assert_eq!(prop(Foo::C(42)).name, "rare");
+
+	// All branches using a named value share its `static`
+	assert!(core::ptr::eq(prop(Foo::A), prop(Foo::D)));
+	assert!(core::ptr::eq(prop(Foo::B), prop(Foo::C(42))));
+	assert!(!core::ptr::eq(prop(Foo::A), prop(Foo::B)));
+}
+
+struct Unit {
+	name: &'static str,
+	dangerous: bool,
+	speed: f32,
+}
+
+const SLOW: Unit = Unit {
+	name: "slow",
+	dangerous: false,
+	speed: 0.5,
+};
+
+props! {
+	impl Deref for Foo as const Unit {
+		defaults {
+			dangerous: false,
+		}
+		/// The units that are faster than usual
+		let FAST = {
+			name: "fast",
+			speed: 2.0,
+		};
+		Self::A | Self::B => FAST,
+		Self::C(_) => {
+			name: "C",
+			speed: 1.0,
+		}
+		// Names that are not named values are just expressions
+		Self::D => SLOW,
+	}
+}
+
+props! {
+	impl Foo : fn caution as const f32 {
+		let CAREFUL = SLOW.speed / 2.0;
+		Self::A => 1.0,
+		_ => CAREFUL,
+	}
+}
+
+#[test]
+fn named_const() {
+	assert_eq!(Foo::A.name, "fast");
+	assert_eq!(Foo::B.speed, 2.0);
+
+	// The defaults apply to named values
+	assert!(!Foo::B.dangerous);
+	assert_eq!(Foo::C(42).speed, 1.0);
+	assert_eq!(Foo::D.name, "slow");
+	assert_eq!(*Foo::C(42).caution(), 0.25);
+}
+
+props! {
+	impl Foo : fn weight as static u32 {
+		let HEAVY = 100;
+		Self::A | Self::B => HEAVY,
+		Self::C(_) => 1,
+		Self::D => HEAVY,
+	}
+}
+
+#[test]
+fn named_primitive() {
+	assert_eq!(*Foo::A.weight(), 100);
+	assert_eq!(*Foo::C(42).weight(), 1);
+	assert!(core::ptr::eq(Foo::A.weight(), Foo::D.weight()));
+}
+
+
+
+// Unknown names are an error
+
+/// ```compile_fail,E0425
+/// use enumeraties::props;
+///
+/// struct Props {
+///     foo: u8,
+/// }
+///
+/// enum Foo {
+///     A,
+///     B,
+/// }
+///
+/// props! {
+///     impl Deref for Foo as static Props {
+///         let FOO = {
+///             foo: 42,
+///         };
+///         Self::A => FOO,
+///         Self::B => BAR,
+///     }
+/// }
+/// ```
+struct UnknownName;
+
+// Unused named values are an error
+
+/// ```compile_fail
+/// use enumeraties::props;
+///
+/// struct Props {
+///     foo: u8,
+/// }
+///
+/// enum Foo {
+///     A,
+/// }
+///
+/// props! {
+///     impl Deref for Foo as static Props {
+///         let FOO = {
+///             foo: 42,
+///         };
+///         Self::A => {
+///             foo: 43,
+///         }
+///     }
+/// }
+/// ```
+struct UnusedName;
+
+// Named values are only supported by `const` and `static` properties
+
+/// ```compile_fail
+/// use enumeraties::props;
+///
+/// struct Props {
+///     foo: u8,
+/// }
+///
+/// enum Foo {
+///     A,
+/// }
+///
+/// props! {
+///     impl Deref for Foo as lazy Props {
+///         let FOO = {
+///             foo: 42,
+///         };
+///         Self::A => FOO,
+///     }
+/// }
+/// ```
+struct NamedLazy;
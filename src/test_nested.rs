@@ -0,0 +1,313 @@
+// This file tests dotted field paths, assigning the fields of nested structs
+#![cfg(any(test, doctest))]
+#![allow(dead_code)]
+
+use super::EnumProp;
+
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Ui {
+	color: u32,
+	icon: &'static str,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Audio {
+	volume: f32,
+	channel: Channel,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Channel {
+	id: u8,
+	muted: bool,
+}
+
+struct Meta {
+	name: &'static str,
+	ui: Ui,
+	audio: Audio,
+}
+
+const META: Meta = Meta {
+	name: "unnamed",
+	ui: Ui {
+		color: 0,
+		icon: "none.png",
+	},
+	audio: Audio {
+		volume: 1.0,
+		channel: Channel {
+			id: 0,
+			muted: false,
+		},
+	},
+};
+
+enum Foo {
+	A,
+	B,
+	C,
+	D,
+}
+
+props! {
+	impl Deref for Foo as const Meta {
+		defaults {
+			ui: Ui {
+				color: 1,
+				icon: "default.png",
+			},
+			audio: Audio {
+				volume: 0.5,
+				channel: Channel {
+					id: 1,
+					muted: false,
+				},
+			},
+		}
+		Self::A => {
+			name: "A",
+			ui.color: 2,
+			audio.volume: 0.1,
+		}
+		// Nested arbitrarily deep
+		Self::B => {
+			name: "B",
+			ui.icon: "b.png",
+			audio.channel.muted: true,
+		}
+		// Along with a whole nested struct
+		Self::C => {
+			name: "C",
+			ui: Ui {
+				color: 3,
+				icon: "c.png",
+			},
+			audio.channel.id: 3,
+		}
+		Self::D => {
+			name: "D",
+		}
+	}
+}
+
+#[test]
+fn nested_defaults() {
+	assert_eq!(Foo::A.name, "A");
+	assert_eq!(
+		Foo::A.ui,
+		Ui {
+			color: 2,
+			icon: "default.png",
+		}
+	);
+	assert_eq!(Foo::A.audio.volume, 0.1);
+	assert_eq!(Foo::A.audio.channel.id, 1);
+
+	assert_eq!(Foo::B.ui.color, 1);
+	assert_eq!(Foo::B.ui.icon, "b.png");
+	assert!(Foo::B.audio.channel.muted);
+	assert_eq!(Foo::B.audio.channel.id, 1);
+
+	assert_eq!(Foo::C.ui.icon, "c.png");
+	assert_eq!(Foo::C.audio.channel.id, 3);
+	assert!(!Foo::C.audio.channel.muted);
+
+	assert_eq!(Foo::D.ui.icon, "default.png");
+}
+
+// Onto a base value, or the value of a preceding branch
+#[derive(Clone, Copy)]
+enum Bar {
+	A,
+	B,
+	C,
+	D,
+}
+
+props! {
+	impl EnumProp for Bar as static Meta {
+		Self::A => {
+			name: "A",
+			ui.icon: "a.png",
+			..META
+		}
+		Self::B => {
+			ui.color: 5,
+			..like Self::A
+		}
+		Self::C => {
+			audio.channel.id: 7,
+			ui.color: 6,
+			..META
+		}
+		Self::D => {
+			..META
+		}
+	}
+}
+
+#[test]
+fn nested_base() {
+	let meta = |variant: Bar| EnumProp::<Meta>::property(&variant);
+
+	assert_eq!(meta(Bar::A).name, "A");
+	assert_eq!(meta(Bar::A).ui.icon, "a.png");
+	assert_eq!(meta(Bar::A).ui.color, 0);
+
+	assert_eq!(meta(Bar::B).name, "A");
+	assert_eq!(meta(Bar::B).ui.icon, "a.png");
+	assert_eq!(meta(Bar::B).ui.color, 5);
+
+	assert_eq!(meta(Bar::C).ui.color, 6);
+	assert_eq!(meta(Bar::C).ui.icon, "none.png");
+	assert_eq!(meta(Bar::C).audio.channel.id, 7);
+	assert_eq!(meta(Bar::C).audio.volume, 1.0);
+
+	assert_eq!(meta(Bar::D).name, "unnamed");
+}
+
+#[cfg(lazy_backend)]
+mod lazy {
+	use super::Foo;
+	use super::Ui;
+	use crate::test_util::compute;
+
+	struct Stats {
+		value: u32,
+		ui: Ui,
+	}
+
+	fn ui() -> Ui {
+		Ui {
+			color: compute(3),
+			icon: "lazy.png",
+		}
+	}
+
+	props! {
+		impl Foo : fn stats as lazy Stats {
+			defaults {
+				ui: ui(),
+			}
+			Self::A => {
+				value: 1,
+				ui.color: compute(2),
+			}
+			Self::B => const {
+				value: 2,
+				ui: Ui {
+					color: 0,
+					icon: "const.png",
+				},
+			}
+			_ => {
+				value: 3,
+			}
+		}
+	}
+
+	#[test]
+	fn nested_lazy() {
+		assert_eq!(Foo::A.stats().ui.color, 4);
+		assert_eq!(Foo::A.stats().ui.icon, "lazy.png");
+		assert_eq!(Foo::B.stats().ui.icon, "const.png");
+		assert_eq!(Foo::C.stats().ui.color, 9);
+	}
+}
+
+
+
+// Dotted field paths require a base value
+
+/// ```compile_fail
+/// use enumeraties::props;
+///
+/// struct Inner {
+///     foo: u8,
+/// }
+///
+/// struct Props {
+///     inner: Inner,
+/// }
+///
+/// enum Foo {
+///     A,
+/// }
+///
+/// props! {
+///     impl EnumProp for Foo as const Props {
+///         Self::A => {
+///             inner.foo: 1,
+///         }
+///     }
+/// }
+/// ```
+struct WithoutBase;
+
+// A field can not be given both as a whole and via dotted field paths
+
+/// ```compile_fail
+/// use enumeraties::props;
+///
+/// struct Inner {
+///     foo: u8,
+///     bar: u8,
+/// }
+///
+/// struct Props {
+///     inner: Inner,
+/// }
+///
+/// const PROPS: Props = Props {
+///     inner: Inner { foo: 0, bar: 0 },
+/// };
+///
+/// enum Foo {
+///     A,
+/// }
+///
+/// props! {
+///     impl EnumProp for Foo as const Props {
+///         Self::A => {
+///             inner: Inner { foo: 1, bar: 1 },
+///             inner.foo: 2,
+///             ..PROPS
+///         }
+///     }
+/// }
+/// ```
+struct WholeAndDotted;
+
+// The dotted field paths must exist
+
+/// ```compile_fail,E0609
+/// use enumeraties::props;
+///
+/// struct Inner {
+///     foo: u8,
+/// }
+///
+/// struct Props {
+///     inner: Inner,
+/// }
+///
+/// const PROPS: Props = Props {
+///     inner: Inner { foo: 0 },
+/// };
+///
+/// enum Foo {
+///     A,
+/// }
+///
+/// props! {
+///     impl EnumProp for Foo as const Props {
+///         Self::A => {
+///             inner.bar: 2,
+///             ..PROPS
+///         }
+///     }
+/// }
+/// ```
+struct UnknownPath;
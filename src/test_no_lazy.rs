@@ -0,0 +1,24 @@
+// This file tests the error when using lazy properties without a lazy backend
+#![cfg(doctest)]
+#![cfg(not(lazy_backend))]
+
+/// ```compile_fail
+/// use enumeraties::props;
+///
+/// struct Props {
+///     foo: u8,
+/// }
+///
+/// enum Foo {
+///     A,
+/// }
+///
+/// props! {
+///     impl Deref for Foo as lazy Props {
+///         Self::A => {
+///             foo: 42,
+///         }
+///     }
+/// }
+/// ```
+struct NoLazyBackend;
@@ -0,0 +1,199 @@
+// This file tests property structs that can not be constructed via a struct
+// literal, e.g. a `non_exhaustive` struct of another crate
+#![cfg(any(test, doctest))]
+#![allow(dead_code)]
+
+
+// Within this crate `non_exhaustive` has no effect, thus the private field
+// imposes the same restrictions, as if it was from another crate
+mod other {
+	#[non_exhaustive]
+	pub struct Meta {
+		pub name: &'static str,
+		pub size: u32,
+		hidden: u8,
+	}
+
+	impl Meta {
+		pub const DEFAULT: Self = Self {
+			name: "",
+			size: 0,
+			hidden: 42,
+		};
+
+		pub const fn new(name: &'static str) -> Self {
+			Self {
+				name,
+				..Self::DEFAULT
+			}
+		}
+
+		pub fn hidden(&self) -> u8 {
+			self.hidden
+		}
+	}
+}
+
+use other::Meta;
+
+#[derive(Clone, Copy)]
+enum Foo {
+	A,
+	B,
+	C,
+}
+
+props! {
+	impl Deref for Foo as const Meta {
+		Self::A => {
+			name: "A",
+			size: 1,
+			..Meta::DEFAULT
+		}
+		Self::B => {
+			name: "B",
+			..Meta::DEFAULT
+		}
+		// Or via a constructor
+		Self::C => Meta::new("C"),
+	}
+}
+
+#[test]
+fn non_exhaustive_const() {
+	assert_eq!(Foo::A.name, "A");
+	assert_eq!(Foo::A.size, 1);
+	assert_eq!(Foo::B.size, 0);
+	assert_eq!(Foo::C.name, "C");
+
+	// The remaining fields are taken from the base
+	assert_eq!(Foo::A.hidden(), 42);
+	assert_eq!(Foo::C.hidden(), 42);
+}
+
+enum Bar {
+	A,
+	B,
+	C,
+}
+
+props! {
+	impl Bar : fn meta as static Meta {
+		defaults {
+			size: 7,
+		}
+		Self::A => {
+			name: "A",
+			..Meta::DEFAULT
+		}
+		_ => {
+			name: "other",
+			size: 8,
+			..Meta::DEFAULT
+		}
+	}
+}
+
+#[test]
+fn non_exhaustive_static_defaults() {
+	assert_eq!(Bar::A.meta().size, 7);
+	assert_eq!(Bar::B.meta().size, 8);
+	assert_eq!(Bar::C.meta().name, "other");
+	assert_eq!(Bar::B.meta().hidden(), 42);
+}
+
+#[cfg(lazy_backend)]
+mod lazy {
+	use super::other::Meta;
+	use crate::test_util::compute;
+
+	enum Baz {
+		A,
+		B,
+		C,
+	}
+
+	// The base of a lazy property needs not be const
+	fn base() -> Meta {
+		Meta::new("base")
+	}
+
+	props! {
+		impl Baz : fn stats as lazy Meta {
+			Self::A => {
+				size: compute(2),
+				..base()
+			}
+			_ => {
+				name: "other",
+				size: compute(3),
+				..base()
+			}
+		}
+	}
+
+	#[test]
+	fn non_exhaustive_lazy() {
+		assert_eq!(Baz::A.stats().name, "base");
+		assert_eq!(Baz::A.stats().size, 4);
+		assert_eq!(Baz::B.stats().name, "other");
+		assert_eq!(Baz::C.stats().size, 9);
+	}
+}
+
+
+
+// Without a base, the struct literal is rejected
+
+/// ```compile_fail
+/// use enumeraties::props;
+///
+/// mod other {
+///     pub struct Meta {
+///         pub name: &'static str,
+///         hidden: u8,
+///     }
+/// }
+///
+/// enum Foo {
+///     A,
+/// }
+///
+/// props! {
+///     impl Deref for Foo as const other::Meta {
+///         Self::A => {
+///             name: "A",
+///         }
+///     }
+/// }
+/// ```
+struct WithoutBase;
+
+// The base of a `const` property must be const too
+
+/// ```compile_fail,E0015
+/// use enumeraties::props;
+///
+/// struct Meta {
+///     name: &'static str,
+///     size: u32,
+/// }
+///
+/// fn base() -> Meta {
+///     Meta { name: "", size: 0 }
+/// }
+///
+/// enum Foo {
+///     A,
+/// }
+///
+/// props! {
+///     impl Deref for Foo as const Meta {
+///         Self::A => {
+///             name: "A",
+///             ..base()
+///         }
+///     }
+/// }
+/// ```
+struct NonConstBase;
@@ -0,0 +1,66 @@
+// This file tests or-pattern branches, sharing a single property instance
+#![cfg(any(test, doctest))]
+#![allow(dead_code)]
+
+use super::EnumProp;
+
+
+struct Prop {
+	name: &'static str,
+}
+
+#[derive(Clone, Copy)]
+enum Foo {
+	A,
+	B,
+	C(u8),
+	D,
+}
+
+props! {
+	impl EnumProp for Foo as static Prop {
+		Self::A | Self::B => {
+			name: "either",
+		}
+		Self::C(0) | Self::C(1) | Self::D => {
+			name: "small",
+		}
+		Self::C(_) => {
+			name: "other",
+		}
+	}
+}
+
+#[test]
+fn or_pattern_static() {
+	let prop = |variant: Foo| EnumProp::<Prop>::property(&variant);
+
+	assert_eq!(prop(Foo::A).name, "either");
+	assert_eq!(prop(Foo::D).name, "small");
+
+	// All variants of a branch share the same `static`
+	assert!(core::ptr::eq(prop(Foo::A), prop(Foo::B)));
+	assert!(core::ptr::eq(prop(Foo::C(0)), prop(Foo::C(1))));
+	assert!(core::ptr::eq(prop(Foo::C(1)), prop(Foo::D)));
+
+	// While each branch has its own `static`
+	assert!(!core::ptr::eq(prop(Foo::A), prop(Foo::D)));
+	assert!(!core::ptr::eq(prop(Foo::A), prop(Foo::C(42))));
+	assert!(!core::ptr::eq(prop(Foo::D), prop(Foo::C(42))));
+}
+
+props! {
+	impl Foo : fn name as const &'static str {
+		| Self::A | Self::B => "either",
+		Self::C(0 | 1) | Self::D => "small",
+		Self::C(_) => "other",
+	}
+}
+
+#[test]
+fn or_pattern_const() {
+	assert_eq!(*Foo::A.name(), "either");
+	assert_eq!(*Foo::B.name(), "either");
+	assert_eq!(*Foo::C(1).name(), "small");
+	assert_eq!(*Foo::C(42).name(), "other");
+}
@@ -0,0 +1,227 @@
+// This file tests the variants sorted by a field of their properties, at
+// compile time
+#![cfg(any(test, doctest))]
+#![allow(dead_code)]
+
+
+struct ShapeDef {
+	name: &'static str,
+	display_order: u16,
+}
+
+#[derive(Debug, PartialEq)]
+enum Shape {
+	Triangle,
+	Square,
+	Rhombus,
+	Circle,
+}
+
+props! {
+	impl Deref for Shape as const ShapeDef {
+		ordered_by(
+			/// The shapes as shown in a menu
+			display_order as pub SHAPES_BY_DISPLAY_ORDER
+		)
+		all {
+			ALL_SHAPE_DEFS
+		}
+		Self::Triangle => {
+			name: "Triangle",
+			display_order: 3,
+		}
+		// Equal values keep their order
+		Self::Square | Self::Rhombus => {
+			name: "Quad",
+			display_order: 1,
+		}
+		Self::Circle => {
+			name: "Circle",
+			display_order: 0,
+		}
+	}
+}
+
+#[test]
+fn ordered_const() {
+	assert_eq!(
+		Shape::SHAPES_BY_DISPLAY_ORDER,
+		[
+			Shape::Circle,
+			Shape::Square,
+			Shape::Rhombus,
+			Shape::Triangle
+		]
+	);
+
+	// Along with the `all` table, in the order of the branches
+	let names = Shape::ALL_SHAPE_DEFS.map(|def| def.name);
+	assert_eq!(names, ["Triangle", "Quad", "Quad", "Circle"]);
+}
+
+// Static props, and via `const fn`, by several fields
+
+struct Rank {
+	value: i8,
+	weight: f32,
+}
+
+props! {
+	impl Shape {
+		EnumProp as static Rank {
+			ordered_by(value as BY_RANK)
+			ordered_by(weight as BY_WEIGHT)
+			Self::Triangle => {
+				value: 2,
+				weight: 0.5,
+			}
+			Self::Square => {
+				value: -1,
+				weight: 2.0,
+			}
+			Self::Rhombus => {
+				value: 2,
+				weight: 1.5,
+			}
+			Self::Circle => {
+				value: 0,
+				weight: -1.0,
+			}
+		}
+	}
+}
+
+struct Cost {
+	value: u8,
+}
+
+props! {
+	impl Shape {
+		const fn cost as const Cost {
+			ordered_by(value as BY_COST)
+			Self::Triangle => {
+				value: 30,
+			}
+			Self::Square => {
+				value: 20,
+			}
+			Self::Rhombus => {
+				value: 10,
+			}
+			Self::Circle => {
+				value: 20,
+			}
+		}
+	}
+}
+
+#[test]
+fn ordered_static_const_fn() {
+	assert_eq!(
+		Shape::BY_RANK,
+		[
+			Shape::Square,
+			Shape::Circle,
+			Shape::Triangle,
+			Shape::Rhombus
+		]
+	);
+	assert_eq!(
+		Shape::BY_WEIGHT,
+		[
+			Shape::Circle,
+			Shape::Triangle,
+			Shape::Rhombus,
+			Shape::Square
+		]
+	);
+	assert_eq!(
+		Shape::BY_COST,
+		[
+			Shape::Rhombus,
+			Shape::Square,
+			Shape::Circle,
+			Shape::Triangle
+		]
+	);
+}
+
+// Only fieldless variants
+
+/// ```compile_fail
+/// use enumeraties::props;
+///
+/// struct Props {
+///     order: u32,
+/// }
+///
+/// enum Foo {
+///     A,
+///     B(u8),
+/// }
+///
+/// props! {
+///     impl Foo : fn getter as const Props {
+///         ordered_by(order as BY_ORDER)
+///         Self::A => {
+///             order: 1,
+///         }
+///         Self::B(_) => {
+///             order: 2,
+///         }
+///     }
+/// }
+/// ```
+struct DataVariant;
+
+// Not with a wildcard
+
+/// ```compile_fail
+/// use enumeraties::props;
+///
+/// struct Props {
+///     order: u32,
+/// }
+///
+/// enum Foo {
+///     A,
+///     B,
+/// }
+///
+/// props! {
+///     impl Foo : fn getter as const Props {
+///         ordered_by(order as BY_ORDER)
+///         Self::A => {
+///             order: 1,
+///         }
+///         _ => {
+///             order: 2,
+///         }
+///     }
+/// }
+/// ```
+struct Wildcard;
+
+// Only `const` and `static` properties
+
+/// ```compile_fail
+/// use enumeraties::props;
+///
+/// struct Props {
+///     order: u32,
+/// }
+///
+/// enum Foo {
+///     A,
+/// }
+///
+/// props! {
+///     impl Foo : fn getter as lazy Props {
+///         ordered_by(order as BY_ORDER)
+///         Self::A => {
+///             order: 1,
+///         }
+///     }
+/// }
+/// ```
+struct LazyOrdered;
@@ -0,0 +1,213 @@
+// This file tests pairwise properties, relating two variants of the same enum
+#![cfg(any(test, doctest))]
+#![allow(dead_code)]
+
+use super::EnumPropPair;
+
+
+struct AreaRatio {
+	ratio: f32,
+}
+
+#[derive(Clone, Copy)]
+enum Shape {
+	Triangle,
+	Square,
+	Circle,
+}
+
+props! {
+	impl EnumPropPair for Shape as static AreaRatio {
+		(Self::Triangle, Self::Square) => {
+			ratio: 0.43,
+		}
+		(Self::Square, Self::Triangle) => {
+			ratio: 2.31,
+		}
+		(Self::Circle, Self::Square) => {
+			ratio: 0.79,
+		}
+		(Self::Square, Self::Circle) => {
+			ratio: 1.27,
+		}
+		(Self::Triangle, Self::Circle) | (Self::Circle, Self::Triangle) => {
+			ratio: f32::NAN,
+		}
+		(first, second) if core::mem::discriminant(first) == core::mem::discriminant(second) => {
+			ratio: 1.0,
+		}
+		_ => {
+			ratio: 0.0,
+		}
+	}
+}
+
+#[test]
+fn pair_full() {
+	let ratio = |a: Shape, b: Shape| EnumPropPair::<AreaRatio>::relation(&a, &b);
+
+	assert_eq!(ratio(Shape::Triangle, Shape::Square).ratio, 0.43);
+	assert_eq!(ratio(Shape::Square, Shape::Triangle).ratio, 2.31);
+	assert_eq!(ratio(Shape::Square, Shape::Circle).ratio, 1.27);
+	assert!(ratio(Shape::Circle, Shape::Triangle).ratio.is_nan());
+	assert_eq!(ratio(Shape::Circle, Shape::Circle).ratio, 1.0);
+
+	// Each cell has a stable address
+	assert!(core::ptr::eq(
+		ratio(Shape::Square, Shape::Circle),
+		ratio(Shape::Square, Shape::Circle)
+	));
+	assert!(core::ptr::eq(
+		ratio(Shape::Square, Shape::Square),
+		ratio(Shape::Triangle, Shape::Triangle)
+	));
+}
+
+// Symmetric, i.e. each branch covers both orders
+struct Contact {
+	touching: bool,
+	kind: &'static str,
+}
+
+enum Piece {
+	Corner,
+	Edge,
+	Center,
+}
+
+props! {
+	impl Piece {
+		EnumPropPair as symmetric const Contact {
+			defaults {
+				touching: true,
+			}
+			(Self::Corner, Self::Edge) => {
+				kind: "corner-edge",
+			}
+			(Self::Edge, Self::Center) => {
+				kind: "edge-center",
+			}
+			(Self::Corner, Self::Center) => {
+				touching: false,
+				kind: "apart",
+			}
+			(Self::Corner | Self::Edge | Self::Center, Self::Corner | Self::Edge | Self::Center) => {
+				kind: "same",
+			}
+		}
+	}
+}
+
+#[test]
+fn pair_symmetric() {
+	assert_eq!(Piece::Corner.relation(&Piece::Edge).kind, "corner-edge");
+	assert_eq!(Piece::Edge.relation(&Piece::Corner).kind, "corner-edge");
+	assert_eq!(Piece::Center.relation(&Piece::Edge).kind, "edge-center");
+	assert!(!Piece::Center.relation(&Piece::Corner).touching);
+	assert!(!Piece::Corner.relation(&Piece::Center).touching);
+	assert_eq!(Piece::Center.relation(&Piece::Center).kind, "same");
+	assert!(Piece::Edge.relation(&Piece::Edge).touching);
+}
+
+// Generic code over pairwise properties
+fn ratio_of<E: EnumPropPair<AreaRatio>>(a: &E, b: &E) -> f32 {
+	a.relation(b).ratio
+}
+
+#[test]
+fn pair_generic() {
+	assert_eq!(ratio_of(&Shape::Circle, &Shape::Square), 0.79);
+}
+
+#[cfg(lazy_backend)]
+mod lazy {
+	use super::EnumPropPair;
+	use super::Shape;
+	use crate::test_util::compute;
+
+	struct Distance {
+		value: u32,
+	}
+
+	props! {
+		impl EnumPropPair for Shape as symmetric lazy Distance {
+			(Self::Triangle, Self::Square) => {
+				value: compute(3),
+			}
+			(Self::Circle, _) => const {
+				value: 0,
+			}
+			(_, _) => {
+				value: compute(1),
+			}
+		}
+	}
+
+	#[test]
+	fn pair_lazy() {
+		let distance = |a: Shape, b: Shape| EnumPropPair::<Distance>::relation(&a, &b).value;
+
+		assert_eq!(distance(Shape::Square, Shape::Triangle), 9);
+		assert_eq!(distance(Shape::Triangle, Shape::Square), 9);
+		assert_eq!(distance(Shape::Square, Shape::Circle), 0);
+		assert_eq!(distance(Shape::Square, Shape::Square), 1);
+	}
+}
+
+
+
+// All pairs must be covered
+
+/// ```compile_fail,E0004
+/// use enumeraties::props;
+///
+/// struct Props {
+///     foo: u32,
+/// }
+///
+/// enum Foo {
+///     A,
+///     B,
+/// }
+///
+/// props! {
+///     impl EnumPropPair for Foo as const Props {
+///         (Self::A, _) => {
+///             foo: 1,
+///         }
+///         (Self::B, Self::B) => {
+///             foo: 2,
+///         }
+///     }
+/// }
+/// ```
+struct NotExhaustive;
+
+// Also with symmetric branches, which cover only one of `(A, B)` and `(B, A)`
+// each
+
+/// ```compile_fail,E0004
+/// use enumeraties::props;
+///
+/// struct Props {
+///     foo: u32,
+/// }
+///
+/// enum Foo {
+///     A,
+///     B,
+///     C,
+/// }
+///
+/// props! {
+///     impl EnumPropPair for Foo as symmetric const Props {
+///         (Self::A, _) => {
+///             foo: 1,
+///         }
+///         (Self::B, Self::B) => {
+///             foo: 2,
+///         }
+///     }
+/// }
+/// ```
+struct SymmetricNotExhaustive;
@@ -0,0 +1,125 @@
+// This file tests property types that are not structs, e.g. primitives
+#![cfg(any(test, doctest))]
+#![allow(dead_code)]
+
+use super::EnumProp;
+
+
+static TABLE: [f32; 4] = [1.0, 2.0, 3.0, 4.0];
+
+#[derive(Clone, Copy)]
+enum Foo {
+	A,
+	B,
+	C(u8),
+}
+
+props! {
+	impl Foo : fn weight as const u32 {
+		Self::A => 3,
+		Self::B => 5,
+		_ => 0,
+	}
+}
+
+props! {
+	impl Foo : fn color as const [f32; 4] {
+		Self::A => [0.0; 4],
+		Self::B => [f32::INFINITY, f32::NEG_INFINITY, f32::NAN, -0.0],
+		Self::C(_) => [1.0, 0.5, 0.25, 0.125],
+	}
+}
+
+props! {
+	impl Foo : fn table as const &'static [f32; 4] {
+		Self::A | Self::B => &TABLE,
+		Self::C(_) => &[0.0; 4],
+	}
+}
+
+props! {
+	impl EnumProp for Foo as static &'static str {
+		Self::A => "A",
+		Self::B => "B",
+		Self::C(_) => "C",
+	}
+}
+
+props! {
+	impl Foo : fn pair as const (u8, &'static str) {
+		Self::A => (1, "A"),
+		Self::B => {
+			let n = 2;
+			(n, "B")
+		}
+		Self::C(_) => (3, "C"),
+	}
+}
+
+// Generic code using the properties
+fn total<E: EnumProp<u32>>(variants: &[E]) -> u32 {
+	variants.iter().map(|v| *v.property()).sum()
+}
+
+#[test]
+fn primitive_const() {
+	assert_eq!(*Foo::A.weight(), 3);
+	assert_eq!(total(&[Foo::A, Foo::B, Foo::C(42)]), 8);
+}
+
+#[test]
+fn array_const() {
+	assert_eq!(Foo::A.color(), &[0.0; 4]);
+	assert!(Foo::B.color()[0].is_infinite());
+	assert!(Foo::B.color()[2].is_nan());
+	assert_eq!(Foo::C(42).color()[3], 0.125);
+}
+
+#[test]
+fn reference_const() {
+	// The reference points to the given static
+	assert!(core::ptr::eq(*Foo::A.table(), &TABLE));
+	assert!(core::ptr::eq(*Foo::B.table(), &TABLE));
+	assert_eq!(Foo::C(42).table()[0], 0.0);
+}
+
+#[test]
+fn tuple_const() {
+	assert_eq!(Foo::A.pair(), &(1, "A"));
+	assert_eq!(Foo::B.pair().0, 2);
+	assert_eq!(Foo::C(42).pair().1, "C");
+}
+
+#[test]
+fn reference_static() {
+	assert_eq!(*EnumProp::<&str>::property(&Foo::B), "B");
+}
+
+#[cfg(lazy_backend)]
+mod lazy {
+	use super::Foo;
+	use crate::test_util::compute;
+
+	props! {
+		impl Foo : fn squared as lazy u64 {
+			Self::A => compute(2),
+			Self::B => compute(3),
+			Self::C(_) => compute(4),
+		}
+	}
+
+	props! {
+		impl Foo : fn powers as lazy(grouped) [u64; 2] {
+			Self::A => [compute(2), compute(4)],
+			Self::B => [compute(3), compute(9)],
+			Self::C(_) => [0; 2],
+		}
+	}
+
+	#[test]
+	fn primitive_lazy() {
+		assert_eq!(*Foo::A.squared(), 4);
+		assert_eq!(*Foo::C(42).squared(), 16);
+		assert_eq!(Foo::B.powers(), &[9, 81]);
+	}
+}
@@ -0,0 +1,197 @@
+// This file tests projections, i.e. methods returning a single field of a
+// property
+#![cfg(any(test, doctest))]
+#![allow(dead_code)]
+
+use super::EnumProp;
+
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Tier {
+	Low,
+	High,
+}
+
+struct Meta {
+	name: &'static str,
+	cost: u32,
+	tier: Tier,
+	tags: [&'static str; 2],
+}
+
+enum Foo {
+	Alpha,
+	Beta,
+}
+
+props! {
+	impl Foo : pub fn name -> &'static str as const Meta.name {
+		Self::Alpha => {
+			name: "alpha",
+			cost: 1,
+			tier: Tier::Low,
+			tags: ["a", "first"],
+		}
+		Self::Beta => {
+			name: "beta",
+			cost: 20,
+			tier: Tier::High,
+			tags: ["b", "second"],
+		}
+	}
+}
+
+// Further projections of the same property, without repeating the branches
+props! {
+	impl Foo : pub fn cost -> u32 as Meta.cost;
+}
+props! {
+	impl Foo :
+		/// The tier of the variant
+		#[must_use]
+		pub fn tier -> Tier as Meta.tier
+}
+props! {
+	impl Foo : fn tags -> &[&'static str; 2] as Meta.tags;
+}
+
+#[test]
+fn projection_reference() {
+	// A field that is a reference itself is returned directly
+	let name: &'static str = Foo::Alpha.name();
+	assert_eq!(name, "alpha");
+	assert_eq!(Foo::Beta.name(), "beta");
+
+	// Other fields are borrowed from the property
+	assert_eq!(Foo::Beta.tags(), &["b", "second"]);
+	assert!(core::ptr::eq(
+		Foo::Beta.tags(),
+		&EnumProp::<Meta>::property(&Foo::Beta).tags
+	));
+}
+
+#[test]
+fn projection_copy() {
+	let cost: u32 = Foo::Beta.cost();
+	assert_eq!(cost, 20);
+	assert_eq!(Foo::Alpha.cost(), 1);
+	assert_eq!(Foo::Alpha.tier(), Tier::Low);
+	assert_eq!(Foo::Beta.tier(), Tier::High);
+}
+
+// A property given by its path, with a `static` modifier
+mod defs {
+	pub struct Label {
+		pub text: &'static str,
+		pub width: usize,
+	}
+}
+
+enum Bar {
+	A,
+	B,
+}
+
+props! {
+	impl Bar : fn width -> usize as static defs::Label.width {
+		Self::A => {
+			text: "a",
+			width: 1,
+		}
+		Self::B => {
+			text: "bb",
+			width: 2,
+		}
+	}
+}
+
+props! {
+	impl Bar : fn text -> &'static str as defs::Label.text;
+}
+
+#[test]
+fn projection_path() {
+	assert_eq!(Bar::A.width(), 1);
+	assert_eq!(Bar::B.width(), 2);
+	assert_eq!(Bar::B.text(), "bb");
+}
+
+#[cfg(lazy_backend)]
+mod lazy {
+	use crate::test_util::compute;
+	use crate::LazyEnumProp;
+
+	struct Stats {
+		value: u32,
+	}
+
+	enum Foo {
+		A,
+		B,
+	}
+
+	props! {
+		impl Foo : fn value -> u32 as lazy Stats.value {
+			Self::A => {
+				value: compute(2),
+			}
+			Self::B => {
+				value: compute(3),
+			}
+		}
+	}
+
+	#[test]
+	fn projection_lazy() {
+		assert!(!LazyEnumProp::<Stats>::is_property_initialized(&Foo::A));
+		assert_eq!(Foo::A.value(), 4);
+		assert!(LazyEnumProp::<Stats>::is_property_initialized(&Foo::A));
+		assert_eq!(Foo::B.value(), 9);
+	}
+}
+
+
+
+// Fields returned by value must be `Copy`
+
+/// ```compile_fail,E0507
+/// use enumeraties::props;
+///
+/// struct Name(&'static str);
+///
+/// struct Props {
+///     name: Name,
+/// }
+///
+/// enum Foo {
+///     A,
+/// }
+///
+/// props! {
+///     impl Foo : fn name -> Name as const Props.name {
+///         Self::A => {
+///             name: Name("a"),
+///         }
+///     }
+/// }
+/// ```
+struct NotCopy;
+
+// The projected property must exist
+
+/// ```compile_fail,E0277
+/// use enumeraties::props;
+///
+/// struct Props {
+///     foo: u32,
+/// }
+///
+/// enum Foo {
+///     A,
+/// }
+///
+/// props! {
+///     impl Foo : fn foo -> u32 as Props.foo;
+/// }
+/// ```
+struct MissingProperty;
@@ -0,0 +1,215 @@
+// This file tests `EnumPropConst`, i.e. the table of the properties of all
+// branches, usable by generic code in const contexts
+#![cfg(any(test, doctest))]
+#![allow(dead_code)]
+
+use super::EnumProp;
+use super::EnumPropConst;
+
+
+struct Size {
+	bytes: usize,
+}
+
+enum Message {
+	Ping,
+	Data(u8),
+	Batch(u8),
+	Close,
+}
+
+props! {
+	impl EnumPropConst for Message as const Size {
+		Self::Ping | Self::Close => {
+			bytes: 1,
+		}
+		Self::Data(_) => {
+			bytes: 9,
+		}
+		Self::Batch(_) => {
+			bytes: 64,
+		}
+	}
+}
+
+enum Other {
+	A,
+	B,
+}
+
+props! {
+	impl EnumPropConst for Other as const Size {
+		Self::A => {
+			bytes: 128,
+		}
+		_ => {
+			bytes: 2,
+		}
+	}
+}
+
+// A generic `const fn`, which can not call `EnumProp::property`
+const fn max_bytes<E: EnumPropConst<Size>>() -> usize {
+	let table = E::PROPERTY_TABLE;
+	let mut max = 0;
+	let mut i = 0;
+	while i < table.len() {
+		if table[i].bytes > max {
+			max = table[i].bytes;
+		}
+		i += 1;
+	}
+	max
+}
+
+const MAX_MESSAGE: usize = max_bytes::<Message>();
+
+#[test]
+fn prop_const_const_context() {
+	assert_eq!(MAX_MESSAGE, 64);
+	assert_eq!(max_bytes::<Other>(), 128);
+
+	// As an array length
+	let buffer = [0u8; max_bytes::<Message>()];
+	assert_eq!(buffer.len(), 64);
+}
+
+#[test]
+fn prop_const_table() {
+	// One entry per branch, in order
+	let table = <Message as EnumPropConst<Size>>::PROPERTY_TABLE;
+	assert_eq!(table.len(), 3);
+	assert_eq!(table[0].bytes, 1);
+	assert_eq!(table[2].bytes, 64);
+
+	// Including the wildcard branch
+	assert_eq!(<Other as EnumPropConst<Size>>::PROPERTY_TABLE.len(), 2);
+}
+
+#[test]
+fn prop_const_enum_prop() {
+	// Also implements `EnumProp`
+	assert_eq!(EnumProp::<Size>::property(&Message::Close).bytes, 1);
+	assert_eq!(EnumProp::<Size>::property(&Message::Data(3)).bytes, 9);
+	assert_eq!(EnumProp::<Size>::property(&Other::B).bytes, 2);
+}
+
+// Named values, overriding branches, associated consts, and a section
+
+struct Cost {
+	value: u32,
+}
+
+static EXPENSIVE: Cost = Cost {
+	value: 100,
+};
+
+props! {
+	impl Message {
+		EnumPropConst as const Cost {
+			consts {
+				PING_COST = Self::Ping,
+			}
+			let CHEAP = {
+				value: 1,
+			};
+			Self::Ping | Self::Close => CHEAP,
+			Self::Data(_) => &EXPENSIVE,
+			Self::Batch(_) => static {
+				value: 10,
+			}
+		}
+	}
+}
+
+const TOTAL_COST: u32 = {
+	let table = <Message as EnumPropConst<Cost>>::PROPERTY_TABLE;
+	table[0].value + table[1].value + table[2].value
+};
+
+#[test]
+fn prop_const_section() {
+	assert_eq!(TOTAL_COST, 111);
+	assert_eq!(Message::PING_COST.value, 1);
+	assert!(core::ptr::eq(
+		<Message as EnumPropConst<Cost>>::PROPERTY_TABLE[1],
+		&EXPENSIVE
+	));
+	assert!(core::ptr::eq(
+		EnumProp::<Cost>::property(&Message::Data(0)),
+		&EXPENSIVE
+	));
+}
+
+// A property type that is not a path
+
+props! {
+	impl EnumPropConst for Other as const [u8; 2] {
+		Self::A => [1, 2],
+		Self::B => [3, 4],
+	}
+}
+
+#[test]
+fn prop_const_plain() {
+	let table = <Other as EnumPropConst<[u8; 2]>>::PROPERTY_TABLE;
+	assert_eq!(table, &[&[1, 2], &[3, 4]]);
+}
+
+
+
+// Only `const` properties
+
+/// ```compile_fail
+/// use enumeraties::props;
+///
+/// struct Props {
+///     foo: u32,
+/// }
+///
+/// enum Foo {
+///     A,
+/// }
+///
+/// props! {
+///     impl EnumPropConst for Foo as static Props {
+///         Self::A => {
+///             foo: 1,
+///         }
+///     }
+/// }
+/// ```
+struct StaticPropConst;
+
+// And no delegating branches
+
+/// ```compile_fail
+/// use enumeraties::props;
+///
+/// struct Props {
+///     foo: u32,
+/// }
+///
+/// enum Inner {
+///     A,
+/// }
+///
+/// props! {
+///     impl EnumProp for Inner as const Props {
+///         Self::A => {
+///             foo: 1,
+///         }
+///     }
+/// }
+///
+/// enum Foo {
+///     Inner(Inner),
+/// }
+///
+/// props! {
+///     impl EnumPropConst for Foo as const Props {
+///         Self::Inner(inner) => delegate inner,
+///     }
+/// }
+/// ```
+struct DelegateBranch;
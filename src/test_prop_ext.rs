@@ -0,0 +1,91 @@
+// This file tests the `PropExt` trait and the prelude
+#![cfg(any(test, doctest))]
+#![allow(dead_code)]
+
+use crate::prelude::*;
+
+struct RenderInfo {
+	color: &'static str,
+}
+
+struct Physics {
+	mass: u32,
+}
+
+#[derive(Debug, PartialEq)]
+struct Name(&'static str);
+
+enum Shape {
+	Triangle,
+	Square,
+}
+
+props! {
+	impl EnumProp for Shape as const RenderInfo {
+		Self::Triangle => {
+			color: "red",
+		}
+		Self::Square => {
+			color: "blue",
+		}
+	}
+}
+
+props! {
+	impl EnumProp for Shape as static Physics {
+		Self::Triangle => {
+			mass: 3,
+		}
+		Self::Square => {
+			mass: 4,
+		}
+	}
+}
+
+props! {
+	impl EnumProp for Shape as const Name {
+		Self::Triangle => Name("triangle"),
+		Self::Square => Name("square"),
+	}
+}
+
+#[test]
+fn turbofish() {
+	let shape = Shape::Square;
+	assert_eq!(shape.prop::<RenderInfo>().color, "blue");
+	assert_eq!(shape.prop::<Physics>().mass, 4);
+	assert_eq!(shape.prop::<Name>(), &Name("square"));
+}
+
+#[test]
+fn inferred() {
+	let info: &RenderInfo = Shape::Triangle.prop();
+	assert_eq!(info.color, "red");
+}
+
+// Generic code
+
+fn mass<T: EnumProp<Physics>>(value: &T) -> u32 {
+	value.prop::<Physics>().mass
+}
+
+#[test]
+fn generic() {
+	assert_eq!(mass(&Shape::Triangle), 3);
+}
+
+/// ```compile_fail
+/// use enumeraties::prelude::*;
+/// struct Name(&'static str);
+/// struct Missing;
+/// enum Shape {
+///     Triangle,
+/// }
+/// props! {
+///     impl EnumProp for Shape as const Name {
+///         Self::Triangle => Name("triangle"),
+///     }
+/// }
+/// let _ = Shape::Triangle.prop::<Missing>();
+/// ```
+struct NoProp;
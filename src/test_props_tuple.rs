@@ -0,0 +1,128 @@
+// This file tests accessing several properties at once via `EnumProps2` etc.
+#![cfg(any(test, doctest))]
+#![allow(dead_code)]
+
+use crate::EnumProps2;
+use crate::EnumProps3;
+use crate::EnumProps4;
+
+// Like the `basic_usage` example
+struct ShapeDef {
+	name: &'static str,
+	vertices: u32,
+}
+
+struct RenderInfo {
+	color: &'static str,
+}
+
+struct Layer(u8);
+
+struct Weight(u32);
+
+enum Shape {
+	Triangle,
+	Square,
+	Hexagon,
+}
+
+props! {
+	impl Deref for Shape as const ShapeDef {
+		Self::Triangle => {
+			name: "Triangle",
+			vertices: 3,
+		}
+		Self::Square => {
+			name: "Square",
+			vertices: 4,
+		}
+		Self::Hexagon => {
+			name: "Hexagon",
+			vertices: 6,
+		}
+	}
+}
+
+props! {
+	impl EnumProp for Shape as static RenderInfo {
+		Self::Triangle => {
+			color: "red",
+		}
+		Self::Square => {
+			color: "green",
+		}
+		Self::Hexagon => {
+			color: "blue",
+		}
+	}
+}
+
+props! {
+	impl EnumProp for Shape as const Layer {
+		Self::Triangle => Layer(2),
+		_ => Layer(1),
+	}
+}
+
+props! {
+	impl EnumProp for Shape as const Weight {
+		Self::Triangle => Weight(30),
+		Self::Square => Weight(40),
+		Self::Hexagon => Weight(60),
+	}
+}
+
+fn label<E: EnumProps2<ShapeDef, RenderInfo>>(value: &E) -> (&'static str, &'static str) {
+	let (def, render) = value.properties();
+	(def.name, render.color)
+}
+
+#[test]
+fn two() {
+	assert_eq!(label(&Shape::Triangle), ("Triangle", "red"));
+	assert_eq!(label(&Shape::Hexagon), ("Hexagon", "blue"));
+	// The order follows the type parameters
+	let (render, def) = EnumProps2::<RenderInfo, ShapeDef>::properties(&Shape::Square);
+	assert_eq!((render.color, def.vertices), ("green", 4));
+}
+
+#[test]
+fn three() {
+	let (def, render, layer) =
+		EnumProps3::<ShapeDef, RenderInfo, Layer>::properties(&Shape::Triangle);
+	assert_eq!((def.vertices, render.color, layer.0), (3, "red", 2));
+}
+
+#[test]
+fn four() {
+	fn total<E: EnumProps4<ShapeDef, RenderInfo, Layer, Weight>>(values: &[E]) -> u32 {
+		values
+			.iter()
+			.map(|value| {
+				let (def, _, layer, weight) = value.properties();
+				def.vertices * u32::from(layer.0) + weight.0
+			})
+			.sum()
+	}
+	assert_eq!(
+		total(&[Shape::Triangle, Shape::Hexagon]),
+		3 * 2 + 30 + 6 + 60
+	);
+}
+
+/// ```compile_fail
+/// use enumeraties::props;
+/// use enumeraties::EnumProps2;
+/// struct Name(&'static str);
+/// struct Missing;
+/// enum Shape {
+///     Triangle,
+/// }
+/// props! {
+///     impl EnumProp for Shape as const Name {
+///         Self::Triangle => Name("triangle"),
+///     }
+/// }
+/// let _ = EnumProps2::<Name, Missing>::properties(&Shape::Triangle);
+/// ```
+struct MissingProp;
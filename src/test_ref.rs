@@ -0,0 +1,367 @@
+// This file tests `ref` properties, borrowed for the lifetime of the enum value
+#![cfg(any(test, doctest))]
+#![allow(dead_code)]
+
+use super::EnumPropRef;
+
+
+#[derive(Debug, PartialEq)]
+struct Span<'a> {
+	text: &'a str,
+	line: u32,
+}
+
+enum Token<'a> {
+	Ident(Span<'a>),
+	Number { span: Span<'a>, value: u64 },
+	Comma,
+	Eof,
+}
+
+props! {
+	impl<'a> Deref + fn span for Token<'a> as ref const Span<'a> {
+		defaults {
+			line: 0,
+		}
+		// Borrowed from the data of the variant
+		Self::Ident(span) => span,
+		Self::Number { span, .. } => &span,
+		// Constants as usual
+		Self::Comma => {
+			text: ",",
+		}
+		Self::Eof => {
+			text: "",
+			line: u32::MAX,
+		}
+	}
+}
+
+#[test]
+fn ref_borrowed() {
+	let buffer = *b"foo 42";
+	let source = core::str::from_utf8(&buffer).unwrap();
+
+	let ident = Token::Ident(Span {
+		text: &source[..3],
+		line: 1,
+	});
+	assert_eq!(ident.text, "foo");
+	assert_eq!(ident.line, 1);
+	assert!(core::ptr::eq(ident.span(), &*ident));
+
+	let number = Token::Number {
+		span: Span {
+			text: &source[4..],
+			line: 2,
+		},
+		value: 42,
+	};
+	assert_eq!(number.span().text, "42");
+	if let Token::Number {
+		span, ..
+	} = &number
+	{
+		assert!(core::ptr::eq(number.span(), span));
+	}
+}
+
+#[test]
+fn ref_const() {
+	assert_eq!(
+		*Token::Comma,
+		Span {
+			text: ",",
+			line: 0,
+		}
+	);
+	assert_eq!(Token::Eof.span().line, u32::MAX);
+}
+
+// A property type that is not a path
+props! {
+	impl<'a> Token<'a> : fn bytes as ref const [u8] {
+		Self::Ident(span) | Self::Number { span, .. } => span.text.as_bytes(),
+		Self::Comma => b",",
+		Self::Eof => &[],
+	}
+}
+
+#[test]
+fn ref_slice() {
+	let buffer = *b"bar";
+	let source = core::str::from_utf8(&buffer).unwrap();
+	assert_eq!(
+		Token::Ident(Span {
+			text: source,
+			line: 0,
+		})
+		.bytes(),
+		b"bar"
+	);
+	assert_eq!(Token::Comma.bytes(), b",");
+	assert!(Token::Eof.bytes().is_empty());
+}
+
+// Every `EnumProp` is an `EnumPropRef`, thus generic code can accept both
+struct Name {
+	name: &'static str,
+}
+
+#[derive(Clone, Copy)]
+enum Keyword {
+	Let,
+	Fn,
+}
+
+props! {
+	impl EnumProp for Keyword as const Name {
+		Self::Let => {
+			name: "let",
+		}
+		Self::Fn => {
+			name: "fn",
+		}
+	}
+}
+
+enum Owned {
+	Custom(Name),
+	Anonymous,
+}
+
+static ANONYMOUS: Name = Name {
+	name: "anonymous",
+};
+
+props! {
+	impl EnumPropRef for Owned as ref static Name {
+		Self::Custom(name) => name,
+		Self::Anonymous => &ANONYMOUS,
+	}
+}
+
+fn name_of(value: &impl EnumPropRef<Name>) -> &str {
+	value.property_ref().name
+}
+
+#[test]
+fn ref_generic() {
+	assert_eq!(name_of(&Keyword::Let), "let");
+	assert_eq!(name_of(&Keyword::Fn), "fn");
+	assert_eq!(
+		name_of(&Owned::Custom(Name {
+			name: "custom",
+		})),
+		"custom"
+	);
+	assert!(core::ptr::eq(Owned::Anonymous.property_ref(), &ANONYMOUS));
+}
+
+// Branches overriding the modifier of a non-generic enum
+enum Labeled {
+	Custom(Name),
+	Anonymous,
+}
+
+props! {
+	impl Labeled {
+		fn label as ref const Name {
+			Self::Custom(name) => name,
+			Self::Anonymous => static {
+				name: "label",
+			}
+		}
+	}
+}
+
+#[test]
+fn ref_override() {
+	assert_eq!(Labeled::Anonymous.label().name, "label");
+	assert!(core::ptr::eq(
+		Labeled::Anonymous.label(),
+		Labeled::Anonymous.label()
+	));
+}
+
+#[cfg(lazy_backend)]
+mod lazy {
+	use super::Name;
+	use crate::test_util::compute;
+
+	struct Stats {
+		value: u32,
+	}
+
+	enum Sample {
+		Given(Stats),
+		Computed,
+	}
+
+	props! {
+		impl Sample : fn stats as ref lazy Stats {
+			Self::Given(stats) => stats,
+			Self::Computed => {
+				value: compute(3),
+			}
+		}
+	}
+
+	#[test]
+	fn ref_lazy() {
+		assert_eq!(
+			Sample::Given(Stats {
+				value: 1
+			})
+			.stats()
+			.value,
+			1
+		);
+		assert_eq!(Sample::Computed.stats().value, 9);
+		assert!(core::ptr::eq(
+			Sample::Computed.stats(),
+			Sample::Computed.stats()
+		));
+	}
+
+	// Single `lazy` branches within a `ref const` property
+	enum Described {
+		Custom(Name),
+		Anonymous,
+	}
+
+	props! {
+		impl Described : fn lazy_label as ref const Name {
+			Self::Custom(name) => name,
+			Self::Anonymous => lazy {
+				name: if compute(2) == 4 { "four" } else { "other" },
+			}
+		}
+	}
+
+	#[test]
+	fn ref_lazy_branch() {
+		assert_eq!(Described::Anonymous.lazy_label().name, "four");
+	}
+}
+
+
+
+// The borrowed property can not outlive the enum value
+
+/// ```compile_fail,E0597
+/// use enumeraties::props;
+///
+/// struct Props {
+///     foo: u32,
+/// }
+///
+/// enum Foo {
+///     A(Props),
+/// }
+///
+/// props! {
+///     impl Deref for Foo as ref const Props {
+///         Self::A(props) => props,
+///     }
+/// }
+///
+/// let props = {
+///     let value = Foo::A(Props { foo: 1 });
+///     &*value
+/// };
+/// assert_eq!(props.foo, 1);
+/// ```
+struct Outlive;
+
+// Borrowing props can not implement `EnumProp`
+
+/// ```compile_fail
+/// use enumeraties::props;
+///
+/// struct Props {
+///     foo: u32,
+/// }
+///
+/// enum Foo {
+///     A(Props),
+/// }
+///
+/// props! {
+///     impl EnumProp for Foo as ref const Props {
+///         Self::A(props) => props,
+///     }
+/// }
+/// ```
+struct RefEnumProp;
+
+// Only `const`, `static`, and `lazy` are supported
+
+/// ```compile_fail
+/// use enumeraties::props;
+///
+/// struct Props {
+///     foo: u32,
+/// }
+///
+/// enum Foo {
+///     A(Props),
+/// }
+///
+/// props! {
+///     impl EnumPropRef for Foo as ref thread_lazy Props {
+///         Self::A(props) => props,
+///     }
+/// }
+/// ```
+struct RefThreadLazy;
+
+// Without `lazy(grouped)`
+
+/// ```compile_fail
+/// use enumeraties::props;
+///
+/// struct Props {
+///     foo: u32,
+/// }
+///
+/// enum Foo {
+///     A(Props),
+///     B,
+/// }
+///
+/// props! {
+///     impl EnumPropRef for Foo as ref lazy(grouped) Props {
+///         Self::A(props) => props,
+///         Self::B => {
+///             foo: 1,
+///         }
+///     }
+/// }
+/// ```
+struct RefGrouped;
+
+// `static` branches can not use the lifetimes of the enum
+
+/// ```compile_fail
+/// use enumeraties::props;
+///
+/// struct Span<'a> {
+///     text: &'a str,
+/// }
+///
+/// enum Token<'a> {
+///     Ident(Span<'a>),
+///     Eof,
+/// }
+///
+/// props! {
+///     impl<'a> EnumPropRef for Token<'a> as ref static Span<'a> {
+///         Self::Ident(span) => span,
+///         Self::Eof => {
+///             text: "",
+///         }
+///     }
+/// }
+/// ```
+struct StaticLifetime;
@@ -0,0 +1,129 @@
+// This file tests branches referencing existing consts and statics
+#![cfg(any(test, doctest))]
+#![allow(dead_code)]
+
+// Canonical definitions, as if also used by code unrelated to the enum
+mod defs {
+	pub struct ShapeDef {
+		pub name: &'static str,
+		pub sides: u8,
+	}
+
+	pub static TRIANGLE_DEF: ShapeDef = ShapeDef {
+		name: "Triangle",
+		sides: 3,
+	};
+
+	pub static SQUARE_DEF: ShapeDef = ShapeDef {
+		name: "Square",
+		sides: 4,
+	};
+
+	pub const CIRCLE_DEF: ShapeDef = ShapeDef {
+		name: "Circle",
+		sides: 0,
+	};
+}
+
+use defs::ShapeDef;
+
+enum Shape {
+	Triangle,
+	Square,
+	Circle,
+	Polygon(u8),
+}
+
+props! {
+	impl Deref for Shape as static ShapeDef {
+		Self::Triangle => &defs::TRIANGLE_DEF,
+		Self::Square => &defs::SQUARE_DEF,
+		Self::Circle => &defs::CIRCLE_DEF,
+		Self::Polygon(_) => {
+			name: "Polygon",
+			sides: 5,
+		}
+	}
+}
+
+#[test]
+fn reference_static() {
+	// The property is the referenced static itself
+	assert!(core::ptr::eq(&*Shape::Triangle, &defs::TRIANGLE_DEF));
+	assert!(core::ptr::eq(&*Shape::Square, &defs::SQUARE_DEF));
+	assert_eq!(Shape::Square.sides, 4);
+	assert_eq!(Shape::Circle.name, "Circle");
+	assert_eq!(Shape::Polygon(42).name, "Polygon");
+}
+
+struct Color {
+	rgb: [u8; 3],
+}
+
+const RED: Color = Color {
+	rgb: [255, 0, 0],
+};
+
+enum Foo {
+	A,
+	B,
+	C(u8),
+}
+
+props! {
+	impl Foo : fn color as const Color {
+		Self::A | Self::C(0) => &RED,
+		Self::B => Color {
+			rgb: [0, 255, 0],
+		},
+		_ => &RED,
+	}
+}
+
+#[test]
+fn reference_const() {
+	assert_eq!(Foo::A.color().rgb, [255, 0, 0]);
+	assert_eq!(Foo::B.color().rgb, [0, 255, 0]);
+	assert_eq!(Foo::C(42).color().rgb, [255, 0, 0]);
+}
+
+// A reference property type still takes the reference as its value
+static GREEN: [u8; 3] = [0, 255, 0];
+
+props! {
+	impl Foo : fn rgb as static &'static [u8; 3] {
+		Self::A => &GREEN,
+		_ => &[0; 3],
+	}
+}
+
+#[test]
+fn reference_value() {
+	assert!(core::ptr::eq(*Foo::A.rgb(), &GREEN));
+	assert_eq!(Foo::B.rgb(), &&[0; 3]);
+}
+
+
+
+// Lazy properties can not reference an existing item
+
+/// ```compile_fail
+/// use enumeraties::props;
+///
+/// struct Props {
+///     foo: u8,
+/// }
+///
+/// static FOO: Props = Props { foo: 42 };
+///
+/// enum Foo {
+///     A,
+/// }
+///
+/// props! {
+///     impl Deref for Foo as lazy Props {
+///         Self::A => &FOO,
+///     }
+/// }
+/// ```
+struct NoLazyReference;
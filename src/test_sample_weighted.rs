@@ -0,0 +1,274 @@
+// This file tests sampling variants randomly by a weight field
+#![cfg(any(test, doctest))]
+#![cfg(feature = "rand")]
+#![cfg(lazy_backend)]
+#![allow(dead_code)]
+
+#[cfg(test)]
+use rand::rngs::SmallRng;
+#[cfg(test)]
+use rand::SeedableRng;
+
+
+// Notice, with `unsync-lazy` each enum is sampled by a single test only, since
+// its cumulative weights are a thread local
+
+struct TileDef {
+	weight: f32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Tile {
+	Grass,
+	Water,
+	Lava,
+	Rock,
+}
+
+props! {
+	impl Deref for Tile as const TileDef {
+		sample_weighted(weight)
+		Self::Grass => {
+			weight: 6.0,
+		}
+		Self::Water => {
+			weight: 3.0,
+		}
+		Self::Lava => {
+			weight: 0.0,
+		}
+		Self::Rock => {
+			weight: 1.0,
+		}
+	}
+}
+
+#[test]
+fn distribution() {
+	const SAMPLES: usize = 100_000;
+	let variants = [Tile::Grass, Tile::Water, Tile::Lava, Tile::Rock];
+	let mut rng = SmallRng::seed_from_u64(42);
+
+	let mut counts = [0usize; 4];
+	for _ in 0..SAMPLES {
+		let tile = Tile::sample_weighted(&mut rng);
+		counts[variants.iter().position(|&v| v == tile).unwrap()] += 1;
+	}
+
+	// A zero weight is never picked
+	assert_eq!(counts[2], 0);
+
+	// Chi-squared over the picked variants, with 2 degrees of freedom, whose
+	// 99.9% quantile is about 13.8
+	let total: f32 = variants.iter().map(|tile| tile.weight).sum();
+	let chi_squared: f64 = variants
+		.iter()
+		.zip(counts)
+		.filter(|(tile, _)| tile.weight > 0.0)
+		.map(|(tile, count)| {
+			let expected = SAMPLES as f64 * f64::from(tile.weight / total);
+			(count as f64 - expected).powi(2) / expected
+		})
+		.sum();
+	assert!(
+		chi_squared < 13.8,
+		"chi-squared: {chi_squared}, counts: {counts:?}"
+	);
+}
+
+// Along with a lazy prop via `fn`, and or-patterns
+
+struct Loot {
+	weight: f32,
+}
+
+#[derive(Debug, PartialEq)]
+enum Item {
+	Coin,
+	Gem,
+	Crown,
+}
+
+props! {
+	impl Item {
+		fn loot as lazy Loot {
+			sample_weighted(weight)
+			Self::Coin | Self::Gem => Loot {
+				weight: 0.0,
+			},
+			Self::Crown => Loot {
+				weight: 0.5,
+			},
+		}
+	}
+}
+
+#[test]
+fn single_positive_weight() {
+	let mut rng = SmallRng::seed_from_u64(7);
+	for _ in 0..1000 {
+		assert_eq!(Item::sample_weighted(&mut rng), Item::Crown);
+	}
+	assert_eq!(Item::Gem.loot().weight, 0.0);
+}
+
+// All weights are zero
+
+struct Weight {
+	weight: f32,
+}
+
+enum Empty {
+	A,
+	B,
+}
+
+props! {
+	impl EnumProp for Empty as const Weight {
+		sample_weighted(weight)
+		Self::A => {
+			weight: 0.0,
+		}
+		Self::B => {
+			weight: 0.0,
+		}
+	}
+}
+
+#[test]
+#[should_panic(
+	expected = "the `sample_weighted` requires a positive weight for some variant, but all \
+	            weights are zero"
+)]
+fn all_zero() {
+	Empty::sample_weighted(&mut SmallRng::seed_from_u64(0));
+}
+
+enum Negative {
+	A,
+	B,
+}
+
+props! {
+	impl EnumProp for Negative as const Weight {
+		sample_weighted(weight)
+		Self::A => {
+			weight: 1.0,
+		}
+		Self::B => {
+			weight: -1.0,
+		}
+	}
+}
+
+#[test]
+#[should_panic(
+	expected = "the weights of `sample_weighted` must be finite and not negative, found -1"
+)]
+fn negative() {
+	Negative::sample_weighted(&mut SmallRng::seed_from_u64(0));
+}
+
+// Variants with data can not be sampled
+
+/// ```compile_fail
+/// use enumeraties::props;
+///
+/// struct Props {
+///     weight: f32,
+/// }
+///
+/// enum Foo {
+///     A,
+///     B(u8),
+/// }
+///
+/// props! {
+///     impl Deref for Foo as const Props {
+///         sample_weighted(weight)
+///         Self::A => {
+///             weight: 1.0,
+///         }
+///         Self::B(_) => {
+///             weight: 1.0,
+///         }
+///     }
+/// }
+/// ```
+struct DataVariant;
+
+// Neither a wildcard branch
+
+/// ```compile_fail
+/// use enumeraties::props;
+///
+/// struct Props {
+///     weight: f32,
+/// }
+///
+/// enum Foo {
+///     A,
+///     B,
+/// }
+///
+/// props! {
+///     impl Deref for Foo as const Props {
+///         sample_weighted(weight)
+///         Self::A => {
+///             weight: 1.0,
+///         }
+///         _ => {
+///             weight: 1.0,
+///         }
+///     }
+/// }
+/// ```
+struct Wildcard;
+
+// The weight must be a `f32`
+
+/// ```compile_fail,E0308
+/// use enumeraties::props;
+///
+/// struct Props {
+///     weight: u32,
+/// }
+///
+/// enum Foo {
+///     A,
+/// }
+///
+/// props! {
+///     impl Deref for Foo as const Props {
+///         sample_weighted(weight)
+///         Self::A => {
+///             weight: 1,
+///         }
+///     }
+/// }
+/// ```
+struct NotF32;
+
+// Generic enums are not supported
+
+/// ```compile_fail
+/// use enumeraties::props;
+///
+/// struct Props {
+///     weight: f32,
+/// }
+///
+/// enum Foo<const N: usize> {
+///     A,
+/// }
+///
+/// props! {
+///     impl<const N: usize> Deref for Foo<N> as const Props {
+///         sample_weighted(weight)
+///         Self::A => {
+///             weight: 1.0,
+///         }
+///     }
+/// }
+/// ```
+struct Generic;
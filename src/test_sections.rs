@@ -0,0 +1,214 @@
+// This file tests several properties given as sections of one invocation
+#![cfg(any(test, doctest))]
+#![allow(dead_code)]
+
+use super::EnumProp;
+
+
+struct Shape {
+	name: &'static str,
+	sides: u8,
+}
+
+struct Render {
+	color: u32,
+}
+
+#[derive(Clone, Copy)]
+enum Foo {
+	A,
+	B,
+	C(u8),
+}
+
+props! {
+	impl Foo {
+		as const Shape {
+			defaults {
+				sides: 4,
+			}
+			Self::A => {
+				name: "triangle",
+				sides: 3,
+			}
+			Self::B => {
+				name: "square",
+			}
+			Self::C(_) => {
+				name: "other",
+			}
+		}
+
+		/// Returns the render info
+		pub fn render as static Render {
+			let GREY = {
+				color: 0x808080,
+			};
+			Self::A => {
+				color: 0xff0000,
+			}
+			Self::B | Self::C(_) => GREY,
+		}
+
+		Deref as const &'static str {
+			Self::A => "A",
+			Self::B => "B",
+			Self::C(n) if *n > 9 => "C(large)",
+			Self::C(_) => "C",
+		}
+
+		#[cfg(any())]
+		as const u8 {
+			Self::A => 1,
+		}
+	}
+}
+
+#[test]
+fn sections() {
+	let shape = |variant: Foo| EnumProp::<Shape>::property(&variant);
+
+	assert_eq!(shape(Foo::A).sides, 3);
+	assert_eq!(shape(Foo::B).sides, 4);
+	assert_eq!(shape(Foo::C(42)).name, "other");
+
+	assert_eq!(Foo::A.render().color, 0xff0000);
+	assert!(core::ptr::eq(Foo::B.render(), Foo::C(1).render()));
+
+	assert_eq!(*Foo::A, "A");
+	assert_eq!(*Foo::C(42), "C(large)");
+	assert_eq!(*Foo::C(1), "C");
+}
+
+#[cfg(lazy_backend)]
+mod lazy {
+	use crate::test_util::compute;
+	use crate::EnumPropInit;
+
+	struct Stats {
+		value: u32,
+	}
+
+	struct GroupedStats {
+		value: u32,
+	}
+
+	enum Bar {
+		A,
+		B,
+	}
+
+	props! {
+		impl Bar {
+			Deref + fn stats as lazy Stats {
+				Self::A => {
+					value: compute(2),
+				}
+				Self::B => {
+					value: compute(3),
+				}
+			}
+			EnumProp as lazy(grouped) GroupedStats {
+				Self::A => {
+					value: compute(4),
+				}
+				_ => {
+					value: compute(5),
+				}
+			}
+		}
+	}
+
+	#[test]
+	fn sections_lazy() {
+		<Bar as EnumPropInit<Stats>>::initialize_all();
+		assert_eq!(Bar::A.value, 4);
+		assert!(core::ptr::eq(&*Bar::B, Bar::B.stats()));
+		assert_eq!(crate::EnumProp::<GroupedStats>::property(&Bar::B).value, 25);
+	}
+}
+
+
+
+// Each section must cover all variants on its own
+
+/// ```compile_fail,E0004
+/// use enumeraties::props;
+///
+/// struct Props {
+///     foo: u8,
+/// }
+///
+/// enum Foo {
+///     A,
+///     B,
+/// }
+///
+/// props! {
+///     impl Foo {
+///         as const Props {
+///             Self::A => {
+///                 foo: 1,
+///             }
+///             Self::B => {
+///                 foo: 2,
+///             }
+///         }
+///         fn number as static u8 {
+///             Self::A => 1,
+///         }
+///     }
+/// }
+/// ```
+struct NonExhaustiveSection;
+
+// Each section needs a valid head
+
+/// ```compile_fail
+/// use enumeraties::props;
+///
+/// struct Props {
+///     foo: u8,
+/// }
+///
+/// enum Foo {
+///     A,
+/// }
+///
+/// props! {
+///     impl Foo {
+///         const Props {
+///             Self::A => {
+///                 foo: 1,
+///             }
+///         }
+///     }
+/// }
+/// ```
+struct InvalidSection;
+
+// Each section needs its branches
+
+/// ```compile_fail
+/// use enumeraties::props;
+///
+/// struct Props {
+///     foo: u8,
+/// }
+///
+/// enum Foo {
+///     A,
+/// }
+///
+/// props! {
+///     impl Foo {
+///         as const Props {
+///             Self::A => {
+///                 foo: 1,
+///             }
+///         }
+///         as static u8
+///     }
+/// }
+/// ```
+struct MissingBranches;
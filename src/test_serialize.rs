@@ -0,0 +1,223 @@
+// This file tests serializing enums as a string field of their property
+#![cfg(any(test, doctest))]
+#![cfg(feature = "serde")]
+#![allow(dead_code)]
+
+#[cfg(test)]
+use serde_json::to_string;
+
+
+struct ColorDef {
+	name: &'static str,
+	hex: u32,
+}
+
+enum Color {
+	Red,
+	Green,
+	Gray(u8),
+}
+
+props! {
+	impl Deref for Color as const ColorDef {
+		serialize_as(name)
+		Self::Red => {
+			name: "red",
+			hex: 0xff0000,
+		}
+		Self::Green => {
+			name: "green",
+			hex: 0x00ff00,
+		}
+		Self::Gray(_) => {
+			name: "gray",
+			hex: 0x808080,
+		}
+	}
+}
+
+#[test]
+fn serialize_name() {
+	assert_eq!(to_string(&Color::Red).unwrap(), r#""red""#);
+	assert_eq!(to_string(&Color::Green).unwrap(), r#""green""#);
+}
+
+#[test]
+fn serialize_data_variant() {
+	// The data is dropped
+	assert_eq!(to_string(&Color::Gray(1)).unwrap(), r#""gray""#);
+	assert_eq!(
+		to_string(&[Color::Gray(1), Color::Gray(200)]).unwrap(),
+		r#"["gray","gray"]"#
+	);
+}
+
+// Along with an inherent method, a static property, defaults, and named values
+
+struct Level {
+	label: &'static str,
+	rank: u8,
+}
+
+enum Priority {
+	Low,
+	Medium,
+	High,
+}
+
+props! {
+	impl Priority {
+		pub fn level as static Level {
+		serialize_as(label)
+		defaults {
+			label: "normal",
+		}
+		let HIGH = {
+			label: "high",
+			rank: 10,
+		};
+		Self::Low => {
+			label: "low",
+			rank: 1,
+		}
+		Self::Medium => {
+			rank: 5,
+		}
+		Self::High => HIGH,
+		}
+	}
+}
+
+#[test]
+fn serialize_static_fn() {
+	assert_eq!(Priority::Low.level().rank, 1);
+	assert_eq!(
+		to_string(&[Priority::Low, Priority::Medium, Priority::High]).unwrap(),
+		r#"["low","normal","high"]"#
+	);
+}
+
+// Generic enums, along with a derived `Display` of the same field
+
+struct Label {
+	text: &'static str,
+}
+
+enum Wrapper<T> {
+	Empty,
+	Value(T),
+}
+
+props! {
+	impl<T> Wrapper<T> {
+		const fn label as const Label {
+			derive(Display = text)
+			serialize_as(text)
+			Self::Empty => {
+				text: "none",
+			}
+			Self::Value(_) => {
+				text: "some",
+			}
+		}
+	}
+}
+
+#[test]
+fn serialize_generic() {
+	use std::string::ToString;
+
+	assert_eq!(to_string(&Wrapper::Value(3)).unwrap(), r#""some""#);
+	assert_eq!(to_string(&Wrapper::<u8>::Empty).unwrap(), r#""none""#);
+	assert_eq!(Wrapper::Value(3).to_string(), "some");
+}
+
+// The field must be a `&'static str`
+
+/// ```compile_fail,E0277
+/// use enumeraties::props;
+///
+/// struct Props {
+///     code: u32,
+/// }
+///
+/// enum Foo {
+///     A,
+/// }
+///
+/// props! {
+///     impl Deref for Foo as const Props {
+///         serialize_as(code)
+///         Self::A => {
+///             code: 1,
+///         }
+///     }
+/// }
+/// ```
+struct NotStr;
+
+// The field must exist
+
+/// ```compile_fail,E0026
+/// use enumeraties::props;
+///
+/// struct Props {
+///     name: &'static str,
+/// }
+///
+/// enum Foo {
+///     A,
+/// }
+///
+/// props! {
+///     impl Deref for Foo as const Props {
+///         serialize_as(title)
+///         Self::A => {
+///             name: "a",
+///         }
+///     }
+/// }
+/// ```
+struct UnknownField;
+
+// Plain expressions have no fields
+
+/// ```compile_fail
+/// use enumeraties::props;
+///
+/// enum Foo {
+///     A,
+/// }
+///
+/// props! {
+///     impl Deref for Foo as const &'static str {
+///         serialize_as(name)
+///         Self::A => "a",
+///     }
+/// }
+/// ```
+struct Plain;
+
+// The clause must come before all branches
+
+/// ```compile_fail
+/// use enumeraties::props;
+///
+/// struct Props {
+///     name: &'static str,
+/// }
+///
+/// enum Foo {
+///     A,
+/// }
+///
+/// props! {
+///     impl Deref for Foo as const Props {
+///         Self::A => {
+///             name: "a",
+///         }
+///         serialize_as(name)
+///     }
+/// }
+/// ```
+struct AfterBranches;
@@ -0,0 +1,317 @@
+// This file tests the variant sets by boolean fields of the properties
+#![cfg(any(test, doctest))]
+#![cfg(feature = "enumset")]
+#![allow(dead_code)]
+
+use enumset::EnumSet;
+use enumset::EnumSetType;
+
+
+struct EnemyDef {
+	name: &'static str,
+	flying: bool,
+	aquatic: bool,
+}
+
+#[derive(EnumSetType, Debug)]
+enum Enemy {
+	Bat,
+	Crab,
+	Duck,
+	Slime,
+}
+
+props! {
+	impl Deref for Enemy as const EnemyDef {
+		set_where(FLYING = flying, AQUATIC = aquatic)
+		Self::Bat => {
+			name: "bat",
+			flying: true,
+			aquatic: false,
+		}
+		Self::Crab => {
+			name: "crab",
+			flying: false,
+			aquatic: true,
+		}
+		Self::Duck => {
+			name: "duck",
+			flying: true,
+			aquatic: true,
+		}
+		Self::Slime => {
+			name: "slime",
+			flying: false,
+			aquatic: false,
+		}
+	}
+}
+
+#[test]
+fn membership() {
+	for enemy in EnumSet::<Enemy>::all() {
+		assert_eq!(
+			Enemy::FLYING.contains(enemy),
+			enemy.flying,
+			"{}",
+			enemy.name
+		);
+		assert_eq!(
+			Enemy::AQUATIC.contains(enemy),
+			enemy.aquatic,
+			"{}",
+			enemy.name
+		);
+	}
+}
+
+#[test]
+fn independent_sets() {
+	assert_eq!(Enemy::FLYING, Enemy::Bat | Enemy::Duck);
+	assert_eq!(Enemy::AQUATIC, Enemy::Crab | Enemy::Duck);
+	assert_eq!(Enemy::FLYING & Enemy::AQUATIC, Enemy::Duck);
+	assert!(!(Enemy::FLYING | Enemy::AQUATIC).contains(Enemy::Slime));
+}
+
+#[test]
+fn const_context() {
+	const GROUNDED: EnumSet<Enemy> = enumset::enum_set_complement!(Enemy::FLYING);
+	assert_eq!(GROUNDED, Enemy::Crab | Enemy::Slime);
+}
+
+// Along with a `const fn`, defaults, or-patterns, and named values
+
+struct Level {
+	urgent: bool,
+	silent: bool,
+}
+
+#[derive(EnumSetType, Debug)]
+enum Priority {
+	Low,
+	Medium,
+	Normal,
+	High,
+}
+
+props! {
+	impl Priority {
+		pub const fn level as const Level {
+			set_where(URGENT = urgent, SILENT = silent,)
+			defaults {
+				urgent: false,
+				silent: false,
+			}
+			let HIGH = {
+				urgent: true,
+			};
+			Self::Low => {
+				silent: true,
+			}
+			Self::Medium | Self::Normal => {}
+			Self::High => HIGH,
+		}
+	}
+}
+
+#[test]
+fn set_where_const_fn() {
+	assert_eq!(Priority::URGENT, Priority::High);
+	assert_eq!(Priority::SILENT, Priority::Low);
+	assert!(Priority::High.level().urgent);
+}
+
+// Or-patterns span multiple variants of the same set, also via `EnumPropConst`
+
+struct Shape {
+	round: bool,
+}
+
+#[derive(EnumSetType, Debug)]
+enum Tile {
+	Circle,
+	Ring,
+	Square,
+}
+
+props! {
+	impl EnumPropConst for Tile as const Shape {
+		set_where(ROUND = round)
+		Self::Circle | Self::Ring => {
+			round: true,
+		}
+		Self::Square => {
+			round: false,
+		}
+	}
+}
+
+#[test]
+fn set_where_or_pattern() {
+	assert_eq!(Tile::ROUND, Tile::Circle | Tile::Ring);
+}
+
+// Variants with data can not be contained in a set
+
+/// ```compile_fail
+/// use enumeraties::props;
+///
+/// struct Props {
+///     flag: bool,
+/// }
+///
+/// enum Foo {
+///     A,
+///     B(u8),
+/// }
+///
+/// props! {
+///     impl Deref for Foo as const Props {
+///         set_where(FLAGGED = flag)
+///         Self::A => {
+///             flag: true,
+///         }
+///         Self::B(_) => {
+///             flag: false,
+///         }
+///     }
+/// }
+/// ```
+struct DataVariant;
+
+// Neither a wildcard branch
+
+/// ```compile_fail
+/// use enumeraties::props;
+/// use enumset::EnumSetType;
+///
+/// struct Props {
+///     flag: bool,
+/// }
+///
+/// #[derive(EnumSetType)]
+/// enum Foo {
+///     A,
+///     B,
+/// }
+///
+/// props! {
+///     impl Deref for Foo as const Props {
+///         set_where(FLAGGED = flag)
+///         Self::A => {
+///             flag: true,
+///         }
+///         _ => {
+///             flag: false,
+///         }
+///     }
+/// }
+/// ```
+struct Wildcard;
+
+// The enum must derive `EnumSetType`
+
+/// ```compile_fail
+/// use enumeraties::props;
+///
+/// struct Props {
+///     flag: bool,
+/// }
+///
+/// #[derive(Clone, Copy)]
+/// enum Foo {
+///     A,
+///     B,
+/// }
+///
+/// props! {
+///     impl Deref for Foo as const Props {
+///         set_where(FLAGGED = flag)
+///         Self::A => {
+///             flag: true,
+///         }
+///         Self::B => {
+///             flag: false,
+///         }
+///     }
+/// }
+/// ```
+struct NotEnumSetType;
+
+// The field must be a `bool`
+
+/// ```compile_fail
+/// use enumeraties::props;
+/// use enumset::EnumSetType;
+///
+/// struct Props {
+///     count: u8,
+/// }
+///
+/// #[derive(EnumSetType)]
+/// enum Foo {
+///     A,
+/// }
+///
+/// props! {
+///     impl Deref for Foo as const Props {
+///         set_where(COUNTED = count)
+///         Self::A => {
+///             count: 1,
+///         }
+///     }
+/// }
+/// ```
+struct NotBool;
+
+// Only const properties are supported
+
+/// ```compile_fail
+/// use enumeraties::props;
+/// use enumset::EnumSetType;
+///
+/// struct Props {
+///     flag: bool,
+/// }
+///
+/// #[derive(EnumSetType)]
+/// enum Foo {
+///     A,
+/// }
+///
+/// props! {
+///     impl Deref for Foo as static Props {
+///         set_where(FLAGGED = flag)
+///         Self::A => {
+///             flag: true,
+///         }
+///     }
+/// }
+/// ```
+struct Static;
+
+// The const must be named
+
+/// ```compile_fail
+/// use enumeraties::props;
+/// use enumset::EnumSetType;
+///
+/// struct Props {
+///     flag: bool,
+/// }
+///
+/// #[derive(EnumSetType)]
+/// enum Foo {
+///     A,
+/// }
+///
+/// props! {
+///     impl Deref for Foo as const Props {
+///         set_where(flag)
+///         Self::A => {
+///             flag: true,
+///         }
+///     }
+/// }
+/// ```
+struct Unnamed;
@@ -0,0 +1,134 @@
+// This file tests the setters of atomic fields of static properties
+#![cfg(any(test, doctest))]
+#![allow(dead_code)]
+
+use core::sync::atomic::AtomicBool;
+use core::sync::atomic::AtomicU32;
+use core::sync::atomic::Ordering;
+
+struct Endpoint {
+	name: &'static str,
+	retry_count: AtomicU32,
+	enabled: AtomicBool,
+}
+
+#[derive(Clone, Copy)]
+enum Service {
+	Auth,
+	Billing,
+	Mail(u8),
+}
+
+props! {
+	impl Deref for Service as static Endpoint {
+		setters {
+			/// Sets the retries
+			retry_count as pub fn set_retry_count: u32,
+			enabled as fn set_enabled: bool,
+		}
+		defaults {
+			retry_count: AtomicU32::new(3),
+			enabled: AtomicBool::new(true),
+		}
+		Self::Auth => {
+			name: "auth",
+		}
+		Self::Billing => {
+			name: "billing",
+			retry_count: AtomicU32::new(5),
+		}
+		Self::Mail(_) => {
+			name: "mail",
+		}
+	}
+}
+
+#[test]
+fn set_static() {
+	let alias = Service::Billing;
+	Service::Billing.set_retry_count(8);
+	// Observed via another value of the same variant
+	assert_eq!(alias.retry_count.load(Ordering::SeqCst), 8);
+	// But not by the other variants
+	assert_eq!(Service::Auth.retry_count.load(Ordering::SeqCst), 3);
+}
+
+#[test]
+fn set_shared_branch() {
+	// The variants of a branch share their property, regardless of their data
+	Service::Mail(1).set_enabled(false);
+	assert!(!Service::Mail(2).enabled.load(Ordering::SeqCst));
+	assert!(Service::Auth.enabled.load(Ordering::SeqCst));
+}
+
+// With an explicit ordering, via a method
+
+struct Counter {
+	hits: AtomicU32,
+}
+
+enum Page {
+	Home,
+	About,
+}
+
+props! {
+	impl Page : fn counter as static Counter {
+		setters(Relaxed) {
+			hits as fn reset_hits: u32,
+		}
+		Self::Home => {
+			hits: AtomicU32::new(0),
+		}
+		Self::About => {
+			hits: AtomicU32::new(0),
+		}
+	}
+}
+
+#[test]
+fn set_ordering() {
+	Page::Home.counter().hits.fetch_add(5, Ordering::Relaxed);
+	assert_eq!(Page::Home.counter().hits.load(Ordering::Relaxed), 5);
+	Page::Home.reset_hits(0);
+	assert_eq!(Page::Home.counter().hits.load(Ordering::Relaxed), 0);
+}
+
+/// ```compile_fail
+/// use enumeraties::props;
+/// use core::sync::atomic::AtomicU32;
+/// struct Counter { hits: AtomicU32 }
+/// enum Page { Home }
+/// props! {
+///     impl Deref for Page as const Counter {
+///         setters {
+///             hits as fn set_hits: u32,
+///         }
+///         Self::Home => {
+///             hits: AtomicU32::new(0),
+///         }
+///     }
+/// }
+/// ```
+struct Const;
+
+/// ```compile_fail
+/// use enumeraties::props;
+/// use core::sync::atomic::AtomicU32;
+/// struct Counter { hits: AtomicU32 }
+/// enum Page { Home, About }
+/// props! {
+///     impl Deref for Page as static Counter {
+///         setters {
+///             hits as fn set_hits: u32,
+///         }
+///         Self::Home => {
+///             hits: AtomicU32::new(0),
+///         }
+///         Self::About => const {
+///             hits: AtomicU32::new(0),
+///         }
+///     }
+/// }
+/// ```
+struct ConstBranch;
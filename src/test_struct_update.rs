@@ -0,0 +1,223 @@
+// This file tests the struct update syntax within the branches
+#![cfg(any(test, doctest))]
+#![allow(dead_code)]
+
+use super::EnumProp;
+
+
+struct Shape {
+	vertices: u8,
+	filled: bool,
+	name: &'static str,
+}
+
+const SHAPE_DEFAULTS: Shape = Shape {
+	vertices: 0,
+	filled: true,
+	name: "unnamed",
+};
+
+enum Foo {
+	Triangle,
+	Square,
+	Circle,
+}
+
+props! {
+	impl Deref for Foo as const Shape {
+		Self::Triangle => {
+			vertices: 3,
+			name: "Triangle",
+			..SHAPE_DEFAULTS
+		}
+		Self::Square => {
+			vertices: 4,
+			filled: false,
+			..SHAPE_DEFAULTS
+		}
+		Self::Circle => {
+			..SHAPE_DEFAULTS
+		}
+	}
+}
+
+#[test]
+fn struct_update_const() {
+	assert_eq!(Foo::Triangle.vertices, 3);
+	assert!(Foo::Triangle.filled);
+	assert_eq!(Foo::Triangle.name, "Triangle");
+
+	assert!(!Foo::Square.filled);
+	assert_eq!(Foo::Square.name, "unnamed");
+
+	assert_eq!(Foo::Circle.vertices, 0);
+	assert_eq!(Foo::Circle.name, "unnamed");
+}
+
+struct StaticShape {
+	vertices: u8,
+	name: &'static str,
+}
+
+const STATIC_DEFAULTS: StaticShape = StaticShape {
+	vertices: 0,
+	name: "unnamed",
+};
+
+props! {
+	impl EnumProp for Foo as static StaticShape {
+		Self::Triangle => {
+			vertices: 3,
+			..STATIC_DEFAULTS
+		},
+		Self::Square => {
+			vertices: 4,
+			..STATIC_DEFAULTS
+		},
+		Self::Circle => {
+			name: "Circle",
+			..STATIC_DEFAULTS
+		},
+	}
+}
+
+#[test]
+fn struct_update_static() {
+	let shape = |variant: Foo| EnumProp::<StaticShape>::property(&variant);
+
+	assert_eq!(shape(Foo::Square).vertices, 4);
+	assert_eq!(shape(Foo::Square).name, "unnamed");
+	assert_eq!(shape(Foo::Circle).vertices, 0);
+	assert_eq!(shape(Foo::Circle).name, "Circle");
+}
+
+#[cfg(lazy_backend)]
+mod lazy {
+	use super::Foo;
+
+	struct Mesh {
+		vertices: u32,
+		edges: u32,
+	}
+
+	struct GroupedMesh {
+		vertices: u32,
+		edges: u32,
+	}
+
+	// A base value computed at runtime
+	fn mesh_defaults() -> Mesh {
+		Mesh {
+			vertices: 0,
+			edges: 2u32.pow(3),
+		}
+	}
+
+	fn grouped_defaults() -> GroupedMesh {
+		GroupedMesh {
+			vertices: 0,
+			edges: 2u32.pow(3),
+		}
+	}
+
+	props! {
+		impl Foo : fn mesh as lazy Mesh {
+			Self::Triangle => {
+				vertices: 3,
+				..mesh_defaults()
+			}
+			Self::Square => {
+				edges: 4,
+				..mesh_defaults()
+			}
+			Self::Circle => {
+				..mesh_defaults()
+			}
+		}
+	}
+
+	props! {
+		impl Foo : fn grouped_mesh as lazy(grouped) GroupedMesh {
+			Self::Triangle => {
+				vertices: 3,
+				..grouped_defaults()
+			},
+			Self::Square => {
+				edges: 4,
+				..grouped_defaults()
+			},
+			Self::Circle => {
+				..grouped_defaults()
+			}
+		}
+	}
+
+	#[test]
+	fn struct_update_lazy() {
+		assert_eq!(Foo::Triangle.mesh().vertices, 3);
+		assert_eq!(Foo::Triangle.mesh().edges, 8);
+		assert_eq!(Foo::Square.mesh().edges, 4);
+		assert_eq!(Foo::Circle.mesh().vertices, 0);
+
+		assert_eq!(Foo::Triangle.grouped_mesh().vertices, 3);
+		assert_eq!(Foo::Square.grouped_mesh().edges, 4);
+		assert_eq!(Foo::Circle.grouped_mesh().edges, 8);
+	}
+}
+
+
+
+// The base value of `const` and `static` properties must be constant
+
+/// ```compile_fail,E0015
+/// use enumeraties::props;
+///
+/// struct Props {
+///     foo: u8,
+///     bar: u8,
+/// }
+///
+/// fn defaults() -> Props {
+///     Props { foo: 0, bar: 0 }
+/// }
+///
+/// enum Foo {
+///     A,
+/// }
+///
+/// props! {
+///     impl Deref for Foo as const Props {
+///         Self::A => {
+///             foo: 42,
+///             ..defaults()
+///         }
+///     }
+/// }
+/// ```
+///
+/// ```compile_fail,E0015
+/// use enumeraties::props;
+///
+/// struct Props {
+///     foo: u8,
+///     bar: u8,
+/// }
+///
+/// fn defaults() -> Props {
+///     Props { foo: 0, bar: 0 }
+/// }
+///
+/// enum Foo {
+///     A,
+/// }
+///
+/// props! {
+///     impl Deref for Foo as static Props {
+///         Self::A => {
+///             foo: 42,
+///             ..defaults()
+///         }
+///     }
+/// }
+/// ```
+struct NonConstBase;
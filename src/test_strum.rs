@@ -0,0 +1,92 @@
+// This file tests iterating the variants of `strum` enums along with their
+// properties
+#![cfg(any(test, doctest))]
+#![cfg(feature = "strum")]
+
+use strum::EnumIter;
+
+use super::iter_props;
+use super::PropIterExt;
+
+
+struct ShapeDef {
+	name: &'static str,
+	vertices: usize,
+}
+
+#[derive(EnumIter, Debug, Clone, Copy, PartialEq)]
+enum Shape {
+	Triangle,
+	Square,
+	Rhombus,
+	Circle,
+}
+
+props! {
+	impl Deref for Shape as static ShapeDef {
+		Self::Triangle => {
+			name: "triangle",
+			vertices: 3,
+		}
+		Self::Square | Self::Rhombus => {
+			name: "quad",
+			vertices: 4,
+		}
+		Self::Circle => {
+			name: "circle",
+			vertices: 0,
+		}
+	}
+}
+
+struct Label(&'static str);
+
+props! {
+	impl EnumProp for Shape as const Label {
+		Self::Triangle => Label("Triangle"),
+		Self::Square => Label("Square"),
+		_ => Label("Other"),
+	}
+}
+
+#[test]
+fn iter_pairs() {
+	let mut iter = iter_props::<Shape, ShapeDef>();
+	for (expected, vertices) in [
+		(Shape::Triangle, 3),
+		(Shape::Square, 4),
+		(Shape::Rhombus, 4),
+		(Shape::Circle, 0),
+	] {
+		let (shape, def) = iter.next().unwrap();
+		assert_eq!(shape, expected);
+		assert_eq!(def.vertices, vertices);
+		// The very same reference as of the variant
+		assert!(core::ptr::eq(def, &*expected));
+	}
+	assert!(iter.next().is_none());
+}
+
+#[test]
+fn iter_with() {
+	assert!(Shape::iter_with::<ShapeDef>()
+		.map(|(_, def)| def.name)
+		.eq(["triangle", "quad", "quad", "circle"]));
+}
+
+#[test]
+fn iter_with_other_prop() {
+	assert!(Shape::iter_with::<Label>()
+		.map(|(_, label)| label.0)
+		.eq(["Triangle", "Square", "Other", "Other"]));
+}
+
+#[test]
+fn iter_generic() {
+	fn fewest_vertices<E: PropIterExt + super::EnumProp<ShapeDef>>() -> Option<E> {
+		E::iter_with::<ShapeDef>()
+			.min_by_key(|(_, def)| def.vertices)
+			.map(|(variant, _)| variant)
+	}
+	assert_eq!(fewest_vertices::<Shape>(), Some(Shape::Circle));
+}
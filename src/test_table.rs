@@ -0,0 +1,339 @@
+// This file tests the properties looked up in a table by the discriminant
+#![cfg(any(test, doctest))]
+#![allow(dead_code)]
+
+use super::EnumProp;
+
+
+struct OpcodeDef {
+	mnemonic: &'static str,
+	operands: u8,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[repr(u8)]
+enum Opcode {
+	Nop,
+	Load,
+	Store,
+	Jump,
+}
+
+props! {
+	impl Deref for Opcode as const OpcodeDef {
+		table
+		Self::Nop => {
+			mnemonic: "nop",
+			operands: 0,
+		}
+		Self::Load | Self::Store => {
+			mnemonic: "mov",
+			operands: 2,
+		}
+		Self::Jump => {
+			mnemonic: "jmp",
+			operands: 1,
+		}
+	}
+}
+
+#[test]
+fn table() {
+	assert_eq!(Opcode::Nop.mnemonic, "nop");
+	assert_eq!(Opcode::Load.mnemonic, "mov");
+	assert_eq!(Opcode::Store.operands, 2);
+	assert_eq!(Opcode::Jump.mnemonic, "jmp");
+	// The table holds the values, one per variant
+	assert!(!core::ptr::eq(
+		Opcode::Load.property(),
+		Opcode::Store.property()
+	));
+}
+
+// The branches in any order, with explicit discriminants, along with defaults,
+// named values, and consts
+
+struct Level {
+	rank: u8,
+	label: &'static str,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[repr(u16)]
+enum Priority {
+	Medium = 1,
+	High = 2,
+	Low = 0,
+}
+
+props! {
+	impl EnumProp for Priority as const Level {
+		table
+		consts {
+			pub HIGH = Self::High,
+		}
+		defaults {
+			label: "",
+		}
+		let TOP = {
+			rank: 10,
+			label: "top",
+		};
+		Self::High => TOP,
+		Self::Low => {
+			rank: 0,
+		}
+		Self::Medium => {
+			rank: 5,
+		}
+	}
+}
+
+#[test]
+fn table_order() {
+	let ranks = [Priority::Low, Priority::Medium, Priority::High].map(|p| p.property().rank);
+	assert_eq!(ranks, [0, 5, 10]);
+	assert_eq!(Priority::HIGH.label, "top");
+	assert_eq!(Priority::Low.property().label, "");
+}
+
+// Properties with a destructor, which are held by the table as well
+#[cfg(test)]
+mod destructor {
+	use std::vec::Vec;
+
+
+	struct Aliases {
+		names: Vec<&'static str>,
+		primary: &'static str,
+	}
+
+	#[derive(Debug, Clone, Copy, PartialEq)]
+	enum Color {
+		Red,
+		Green,
+	}
+
+	props! {
+		impl Deref for Color as const Aliases {
+			table
+			defaults {
+				names: Vec::new(),
+			}
+			Self::Red => {
+				primary: "red",
+			}
+			Self::Green => {
+				primary: "green",
+			}
+		}
+	}
+
+	#[test]
+	fn table_destructor() {
+		assert_eq!(Color::Green.primary, "green");
+		assert!(Color::Red.names.is_empty());
+	}
+}
+
+// Generic enums, whose variants can not have data, e.g. by a const parameter
+
+struct Label {
+	text: &'static str,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Side<const N: usize> {
+	Left,
+	Right,
+}
+
+props! {
+	impl<const N: usize> EnumProp for Side<N> as const Label {
+		table
+		Self::Left => {
+			text: "left",
+		}
+		Self::Right => {
+			text: "right",
+		}
+	}
+}
+
+#[test]
+fn table_generic() {
+	assert_eq!(Side::<3>::Right.property().text, "right");
+	assert_eq!(Side::<5>::Left.property().text, "left");
+}
+
+// The discriminants must be dense
+
+/// ```compile_fail,E0080
+/// use enumeraties::props;
+///
+/// struct Props {
+///     name: &'static str,
+/// }
+///
+/// #[derive(Clone, Copy)]
+/// #[repr(u8)]
+/// enum Foo {
+///     A = 0,
+///     B = 2,
+/// }
+///
+/// props! {
+///     impl Deref for Foo as const Props {
+///         table
+///         Self::A => {
+///             name: "a",
+///         }
+///         Self::B => {
+///             name: "b",
+///         }
+///     }
+/// }
+///
+/// let _ = Foo::A.name;
+/// ```
+struct Sparse;
+
+// Variants with data can not be indexed
+
+/// ```compile_fail
+/// use enumeraties::props;
+///
+/// struct Props {
+///     name: &'static str,
+/// }
+///
+/// #[derive(Clone, Copy)]
+/// enum Foo {
+///     A,
+///     B(u8),
+/// }
+///
+/// props! {
+///     impl Deref for Foo as const Props {
+///         table
+///         Self::A => {
+///             name: "a",
+///         }
+///         Self::B(_) => {
+///             name: "b",
+///         }
+///     }
+/// }
+/// ```
+struct DataVariant;
+
+// Neither a wildcard branch
+
+/// ```compile_fail
+/// use enumeraties::props;
+///
+/// struct Props {
+///     name: &'static str,
+/// }
+///
+/// #[derive(Clone, Copy)]
+/// enum Foo {
+///     A,
+///     B,
+/// }
+///
+/// props! {
+///     impl Deref for Foo as const Props {
+///         table
+///         Self::A => {
+///             name: "a",
+///         }
+///         _ => {
+///             name: "b",
+///         }
+///     }
+/// }
+/// ```
+struct Wildcard;
+
+// The enum must be `Copy`
+
+/// ```compile_fail,E0277
+/// use enumeraties::props;
+///
+/// struct Props {
+///     name: &'static str,
+/// }
+///
+/// enum Foo {
+///     A,
+///     B,
+/// }
+///
+/// props! {
+///     impl Deref for Foo as const Props {
+///         table
+///         Self::A => {
+///             name: "a",
+///         }
+///         Self::B => {
+///             name: "b",
+///         }
+///     }
+/// }
+/// ```
+struct NotCopy;
+
+// The table holds the values, thus a branch can not override the modifier
+
+/// ```compile_fail
+/// use enumeraties::props;
+///
+/// struct Props {
+///     name: &'static str,
+/// }
+///
+/// #[derive(Clone, Copy)]
+/// enum Foo {
+///     A,
+///     B,
+/// }
+///
+/// props! {
+///     impl Deref for Foo as const Props {
+///         table
+///         Self::A => {
+///             name: "a",
+///         }
+///         Self::B => static {
+///             name: "b",
+///         }
+///     }
+/// }
+/// ```
+struct Modifier;
+
+// Only const properties are supported
+
+/// ```compile_fail
+/// use enumeraties::props;
+///
+/// struct Props {
+///     name: &'static str,
+/// }
+///
+/// #[derive(Clone, Copy)]
+/// enum Foo {
+///     A,
+/// }
+///
+/// props! {
+///     impl Deref for Foo as static Props {
+///         table
+///         Self::A => {
+///             name: "a",
+///         }
+///     }
+/// }
+/// ```
+struct Static;
@@ -0,0 +1,169 @@
+// This file tests tagged properties, i.e. the same property type defined
+// several times on the same enum, distinguished by a marker type
+#![cfg(any(test, doctest))]
+#![allow(dead_code)]
+
+use crate::EnumProp;
+use crate::EnumPropTagged;
+
+#[derive(Debug, PartialEq)]
+struct Limits {
+	requests: u32,
+	storage: u32,
+}
+
+// The tags
+enum Soft {}
+struct Hard;
+
+#[derive(Clone, Copy)]
+enum Plan {
+	Free,
+	Pro,
+	Enterprise(u8),
+}
+
+props! {
+	impl EnumPropTagged for Plan as const Limits @ Soft {
+		Self::Free => {
+			requests: 10,
+			storage: 1,
+		}
+		Self::Pro => {
+			requests: 100,
+			storage: 10,
+		}
+		Self::Enterprise(_) => {
+			requests: 1000,
+			storage: 100,
+		}
+	}
+}
+
+props! {
+	impl Plan : pub fn hard_limits as static Limits @ Hard {
+		defaults {
+			storage: 0,
+		}
+		Self::Free => {
+			requests: 20,
+		}
+		Self::Pro | Self::Enterprise(_) => {
+			requests: 200,
+			storage: 20,
+		}
+	}
+}
+
+// The untagged property of the same type
+props! {
+	impl EnumProp for Plan as const Limits {
+		_ => {
+			requests: 1,
+			storage: 1,
+		}
+	}
+}
+
+fn soft<E: EnumPropTagged<Soft, Limits>>(value: &E) -> &'static Limits {
+	value.property_tagged()
+}
+
+#[test]
+fn tagged() {
+	assert_eq!(soft(&Plan::Pro).requests, 100);
+	assert_eq!(soft(&Plan::Enterprise(3)).storage, 100);
+	assert_eq!(
+		EnumPropTagged::<Hard, Limits>::property_tagged(&Plan::Free),
+		&Limits {
+			requests: 20,
+			storage: 0,
+		}
+	);
+	assert_eq!(Plan::Enterprise(1).hard_limits().requests, 200);
+	assert_eq!(Plan::Pro.property().requests, 1);
+}
+
+#[test]
+fn tagged_static() {
+	// Being a `static`, the branch has a single address
+	assert!(core::ptr::eq(
+		Plan::Pro.hard_limits(),
+		Plan::Enterprise(0).hard_limits()
+	));
+}
+
+#[cfg(lazy_backend)]
+mod lazy {
+	use super::*;
+
+	enum Burst {}
+
+	props! {
+		impl EnumPropTagged for Plan as lazy Limits @ Burst {
+			Self::Free => {
+				requests: 5 * 10,
+				storage: 1,
+			}
+			_ => {
+				requests: 5 * 100,
+				storage: 10,
+			}
+		}
+	}
+
+	#[test]
+	fn tagged_lazy() {
+		assert_eq!(
+			EnumPropTagged::<Burst, Limits>::property_tagged(&Plan::Free).requests,
+			50
+		);
+		assert_eq!(
+			EnumPropTagged::<Burst, Limits>::property_tagged(&Plan::Pro).requests,
+			500
+		);
+	}
+}
+
+/// ```compile_fail
+/// use enumeraties::props;
+/// struct Limits { requests: u32 }
+/// enum Soft {}
+/// enum Plan { Free, Pro }
+/// props! {
+///     impl EnumPropTagged for Plan as const Limits @ Soft {
+///         Self::Free => {
+///             requests: 10,
+///         }
+///         Self::Pro => {
+///             requests: 100,
+///         }
+///     }
+/// }
+/// props! {
+///     impl EnumPropTagged for Plan as const Limits @ Soft {
+///         _ => {
+///             requests: 0,
+///         }
+///     }
+/// }
+/// ```
+struct SameTag;
+
+/// ```compile_fail
+/// use enumeraties::props;
+/// use enumeraties::EnumProp;
+/// struct Limits { requests: u32 }
+/// enum Soft {}
+/// enum Plan { Free }
+/// props! {
+///     impl EnumPropTagged for Plan as const Limits @ Soft {
+///         Self::Free => {
+///             requests: 10,
+///         }
+///     }
+/// }
+/// // Not implementing `EnumProp`
+/// let _ = EnumProp::<Limits>::property(&Plan::Free);
+/// ```
+struct NotUntagged;
@@ -0,0 +1,123 @@
+// This file tests thread local properties
+#![cfg(any(test, doctest))]
+#![cfg(feature = "std")]
+#![allow(dead_code)]
+
+use core::cell::RefCell;
+use std::vec::Vec;
+
+use super::EnumPropLocal;
+
+
+// A property that is not `Sync`, thus it can only be used as `thread_lazy`
+struct Scratch {
+	buffer: RefCell<Vec<u32>>,
+}
+
+#[derive(Copy, Clone)]
+enum Foo {
+	A,
+	B,
+}
+
+props! {
+	impl Foo : fn scratch as thread_lazy Scratch {
+		Self::A => {
+			buffer: RefCell::new(Vec::new()),
+		}
+		Self::B => {
+			buffer: RefCell::new(std::vec![1, 2, 3]),
+		}
+	}
+}
+
+fn push(variant: Foo, value: u32) -> usize {
+	variant.scratch(|s| {
+		let mut buffer = s.buffer.borrow_mut();
+		buffer.push(value);
+		buffer.len()
+	})
+}
+
+#[test]
+fn thread_lazy_per_variant() {
+	// Each variant has its own instance
+	assert_eq!(push(Foo::A, 1), 1);
+	assert_eq!(push(Foo::A, 2), 2);
+	assert_eq!(push(Foo::B, 4), 4);
+
+	// Also accessible via the trait
+	let sum = EnumPropLocal::<Scratch>::with_property(&Foo::B, |s: &Scratch| {
+		s.buffer.borrow().iter().sum::<u32>()
+	});
+	assert_eq!(sum, 10);
+}
+
+#[test]
+fn thread_lazy_per_thread() {
+	assert_eq!(push(Foo::B, 42), 4);
+
+	// Two other threads, each observing their own instance
+	let handles: Vec<_> = (0..2)
+		.map(|i| {
+			std::thread::spawn(move || {
+				let first = push(Foo::B, i);
+				let second = push(Foo::B, i);
+				(first, second)
+			})
+		})
+		.collect();
+	for h in handles {
+		assert_eq!(h.join().unwrap(), (4, 5));
+	}
+
+	// The instance of this thread is unaffected by the other threads
+	assert_eq!(Foo::B.scratch(|s| s.buffer.borrow().len()), 4);
+}
+
+
+
+// `thread_lazy` can not be used with `Deref` nor `EnumProp`
+
+/// ```compile_fail
+/// use enumeraties::props;
+/// use core::cell::Cell;
+///
+/// struct Props {
+///     foo: Cell<u8>,
+/// }
+///
+/// enum Foo {
+///     A,
+/// }
+///
+/// props! {
+///     impl Deref for Foo as thread_lazy Props {
+///         Self::A => {
+///             foo: Cell::new(42),
+///         }
+///     }
+/// }
+/// ```
+///
+/// ```compile_fail
+/// use enumeraties::props;
+/// use core::cell::Cell;
+///
+/// struct Props {
+///     foo: Cell<u8>,
+/// }
+///
+/// enum Foo {
+///     A,
+/// }
+///
+/// props! {
+///     impl EnumProp for Foo as thread_lazy Props {
+///         Self::A => {
+///             foo: Cell::new(42),
+///         }
+///     }
+/// }
+/// ```
+struct NoThreadLazyDeref;
@@ -0,0 +1,198 @@
+// This file tests user traits implemented by giving their methods per variant
+#![cfg(any(test, doctest))]
+#![allow(dead_code)]
+
+
+#[derive(Default)]
+struct Canvas {
+	strokes: u32,
+	fills: u32,
+}
+
+trait Renderer {
+	fn draw(&self, canvas: &mut Canvas);
+	fn area(&self, scale: f32) -> f32;
+	fn name(&self) -> &'static str;
+}
+
+#[derive(Clone, Copy)]
+enum Shape {
+	Triangle,
+	Square,
+	Circle(f32),
+}
+
+props! {
+	impl trait Renderer for Shape as const {
+		Self::Triangle => {
+			fn draw(&self, canvas: &mut Canvas) {
+				canvas.strokes += 3;
+			}
+			fn area(&self, scale: f32) -> f32 {
+				0.43 * scale * scale
+			}
+			fn name(&self) -> &'static str {
+				"triangle"
+			}
+		}
+		Self::Square => {
+			fn draw(&self, canvas: &mut Canvas) {
+				canvas.strokes += 4;
+				canvas.fills += 1;
+			}
+			fn area(&self, scale: f32) -> f32 {
+				scale * scale
+			}
+			fn name(&self) -> &'static str {
+				"square"
+			}
+		}
+		// The methods may use `self`, e.g. the data of the variant
+		Self::Circle(_) => {
+			fn draw(&self, canvas: &mut Canvas) {
+				canvas.fills += 1;
+			}
+			fn area(&self, scale: f32) -> f32 {
+				match self {
+					Self::Circle(radius) => 3.0 * radius * radius * scale * scale,
+					_ => unreachable!(),
+				}
+			}
+			fn name(&self) -> &'static str {
+				"circle"
+			}
+		}
+	}
+}
+
+#[test]
+fn trait_dispatch() {
+	let mut canvas = Canvas::default();
+	for shape in [Shape::Triangle, Shape::Square, Shape::Circle(1.0)] {
+		shape.draw(&mut canvas);
+	}
+	assert_eq!(canvas.strokes, 7);
+	assert_eq!(canvas.fills, 2);
+
+	assert_eq!(Shape::Square.area(2.0), 4.0);
+	assert_eq!(Shape::Circle(2.0).area(1.0), 12.0);
+	assert_eq!(Shape::Triangle.name(), "triangle");
+}
+
+// Via a trait object
+fn names(shapes: &[&dyn Renderer]) -> [&'static str; 2] {
+	[shapes[0].name(), shapes[1].name()]
+}
+
+#[test]
+fn trait_object() {
+	assert_eq!(
+		names(&[&Shape::Circle(1.0), &Shape::Square]),
+		["circle", "square"]
+	);
+}
+
+// A `static` table and a wildcard branch, with a trait given by its path
+mod traits {
+	pub trait Cost {
+		fn cost(&self) -> u32;
+	}
+}
+
+enum Item {
+	Sword,
+	Shield,
+	Potion,
+}
+
+props! {
+	impl trait traits::Cost for Item as static {
+		Self::Sword => {
+			fn cost(&self) -> u32 {
+				10
+			}
+		}
+		_ => {
+			fn cost(&self) -> u32 {
+				1
+			}
+		}
+	}
+}
+
+#[test]
+fn trait_wildcard() {
+	use traits::Cost;
+
+	assert_eq!(Item::Sword.cost(), 10);
+	assert_eq!(Item::Shield.cost(), 1);
+	assert_eq!(Item::Potion.cost(), 1);
+}
+
+
+
+// Each branch must give all methods
+
+/// ```compile_fail,E0063
+/// use enumeraties::props;
+///
+/// trait Named {
+///     fn name(&self) -> &'static str;
+///     fn len(&self) -> usize;
+/// }
+///
+/// enum Foo {
+///     A,
+///     B,
+/// }
+///
+/// props! {
+///     impl trait Named for Foo as const {
+///         Self::A => {
+///             fn name(&self) -> &'static str {
+///                 "a"
+///             }
+///             fn len(&self) -> usize {
+///                 1
+///             }
+///         }
+///         Self::B => {
+///             fn name(&self) -> &'static str {
+///                 "b"
+///             }
+///         }
+///     }
+/// }
+/// ```
+struct MissingMethod;
+
+// With the same signatures
+
+/// ```compile_fail,E0308
+/// use enumeraties::props;
+///
+/// trait Named {
+///     fn len(&self) -> usize;
+/// }
+///
+/// enum Foo {
+///     A,
+///     B,
+/// }
+///
+/// props! {
+///     impl trait Named for Foo as const {
+///         Self::A => {
+///             fn len(&self) -> usize {
+///                 1
+///             }
+///         }
+///         Self::B => {
+///             fn len(&self) -> u8 {
+///                 2
+///             }
+///         }
+///     }
+/// }
+/// ```
+struct WrongSignature;
@@ -0,0 +1,169 @@
+// This file tests the conversion of a value into the enum via a unique field
+#![cfg(any(test, doctest))]
+#![allow(dead_code)]
+
+#[cfg(test)]
+use std::string::ToString;
+
+use super::UnknownValue;
+
+struct OpDef {
+	code: u16,
+	mnemonic: &'static str,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Op {
+	Nop,
+	Push,
+	Pop,
+	Jump,
+}
+
+props! {
+	impl Deref for Op as const OpDef {
+		try_from(code: u16)
+		Self::Nop => {
+			code: 0x00,
+			mnemonic: "nop",
+		}
+		Self::Push => {
+			code: 0x10,
+			mnemonic: "push",
+		}
+		Self::Pop => {
+			code: 0x11,
+			mnemonic: "pop",
+		}
+		Self::Jump => {
+			code: 0x100,
+			mnemonic: "jmp",
+		}
+	}
+}
+
+#[test]
+fn try_from_code() {
+	assert_eq!(Op::try_from(0x11), Ok(Op::Pop));
+	assert_eq!(Op::try_from(0x100), Ok(Op::Jump));
+	// Decoding the codes of all variants gives them back
+	for op in [Op::Nop, Op::Push, Op::Pop, Op::Jump] {
+		assert_eq!(Op::try_from(op.code), Ok(op));
+	}
+}
+
+#[test]
+fn try_from_unknown() {
+	assert_eq!(Op::try_from(0x12), Err(UnknownValue(0x12)));
+	let result: Result<Op, _> = 7u16.try_into();
+	assert_eq!(
+		result.unwrap_err().to_string(),
+		"the value 7 does not belong to any variant"
+	);
+}
+
+// A shared branch converts into its first variant, via `const fn`
+
+#[derive(Debug, PartialEq)]
+enum Level {
+	Low,
+	Lowest,
+	High,
+}
+
+props! {
+	impl Level : const fn def as const OpDef {
+		try_from(mnemonic: &'static str)
+		Self::Low | Self::Lowest => {
+			code: 1,
+			mnemonic: "low",
+		}
+		Self::High => {
+			code: 2,
+			mnemonic: "high",
+		}
+	}
+}
+
+#[test]
+fn try_from_shared() {
+	assert_eq!(Level::try_from("low"), Ok(Level::Low));
+	assert_eq!(Level::try_from("high"), Ok(Level::High));
+	assert_eq!(Level::try_from("none"), Err(UnknownValue("none")));
+}
+
+// The field must be unique
+
+/// ```compile_fail
+/// use enumeraties::props;
+///
+/// struct Props {
+///     code: u8,
+/// }
+///
+/// enum Foo {
+///     A,
+///     B,
+/// }
+///
+/// props! {
+///     impl Deref for Foo as const Props {
+///         try_from(code: u8)
+///         Self::A => {
+///             code: 1,
+///         }
+///         Self::B => {
+///             code: 1,
+///         }
+///     }
+/// }
+/// ```
+struct Duplicate;
+
+// Variants with data have no value to convert into
+
+/// ```compile_fail
+/// use enumeraties::props;
+///
+/// struct Props {
+///     code: u8,
+/// }
+///
+/// enum Foo {
+///     A(u8),
+/// }
+///
+/// props! {
+///     impl Deref for Foo as const Props {
+///         try_from(code: u8)
+///         Self::A(_) => {
+///             code: 1,
+///         }
+///     }
+/// }
+/// ```
+struct DataVariant;
+
+// Only const properties are checked for uniqueness
+
+/// ```compile_fail
+/// use enumeraties::props;
+///
+/// struct Props {
+///     code: u8,
+/// }
+///
+/// enum Foo {
+///     A,
+/// }
+///
+/// props! {
+///     impl Deref for Foo as static Props {
+///         try_from(code: u8)
+///         Self::A => {
+///             code: 1,
+///         }
+///     }
+/// }
+/// ```
+struct StaticProp;
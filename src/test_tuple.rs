@@ -0,0 +1,87 @@
+// This file tests tuple struct properties, given as positional values
+#![cfg(any(test, doctest))]
+#![allow(dead_code)]
+
+use super::EnumProp;
+
+
+struct Cost(u32);
+
+struct Name(&'static str);
+
+struct Stats(&'static str, u32, bool);
+
+enum Foo {
+	A,
+	B,
+	C(u8),
+}
+
+props! {
+	impl Deref for Foo as const Cost {
+		Self::A => (42),
+		Self::B => (43,),
+		_ => Cost(44),
+	}
+}
+
+props! {
+	impl Foo : fn name as static Name {
+		Self::A => ("A"),
+		Self::B => ("B"),
+		Self::C(_) => ("C")
+	}
+}
+
+props! {
+	impl EnumProp for Foo as const Stats {
+		Self::A => ("A", 1, true),
+		Self::B => ("B", 2, false),
+		Self::C(_) => (
+			"C",
+			3,
+			true,
+		),
+	}
+}
+
+#[test]
+fn tuple_const() {
+	assert_eq!(Foo::A.0, 42);
+	assert_eq!(Foo::B.0, 43);
+	assert_eq!(Foo::C(42).0, 44);
+
+	let stats = |variant: Foo| EnumProp::<Stats>::property(&variant);
+	assert_eq!(stats(Foo::B).0, "B");
+	assert_eq!(stats(Foo::B).1, 2);
+	assert!(stats(Foo::C(42)).2);
+}
+
+#[test]
+fn tuple_static() {
+	assert_eq!(Foo::A.name().0, "A");
+	assert_eq!(Foo::C(42).name().0, "C");
+}
+
+#[cfg(lazy_backend)]
+mod lazy {
+	use super::Foo;
+	use crate::test_util::compute;
+
+	struct Score(u32, u32);
+
+	props! {
+		impl Foo : fn score as lazy Score {
+			Self::A => (compute(2), 1),
+			Self::B => (compute(3), 2),
+			Self::C(_) => Score(compute(4), 3),
+		}
+	}
+
+	#[test]
+	fn tuple_lazy() {
+		assert_eq!(Foo::A.score().0, 4);
+		assert_eq!(Foo::B.score().1, 2);
+		assert_eq!(Foo::C(42).score().0, 16);
+	}
+}
@@ -0,0 +1,166 @@
+// This file tests the compile time check of unique fields of const properties
+#![cfg(any(test, doctest))]
+#![allow(dead_code)]
+
+
+struct Message {
+	code: u16,
+	name: &'static str,
+	tag: char,
+}
+
+enum Request {
+	Ping,
+	Query,
+	Update,
+	Other(u8),
+}
+
+props! {
+	impl Deref for Request as const Message {
+		unique(code, name)
+		unique(tag)
+		let UPDATE = {
+			code: 3,
+			name: "update",
+			tag: 'u',
+		};
+		Self::Ping => {
+			code: 1,
+			name: "ping",
+			tag: 'p',
+		}
+		Self::Query => {
+			code: 2,
+			name: "query",
+			tag: 'q',
+		}
+		Self::Update => UPDATE,
+		_ => {
+			code: 0xFFFF,
+			name: "other",
+			tag: 'o',
+		}
+	}
+}
+
+#[test]
+fn unique_const() {
+	assert_eq!(Request::Query.code, 2);
+	assert_eq!(Request::Update.name, "update");
+	assert_eq!(Request::Other(5).tag, 'o');
+}
+
+// Via `const fn`, along with further clauses, shared branches only count once
+
+struct Flags {
+	bit: u8,
+}
+
+props! {
+	impl Request {
+		const fn flags as const Flags {
+			unique(bit,)
+			aggregate {
+				max bit as MAX_BIT: u8,
+			}
+			Self::Ping | Self::Query => {
+				bit: 0,
+			}
+			Self::Update => {
+				bit: 1,
+			}
+			Self::Other(_) => {
+				bit: 2,
+			}
+		}
+	}
+}
+
+#[test]
+fn unique_const_fn() {
+	const QUERY: u8 = Request::Query.flags().bit;
+	assert_eq!(QUERY, 0);
+	assert_eq!(Request::MAX_BIT, 2);
+}
+
+// Duplicate integers
+
+/// ```compile_fail,E0080
+/// use enumeraties::props;
+///
+/// struct Props {
+///     code: u16,
+/// }
+///
+/// enum Foo {
+///     A,
+///     B,
+/// }
+///
+/// props! {
+///     impl Foo : fn getter as const Props {
+///         unique(code)
+///         Self::A => {
+///             code: 1,
+///         }
+///         Self::B => {
+///             code: 1,
+///         }
+///     }
+/// }
+/// ```
+struct DuplicateInt;
+
+// Duplicate strings, also through a wildcard
+
+/// ```compile_fail,E0080
+/// use enumeraties::props;
+///
+/// struct Props {
+///     name: &'static str,
+/// }
+///
+/// enum Foo {
+///     A,
+///     B,
+///     C,
+/// }
+///
+/// props! {
+///     impl Foo : fn getter as const Props {
+///         unique(name)
+///         Self::A => {
+///             name: "a",
+///         }
+///         _ => {
+///             name: "a",
+///         }
+///     }
+/// }
+/// ```
+struct DuplicateStr;
+
+// Only `const` properties
+
+/// ```compile_fail
+/// use enumeraties::props;
+///
+/// struct Props {
+///     code: u16,
+/// }
+///
+/// enum Foo {
+///     A,
+/// }
+///
+/// props! {
+///     impl Foo : fn getter as static Props {
+///         unique(code)
+///         Self::A => {
+///             code: 1,
+///         }
+///     }
+/// }
+/// ```
+struct StaticUnique;
@@ -0,0 +1,11 @@
+// This file provides the fixtures shared by the tests
+#![cfg(any(test, doctest))]
+#![allow(dead_code)]
+
+use core::ops::Mul;
+
+
+// Some value computed at runtime
+pub(crate) fn compute<T: Mul<Output = T> + Copy>(base: T) -> T {
+	base * base
+}
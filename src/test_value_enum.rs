@@ -0,0 +1,264 @@
+// This file tests the `clap::ValueEnum` impl by the fields of the properties
+#![cfg(any(test, doctest))]
+#![cfg(feature = "clap")]
+#![allow(dead_code)]
+
+#[cfg(test)]
+use std::borrow::ToOwned;
+#[cfg(test)]
+use std::string::ToString;
+#[cfg(test)]
+use std::vec::Vec;
+
+#[cfg(test)]
+use clap::builder::PossibleValue;
+#[cfg(test)]
+use clap::Arg;
+#[cfg(test)]
+use clap::Command;
+#[cfg(test)]
+use clap::ValueEnum;
+
+
+struct ShapeDef {
+	name: &'static str,
+	description: &'static str,
+	vertices: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Shape {
+	Triangle,
+	Square,
+	Rhombus,
+}
+
+props! {
+	impl Deref for Shape as const ShapeDef {
+		value_enum(name, help = description)
+		Self::Triangle => {
+			name: "triangle",
+			description: "three vertices",
+			vertices: 3,
+		}
+		Self::Square => {
+			name: "square",
+			description: "four right angles",
+			vertices: 4,
+		}
+		Self::Rhombus => {
+			name: "rhombus",
+			description: "four equal sides",
+			vertices: 4,
+		}
+	}
+}
+
+#[test]
+fn value_variants() {
+	assert_eq!(
+		Shape::value_variants(),
+		[Shape::Triangle, Shape::Square, Shape::Rhombus]
+	);
+}
+
+#[test]
+fn possible_values() {
+	let value = Shape::Square.to_possible_value().unwrap();
+	assert_eq!(value.get_name(), "square");
+	assert_eq!(
+		value.get_help().map(|help| help.to_string()).as_deref(),
+		Some("four right angles")
+	);
+	assert!(Shape::value_variants()
+		.iter()
+		.filter_map(ValueEnum::to_possible_value)
+		.map(|value| value.get_name().to_owned())
+		.eq(["triangle", "square", "rhombus"]));
+}
+
+#[test]
+fn from_str() {
+	assert_eq!(Shape::from_str("rhombus", false), Ok(Shape::Rhombus));
+	assert_eq!(Shape::from_str("SQUARE", true), Ok(Shape::Square));
+	assert!(Shape::from_str("circle", false).is_err());
+}
+
+#[test]
+fn parse_arg() {
+	let command = || {
+		Command::new("draw").arg(
+			Arg::new("shape")
+				.long("shape")
+				.value_parser(clap::value_parser!(Shape)),
+		)
+	};
+
+	let matches = command()
+		.try_get_matches_from(["draw", "--shape", "triangle"])
+		.unwrap();
+	assert_eq!(matches.get_one::<Shape>("shape"), Some(&Shape::Triangle));
+	assert!(command()
+		.try_get_matches_from(["draw", "--shape", "circle"])
+		.is_err());
+
+	// The possible values of the argument
+	let command = command();
+	let arg = command.get_arguments().next().unwrap();
+	let names: Vec<_> = arg
+		.get_possible_values()
+		.iter()
+		.map(PossibleValue::get_name)
+		.map(str::to_owned)
+		.collect();
+	assert_eq!(names, ["triangle", "square", "rhombus"]);
+}
+
+// Without help, along with an or-pattern and a static prop via `fn`
+
+struct Level {
+	label: &'static str,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Priority {
+	Low,
+	Normal,
+	Default,
+	High,
+}
+
+props! {
+	impl Priority {
+		fn level as static Level {
+			value_enum(label)
+			Self::Low => {
+				label: "low",
+			}
+			Self::Normal | Self::Default => {
+				label: "normal",
+			}
+			Self::High => {
+				label: "high",
+			}
+		}
+	}
+}
+
+#[test]
+fn value_enum_fn() {
+	assert_eq!(Priority::High.level().label, "high");
+	assert_eq!(Priority::value_variants().len(), 4);
+	assert_eq!(Priority::from_str("normal", false), Ok(Priority::Normal));
+	assert_eq!(
+		Priority::Default.to_possible_value().unwrap().get_help(),
+		None
+	);
+}
+
+// Variants with data can not be listed
+
+/// ```compile_fail
+/// use enumeraties::props;
+///
+/// struct Props {
+///     name: &'static str,
+/// }
+///
+/// #[derive(Clone)]
+/// enum Foo {
+///     A,
+///     B(u8),
+/// }
+///
+/// props! {
+///     impl Deref for Foo as const Props {
+///         value_enum(name)
+///         Self::A => {
+///             name: "a",
+///         }
+///         Self::B(_) => {
+///             name: "b",
+///         }
+///     }
+/// }
+/// ```
+struct DataVariant;
+
+// Neither a wildcard branch
+
+/// ```compile_fail
+/// use enumeraties::props;
+///
+/// struct Props {
+///     name: &'static str,
+/// }
+///
+/// #[derive(Clone)]
+/// enum Foo {
+///     A,
+///     B,
+/// }
+///
+/// props! {
+///     impl Deref for Foo as const Props {
+///         value_enum(name)
+///         Self::A => {
+///             name: "a",
+///         }
+///         _ => {
+///             name: "b",
+///         }
+///     }
+/// }
+/// ```
+struct Wildcard;
+
+// Generic enums are not supported
+
+/// ```compile_fail
+/// use enumeraties::props;
+///
+/// struct Props {
+///     name: &'static str,
+/// }
+///
+/// #[derive(Clone)]
+/// enum Foo<const N: usize> {
+///     A,
+/// }
+///
+/// props! {
+///     impl<const N: usize> Deref for Foo<N> as const Props {
+///         value_enum(name)
+///         Self::A => {
+///             name: "a",
+///         }
+///     }
+/// }
+/// ```
+struct Generic;
+
+// The enum must be `Clone`, as required by `ValueEnum`
+
+/// ```compile_fail,E0277
+/// use enumeraties::props;
+///
+/// struct Props {
+///     name: &'static str,
+/// }
+///
+/// enum Foo {
+///     A,
+/// }
+///
+/// props! {
+///     impl Deref for Foo as const Props {
+///         value_enum(name)
+///         Self::A => {
+///             name: "a",
+///         }
+///     }
+/// }
+/// ```
+struct NotClone;
@@ -0,0 +1,137 @@
+// This file tests the list of variants, in the order of the branches
+#![cfg(any(test, doctest))]
+#![allow(dead_code)]
+
+struct IntPropConst {
+	int: u32,
+}
+
+// Like the `Quad` of the benchmarks
+#[derive(Debug, Copy, Clone, PartialEq)]
+enum Quad {
+	A,
+	B,
+	C,
+	D,
+}
+
+props! {
+	impl EnumProp for Quad as const IntPropConst {
+		variants {
+			/// All variants
+			pub VARIANTS
+		}
+		Quad::A => {
+			int: 3
+		}
+		Quad::B => {
+			int: 5
+		}
+		Quad::C => {
+			int: 7
+		}
+		Quad::D => {
+			int: 11
+		}
+	}
+}
+
+// Usable in const contexts
+const COUNT: usize = Quad::VARIANTS.len();
+
+#[test]
+fn variants_const() {
+	assert_eq!(Quad::VARIANTS, [Quad::A, Quad::B, Quad::C, Quad::D]);
+	assert_eq!(COUNT, 4);
+}
+
+// A second property of the same enum, under another name, with or patterns
+// and attributes
+
+struct Parity(bool);
+
+props! {
+	impl Quad : fn parity as const Parity {
+		variants { ODD_FIRST }
+		Self::A | Self::C => Parity(true),
+		#[allow(unused)]
+		Self::B | Self::D => Parity(false),
+	}
+}
+
+#[test]
+fn variants_or_pattern() {
+	assert_eq!(Quad::ODD_FIRST, [Quad::A, Quad::C, Quad::B, Quad::D]);
+	assert!(Quad::ODD_FIRST[..2].iter().all(|quad| quad.parity().0));
+}
+
+// Lazy props list their variants without initializing any of them
+#[cfg(all(test, feature = "lazy"))]
+mod lazy {
+	use crate::EnumProp;
+
+	struct IntPropLazy {
+		int: u32,
+	}
+
+	#[derive(Debug, Copy, Clone, PartialEq)]
+	enum Pair {
+		First,
+		Second,
+	}
+
+	props! {
+		impl EnumProp for Pair as lazy IntPropLazy {
+			variants { VARIANTS }
+			Self::First => {
+				int: 1,
+			}
+			Self::Second => {
+				int: 2,
+			}
+		}
+	}
+
+	#[test]
+	fn variants_lazy() {
+		let sum: u32 = Pair::VARIANTS.iter().map(|pair| pair.property().int).sum();
+		assert_eq!(Pair::VARIANTS, [Pair::First, Pair::Second]);
+		assert_eq!(sum, 3);
+	}
+}
+
+/// ```compile_fail
+/// use enumeraties::props;
+/// struct Prop { int: u32 }
+/// enum Foo { A, B(u8) }
+/// props! {
+///     impl EnumProp for Foo as const Prop {
+///         variants { VARIANTS }
+///         Self::A => {
+///             int: 1,
+///         }
+///         Self::B(_) => {
+///             int: 2,
+///         }
+///     }
+/// }
+/// ```
+struct VariantData;
+
+/// ```compile_fail
+/// use enumeraties::props;
+/// struct Prop { int: u32 }
+/// enum Foo { A, B }
+/// props! {
+///     impl EnumProp for Foo as const Prop {
+///         variants { VARIANTS }
+///         Self::A => {
+///             int: 1,
+///         }
+///         _ => {
+///             int: 2,
+///         }
+///     }
+/// }
+/// ```
+struct Wildcard;
@@ -0,0 +1,126 @@
+// This file tests `where` clauses on the impls of generic enums
+#![cfg(any(test, doctest))]
+#![allow(dead_code)]
+
+use super::EnumProp;
+
+
+struct Meta {
+	name: &'static str,
+}
+
+trait Unit {
+	const SYMBOL: &'static str;
+}
+
+struct Meter;
+
+impl Unit for Meter {
+	const SYMBOL: &'static str = "m";
+}
+
+// The bound of the enum is required on each impl
+enum Quantity<T>
+where
+	T: Unit,
+{
+	Exact(f64, T),
+	Unknown,
+}
+
+props! {
+	impl<T> Deref for Quantity<T> as const Meta where T: Unit {
+		Self::Exact(..) => {
+			name: "exact",
+		}
+		Self::Unknown => {
+			name: "unknown",
+		}
+	}
+}
+
+props! {
+	impl<T> Quantity<T> : pub fn precision as static u8 where T: Unit, {
+		Self::Exact(..) => 3,
+		Self::Unknown => 0,
+	}
+}
+
+props! {
+	impl<T> Quantity<T>
+	where
+		T: Unit,
+	{
+		as const &'static str {
+			Self::Exact(..) => "=",
+			Self::Unknown => "?",
+		}
+	}
+}
+
+#[test]
+fn where_clause() {
+	assert_eq!(Quantity::Exact(1.0, Meter).name, "exact");
+	assert_eq!(Quantity::<Meter>::Unknown.name, "unknown");
+	assert_eq!(*Quantity::Exact(1.0, Meter).precision(), 3);
+	assert_eq!(
+		*EnumProp::<&str>::property(&Quantity::<Meter>::Unknown),
+		"?"
+	);
+}
+
+#[cfg(lazy_backend)]
+mod lazy {
+	use super::Meter;
+	use super::Quantity;
+	use super::Unit;
+	use crate::test_util::compute;
+
+	struct Stats {
+		value: u32,
+	}
+
+	props! {
+		impl<T> Quantity<T> : fn stats as lazy Stats where T: Unit {
+			Self::Exact(..) => {
+				value: compute(2),
+			}
+			Self::Unknown => {
+				value: compute(3),
+			}
+		}
+	}
+
+	#[test]
+	fn where_clause_lazy() {
+		assert_eq!(Quantity::Exact(1.0, Meter).stats().value, 4);
+		assert_eq!(Quantity::<Meter>::Unknown.stats().value, 9);
+	}
+}
+
+
+
+// Without the `where` clause, the bound of the enum is missing
+
+/// ```compile_fail,E0277
+/// use enumeraties::props;
+///
+/// struct Props {
+///     foo: u8,
+/// }
+///
+/// trait Unit {}
+///
+/// enum Foo<T: Unit> {
+///     A(T),
+/// }
+///
+/// props! {
+///     impl<T> Deref for Foo<T> as const Props {
+///         Self::A(_) => {
+///             foo: 1,
+///         }
+///     }
+/// }
+/// ```
+struct MissingWhereClause;
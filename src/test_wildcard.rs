@@ -0,0 +1,217 @@
+// This file tests the wildcard branch, providing the property of all variants
+// not listed explicitly
+#![cfg(any(test, doctest))]
+#![allow(dead_code)]
+
+use super::EnumProp;
+
+
+struct Name {
+	name: &'static str,
+}
+
+struct StaticName {
+	name: &'static str,
+}
+
+enum Foo {
+	A,
+	B,
+	C(u8),
+	D(u16, bool),
+}
+
+props! {
+	impl Foo : fn const_name as const Name {
+		Self::A => {
+			name: "A",
+		}
+		_ => {
+			name: "other",
+		}
+	}
+}
+
+props! {
+	impl EnumProp for Foo as static StaticName {
+		Self::A | Self::C(0) => {
+			name: "A or C(0)",
+		},
+		default => {
+			name: "other",
+		},
+	}
+}
+
+#[test]
+fn wildcard_const() {
+	assert_eq!(Foo::A.const_name().name, "A");
+	// Fieldless variants
+	assert_eq!(Foo::B.const_name().name, "other");
+	// Variants with data
+	assert_eq!(Foo::C(42).const_name().name, "other");
+	assert_eq!(Foo::D(42, true).const_name().name, "other");
+}
+
+#[test]
+fn wildcard_static() {
+	let name = |variant: Foo| EnumProp::<StaticName>::property(&variant).name;
+
+	assert_eq!(name(Foo::A), "A or C(0)");
+	assert_eq!(name(Foo::C(0)), "A or C(0)");
+	// Fieldless variants
+	assert_eq!(name(Foo::B), "other");
+	// Variants with data
+	assert_eq!(name(Foo::C(42)), "other");
+	assert_eq!(name(Foo::D(42, true)), "other");
+}
+
+struct ExhaustiveName {
+	name: &'static str,
+}
+
+// A wildcard on an enum where all variants are listed, e.g. as it might be the
+// case with a `non_exhaustive` enum, is fine
+props! {
+	impl Foo : fn exhaustive_name as const ExhaustiveName {
+		Self::A => {
+			name: "A",
+		}
+		Self::B => {
+			name: "B",
+		}
+		Self::C(_) => {
+			name: "C",
+		}
+		Self::D(..) => {
+			name: "D",
+		}
+		_ => {
+			name: "unreachable",
+		}
+	}
+}
+
+#[test]
+fn wildcard_unreachable() {
+	assert_eq!(Foo::B.exhaustive_name().name, "B");
+	assert_eq!(Foo::D(42, true).exhaustive_name().name, "D");
+}
+
+#[cfg(lazy_backend)]
+mod lazy {
+	use super::Foo;
+	use crate::test_util::compute;
+
+	struct Label {
+		label: u32,
+	}
+
+	props! {
+		impl Foo : fn lazy_label as lazy Label {
+			Self::A => {
+				label: compute(2),
+			}
+			_ => {
+				label: compute(3),
+			}
+		}
+	}
+
+	#[test]
+	fn wildcard_lazy() {
+		assert_eq!(Foo::A.lazy_label().label, 4);
+		assert_eq!(Foo::B.lazy_label().label, 9);
+		assert_eq!(Foo::C(42).lazy_label().label, 9);
+
+		// All variants falling through share the same lazy property
+		let b: *const Label = Foo::B.lazy_label();
+		let d: *const Label = Foo::D(42, true).lazy_label();
+		assert_eq!(b, d);
+	}
+}
+
+#[cfg(feature = "std")]
+mod thread_lazy {
+	use core::cell::Cell;
+
+	use super::Foo;
+
+	struct Count {
+		count: Cell<u32>,
+	}
+
+	props! {
+		impl Foo : fn count as thread_lazy Count {
+			Self::A => {
+				count: Cell::new(0),
+			}
+			default => {
+				count: Cell::new(100),
+			}
+		}
+	}
+
+	#[test]
+	fn wildcard_thread_lazy() {
+		Foo::A.count(|c| c.count.set(c.count.get() + 1));
+		Foo::B.count(|c| c.count.set(c.count.get() + 1));
+		Foo::C(42).count(|c| c.count.set(c.count.get() + 1));
+
+		assert_eq!(Foo::A.count(|c| c.count.get()), 1);
+		assert_eq!(Foo::D(42, true).count(|c| c.count.get()), 102);
+	}
+}
+
+
+
+// The wildcard branch must be the last one
+
+/// ```compile_fail
+/// use enumeraties::props;
+///
+/// struct Props {
+///     foo: u8,
+/// }
+///
+/// enum Foo {
+///     A,
+///     B,
+/// }
+///
+/// props! {
+///     impl Deref for Foo as const Props {
+///         _ => {
+///             foo: 42,
+///         }
+///         Self::A => {
+///             foo: 43,
+///         }
+///     }
+/// }
+/// ```
+///
+/// ```compile_fail
+/// use enumeraties::props;
+///
+/// struct Props {
+///     foo: u8,
+/// }
+///
+/// enum Foo {
+///     A,
+///     B,
+/// }
+///
+/// props! {
+///     impl Deref for Foo as const Props {
+///         default => {
+///             foo: 42,
+///         }
+///         Self::A => {
+///             foo: 43,
+///         }
+///     }
+/// }
+/// ```
+struct WildcardLast;
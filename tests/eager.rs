@@ -0,0 +1,82 @@
+// This integration test checks the initialization of eager properties, it is
+// a separate crate, because the constructors of eager properties can not be
+// used within crates that forbid `unsafe_code`.
+#![cfg(feature = "eager")]
+
+use core::sync::atomic::AtomicUsize;
+use core::sync::atomic::Ordering;
+
+use enumeraties::props;
+use enumeraties::EnumProp;
+
+
+// Counts how often the eager properties got initialized
+static INIT_COUNT: AtomicUsize = AtomicUsize::new(0);
+
+struct Counted {
+	value: usize,
+}
+
+enum Foo {
+	A,
+	B,
+}
+
+props! {
+	impl EnumProp for Foo as eager Counted {
+		Self::A => {
+			value: INIT_COUNT.fetch_add(1, Ordering::SeqCst) + 100,
+		}
+		Self::B => {
+			value: INIT_COUNT.fetch_add(1, Ordering::SeqCst) + 200,
+		}
+	}
+}
+
+#[test]
+fn eager_initialized_once() {
+	let a: &'static Counted = Foo::A.property();
+	let b: &'static Counted = Foo::B.property();
+	assert!(core::ptr::eq(a, Foo::A.property()));
+	assert!(core::ptr::eq(b, Foo::B.property()));
+	assert_eq!(a.value / 100, 1);
+	assert_eq!(b.value / 100, 2);
+	assert_eq!(INIT_COUNT.load(Ordering::SeqCst), 2);
+}
+
+// On the targets supporting it, the props are initialized before `main`, i.e.
+// even before any test starts
+#[cfg(any(target_os = "linux", target_os = "macos", windows))]
+#[test]
+fn eager_initialized_before_main() {
+	assert_eq!(INIT_COUNT.load(Ordering::SeqCst), 2);
+}
+
+// The variants of a branch share a single property instance
+struct Named {
+	name: &'static str,
+}
+
+enum Bar {
+	A,
+	B,
+	C,
+}
+
+props! {
+	impl EnumProp for Bar as eager Named {
+		Self::A => {
+			name: "a",
+		}
+		Self::B | Self::C => {
+			name: "either",
+		}
+	}
+}
+
+#[test]
+fn eager_or_pattern() {
+	enumeraties::assert_shared_props!(Named: Bar::B, Bar::C);
+	enumeraties::assert_distinct_props!(Named: Bar::A, Bar::B);
+	assert_eq!(EnumProp::<Named>::property(&Bar::C).name, "either");
+}
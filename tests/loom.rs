@@ -0,0 +1,75 @@
+// This integration test checks the guarantees of lazy properties under
+// concurrent access, model checked via `loom`. The backend is replaced by a
+// loom mutex standing in for the once-cell, thus, this checks the tracking of
+// the initialization around the backends, not the backends themselves:
+//
+// RUSTFLAGS="--cfg loom" cargo test --release --test loom
+#![cfg(loom)]
+#![cfg(feature = "std")]
+
+use enumeraties::props;
+use enumeraties::EnumProp;
+use enumeraties::LazyEnumProp;
+use loom::sync::atomic::AtomicUsize;
+use loom::sync::atomic::Ordering;
+
+loom::lazy_static! {
+	// Counts the initializations, reset for each execution
+	static ref INIT_COUNT: AtomicUsize = AtomicUsize::new(0);
+}
+
+struct Prop {
+	value: usize,
+}
+
+#[derive(Copy, Clone)]
+enum Foo {
+	A,
+	B,
+}
+
+props! {
+	impl EnumProp for Foo as lazy Prop {
+		Self::A => {
+			value: INIT_COUNT.fetch_add(1, Ordering::SeqCst),
+		}
+		Self::B => {
+			value: INIT_COUNT.fetch_add(1, Ordering::SeqCst) + 10,
+		}
+	}
+}
+
+fn address(variant: Foo) -> usize {
+	let prop: &'static Prop = variant.property();
+	prop as *const Prop as usize
+}
+
+#[test]
+fn racing_first_access_initializes_once() {
+	loom::model(|| {
+		let other = loom::thread::spawn(|| address(Foo::A));
+		// The state is reset for each execution
+		assert!(Foo::B.try_property().is_none());
+		let this = address(Foo::A);
+
+		// Both threads observe the same value
+		assert_eq!(this, other.join().unwrap());
+		assert_eq!(INIT_COUNT.load(Ordering::SeqCst), 1);
+		assert_eq!(Foo::A.property().value, 0);
+	});
+}
+
+#[test]
+fn racing_first_access_of_different_variants() {
+	loom::model(|| {
+		let other = loom::thread::spawn(|| address(Foo::B));
+		let this = address(Foo::A);
+
+		// Each variant is initialized once, independently
+		assert_ne!(this, other.join().unwrap());
+		assert!(Foo::A.is_property_initialized());
+		assert!(Foo::B.is_property_initialized());
+		assert_eq!(INIT_COUNT.load(Ordering::SeqCst), 2);
+		assert_eq!(address(Foo::A), this);
+	});
+}
@@ -0,0 +1,41 @@
+// This integration test is a `no_std` crate, proving that the expansion of the
+// `props` macro, including `lazy` properties, does not require std.
+#![cfg(feature = "no_std-lazy")]
+#![no_std]
+
+use enumeraties::props;
+use enumeraties::EnumProp;
+
+struct Prop {
+	name: &'static str,
+	len: usize,
+}
+
+#[derive(Copy, Clone)]
+enum Foo {
+	A,
+	B,
+}
+
+props! {
+	impl Deref for Foo as lazy Prop {
+		Self::A => {
+			name: "A",
+			len: "A".len(),
+		}
+		Self::B => {
+			name: "Bb",
+			len: "Bb".len(),
+		}
+	}
+}
+
+#[test]
+fn no_std_lazy_access() {
+	assert_eq!(Foo::A.name, "A");
+	assert_eq!(Foo::B.len, 2);
+	assert!(core::ptr::eq(
+		EnumProp::<Prop>::property(&Foo::B),
+		EnumProp::<Prop>::property(&Foo::B)
+	));
+}
@@ -0,0 +1,97 @@
+// This integration test checks the `tracing` span around lazy initialization
+#![cfg(feature = "tracing")]
+#![cfg(lazy_backend)]
+
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+use std::sync::Mutex;
+
+use enumeraties::props;
+use tracing::field::Field;
+use tracing::field::Visit;
+use tracing::span;
+use tracing::Event;
+use tracing::Metadata;
+use tracing::Subscriber;
+
+struct Prop {
+	value: u32,
+}
+
+#[derive(Copy, Clone)]
+enum Foo {
+	A,
+	B,
+}
+
+props! {
+	impl Deref for Foo as lazy Prop {
+		Self::A => {
+			value: 1,
+		}
+		Self::B => {
+			value: 2,
+		}
+	}
+}
+
+
+// A subscriber recording the `branch` field of the new spans
+#[derive(Default)]
+struct Recorder {
+	next_id: AtomicU64,
+	branches: Mutex<Vec<String>>,
+}
+
+struct BranchVisitor<'a>(&'a mut Option<String>);
+
+impl Visit for BranchVisitor<'_> {
+	fn record_str(&mut self, field: &Field, value: &str) {
+		if field.name() == "branch" {
+			*self.0 = Some(value.to_string());
+		}
+	}
+
+	fn record_debug(&mut self, _field: &Field, _value: &dyn std::fmt::Debug) {}
+}
+
+impl Subscriber for &'static Recorder {
+	fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+		true
+	}
+
+	fn new_span(&self, span: &span::Attributes<'_>) -> span::Id {
+		assert_eq!(span.metadata().name(), "enum_prop_init");
+
+		let mut branch = None;
+		span.record(&mut BranchVisitor(&mut branch));
+		self.branches.lock().unwrap().push(branch.unwrap());
+
+		span::Id::from_u64(self.next_id.fetch_add(1, Ordering::SeqCst) + 1)
+	}
+
+	fn record(&self, _span: &span::Id, _values: &span::Record<'_>) {}
+
+	fn record_follows_from(&self, _span: &span::Id, _follows: &span::Id) {}
+
+	fn event(&self, _event: &Event<'_>) {}
+
+	fn enter(&self, _span: &span::Id) {}
+
+	fn exit(&self, _span: &span::Id) {}
+}
+
+#[test]
+fn tracing_span_on_first_access() {
+	let recorder: &'static Recorder = Box::leak(Box::default());
+
+	tracing::subscriber::with_default(recorder, || {
+		assert_eq!(Foo::A.value, 1);
+		assert_eq!(Foo::A.value, 1);
+		assert_eq!(Foo::B.value, 2);
+		assert_eq!(Foo::A.value, 1);
+	});
+
+	// Exactly one span per variant
+	assert_eq!(*recorder.branches.lock().unwrap(), ["Self::A", "Self::B"]);
+}
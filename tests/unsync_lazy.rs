@@ -0,0 +1,52 @@
+// This integration test checks the `unsync-lazy` backend, which is intended
+// for single-threaded targets such as `wasm32-unknown-unknown`.
+#![cfg(feature = "unsync-lazy")]
+
+use enumeraties::props;
+use enumeraties::EnumProp;
+
+pub struct Prop {
+	name: &'static str,
+}
+
+#[derive(Copy, Clone)]
+pub enum Foo {
+	A,
+	B,
+}
+
+props! {
+	impl Deref for Foo as lazy Prop {
+		Self::A => {
+			name: "A",
+		}
+		Self::B => {
+			name: "B",
+		}
+	}
+}
+
+// A compile check for the wasm target, this test can not run there (without
+// `wasm-bindgen-test`), but it ensures that the expansion builds
+#[cfg(target_arch = "wasm32")]
+pub fn wasm_access(variant: Foo) -> &'static str {
+	EnumProp::<Prop>::property(&variant).name
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[test]
+fn unsync_lazy_single_threaded() {
+	// Everything is on the same thread, thus, this works as usual
+	assert_eq!(Foo::A.name, "A");
+	assert!(core::ptr::eq(
+		EnumProp::<Prop>::property(&Foo::A),
+		EnumProp::<Prop>::property(&Foo::A)
+	));
+
+	// Accessing it from another thread is not supported and panics
+	let other = std::thread::spawn(|| Foo::A.name).join();
+	assert!(other.is_err());
+
+	// Other variants are still independent
+	assert_eq!(std::thread::spawn(|| Foo::B.name).join().unwrap(), "B");
+}